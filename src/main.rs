@@ -1,22 +1,65 @@
 use todo_manager::controller::ApplicationController;
-use todo_manager::{run_tui, App, TodoListStorage};
+use todo_manager::persistence::migration::SCHEMA_VERSION;
+use todo_manager::persistence::recovery::salvage_tasks;
+use todo_manager::persistence::{Storage, StorageKind};
+use todo_manager::{run_tui, App, TodoService};
 use std::env;
+use std::fs;
+use std::path::PathBuf;
 
 fn main() {
     // Check for --tui flag
     let args: Vec<String> = env::args().collect();
     let use_tui = args.contains(&"--tui".to_string());
 
-    if use_tui {
-        // Run TUI mode
-        let storage = TodoListStorage::new("tasks.json");
-        let todo_list = storage.load().unwrap_or_default();
-        let app = App::new(todo_list);
-        
+    // The projects file, with the backend chosen by its extension unless --backend is given
+    let storage_path = flag_value(&args, "--storage")
+        .map(PathBuf::from)
+        .unwrap_or_else(ApplicationController::default_storage_path);
+    let backend = match flag_value(&args, "--backend") {
+        Some(name) => match StorageKind::parse(name) {
+            Some(kind) => Some(kind),
+            None => {
+                let names: Vec<&str> = StorageKind::ALL.iter().map(StorageKind::as_str).collect();
+                eprintln!("Unknown backend '{}'. Use one of: {}", name, names.join(", "));
+                return;
+            }
+        },
+        None => None,
+    };
+    let kind_for = |path: &PathBuf| backend.unwrap_or_else(|| StorageKind::from_path(path));
+    let kind = kind_for(&storage_path);
+
+    if args.iter().any(|arg| arg == "--recover") {
+        // Show what can be salvaged from a damaged file, without writing anything
+        let path = flag_value(&args, "--recover")
+            .map(PathBuf::from)
+            .unwrap_or(storage_path);
+        recover(path);
+    } else if args.iter().any(|arg| arg == "--migrate-only") {
+        // Upgrade the file to the current schema and exit
+        let path = flag_value(&args, "--migrate-only")
+            .map(PathBuf::from)
+            .unwrap_or(storage_path);
+        migrate(kind_for(&path).open(&path));
+    } else if use_tui {
+        // Run TUI mode on the same projects file as the command line
+        let (service, quarantine) =
+            match TodoService::load_or_quarantine(kind.open(&storage_path)) {
+                Ok(opened) => opened,
+                Err(e) => {
+                    // Don't start with empty projects that would overwrite the file on exit
+                    eprintln!("Failed to load tasks: {}", e);
+                    return;
+                }
+            };
+        let mut app = App::new(service);
+        app.status_message = quarantine.map(|quarantine| quarantine.warning());
+
         match run_tui(app) {
             Ok(final_app) => {
-                // Save the todo list before exiting
-                if let Err(e) = storage.save(&final_app.todo_list) {
+                // Save the projects before exiting
+                if let Err(e) = final_app.service.save() {
                     eprintln!("Failed to save tasks: {}", e);
                 }
             }
@@ -26,8 +69,62 @@ fn main() {
         }
     } else {
         // Run normal CLI mode
-        let mut controller = ApplicationController::with_stdio_default();
+        let mut controller = ApplicationController::with_stdio_storage(kind.open(&storage_path));
         controller.run();
     }
 }
 
+/// Returns the value following a flag, e.g. the path after `--storage`.
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    let index = args.iter().position(|arg| arg == flag)?;
+    args.get(index + 1)
+        .map(String::as_str)
+        .filter(|value| !value.starts_with("--"))
+}
+
+/// Rewrites stored projects in the current schema version.
+fn migrate(storage: Box<dyn Storage>) {
+    let location = storage.location();
+    match storage.migrate() {
+        Ok(found) if found == SCHEMA_VERSION => println!(
+            "{} already uses schema version {}",
+            location, SCHEMA_VERSION
+        ),
+        Ok(found) => println!(
+            "Migrated {} from schema version {} to {}",
+            location, found, SCHEMA_VERSION
+        ),
+        Err(e) => eprintln!("Failed to migrate {}: {}", location, e),
+    }
+}
+
+/// Prints the tasks that can be salvaged from a projects file.
+fn recover(path: PathBuf) {
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", path.display(), e);
+            return;
+        }
+    };
+
+    let salvage = salvage_tasks(&contents);
+    println!(
+        "Salvaged {} task(s) from {}",
+        salvage.task_count(),
+        path.display()
+    );
+    for (project, tasks) in &salvage.projects {
+        println!();
+        println!("{}:", project.as_deref().unwrap_or("unknown project"));
+        for task in tasks {
+            println!("  {}. {} [{:?}]", task.id, task.description, task.status);
+        }
+    }
+    if salvage.skipped > 0 {
+        println!();
+        println!("{} task(s) could not be read", salvage.skipped);
+    }
+    println!();
+    println!("Nothing was written.");
+}
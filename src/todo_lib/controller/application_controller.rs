@@ -1,10 +1,14 @@
+use crate::controller::change_preview::{ChangePreview, DEFAULT_CONFIRMATION_THRESHOLD};
 use crate::controller::project_command::ProjectManager;
+use crate::controller::undo_history::{describe_changes, UndoHistory};
 use crate::controller::CommandControllerRegistry;
 use crate::models::command_controller_result::{
     CommandControllerResult, CommandControllerResultAction,
 };
 use crate::models::loop_control::LoopControl;
-use crate::persistence::TodoListStorage;
+use crate::models::todo_error::TodoError;
+use crate::persistence::{open_storage, Storage};
+use crate::service::TodoService;
 use crate::ui::{InputStream, OutputManager};
 use crate::{FileInputStream, FileOutputWriter, OutputWriter};
 use std::cell::RefCell;
@@ -33,11 +37,16 @@ use std::rc::Rc;
 /// controller.run();
 /// ```
 pub struct ApplicationController<I: InputStream, O: OutputWriter> {
-    project_manager: Rc<RefCell<ProjectManager>>,
+    service: Rc<RefCell<TodoService>>,
     input_stream: Rc<RefCell<I>>,
+    output_writer: Rc<RefCell<O>>,
     output_manager: OutputManager<O>,
     command_controller_registry: CommandControllerRegistry<O>,
-    storage: TodoListStorage,
+    history: UndoHistory,
+    /// Commands removing or changing more existing tasks than this must be confirmed
+    confirmation_threshold: usize,
+    /// Problem with the projects file, shown after the welcome message
+    startup_warning: Option<String>,
 }
 
 impl<I: InputStream, O: OutputWriter> ApplicationController<I, O> {
@@ -47,7 +56,8 @@ impl<I: InputStream, O: OutputWriter> ApplicationController<I, O> {
     ///
     /// * `input_stream` - The input stream for reading user commands
     /// * `output_writer` - The output writer for displaying results
-    /// * `storage_path` - The file path where tasks will be saved/loaded
+    /// * `storage_path` - The file path where tasks will be saved/loaded; the
+    ///   backend is chosen by its extension (see `StorageKind::from_path`)
     ///
     /// # Examples
     ///
@@ -71,29 +81,58 @@ impl<I: InputStream, O: OutputWriter> ApplicationController<I, O> {
         output_writer: Rc<RefCell<O>>,
         storage_path: P,
     ) -> Self {
-        let storage = TodoListStorage::new(storage_path);
+        Self::with_storage(input_stream, output_writer, open_storage(storage_path))
+    }
 
+    /// Creates a new manager that keeps the projects in `storage`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_stream` - The input stream for reading user commands
+    /// * `output_writer` - The output writer for displaying results
+    /// * `storage` - The backend the projects are loaded from and saved to
+    pub fn with_storage(
+        input_stream: Rc<RefCell<I>>,
+        output_writer: Rc<RefCell<O>>,
+        storage: Box<dyn Storage>,
+    ) -> Self {
         // During tests we prefer a fresh in-memory ProjectManager to avoid interfering with
         // local developer/state files. When not testing, try to load persisted projects.
-        let project_manager_inner = if cfg!(test) {
-            ProjectManager::new()
+        let mut startup_warning = None;
+        let service = if cfg!(test) {
+            TodoService::with_storage(ProjectManager::new(), storage)
         } else {
-            storage
-                .load_projects()
-                .unwrap_or_else(|_| ProjectManager::new())
+            let location = storage.location();
+            match TodoService::load_or_quarantine(storage) {
+                Ok((service, quarantine)) => {
+                    startup_warning = quarantine.map(|quarantine| quarantine.warning());
+                    service
+                }
+                Err(err) => {
+                    // Never save over a file that could not be loaded
+                    startup_warning = Some(format!(
+                        "Could not load {}: {} Changes in this session will not be saved.",
+                        location, err
+                    ));
+                    TodoService::new(ProjectManager::new())
+                }
+            }
         };
 
-        let project_manager = Rc::new(RefCell::new(project_manager_inner));
+        let service = Rc::new(RefCell::new(service));
 
         Self {
-            project_manager: Rc::clone(&project_manager),
+            service: Rc::clone(&service),
             input_stream,
             output_manager: OutputManager::new(Rc::clone(&output_writer)),
             command_controller_registry: CommandControllerRegistry::new(
-                Rc::clone(&project_manager),
+                Rc::clone(&service),
                 Rc::clone(&output_writer),
             ),
-            storage,
+            output_writer,
+            history: UndoHistory::default(),
+            confirmation_threshold: DEFAULT_CONFIRMATION_THRESHOLD,
+            startup_warning,
         }
     }
 }
@@ -115,13 +154,20 @@ impl ApplicationController<FileInputStream<std::io::Stdin>, FileOutputWriter<std
     }
 
     pub fn with_stdio_default() -> Self {
+        Self::with_stdio(Self::default_storage_path())
+    }
+
+    /// Creates a new manager with stdio-based UI components that keeps the
+    /// projects in `storage`.
+    pub fn with_stdio_storage(storage: Box<dyn Storage>) -> Self {
         let input_stream = Rc::new(RefCell::new(FileInputStream::new(std::io::stdin())));
         let output_writer = Rc::new(RefCell::new(FileOutputWriter::new(std::io::stdout())));
-        Self::new(
-            input_stream,
-            output_writer,
-            std::env::temp_dir().join("tasks.json"),
-        )
+        Self::with_storage(input_stream, output_writer, storage)
+    }
+
+    /// Returns the path where the interactive application stores its projects.
+    pub fn default_storage_path() -> std::path::PathBuf {
+        std::env::temp_dir().join("tasks.json")
     }
 }
 
@@ -151,6 +197,9 @@ impl<I: InputStream, O: OutputWriter> ApplicationController<I, O> {
     /// ```
     pub fn run(&mut self) {
         self.output_manager.show_welcome();
+        if let Some(warning) = self.startup_warning.take() {
+            self.output_manager.show_error(&warning);
+        }
 
         loop {
             self.output_manager.print_prompt();
@@ -164,6 +213,12 @@ impl<I: InputStream, O: OutputWriter> ApplicationController<I, O> {
 
     /// Handles user input by trying each specialized controller in turn.
     ///
+    /// A trailing `--dry-run` shows what the command would change without
+    /// keeping the changes; commands that write files describe what they would
+    /// write instead (see `CommandController::try_preview`). Commands that remove or change more existing tasks
+    /// than the confirmation threshold ask before the changes are kept, unless
+    /// `--yes` is given.
+    ///
     /// # Arguments
     ///
     /// * `input` - The raw input string from the user
@@ -179,19 +234,79 @@ impl<I: InputStream, O: OutputWriter> ApplicationController<I, O> {
             return LoopControl::Continue;
         }
 
-        if let Some(result) = self.command_controller_registry.try_execute(trimmed) {
-            match result {
-                Ok(result) => return self.handle_command_controller_result(result),
-                Err(err) => {
-                    self.output_manager.show_error(&err.message());
-                    return LoopControl::Continue;
+        let (command, dry_run, assume_yes) = split_command_flags(trimmed);
+        let snapshot = self.service.borrow().project_manager().clone();
+
+        // Hold back the command's output until it is known whether its changes are kept
+        let guarded = dry_run || !assume_yes;
+        if guarded {
+            self.output_writer.borrow_mut().begin_buffering();
+        }
+        let result = if dry_run {
+            self.command_controller_registry.try_preview(command)
+        } else {
+            self.command_controller_registry.try_execute(command)
+        };
+        let held_output = if guarded {
+            self.output_writer.borrow_mut().end_buffering()
+        } else {
+            Vec::new()
+        };
+
+        let result = match result {
+            Some(Ok(result)) => result,
+            Some(Err(err)) => {
+                self.write_lines(&held_output);
+                self.output_manager.show_error(&err.message());
+                return LoopControl::Continue;
+            }
+            None => {
+                self.write_lines(&held_output);
+                self.output_manager.handle_unknown_command(command);
+                return LoopControl::Continue;
+            }
+        };
+
+        if guarded {
+            let preview =
+                ChangePreview::between(&snapshot, self.service.borrow().project_manager());
+            if dry_run {
+                self.service.borrow_mut().replace_project_manager(snapshot);
+                if result.has_action(&CommandControllerResultAction::PreviewShown) {
+                    self.write_lines(&held_output);
+                } else {
+                    self.output_manager.show_dry_run(command, &preview);
                 }
+                return LoopControl::Continue;
+            }
+            if preview.affected_count() > self.confirmation_threshold
+                && !self.confirm(command, &preview)
+            {
+                self.service.borrow_mut().replace_project_manager(snapshot);
+                self.output_manager.show_command_cancelled(command);
+                return LoopControl::Continue;
             }
+            self.write_lines(&held_output);
         }
 
-        // Unknown command
-        self.output_manager.handle_unknown_command(trimmed);
-        LoopControl::Continue
+        self.record_history(command, snapshot, &result);
+        self.handle_command_controller_result(result)
+    }
+
+    /// Shows the changes of a command and reads the answer from the input stream.
+    ///
+    /// Anything but `y` or `yes` (including the end of the input) declines.
+    fn confirm(&mut self, command: &str, preview: &ChangePreview) -> bool {
+        self.output_manager
+            .show_confirmation_prompt(command, preview);
+        let answer = self.input_stream.borrow_mut().get_next_input();
+        matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+    }
+
+    fn write_lines(&mut self, lines: &[String]) {
+        for line in lines {
+            self.output_writer.borrow_mut().write_line(line);
+        }
     }
 
     fn handle_command_controller_result(&mut self, result: CommandControllerResult) -> LoopControl {
@@ -204,25 +319,95 @@ impl<I: InputStream, O: OutputWriter> ApplicationController<I, O> {
                 CommandControllerResultAction::DisableDebugMode => {
                     self.command_controller_registry.disable_debug();
                 }
-                CommandControllerResultAction::SaveTodoList => {
-                    if let Err(e) = self.save_tasks_to_disk() {
-                        self.output_manager
-                            .show_error(&format!("failed to save tasks: {}", e));
-                    }
-                }
+                CommandControllerResultAction::SaveTodoList => self.save_and_report(),
+                CommandControllerResultAction::UndoLastChange => self.undo_last_change(),
+                CommandControllerResultAction::RedoLastChange => self.redo_last_change(),
+                // Only used for dry runs, which are handled before
+                CommandControllerResultAction::PreviewShown => {}
             }
         }
         LoopControl::Continue
     }
 
-    fn save_tasks_to_disk(&self) -> Result<(), String> {
-        self.storage.save_projects(&self.project_manager.borrow())
+    /// Records the state before a command in the undo history if the command changed it.
+    fn record_history(
+        &mut self,
+        command: &str,
+        snapshot: ProjectManager,
+        result: &CommandControllerResult,
+    ) {
+        if result.has_action(&CommandControllerResultAction::SaveTodoList)
+            && *self.service.borrow().project_manager() != snapshot
+        {
+            self.history.record(command, snapshot);
+        }
+    }
+
+    /// Restores the state before the most recent change.
+    fn undo_last_change(&mut self) {
+        let step = self.history.undo(self.service.borrow().project_manager());
+        match step {
+            Some((command, snapshot)) => self.restore_snapshot("Undid", &command, snapshot),
+            None => self.output_manager.show_error("Nothing to undo."),
+        }
+    }
+
+    /// Reapplies the most recently undone change.
+    fn redo_last_change(&mut self) {
+        let step = self.history.redo(self.service.borrow().project_manager());
+        match step {
+            Some((command, snapshot)) => self.restore_snapshot("Redid", &command, snapshot),
+            None => self.output_manager.show_error("Nothing to redo."),
+        }
     }
+
+    fn restore_snapshot(&mut self, verb: &str, command: &str, snapshot: ProjectManager) {
+        let changes = describe_changes(self.service.borrow().project_manager(), &snapshot);
+        self.service.borrow_mut().replace_project_manager(snapshot);
+        self.output_manager
+            .show_history_step(verb, command, &changes);
+        self.save_and_report();
+    }
+
+    fn save_and_report(&mut self) {
+        if let Err(e) = self.save_tasks_to_disk() {
+            self.output_manager
+                .show_error(&format!("failed to save tasks: {}", e));
+        }
+    }
+
+    fn save_tasks_to_disk(&self) -> Result<(), TodoError> {
+        self.service.borrow().save()
+    }
+}
+
+/// Splits trailing `--dry-run` and `--yes` flags off a command.
+///
+/// # Returns
+///
+/// The command without the flags, whether `--dry-run` was given and whether `--yes` was given
+fn split_command_flags(input: &str) -> (&str, bool, bool) {
+    let mut command = input;
+    let mut dry_run = false;
+    let mut assume_yes = false;
+
+    while let Some((rest, last)) = command.rsplit_once(char::is_whitespace) {
+        match last {
+            "--dry-run" => dry_run = true,
+            "--yes" => assume_yes = true,
+            _ => break,
+        }
+        command = rest.trim_end();
+    }
+
+    (command, dry_run, assume_yes)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::priority::Priority;
+    use crate::models::recurrence::Recurrence;
     use crate::ui::input::FileInputStream;
     use crate::ui::output::FileOutputWriter;
     use std::env;
@@ -243,8 +428,9 @@ mod tests {
             storage_path,
         );
         assert!(manager
-            .project_manager
+            .service
             .borrow()
+            .project_manager()
             .get_current_todo_list()
             .is_empty());
     }
@@ -263,8 +449,9 @@ mod tests {
 
         assert_eq!(
             manager
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_tasks()
                 .len(),
@@ -272,8 +459,9 @@ mod tests {
         );
         assert_eq!(
             manager
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_tasks()[0]
                 .description,
@@ -298,8 +486,9 @@ mod tests {
 
         assert_eq!(
             controller
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_tasks()
                 .len(),
@@ -320,8 +509,9 @@ mod tests {
 
         controller.handle_input("add Task to remove");
         let task_id = controller
-            .project_manager
+            .service
             .borrow()
+            .project_manager()
             .get_current_todo_list()
             .get_tasks()[0]
             .id;
@@ -329,8 +519,9 @@ mod tests {
         controller.handle_input(&format!("remove {}", task_id));
 
         assert!(controller
-            .project_manager
+            .service
             .borrow()
+            .project_manager()
             .get_current_todo_list()
             .is_empty());
     }
@@ -351,8 +542,9 @@ mod tests {
 
         assert_eq!(
             controller
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_tasks()
                 .len(),
@@ -373,8 +565,9 @@ mod tests {
 
         controller.handle_input("add Task to complete");
         let task_id = controller
-            .project_manager
+            .service
             .borrow()
+            .project_manager()
             .get_current_todo_list()
             .get_tasks()[0]
             .id;
@@ -382,15 +575,17 @@ mod tests {
         controller.handle_input(&format!("complete {}", task_id));
 
         assert!(controller
-            .project_manager
+            .service
             .borrow()
+            .project_manager()
             .get_current_todo_list()
             .get_tasks()[0]
             .is_completed());
         assert_eq!(
             controller
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_completed_tasks()
                 .len(),
@@ -413,8 +608,9 @@ mod tests {
         controller.handle_input("complete 999");
 
         assert!(!controller
-            .project_manager
+            .service
             .borrow()
+            .project_manager()
             .get_current_todo_list()
             .get_tasks()[0]
             .is_completed());
@@ -433,31 +629,35 @@ mod tests {
 
         controller.handle_input("add Task to uncomplete");
         let task_id = controller
-            .project_manager
+            .service
             .borrow()
+            .project_manager()
             .get_current_todo_list()
             .get_tasks()[0]
             .id;
 
         controller.handle_input(&format!("complete {}", task_id));
         assert!(controller
-            .project_manager
+            .service
             .borrow()
+            .project_manager()
             .get_current_todo_list()
             .get_tasks()[0]
             .is_completed());
 
         controller.handle_input(&format!("uncomplete {}", task_id));
         assert!(!controller
-            .project_manager
+            .service
             .borrow()
+            .project_manager()
             .get_current_todo_list()
             .get_tasks()[0]
             .is_completed());
         assert_eq!(
             controller
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_pending_tasks()
                 .len(),
@@ -480,8 +680,9 @@ mod tests {
         controller.handle_input("uncomplete 999");
 
         assert!(!controller
-            .project_manager
+            .service
             .borrow()
+            .project_manager()
             .get_current_todo_list()
             .get_tasks()[0]
             .is_completed());
@@ -500,31 +701,35 @@ mod tests {
 
         controller.handle_input("add Task to toggle");
         let task_id = controller
-            .project_manager
+            .service
             .borrow()
+            .project_manager()
             .get_current_todo_list()
             .get_tasks()[0]
             .id;
 
         assert!(!controller
-            .project_manager
+            .service
             .borrow()
+            .project_manager()
             .get_current_todo_list()
             .get_tasks()[0]
             .is_completed());
 
         controller.handle_input(&format!("toggle {}", task_id));
         assert!(controller
-            .project_manager
+            .service
             .borrow()
+            .project_manager()
             .get_current_todo_list()
             .get_tasks()[0]
             .is_completed());
 
         controller.handle_input(&format!("toggle {}", task_id));
         assert!(!controller
-            .project_manager
+            .service
             .borrow()
+            .project_manager()
             .get_current_todo_list()
             .get_tasks()[0]
             .is_completed());
@@ -543,8 +748,9 @@ mod tests {
 
         controller.handle_input("add Test task");
         let initial_status = controller
-            .project_manager
+            .service
             .borrow()
+            .project_manager()
             .get_current_todo_list()
             .get_tasks()[0]
             .is_completed();
@@ -553,8 +759,9 @@ mod tests {
 
         assert_eq!(
             controller
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_tasks()[0]
                 .is_completed(),
@@ -581,8 +788,9 @@ mod tests {
 
         assert_eq!(
             controller
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_tasks()
                 .len(),
@@ -609,8 +817,9 @@ mod tests {
 
         assert_eq!(
             controller
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_completed_tasks()
                 .len(),
@@ -637,8 +846,9 @@ mod tests {
 
         assert_eq!(
             controller
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_pending_tasks()
                 .len(),
@@ -694,8 +904,9 @@ mod tests {
         assert_eq!(control, LoopControl::Continue);
         assert_eq!(
             controller
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_tasks()
                 .len(),
@@ -753,8 +964,9 @@ mod tests {
 
         assert_eq!(
             controller
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_tasks()
                 .len(),
@@ -767,8 +979,9 @@ mod tests {
 
         assert_eq!(
             controller
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_completed_tasks()
                 .len(),
@@ -776,8 +989,9 @@ mod tests {
         );
         assert_eq!(
             controller
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_pending_tasks()
                 .len(),
@@ -789,8 +1003,9 @@ mod tests {
 
         assert_eq!(
             controller
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_tasks()
                 .len(),
@@ -802,8 +1017,9 @@ mod tests {
 
         assert_eq!(
             controller
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_completed_tasks()
                 .len(),
@@ -811,8 +1027,9 @@ mod tests {
         );
         assert_eq!(
             controller
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_pending_tasks()
                 .len(),
@@ -875,4 +1092,400 @@ mod tests {
 
         // Should handle gracefully with empty list
     }
+
+    fn task_count<I: InputStream, O: OutputWriter>(
+        controller: &ApplicationController<I, O>,
+    ) -> usize {
+        controller
+            .service
+            .borrow()
+            .project_manager()
+            .get_current_todo_list()
+            .get_tasks()
+            .len()
+    }
+
+    #[test]
+    fn test_undo_and_redo_remove_all() {
+        let input_stream = FileInputStream::new(std::io::stdin());
+        let output_writer = FileOutputWriter::new(std::io::stdout());
+        let storage_path = get_test_storage_path("undo_remove_all");
+        let mut controller = ApplicationController::new(
+            Rc::new(RefCell::new(input_stream)),
+            Rc::new(RefCell::new(output_writer)),
+            storage_path,
+        );
+
+        controller.handle_input("add Task one");
+        controller.handle_input("add Task two");
+        controller.handle_input("remove all");
+        assert_eq!(task_count(&controller), 0);
+
+        controller.handle_input("undo");
+        assert_eq!(task_count(&controller), 2);
+
+        controller.handle_input("redo");
+        assert_eq!(task_count(&controller), 0);
+
+        controller.handle_input("undo");
+        controller.handle_input("undo");
+        assert_eq!(task_count(&controller), 1);
+    }
+
+    #[test]
+    fn test_undo_skips_commands_without_changes() {
+        let input_stream = FileInputStream::new(std::io::stdin());
+        let output_writer = FileOutputWriter::new(std::io::stdout());
+        let storage_path = get_test_storage_path("undo_skips_no_changes");
+        let mut controller = ApplicationController::new(
+            Rc::new(RefCell::new(input_stream)),
+            Rc::new(RefCell::new(output_writer)),
+            storage_path,
+        );
+
+        controller.handle_input("add Task one");
+        controller.handle_input("list");
+        controller.handle_input("complete 42");
+        assert_eq!(controller.history.undo_count(), 1);
+
+        controller.handle_input("undo");
+        assert_eq!(task_count(&controller), 0);
+
+        // Nothing left to undo
+        controller.handle_input("undo");
+        assert_eq!(task_count(&controller), 0);
+    }
+
+    #[test]
+    fn test_new_change_clears_redo() {
+        let input_stream = FileInputStream::new(std::io::stdin());
+        let output_writer = FileOutputWriter::new(std::io::stdout());
+        let storage_path = get_test_storage_path("undo_clears_redo");
+        let mut controller = ApplicationController::new(
+            Rc::new(RefCell::new(input_stream)),
+            Rc::new(RefCell::new(output_writer)),
+            storage_path,
+        );
+
+        controller.handle_input("add Task one");
+        controller.handle_input("undo");
+        assert_eq!(controller.history.redo_count(), 1);
+
+        controller.handle_input("add Task two");
+        assert_eq!(controller.history.redo_count(), 0);
+    }
+
+    #[test]
+    fn test_undo_delete_and_rename_project() {
+        let input_stream = FileInputStream::new(std::io::stdin());
+        let output_writer = FileOutputWriter::new(std::io::stdout());
+        let storage_path = get_test_storage_path("undo_projects");
+        let mut controller = ApplicationController::new(
+            Rc::new(RefCell::new(input_stream)),
+            Rc::new(RefCell::new(output_writer)),
+            storage_path,
+        );
+
+        controller.handle_input("new-project Work");
+        controller.handle_input("delete-project Work");
+        assert!(controller
+            .service
+            .borrow()
+            .project_manager()
+            .get_project("Work")
+            .is_none());
+
+        controller.handle_input("undo");
+        assert!(controller
+            .service
+            .borrow()
+            .project_manager()
+            .get_project("Work")
+            .is_some());
+
+        controller.handle_input("rename-project Work Job");
+        controller.handle_input("undo");
+        assert!(controller
+            .service
+            .borrow()
+            .project_manager()
+            .get_project("Work")
+            .is_some());
+        assert!(controller
+            .service
+            .borrow()
+            .project_manager()
+            .get_project("Job")
+            .is_none());
+    }
+
+    #[test]
+    fn test_remove_and_restore_from_trash() {
+        let input_stream = FileInputStream::new(std::io::stdin());
+        let output_writer = FileOutputWriter::new(std::io::stdout());
+        let storage_path = get_test_storage_path("trash_restore");
+        let mut controller = ApplicationController::new(
+            Rc::new(RefCell::new(input_stream)),
+            Rc::new(RefCell::new(output_writer)),
+            storage_path,
+        );
+
+        controller.handle_input("add Task one");
+        controller.handle_input("add Task two");
+        controller.handle_input("remove all");
+        controller.handle_input("trash");
+        controller.handle_input("restore 2");
+        assert_eq!(task_count(&controller), 1);
+        assert_eq!(
+            controller
+                .service
+                .borrow()
+                .project_manager()
+                .get_current_todo_list()
+                .get_tasks()[0]
+                .id,
+            2
+        );
+
+        controller.handle_input("new-project Work");
+        controller.handle_input("delete-project Work");
+        controller.handle_input("restore-project Work");
+        assert!(controller
+            .service
+            .borrow()
+            .project_manager()
+            .get_project("Work")
+            .is_some());
+
+        controller.handle_input("empty-trash");
+        assert!(controller
+            .service
+            .borrow()
+            .project_manager()
+            .get_current_todo_list()
+            .get_trash()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_uncomplete_alias_is_reopen() {
+        let input_stream = FileInputStream::new(std::io::stdin());
+        let output_writer = FileOutputWriter::new(std::io::stdout());
+        let storage_path = get_test_storage_path("uncomplete_alias_reopen");
+        let mut controller = ApplicationController::new(
+            Rc::new(RefCell::new(input_stream)),
+            Rc::new(RefCell::new(output_writer)),
+            storage_path,
+        );
+
+        controller.handle_input("add Task one");
+        controller.handle_input("complete 1");
+        controller.handle_input("reopen 1");
+        assert!(!controller
+            .service
+            .borrow()
+            .project_manager()
+            .get_current_todo_list()
+            .get_tasks()[0]
+            .is_completed());
+    }
+
+    #[test]
+    fn test_saved_views_are_stored_with_projects() {
+        let input_stream = FileInputStream::new(std::io::stdin());
+        let output_writer = FileOutputWriter::new(std::io::stdout());
+        let storage_path = get_test_storage_path("saved_views");
+        let mut controller = ApplicationController::new(
+            Rc::new(RefCell::new(input_stream)),
+            Rc::new(RefCell::new(output_writer)),
+            storage_path.clone(),
+        );
+
+        controller.handle_input("view save Work pending high +work sort:due");
+        controller.handle_input("view work");
+        controller.handle_input("view list");
+        assert_eq!(
+            controller
+                .service
+                .borrow()
+                .project_manager()
+                .get_view("work")
+                .map(|view| view.filter.clone()),
+            Some("pending high +work sort:due".to_string())
+        );
+
+        let saved = open_storage(&storage_path).load_projects().unwrap();
+        assert_eq!(saved.get_views().len(), 1);
+        let _ = std::fs::remove_file(&storage_path);
+
+        controller.handle_input("view delete WORK");
+        assert!(controller
+            .service
+            .borrow()
+            .project_manager()
+            .get_views()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_add_with_inline_attributes() {
+        let input_stream = FileInputStream::new(std::io::stdin());
+        let output_writer = FileOutputWriter::new(std::io::stdout());
+        let storage_path = get_test_storage_path("add_inline_attributes");
+        let mut controller = ApplicationController::new(
+            Rc::new(RefCell::new(input_stream)),
+            Rc::new(RefCell::new(output_writer)),
+            storage_path,
+        );
+
+        controller.handle_input("add Finance");
+        controller.handle_input("add Request invoice");
+        controller.handle_input("add Pay invoice !high @finance every:month parent:1 after:2");
+        // Unknown parents or dependencies do not add the task
+        controller.handle_input("add Orphan parent:42");
+        controller.handle_input("add Waiting after:1,42");
+        assert_eq!(task_count(&controller), 3);
+
+        let service = controller.service.borrow();
+        let task = &service.todo_list().get_tasks()[2];
+        assert_eq!(task.description, "Pay invoice");
+        assert_eq!(task.get_priority(), Priority::High);
+        assert_eq!(task.get_category(), Some(&"finance".to_string()));
+        assert_eq!(task.get_recurrence(), Some(Recurrence::Monthly));
+        assert_eq!(task.get_parent_id(), Some(1));
+        assert_eq!(task.get_dependencies(), &vec![2]);
+    }
+
+    #[test]
+    fn test_bulk_commands_with_where_filter() {
+        let input_stream = FileInputStream::new(std::io::stdin());
+        let output_writer = FileOutputWriter::new(std::io::stdout());
+        let storage_path = get_test_storage_path("where_filter");
+        let mut controller = ApplicationController::new(
+            Rc::new(RefCell::new(input_stream)),
+            Rc::new(RefCell::new(output_writer)),
+            storage_path,
+        );
+
+        controller.handle_input("add Late report @work due:yesterday");
+        controller.handle_input("add Late groceries @home due:yesterday");
+        controller.handle_input("add Future report @work due:+7d !low");
+        controller.handle_input("complete where overdue category:work");
+        controller.handle_input("priority where todo low high");
+        // A filter matching nothing changes nothing
+        controller.handle_input("remove where cancelled");
+
+        let service = controller.service.borrow();
+        let tasks = service.todo_list().get_tasks();
+        assert_eq!(tasks.len(), 3);
+        assert!(tasks[0].is_completed());
+        assert!(!tasks[1].is_completed());
+        assert_eq!(tasks[1].get_priority(), Priority::Medium);
+        assert_eq!(tasks[2].get_priority(), Priority::High);
+    }
+
+    fn controller_with_input(
+        name: &str,
+        input: &'static [u8],
+    ) -> ApplicationController<FileInputStream<&'static [u8]>, FileOutputWriter<std::io::Stdout>>
+    {
+        let output_writer = FileOutputWriter::new(std::io::stdout());
+        let mut controller = ApplicationController::new(
+            Rc::new(RefCell::new(FileInputStream::new(input))),
+            Rc::new(RefCell::new(output_writer)),
+            get_test_storage_path(name),
+        );
+        for i in 1..=6 {
+            controller.handle_input(&format!("add Task {}", i));
+        }
+        controller
+    }
+
+    #[test]
+    fn test_dry_run_keeps_tasks_unchanged() {
+        let mut controller = controller_with_input("dry_run", b"");
+
+        controller.handle_input("complete where todo --dry-run");
+        controller.handle_input("remove all --dry-run --yes");
+        assert_eq!(task_count(&controller), 6);
+        assert!(controller
+            .service
+            .borrow()
+            .project_manager()
+            .get_current_todo_list()
+            .get_tasks()
+            .iter()
+            .all(|task| !task.is_completed()));
+        assert_eq!(controller.history.undo_count(), 6);
+    }
+
+    #[test]
+    fn test_dry_run_writes_no_files() {
+        let mut controller = controller_with_input("dry_run_files", b"");
+        let target = std::env::temp_dir().join("todo_dry_run_convert_test.db");
+        let _ = std::fs::remove_file(&target);
+
+        controller.handle_input(&format!("convert {} --dry-run", target.display()));
+        assert!(!target.exists());
+        controller.handle_input(&format!("convert {} --dry-run --yes", target.display()));
+        assert!(!target.exists());
+
+        controller.handle_input("restore-backup 1 --dry-run");
+        assert_eq!(task_count(&controller), 6);
+    }
+
+    #[test]
+    fn test_large_removal_needs_confirmation() {
+        let mut controller = controller_with_input("confirm_declined", b"n\ny\n");
+
+        controller.handle_input("remove all");
+        assert_eq!(task_count(&controller), 6);
+        assert_eq!(controller.history.undo_count(), 6);
+
+        controller.handle_input("remove all");
+        assert_eq!(task_count(&controller), 0);
+    }
+
+    #[test]
+    fn test_end_of_input_declines_and_yes_skips_confirmation() {
+        let mut controller = controller_with_input("confirm_yes", b"");
+
+        controller.handle_input("priority all high");
+        assert!(controller
+            .service
+            .borrow()
+            .project_manager()
+            .get_current_todo_list()
+            .get_tasks()
+            .iter()
+            .all(|task| task.get_priority() != Priority::High));
+
+        // Up to the threshold no confirmation is needed
+        controller.handle_input("remove 1");
+        assert_eq!(task_count(&controller), 5);
+
+        controller.confirmation_threshold = 2;
+        controller.handle_input("remove all --yes");
+        assert_eq!(task_count(&controller), 0);
+    }
+
+    #[test]
+    fn test_split_command_flags() {
+        assert_eq!(
+            split_command_flags("remove all"),
+            ("remove all", false, false)
+        );
+        assert_eq!(
+            split_command_flags("remove all --yes  --dry-run"),
+            ("remove all", true, true)
+        );
+        assert_eq!(
+            split_command_flags("add Ask --yes later"),
+            ("add Ask --yes later", false, false)
+        );
+        assert_eq!(
+            split_command_flags("--dry-run"),
+            ("--dry-run", false, false)
+        );
+    }
 }
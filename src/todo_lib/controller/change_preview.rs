@@ -0,0 +1,311 @@
+use crate::controller::project_command::ProjectManager;
+use crate::models::task::Task;
+use crate::models::todo_list::TodoList;
+use crate::models::trash::TrashedTask;
+
+/// Default number of removed or overwritten tasks above which a command must be confirmed.
+pub const DEFAULT_CONFIRMATION_THRESHOLD: usize = 5;
+
+/// Task-level description of what a command changed.
+///
+/// Used to show what a `--dry-run` command would do and to decide whether a
+/// command touches enough existing tasks to ask for confirmation.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::controller::change_preview::ChangePreview;
+/// use todo_manager::controller::project_command::ProjectManager;
+/// use todo_manager::models::task::TaskWithoutId;
+///
+/// let mut before = ProjectManager::new();
+/// let id = before
+///     .get_current_todo_list_mut()
+///     .add_task(TaskWithoutId::new("Write report".to_string()));
+/// let mut after = before.clone();
+/// after.get_current_todo_list_mut().complete_task(id).unwrap();
+///
+/// let preview = ChangePreview::between(&before, &after);
+/// assert_eq!(preview.affected_count(), 1);
+/// assert_eq!(
+///     preview.lines(),
+///     &["Project 'default':", "  #1 'Write report': status Pending -> Completed"]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ChangePreview {
+    lines: Vec<String>,
+    affected_count: usize,
+}
+
+impl ChangePreview {
+    /// Compares two project states.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The state before the command
+    /// * `to` - The state after the command
+    pub fn between(from: &ProjectManager, to: &ProjectManager) -> Self {
+        let mut preview = ChangePreview::default();
+        let from_names = from.list_projects();
+        let to_names = to.list_projects();
+
+        for name in &from_names {
+            let Some(old) = from.get_project(name) else {
+                continue;
+            };
+            match to.get_project(name) {
+                Some(new) => preview.add_todo_list_changes(name, &old.todo_list, &new.todo_list),
+                None => {
+                    let task_count = old.todo_list.get_tasks().len();
+                    preview.affected_count += task_count;
+                    preview.lines.push(format!(
+                        "Project '{}': deleted with {} task(s)",
+                        name, task_count
+                    ));
+                }
+            }
+        }
+
+        for name in to_names.iter().filter(|name| !from_names.contains(name)) {
+            preview.lines.push(format!("Project '{}': created", name));
+        }
+
+        // Trashed projects that are neither in the trash nor restored are gone for good
+        for trashed in from.get_trashed_projects() {
+            let name = &trashed.project.name;
+            let still_kept = to_names.contains(name)
+                || to
+                    .get_trashed_projects()
+                    .iter()
+                    .any(|entry| &entry.project.name == name);
+            if !still_kept {
+                let task_count = trashed.project.todo_list.get_tasks().len();
+                preview.affected_count += task_count;
+                preview.lines.push(format!(
+                    "Project '{}': permanently deleted from the trash with {} task(s)",
+                    name, task_count
+                ));
+            }
+        }
+
+        preview
+    }
+
+    /// Returns the description lines, grouped by project.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Returns the number of existing tasks that were removed or changed.
+    pub fn affected_count(&self) -> usize {
+        self.affected_count
+    }
+
+    /// Returns whether nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    fn add_todo_list_changes(&mut self, project: &str, old: &TodoList, new: &TodoList) {
+        if old == new {
+            return;
+        }
+
+        let mut task_lines = Vec::new();
+        for old_task in old.get_tasks() {
+            match new.get_tasks().iter().find(|task| task.id == old_task.id) {
+                Some(new_task) => {
+                    let fields = describe_task_fields(old_task, new_task);
+                    if !fields.is_empty() {
+                        self.affected_count += 1;
+                        task_lines.push(format!(
+                            "  #{} '{}': {}",
+                            old_task.id,
+                            old_task.description,
+                            fields.join(", ")
+                        ));
+                    }
+                }
+                None => {
+                    self.affected_count += 1;
+                    task_lines.push(format!(
+                        "  #{} '{}': removed",
+                        old_task.id, old_task.description
+                    ));
+                }
+            }
+        }
+        for new_task in new.get_tasks() {
+            if old.get_tasks().iter().all(|task| task.id != new_task.id) {
+                task_lines.push(format!(
+                    "  #{} '{}': added",
+                    new_task.id, new_task.description
+                ));
+            }
+        }
+
+        let purged: Vec<&TrashedTask> = old
+            .get_trash()
+            .iter()
+            .filter(|entry| {
+                !new.get_trash().iter().any(|e| e.task.id == entry.task.id)
+                    && new.get_tasks().iter().all(|task| task.id != entry.task.id)
+            })
+            .collect();
+        for entry in purged {
+            self.affected_count += 1 + entry.subtasks.len();
+            task_lines.push(format!(
+                "  #{} '{}': permanently deleted from the trash",
+                entry.task.id, entry.task.description
+            ));
+        }
+
+        if !task_lines.is_empty() {
+            self.lines.push(format!("Project '{}':", project));
+            self.lines.extend(task_lines);
+        }
+    }
+}
+
+/// Describes the user-visible fields that differ between two versions of a task.
+///
+/// Timestamps are left out since they change with every edit.
+fn describe_task_fields(old: &Task, new: &Task) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut push = |name: &str, from: String, to: String| {
+        if from != to {
+            fields.push(format!("{} {} -> {}", name, from, to));
+        }
+    };
+
+    push(
+        "description",
+        format!("'{}'", old.description),
+        format!("'{}'", new.description),
+    );
+    push(
+        "status",
+        old.status.as_str().to_string(),
+        new.status.as_str().to_string(),
+    );
+    push(
+        "priority",
+        old.priority.as_str().to_string(),
+        new.priority.as_str().to_string(),
+    );
+    push("due", format_due(old), format_due(new));
+    push("tags", format_list(&old.tags), format_list(&new.tags));
+    push("parent", format_id(old.parent_id), format_id(new.parent_id));
+    push(
+        "recurrence",
+        old.recurrence
+            .map_or("none".to_string(), |r| r.as_str().to_string()),
+        new.recurrence
+            .map_or("none".to_string(), |r| r.as_str().to_string()),
+    );
+    push(
+        "dependencies",
+        format_list(&old.depends_on),
+        format_list(&new.depends_on),
+    );
+
+    fields
+}
+
+fn format_due(task: &Task) -> String {
+    task.due_date.map_or("none".to_string(), |date| {
+        date.format("%d.%m.%Y").to_string()
+    })
+}
+
+fn format_id(id: Option<usize>) -> String {
+    id.map_or("none".to_string(), |id| format!("#{}", id))
+}
+
+fn format_list<T: ToString>(items: &[T]) -> String {
+    if items.is_empty() {
+        "none".to_string()
+    } else {
+        items
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::priority::Priority;
+    use crate::models::task::TaskWithoutId;
+
+    fn manager_with_tasks(count: usize) -> ProjectManager {
+        let mut manager = ProjectManager::new();
+        for i in 1..=count {
+            manager
+                .get_current_todo_list_mut()
+                .add_task(TaskWithoutId::new(format!("Task {}", i)));
+        }
+        manager
+    }
+
+    #[test]
+    fn test_no_changes() {
+        let manager = manager_with_tasks(2);
+        let preview = ChangePreview::between(&manager, &manager.clone());
+        assert!(preview.is_empty());
+        assert_eq!(preview.affected_count(), 0);
+    }
+
+    #[test]
+    fn test_added_tasks_are_not_affected() {
+        let before = manager_with_tasks(1);
+        let mut after = before.clone();
+        after
+            .get_current_todo_list_mut()
+            .add_task(TaskWithoutId::new("New".to_string()));
+
+        let preview = ChangePreview::between(&before, &after);
+        assert_eq!(preview.affected_count(), 0);
+        assert_eq!(preview.lines()[1], "  #2 'New': added");
+    }
+
+    #[test]
+    fn test_removed_and_changed_tasks() {
+        let before = manager_with_tasks(3);
+        let mut after = before.clone();
+        let list = after.get_current_todo_list_mut();
+        list.remove_task(1).unwrap();
+        list.set_task_priority(2, Priority::High).unwrap();
+
+        let preview = ChangePreview::between(&before, &after);
+        assert_eq!(preview.affected_count(), 2);
+        assert_eq!(
+            preview.lines(),
+            &[
+                "Project 'default':",
+                "  #1 'Task 1': removed",
+                "  #2 'Task 2': priority Medium -> High",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deleted_project_counts_its_tasks() {
+        let mut before = ProjectManager::new();
+        before.create_project("Work".to_string()).unwrap();
+        before.switch_project("Work".to_string()).unwrap();
+        before
+            .get_current_todo_list_mut()
+            .add_task(TaskWithoutId::new("Report".to_string()));
+        before.switch_project("default".to_string()).unwrap();
+        let mut after = before.clone();
+        after.delete_project("Work".to_string()).unwrap();
+
+        let preview = ChangePreview::between(&before, &after);
+        assert_eq!(preview.affected_count(), 1);
+        assert_eq!(preview.lines(), &["Project 'Work': deleted with 1 task(s)"]);
+    }
+}
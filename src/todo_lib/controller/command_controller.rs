@@ -3,4 +3,14 @@ use crate::models::parse_error::ParseError;
 
 pub trait CommandController {
     fn try_execute(&mut self, input: &str) -> Option<Result<CommandControllerResult, ParseError>>;
+
+    /// Handles the input of a `--dry-run`.
+    ///
+    /// The caller rolls back any changes to the projects afterwards, so by
+    /// default the command simply runs. Commands with effects beyond the
+    /// projects, such as writing files, must override this and describe those
+    /// effects instead, returning `CommandControllerResultAction::PreviewShown`.
+    fn try_preview(&mut self, input: &str) -> Option<Result<CommandControllerResult, ParseError>> {
+        self.try_execute(input)
+    }
 }
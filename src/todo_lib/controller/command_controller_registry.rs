@@ -2,10 +2,10 @@ use crate::controller::command_controller::CommandController;
 use crate::controller::debug_command::DebugCommandController;
 use crate::controller::general_command::GeneralCommandController;
 use crate::controller::project_command::ProjectCommandController;
-use crate::controller::project_command::ProjectManager;
 use crate::controller::task_command::TaskCommandController;
 use crate::models::command_controller_result::CommandControllerResult;
 use crate::models::ParseError;
+use crate::service::TodoService;
 use crate::OutputWriter;
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -25,24 +25,18 @@ pub struct CommandControllerRegistry<O: OutputWriter> {
 impl<O: OutputWriter> CommandControllerRegistry<O> {
     /// Creates a new CommandControllerRegistry with task and general controllers active,
     /// and debug controller inactive by default.
-    pub fn new(
-        project_manager: Rc<RefCell<ProjectManager>>,
-        output_writer: Rc<RefCell<O>>,
-    ) -> Self {
+    pub fn new(service: Rc<RefCell<TodoService>>, output_writer: Rc<RefCell<O>>) -> Self {
         Self {
             task_controller: TaskCommandController::new(
-                Rc::clone(&project_manager),
+                Rc::clone(&service),
                 Rc::clone(&output_writer),
             ),
             general_controller: GeneralCommandController::new(Rc::clone(&output_writer)),
             debug_controller: DebugCommandController::new(
-                Rc::clone(&project_manager),
-                Rc::clone(&output_writer),
-            ),
-            project_controller: ProjectCommandController::new(
-                project_manager,
+                Rc::clone(&service),
                 Rc::clone(&output_writer),
             ),
+            project_controller: ProjectCommandController::new(service, Rc::clone(&output_writer)),
             is_debug_active: false,
         }
     }
@@ -75,17 +69,42 @@ impl<O: OutputWriter> CommandControllerRegistry<O> {
         &mut self,
         input: &str,
     ) -> Option<Result<CommandControllerResult, ParseError>> {
-        if let Some(result) = self.task_controller.try_execute(input) {
+        self.dispatch(input, false)
+    }
+
+    /// Tries to handle the input of a `--dry-run` with all active controllers,
+    /// in the same order as `try_execute` (see `CommandController::try_preview`).
+    pub fn try_preview(
+        &mut self,
+        input: &str,
+    ) -> Option<Result<CommandControllerResult, ParseError>> {
+        self.dispatch(input, true)
+    }
+
+    fn dispatch(
+        &mut self,
+        input: &str,
+        preview: bool,
+    ) -> Option<Result<CommandControllerResult, ParseError>> {
+        let run = |controller: &mut dyn CommandController| {
+            if preview {
+                controller.try_preview(input)
+            } else {
+                controller.try_execute(input)
+            }
+        };
+
+        if let Some(result) = run(&mut self.task_controller) {
             return Some(result);
         }
-        if let Some(result) = self.project_controller.try_execute(input) {
+        if let Some(result) = run(&mut self.project_controller) {
             return Some(result);
         }
-        if let Some(result) = self.general_controller.try_execute(input) {
+        if let Some(result) = run(&mut self.general_controller) {
             return Some(result);
         }
         if self.is_debug_active {
-            if let Some(result) = self.debug_controller.try_execute(input) {
+            if let Some(result) = run(&mut self.debug_controller) {
                 return Some(result);
             }
         }
@@ -96,35 +115,26 @@ impl<O: OutputWriter> CommandControllerRegistry<O> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::controller::project_command::ProjectManager;
 
     #[test]
     fn test_try_execute_with_task_command() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
         let output = crate::ui::output::FileOutputWriter::new(std::io::stdout());
-        let mut registry = CommandControllerRegistry::new(
-            Rc::clone(&project_manager),
-            Rc::new(RefCell::new(output)),
-        );
+        let mut registry =
+            CommandControllerRegistry::new(Rc::clone(&service), Rc::new(RefCell::new(output)));
 
         let result = registry.try_execute("add Test task");
         assert!(result.is_some());
         assert!(result.unwrap().is_ok());
-        assert_eq!(
-            project_manager
-                .borrow()
-                .get_current_todo_list()
-                .get_tasks()
-                .len(),
-            1
-        );
+        assert_eq!(service.borrow().todo_list().get_tasks().len(), 1);
     }
 
     #[test]
     fn test_try_execute_with_general_command() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
         let output = crate::ui::output::FileOutputWriter::new(std::io::stdout());
-        let mut registry =
-            CommandControllerRegistry::new(project_manager, Rc::new(RefCell::new(output)));
+        let mut registry = CommandControllerRegistry::new(service, Rc::new(RefCell::new(output)));
 
         let result = registry.try_execute("quit");
         assert!(result.is_some());
@@ -133,10 +143,9 @@ mod tests {
 
     #[test]
     fn test_try_execute_with_debug_command_inactive() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
         let output = crate::ui::output::FileOutputWriter::new(std::io::stdout());
-        let mut registry =
-            CommandControllerRegistry::new(project_manager, Rc::new(RefCell::new(output)));
+        let mut registry = CommandControllerRegistry::new(service, Rc::new(RefCell::new(output)));
 
         // Debug controller is not active, so debug commands should not be recognized
         let result = registry.try_execute("debug:gen 5");
@@ -145,23 +154,17 @@ mod tests {
 
     #[test]
     fn test_try_execute_with_debug_command_active() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
         let output = crate::ui::output::FileOutputWriter::new(std::io::stdout());
-        let mut registry = CommandControllerRegistry::new(
-            Rc::clone(&project_manager),
-            Rc::new(RefCell::new(output)),
-        );
+        let mut registry =
+            CommandControllerRegistry::new(Rc::clone(&service), Rc::new(RefCell::new(output)));
         registry.enable_debug();
 
         let result = registry.try_execute("debug:gen 3");
         assert!(result.is_some());
         assert!(result.unwrap().is_ok());
         // Should have at least 3 tasks (parents), possibly more with subtasks
-        let total_tasks = project_manager
-            .borrow()
-            .get_current_todo_list()
-            .get_tasks()
-            .len();
+        let total_tasks = service.borrow().todo_list().get_tasks().len();
         assert!(
             total_tasks >= 3,
             "Expected at least 3 tasks, got {}",
@@ -171,10 +174,9 @@ mod tests {
 
     #[test]
     fn test_try_execute_unknown_command() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
         let output = crate::ui::output::FileOutputWriter::new(std::io::stdout());
-        let mut registry =
-            CommandControllerRegistry::new(project_manager, Rc::new(RefCell::new(output)));
+        let mut registry = CommandControllerRegistry::new(service, Rc::new(RefCell::new(output)));
 
         let result = registry.try_execute("unknown command");
         assert!(result.is_none());
@@ -182,10 +184,9 @@ mod tests {
 
     #[test]
     fn test_try_execute_with_disabled_task_controller() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
         let output = crate::ui::output::FileOutputWriter::new(std::io::stdout());
-        let mut registry =
-            CommandControllerRegistry::new(project_manager, Rc::new(RefCell::new(output)));
+        let mut registry = CommandControllerRegistry::new(service, Rc::new(RefCell::new(output)));
         registry.disable_debug();
 
         // Task controller is still active, so this should succeed
@@ -195,12 +196,10 @@ mod tests {
 
     #[test]
     fn test_try_execute_with_project_command() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
         let output = crate::ui::output::FileOutputWriter::new(std::io::stdout());
-        let mut registry = CommandControllerRegistry::new(
-            Rc::clone(&project_manager),
-            Rc::new(RefCell::new(output)),
-        );
+        let mut registry =
+            CommandControllerRegistry::new(Rc::clone(&service), Rc::new(RefCell::new(output)));
 
         // Create a new project
         let result = registry.try_execute("new-project Work");
@@ -208,7 +207,7 @@ mod tests {
         assert!(result.unwrap().is_ok());
 
         // Verify project was created
-        assert_eq!(project_manager.borrow().project_count(), 2); // Default + Work
+        assert_eq!(service.borrow().project_manager().project_count(), 2); // Default + Work
 
         // Switch to the new project
         let result = registry.try_execute("switch-project Work");
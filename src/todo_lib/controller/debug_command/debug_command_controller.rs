@@ -2,11 +2,11 @@ use crate::controller::debug_command::DebugCommand;
 use crate::controller::debug_command::DebugCommandInputParser;
 use crate::controller::debug_command::DebugCommandOutputManager;
 use crate::controller::debug_command::RandomTaskGenerator;
-use crate::controller::project_command::ProjectManager;
 use crate::controller::CommandController;
 use crate::models::command_controller_result::CommandControllerResult;
 use crate::models::command_controller_result::CommandControllerResultAction;
 use crate::models::ParseError;
+use crate::service::TodoService;
 use crate::OutputWriter;
 use rand::Rng;
 use std::cell::RefCell;
@@ -14,7 +14,7 @@ use std::rc::Rc;
 
 /// Handler for debug commands and operations
 pub struct DebugCommandController<O: OutputWriter> {
-    project_manager: Rc<RefCell<ProjectManager>>,
+    service: Rc<RefCell<TodoService>>,
     input_parser: DebugCommandInputParser,
     /// Output writer for displaying results
     output_manager: DebugCommandOutputManager<O>,
@@ -24,12 +24,9 @@ pub struct DebugCommandController<O: OutputWriter> {
 
 impl<O: OutputWriter> DebugCommandController<O> {
     /// Creates a new DebugCommandController with a custom output writer
-    pub fn new(
-        project_manager: Rc<RefCell<ProjectManager>>,
-        output_writer: Rc<RefCell<O>>,
-    ) -> Self {
+    pub fn new(service: Rc<RefCell<TodoService>>, output_writer: Rc<RefCell<O>>) -> Self {
         Self {
-            project_manager,
+            service,
             input_parser: DebugCommandInputParser::new(),
             output_manager: DebugCommandOutputManager::new(output_writer),
             task_generator: RandomTaskGenerator::new(),
@@ -66,8 +63,9 @@ impl<O: OutputWriter> DebugCommandController<O> {
         // Add each generated task to the todo list
         for new_task in new_tasks {
             let parent_id = self
-                .project_manager
+                .service
                 .borrow_mut()
+                .project_manager_mut()
                 .get_current_todo_list_mut()
                 .add_task(new_task);
             task_ids.push(parent_id);
@@ -78,11 +76,12 @@ impl<O: OutputWriter> DebugCommandController<O> {
             for _ in 0..subtask_count {
                 let subtask = self.task_generator.generate_single_subtask(0.2);
                 if self
-                    .project_manager
+                    .service
                     .borrow_mut()
+                    .project_manager_mut()
                     .get_current_todo_list_mut()
                     .add_subtask(parent_id, subtask.description)
-                    .is_some()
+                    .is_ok()
                 {
                     total_subtasks += 1;
                 }
@@ -99,11 +98,12 @@ impl<O: OutputWriter> DebugCommandController<O> {
                 let depends_on_idx = rng.random_range(0..idx);
                 let depends_on_id = task_ids[depends_on_idx];
                 if self
-                    .project_manager
+                    .service
                     .borrow_mut()
+                    .project_manager_mut()
                     .get_current_todo_list_mut()
                     .add_task_dependency(task_id, depends_on_id)
-                    .is_some()
+                    .is_ok()
                 {
                     dependencies_added += 1;
                 }
@@ -113,13 +113,16 @@ impl<O: OutputWriter> DebugCommandController<O> {
         // Uncomplete tasks that have incomplete dependencies
         for &task_id in &task_ids {
             let has_incomplete_deps = !self
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .are_dependencies_completed(task_id);
             if has_incomplete_deps {
-                self.project_manager
+                let _ = self
+                    .service
                     .borrow_mut()
+                    .project_manager_mut()
                     .get_current_todo_list_mut()
                     .uncomplete_task(task_id);
             }
@@ -150,13 +153,15 @@ impl<O: OutputWriter> DebugCommandController<O> {
     /// * `todo_list` - The todo list to clear
     fn clear_all_tasks(&mut self) -> CommandControllerResult {
         let count = self
-            .project_manager
+            .service
             .borrow()
+            .project_manager()
             .get_current_todo_list()
             .get_tasks()
             .len();
-        self.project_manager
+        self.service
             .borrow_mut()
+            .project_manager_mut()
             .get_current_todo_list_mut()
             .clear_all();
         self.output_manager
@@ -184,16 +189,19 @@ impl<O: OutputWriter> DebugCommandController<O> {
         for project_name in project_names {
             // Create the project
             if self
-                .project_manager
+                .service
                 .borrow_mut()
+                .project_manager_mut()
                 .create_project(project_name.clone())
-                .is_some()
+                .is_ok()
             {
                 created_projects += 1;
 
                 // Switch to the new project
-                self.project_manager
+                let _ = self
+                    .service
                     .borrow_mut()
+                    .project_manager_mut()
                     .switch_project(project_name.clone());
 
                 // Generate tasks for this project
@@ -203,8 +211,9 @@ impl<O: OutputWriter> DebugCommandController<O> {
                 // Add each generated task to the project's todo list
                 for new_task in new_tasks {
                     let parent_id = self
-                        .project_manager
+                        .service
                         .borrow_mut()
+                        .project_manager_mut()
                         .get_current_todo_list_mut()
                         .add_task(new_task);
                     task_ids.push(parent_id);
@@ -215,11 +224,12 @@ impl<O: OutputWriter> DebugCommandController<O> {
                     for _ in 0..subtask_count {
                         let subtask = self.task_generator.generate_single_subtask(0.2);
                         if self
-                            .project_manager
+                            .service
                             .borrow_mut()
+                            .project_manager_mut()
                             .get_current_todo_list_mut()
                             .add_subtask(parent_id, subtask.description)
-                            .is_some()
+                            .is_ok()
                         {
                             total_subtasks += 1;
                         }
@@ -235,11 +245,12 @@ impl<O: OutputWriter> DebugCommandController<O> {
                         let depends_on_idx = rng.random_range(0..idx);
                         let depends_on_id = task_ids[depends_on_idx];
                         if self
-                            .project_manager
+                            .service
                             .borrow_mut()
+                            .project_manager_mut()
                             .get_current_todo_list_mut()
                             .add_task_dependency(task_id, depends_on_id)
-                            .is_some()
+                            .is_ok()
                         {
                             total_dependencies += 1;
                         }
@@ -249,13 +260,16 @@ impl<O: OutputWriter> DebugCommandController<O> {
                 // Uncomplete tasks that have incomplete dependencies
                 for &task_id in &task_ids {
                     let has_incomplete_deps = !self
-                        .project_manager
+                        .service
                         .borrow()
+                        .project_manager()
                         .get_current_todo_list()
                         .are_dependencies_completed(task_id);
                     if has_incomplete_deps {
-                        self.project_manager
+                        let _ = self
+                            .service
                             .borrow_mut()
+                            .project_manager_mut()
                             .get_current_todo_list_mut()
                             .uncomplete_task(task_id);
                     }
@@ -283,44 +297,51 @@ impl<O: OutputWriter> DebugCommandController<O> {
 
     /// Clears and deletes all projects, keeping only an empty default project
     fn clear_all_projects(&mut self) -> CommandControllerResult {
-        let all_projects = self.project_manager.borrow().list_projects();
+        let all_projects = self.service.borrow().project_manager().list_projects();
         let mut deleted_count = 0;
         let mut total_tasks_cleared = 0;
 
         // Count total tasks before clearing
         for project_name in &all_projects {
-            self.project_manager
+            let _ = self
+                .service
                 .borrow_mut()
+                .project_manager_mut()
                 .switch_project(project_name.clone());
             total_tasks_cleared += self
-                .project_manager
+                .service
                 .borrow()
+                .project_manager()
                 .get_current_todo_list()
                 .get_tasks()
                 .len();
         }
 
         // Switch to default project
-        self.project_manager
+        let _ = self
+            .service
             .borrow_mut()
+            .project_manager_mut()
             .switch_project("default".to_string());
 
         // Delete all non-default projects
         for project_name in all_projects {
             if project_name != "default"
                 && self
-                    .project_manager
+                    .service
                     .borrow_mut()
+                    .project_manager_mut()
                     .delete_project(project_name)
-                    .is_some()
+                    .is_ok()
             {
                 deleted_count += 1;
             }
         }
 
         // Clear all tasks from default project
-        self.project_manager
+        self.service
             .borrow_mut()
+            .project_manager_mut()
             .get_current_todo_list_mut()
             .clear_all();
 
@@ -360,27 +381,22 @@ impl<O: OutputWriter> CommandController for DebugCommandController<O> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::controller::project_command::ProjectManager;
     use crate::models::task::TaskWithoutId;
     use crate::ui::output::FileOutputWriter;
 
     #[test]
     fn test_generate_random_tasks() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
         let buffer = Vec::new();
         let output_writer = FileOutputWriter::new(buffer);
-        let mut controller = DebugCommandController::new(
-            Rc::clone(&project_manager),
-            Rc::new(RefCell::new(output_writer)),
-        );
+        let mut controller =
+            DebugCommandController::new(Rc::clone(&service), Rc::new(RefCell::new(output_writer)));
 
         controller.generate_random_tasks(10);
 
         // Should have at least 10 tasks (parents), possibly more with subtasks
-        let total_tasks = project_manager
-            .borrow()
-            .get_current_todo_list()
-            .get_tasks()
-            .len();
+        let total_tasks = service.borrow().todo_list().get_tasks().len();
         assert!(
             total_tasks >= 10,
             "Expected at least 10 tasks, got {}",
@@ -390,48 +406,37 @@ mod tests {
 
     #[test]
     fn test_clear_all_tasks() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
-        project_manager
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
+        service
             .borrow_mut()
-            .get_current_todo_list_mut()
-            .add_task(TaskWithoutId::new("Test task 1".to_string()));
-        project_manager
+            .add_task(TaskWithoutId::new("Test task 1".to_string()))
+            .unwrap();
+        service
             .borrow_mut()
-            .get_current_todo_list_mut()
-            .add_task(TaskWithoutId::new("Test task 2".to_string()));
+            .add_task(TaskWithoutId::new("Test task 2".to_string()))
+            .unwrap();
         let buffer = Vec::new();
         let output_writer = FileOutputWriter::new(buffer);
-        let mut controller = DebugCommandController::new(
-            Rc::clone(&project_manager),
-            Rc::new(RefCell::new(output_writer)),
-        );
+        let mut controller =
+            DebugCommandController::new(Rc::clone(&service), Rc::new(RefCell::new(output_writer)));
 
         controller.clear_all_tasks();
 
-        assert_eq!(
-            project_manager
-                .borrow()
-                .get_current_todo_list()
-                .get_tasks()
-                .len(),
-            0
-        );
+        assert_eq!(service.borrow().todo_list().get_tasks().len(), 0);
     }
 
     #[test]
     fn test_generate_random_projects() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
         let buffer = Vec::new();
         let output_writer = FileOutputWriter::new(buffer);
-        let mut controller = DebugCommandController::new(
-            Rc::clone(&project_manager),
-            Rc::new(RefCell::new(output_writer)),
-        );
+        let mut controller =
+            DebugCommandController::new(Rc::clone(&service), Rc::new(RefCell::new(output_writer)));
 
         controller.generate_random_projects(3, 5);
 
         // Should have 4 projects total (default + 3 new ones)
-        let project_count = project_manager.borrow().project_count();
+        let project_count = service.borrow().project_manager().project_count();
         assert_eq!(
             project_count, 4,
             "Expected 4 projects, got {}",
@@ -439,17 +444,15 @@ mod tests {
         );
 
         // Each project should have tasks (we generated 5 tasks per project)
-        let projects = project_manager.borrow().list_projects();
+        let projects = service.borrow().project_manager().list_projects();
         for project_name in projects {
             if project_name != "default" {
-                project_manager
+                service
                     .borrow_mut()
-                    .switch_project(project_name.clone());
-                let task_count = project_manager
-                    .borrow()
-                    .get_current_todo_list()
-                    .get_tasks()
-                    .len();
+                    .project_manager_mut()
+                    .switch_project(project_name.clone())
+                    .unwrap();
+                let task_count = service.borrow().todo_list().get_tasks().len();
                 assert!(
                     task_count >= 5,
                     "Project {} should have at least 5 tasks, got {}",
@@ -462,63 +465,67 @@ mod tests {
 
     #[test]
     fn test_clear_all_projects() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
 
         // Create some projects with tasks
-        project_manager
+        service
             .borrow_mut()
-            .create_project("Work".to_string());
-        project_manager
+            .project_manager_mut()
+            .create_project("Work".to_string())
+            .unwrap();
+        service
             .borrow_mut()
-            .switch_project("Work".to_string());
-        project_manager
+            .project_manager_mut()
+            .switch_project("Work".to_string())
+            .unwrap();
+        service
             .borrow_mut()
-            .get_current_todo_list_mut()
-            .add_task(TaskWithoutId::new("Work task".to_string()));
+            .add_task(TaskWithoutId::new("Work task".to_string()))
+            .unwrap();
 
-        project_manager
+        service
             .borrow_mut()
-            .create_project("Personal".to_string());
-        project_manager
+            .project_manager_mut()
+            .create_project("Personal".to_string())
+            .unwrap();
+        service
             .borrow_mut()
-            .switch_project("Personal".to_string());
-        project_manager
+            .project_manager_mut()
+            .switch_project("Personal".to_string())
+            .unwrap();
+        service
             .borrow_mut()
-            .get_current_todo_list_mut()
-            .add_task(TaskWithoutId::new("Personal task".to_string()));
+            .add_task(TaskWithoutId::new("Personal task".to_string()))
+            .unwrap();
 
-        project_manager
+        service
             .borrow_mut()
-            .switch_project("default".to_string());
-        project_manager
+            .project_manager_mut()
+            .switch_project("default".to_string())
+            .unwrap();
+        service
             .borrow_mut()
-            .get_current_todo_list_mut()
-            .add_task(TaskWithoutId::new("Default task".to_string()));
+            .add_task(TaskWithoutId::new("Default task".to_string()))
+            .unwrap();
 
         let buffer = Vec::new();
         let output_writer = FileOutputWriter::new(buffer);
-        let mut controller = DebugCommandController::new(
-            Rc::clone(&project_manager),
-            Rc::new(RefCell::new(output_writer)),
-        );
+        let mut controller =
+            DebugCommandController::new(Rc::clone(&service), Rc::new(RefCell::new(output_writer)));
 
         controller.clear_all_projects();
 
         // Should only have default project left
-        assert_eq!(project_manager.borrow().project_count(), 1);
+        assert_eq!(service.borrow().project_manager().project_count(), 1);
         assert_eq!(
-            project_manager.borrow().get_current_project_name(),
+            service
+                .borrow()
+                .project_manager()
+                .get_current_project_name(),
             "default"
         );
 
         // Default project should be empty
-        assert_eq!(
-            project_manager
-                .borrow()
-                .get_current_todo_list()
-                .get_tasks()
-                .len(),
-            0
-        );
+        assert_eq!(service.borrow().todo_list().get_tasks().len(), 0);
     }
 }
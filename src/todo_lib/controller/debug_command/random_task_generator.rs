@@ -1,6 +1,7 @@
 use crate::models::priority::Priority;
 use crate::models::recurrence::Recurrence;
 use crate::models::task::TaskWithoutId;
+use crate::models::task_status::TaskStatus;
 use rand::Rng;
 
 /// Generates random tasks for testing purposes
@@ -264,12 +265,19 @@ impl RandomTaskGenerator {
         TaskWithoutId {
             description,
             priority,
-            completed,
+            status: if completed {
+                TaskStatus::Completed
+            } else {
+                TaskStatus::Pending
+            },
             due_date,
-            category,
+            tags: category.into_iter().collect(),
             parent_id: None,
             recurrence,
             depends_on: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            completed_at: None,
         }
     }
 
@@ -326,12 +334,19 @@ impl RandomTaskGenerator {
         TaskWithoutId {
             description,
             priority,
-            completed,
+            status: if completed {
+                TaskStatus::Completed
+            } else {
+                TaskStatus::Pending
+            },
             due_date: None,
-            category: None,
+            tags: Vec::new(),
             parent_id: None,  // Will be set when added to TodoList
             recurrence: None, // Subtasks typically don't recur independently
             depends_on: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            completed_at: None,
         }
     }
 
@@ -470,7 +485,7 @@ mod tests {
         ));
         // Subtasks should not have due dates or categories
         assert!(subtask.due_date.is_none());
-        assert!(subtask.category.is_none());
+        assert!(subtask.tags.is_empty());
         // parent_id should be None initially (will be set by TodoList.add_subtask)
         assert!(subtask.parent_id.is_none());
     }
@@ -11,4 +11,10 @@ pub enum GeneralCommand {
 
     /// Toggle debug mode on/off
     ToggleDebug,
+
+    /// Undo the most recent change
+    Undo,
+
+    /// Redo the most recently undone change
+    Redo,
 }
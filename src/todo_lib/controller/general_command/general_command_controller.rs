@@ -35,6 +35,16 @@ impl<O: OutputWriter> GeneralCommandController<O> {
             GeneralCommand::ShowHelp => self.show_help(),
             GeneralCommand::Quit => return self.handle_quit(),
             GeneralCommand::ToggleDebug => return self.handle_toggle_debug(),
+            GeneralCommand::Undo => {
+                return CommandControllerResult::with_action(
+                    CommandControllerResultAction::UndoLastChange,
+                )
+            }
+            GeneralCommand::Redo => {
+                return CommandControllerResult::with_action(
+                    CommandControllerResultAction::RedoLastChange,
+                )
+            }
         }
         CommandControllerResult::empty()
     }
@@ -149,4 +159,15 @@ mod tests {
             vec![&CommandControllerResultAction::DisableDebugMode]
         );
     }
+
+    #[test]
+    fn test_undo_and_redo_return_history_actions() {
+        let buffer = Vec::new();
+        let output_writer = FileOutputWriter::new(buffer);
+        let mut handler = GeneralCommandController::new(Rc::new(RefCell::new(output_writer)));
+        let result = handler.handle_command(&GeneralCommand::Undo);
+        assert!(result.has_action(&CommandControllerResultAction::UndoLastChange));
+        let result = handler.handle_command(&GeneralCommand::Redo);
+        assert!(result.has_action(&CommandControllerResultAction::RedoLastChange));
+    }
 }
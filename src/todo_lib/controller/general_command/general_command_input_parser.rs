@@ -29,6 +29,8 @@ impl GeneralCommandInputParser {
             "help" | "h" => Some(Ok(GeneralCommand::ShowHelp)),
             "quit" | "exit" | "q" => Some(Ok(GeneralCommand::Quit)),
             "debug" => Some(Ok(GeneralCommand::ToggleDebug)),
+            "undo" => Some(Ok(GeneralCommand::Undo)),
+            "redo" => Some(Ok(GeneralCommand::Redo)),
             _ => None,
         }
     }
@@ -62,6 +62,18 @@ impl<O: OutputWriter> GeneralCommandOutputManager<O> {
                 "add <description>",
                 "Add a new task",
             ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::subinfo(
+                "Inline:",
+                "!high @tag due:friday every:month parent:<id> after:<ids>",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::subinfo(
+                "Escape:",
+                "\\!text or \\@text for a literal '!' or '@'",
+            ));
         self.output_writer.borrow_mut().write_line("");
         self.output_writer
             .borrow_mut()
@@ -83,7 +95,7 @@ impl<O: OutputWriter> GeneralCommandOutputManager<O> {
             .borrow_mut()
             .write_line(&MessageFormatter::subinfo(
                 "Status:",
-                "completed/done, pending/todo, overdue",
+                "completed/done, pending/todo, in-progress, waiting, blocked, cancelled, overdue",
             ));
         self.output_writer
             .borrow_mut()
@@ -97,17 +109,35 @@ impl<O: OutputWriter> GeneralCommandOutputManager<O> {
                 "Category:",
                 "category:name or cat:name",
             ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::subinfo(
+                "Tags:",
+                "+tag to include, -tag to exclude",
+            ));
         self.output_writer
             .borrow_mut()
             .write_line(&MessageFormatter::subinfo(
                 "Sort:",
-                "sort:id, sort:priority, sort:due, sort:category, sort:status",
+                "sort:<keys> with id, priority, due, category, status, description, progress, deps",
             ));
         self.output_writer
             .borrow_mut()
             .write_line(&MessageFormatter::subinfo(
                 "Order:",
-                "--reverse or -r for descending order",
+                "sort:due,-priority,id (-key reverses a key), --reverse or -r reverses all",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::subinfo(
+                "Expressions:",
+                "and/or/not, ( ), due<friday, priority>=medium, id<=10, desc~text",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::subinfo(
+                "Predicates:",
+                "has:subtasks, has:deps, has:due, is:blocked, is:recurring, is:subtask",
             ));
         self.output_writer
             .borrow_mut()
@@ -115,6 +145,31 @@ impl<O: OutputWriter> GeneralCommandOutputManager<O> {
                 "Example:",
                 "list pending high category:work sort:priority",
             ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::subinfo(
+                "Example:",
+                "list (high or due<friday) and not +someday",
+            ));
+        self.output_writer.borrow_mut().write_line("");
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "view save <name> <filters>",
+                "Save list filters under a name",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "view <name>",
+                "List tasks using a saved view",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "view list | view delete <name>",
+                "Show or delete saved views",
+            ));
         self.output_writer.borrow_mut().write_line("");
         self.output_writer
             .borrow_mut()
@@ -162,7 +217,7 @@ impl<O: OutputWriter> GeneralCommandOutputManager<O> {
             ));
         self.output_writer
             .borrow_mut()
-            .write_line(&MessageFormatter::label("Alias:", "undo"));
+            .write_line(&MessageFormatter::label("Alias:", "reopen"));
         self.output_writer.borrow_mut().write_line("");
         self.output_writer
             .borrow_mut()
@@ -177,6 +232,25 @@ impl<O: OutputWriter> GeneralCommandOutputManager<O> {
                 "1, 1-5, 1,3,5, all",
             ));
         self.output_writer.borrow_mut().write_line("");
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "status <id|range|all> <state>",
+                "Set task(s) workflow status",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::subinfo(
+                "Examples:",
+                "1 in-progress, 1-5 blocked, all pending",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::subinfo(
+                "States:",
+                "pending, in-progress, waiting, blocked, completed, cancelled",
+            ));
+        self.output_writer.borrow_mut().write_line("");
         self.output_writer
             .borrow_mut()
             .write_line(&MessageFormatter::command(
@@ -209,7 +283,7 @@ impl<O: OutputWriter> GeneralCommandOutputManager<O> {
             .borrow_mut()
             .write_line(&MessageFormatter::subinfo(
                 "Format:",
-                "DD.MM.YYYY or 'none' to clear",
+                "DD.MM.YYYY, YYYY-MM-DD, today, tomorrow, fri, next monday, +3d, +2w, +1m, end-of-month or 'none' to clear",
             ));
         self.output_writer
             .borrow_mut()
@@ -226,7 +300,7 @@ impl<O: OutputWriter> GeneralCommandOutputManager<O> {
             .borrow_mut()
             .write_line(&MessageFormatter::command(
                 "set-category <id|range|all> <name>",
-                "Set task(s) category",
+                "Set task(s) category (replaces all tags)",
             ));
         self.output_writer
             .borrow_mut()
@@ -244,6 +318,25 @@ impl<O: OutputWriter> GeneralCommandOutputManager<O> {
             .borrow_mut()
             .write_line(&MessageFormatter::label("Aliases:", "category, cat"));
         self.output_writer.borrow_mut().write_line("");
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "tag <id|range|all> <tag> [tag...]",
+                "Add tag(s) to task(s)",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "untag <id|range|all> <tag> [tag...]",
+                "Remove tag(s) from task(s)",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::subinfo(
+                "Examples:",
+                "tag 1 work urgent, untag 1-5 urgent",
+            ));
+        self.output_writer.borrow_mut().write_line("");
         self.output_writer
             .borrow_mut()
             .write_line(&MessageFormatter::command(
@@ -266,6 +359,25 @@ impl<O: OutputWriter> GeneralCommandOutputManager<O> {
             .borrow_mut()
             .write_line(&MessageFormatter::label("Aliases:", "recurring, recur"));
         self.output_writer.borrow_mut().write_line("");
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "<command> where <filters> [value]",
+                "Select tasks by list filters instead of IDs",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::subinfo(
+                "Examples:",
+                "complete where overdue category:work, priority where todo low high",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::subinfo(
+                "Note:",
+                "the last word is the value; matched IDs are shown first",
+            ));
+        self.output_writer.borrow_mut().write_line("");
         self.output_writer
             .borrow_mut()
             .write_line(&MessageFormatter::command(
@@ -347,6 +459,19 @@ impl<O: OutputWriter> GeneralCommandOutputManager<O> {
             .borrow_mut()
             .write_line(&MessageFormatter::label("Alias:", "stats"));
         self.output_writer.borrow_mut().write_line("");
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "agenda [days]",
+                "Show open tasks due in the next days (default 7), grouped by day",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::subinfo(
+                "Groups:",
+                "overdue, today, tomorrow, this week, later; recurring tasks show their next occurrences",
+            ));
+        self.output_writer.borrow_mut().write_line("");
         self.output_writer
             .borrow_mut()
             .write_line(&MessageFormatter::command(
@@ -412,6 +537,75 @@ impl<O: OutputWriter> GeneralCommandOutputManager<O> {
                 "mv-project, move-project",
             ));
         self.output_writer.borrow_mut().write_line("");
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "restore-project <name>",
+                "Restore a deleted project from the trash",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "restore-backup [number]",
+                "List the backups of the projects file, or restore one",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "convert <file> [backend]",
+                "Copy all projects to a new file (json, compact-json or sqlite)",
+            ));
+        self.output_writer.borrow_mut().write_line("");
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "trash",
+                "Show removed tasks and deleted projects",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "restore <id>",
+                "Restore a removed task with its subtasks",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "empty-trash",
+                "Permanently delete everything in the trash",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "trash-retention <days>",
+                "Purge trash entries after the given number of days",
+            ));
+        self.output_writer.borrow_mut().write_line("");
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "undo",
+                "Revert the most recent change",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "redo",
+                "Reapply the most recently undone change",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "<command> --dry-run",
+                "Show which tasks would change, without changing them",
+            ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::command(
+                "<command> --yes",
+                "Skip the confirmation asked before removing or changing more than 5 tasks",
+            ));
+        self.output_writer.borrow_mut().write_line("");
         self.output_writer
             .borrow_mut()
             .write_line(&MessageFormatter::command("help", "Show this help message"));
@@ -517,7 +711,15 @@ mod tests {
         assert!(output.contains("remove <id|range|all>"));
         assert!(output.contains("complete <id|range|all>"));
         assert!(output.contains("priority <id|range|all> <level>"));
+        assert!(output.contains("status <id|range|all> <state>"));
+        assert!(output.contains("undo"));
+        assert!(output.contains("restore <id>"));
+        assert!(output.contains("empty-trash"));
+        assert!(output.contains("redo"));
+        assert!(output.contains("<command> --dry-run"));
+        assert!(output.contains("view save <name> <filters>"));
         assert!(output.contains("set-category <id|range|all> <name>"));
+        assert!(output.contains("tag <id|range|all> <tag> [tag...]"));
         assert!(output.contains("help"));
         assert!(output.contains("quit"));
     }
@@ -4,15 +4,18 @@
 //! the interaction between UI and model layers.
 
 pub mod application_controller;
+pub mod change_preview;
 pub mod command_controller;
 pub mod command_controller_registry;
 pub mod debug_command;
 pub mod general_command;
 pub mod project_command;
 pub mod task_command;
+pub mod undo_history;
 
 // Re-export commonly used types
 pub use application_controller::ApplicationController;
+pub use change_preview::ChangePreview;
 pub use command_controller::CommandController;
 pub use command_controller_registry::CommandControllerRegistry;
 pub use debug_command::DebugCommandController;
@@ -21,3 +24,4 @@ pub use general_command::GeneralCommandController;
 pub use project_command::ProjectCommandController;
 pub use project_command::ProjectManager;
 pub use task_command::TaskCommandController;
+pub use undo_history::UndoHistory;
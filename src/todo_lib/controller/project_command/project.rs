@@ -1,3 +1,6 @@
+use crate::persistence::StorageKind;
+use std::path::PathBuf;
+
 /// Represents project-related commands.
 ///
 /// These commands handle operations on projects including creation,
@@ -18,4 +21,16 @@ pub enum ProjectCommand {
 
     /// Rename a project
     RenameProject(String, String), // (old_name, new_name)
+
+    /// Restore a deleted project from the trash
+    RestoreProject(String),
+
+    /// List the backups of the projects file
+    ListBackups,
+
+    /// Replace all projects with a backup (1 is the newest)
+    RestoreBackup(usize),
+
+    /// Copy all projects into a new file of the given backend
+    Convert(PathBuf, StorageKind),
 }
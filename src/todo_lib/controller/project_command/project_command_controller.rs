@@ -6,25 +6,25 @@ use crate::controller::CommandController;
 use crate::models::command_controller_result::CommandControllerResult;
 use crate::models::command_controller_result::CommandControllerResultAction;
 use crate::models::parse_error::ParseError;
+use crate::persistence::StorageKind;
+use crate::service::TodoService;
 use crate::OutputWriter;
 use std::cell::RefCell;
+use std::path::Path;
 use std::rc::Rc;
 
 /// Handler for project commands and operations.
 pub struct ProjectCommandController<O: OutputWriter> {
-    project_manager: Rc<RefCell<ProjectManager>>,
+    service: Rc<RefCell<TodoService>>,
     input_parser: ProjectCommandInputParser,
     output_manager: ProjectCommandOutputManager<O>,
 }
 
 impl<O: OutputWriter> ProjectCommandController<O> {
     /// Creates a new ProjectCommandController.
-    pub fn new(
-        project_manager: Rc<RefCell<ProjectManager>>,
-        output_writer: Rc<RefCell<O>>,
-    ) -> Self {
+    pub fn new(service: Rc<RefCell<TodoService>>, output_writer: Rc<RefCell<O>>) -> Self {
         Self {
-            project_manager,
+            service,
             input_parser: ProjectCommandInputParser::new(),
             output_manager: ProjectCommandOutputManager::new(output_writer),
         }
@@ -40,45 +40,42 @@ impl<O: OutputWriter> ProjectCommandController<O> {
             ProjectCommand::RenameProject(old_name, new_name) => {
                 self.rename_project(old_name, new_name)
             }
+            ProjectCommand::RestoreProject(name) => self.restore_project(name),
+            ProjectCommand::ListBackups => self.list_backups(),
+            ProjectCommand::RestoreBackup(number) => self.restore_backup(*number),
+            ProjectCommand::Convert(path, kind) => self.convert(path, *kind),
         }
     }
 
     fn new_project(&mut self, name: &str) -> CommandControllerResult {
-        match self
-            .project_manager
-            .borrow_mut()
-            .create_project(name.to_string())
-        {
-            Some(()) => {
+        match self.service.borrow_mut().create_project(name) {
+            Ok(()) => {
                 self.output_manager.show_project_created(name);
                 CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
             }
-            None => {
-                self.output_manager.show_project_already_exists(name);
+            Err(err) => {
+                self.output_manager.show_todo_error(&err);
                 CommandControllerResult::default()
             }
         }
     }
 
     fn switch_project(&mut self, name: &str) -> CommandControllerResult {
-        match self
-            .project_manager
-            .borrow_mut()
-            .switch_project(name.to_string())
-        {
-            Some(()) => {
+        match self.service.borrow_mut().switch_project(name) {
+            Ok(()) => {
                 self.output_manager.show_switched_to_project(name);
                 CommandControllerResult::default()
             }
-            None => {
-                self.output_manager.show_project_not_found(name);
+            Err(err) => {
+                self.output_manager.show_todo_error(&err);
                 CommandControllerResult::default()
             }
         }
     }
 
     fn list_projects(&mut self) -> CommandControllerResult {
-        let project_manager = self.project_manager.borrow();
+        let service = self.service.borrow();
+        let project_manager = service.project_manager();
         let projects = project_manager.list_projects();
         let current_project = project_manager.get_current_project_name();
         self.output_manager
@@ -87,60 +84,134 @@ impl<O: OutputWriter> ProjectCommandController<O> {
     }
 
     fn delete_project(&mut self, name: &str) -> CommandControllerResult {
-        let current_project = self
-            .project_manager
-            .borrow()
-            .get_current_project_name()
-            .to_string();
-
-        if name == current_project {
-            self.output_manager.show_cannot_delete_current_project(name);
-            return CommandControllerResult::default();
+        match self.service.borrow_mut().delete_project(name) {
+            Ok(()) => {
+                self.output_manager.show_project_deleted(name);
+                CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+            }
+            Err(err) => {
+                self.output_manager.show_todo_error(&err);
+                CommandControllerResult::default()
+            }
         }
+    }
 
-        match self
-            .project_manager
-            .borrow_mut()
-            .delete_project(name.to_string())
-        {
-            Some(()) => {
-                self.output_manager.show_project_deleted(name);
+    fn restore_project(&mut self, name: &str) -> CommandControllerResult {
+        let restored = self.service.borrow_mut().restore_project(name);
+        match restored {
+            Ok(()) => {
+                self.output_manager.show_project_restored(name);
                 CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
             }
-            None => {
-                self.output_manager.show_project_not_found(name);
+            Err(err) => {
+                self.output_manager.show_todo_error(&err);
                 CommandControllerResult::default()
             }
         }
     }
 
+    fn list_backups(&mut self) -> CommandControllerResult {
+        let backups = match self.service.borrow().list_backups() {
+            Ok(backups) => backups,
+            Err(err) => {
+                self.output_manager.show_todo_error(&err);
+                return CommandControllerResult::default();
+            }
+        };
+
+        let summaries: Vec<_> = backups
+            .iter()
+            .map(|backup| {
+                let counts = backup
+                    .load()
+                    .ok()
+                    .map(|project_manager| counts(&project_manager));
+                (backup.created_at, counts)
+            })
+            .collect();
+        self.output_manager.show_backups(&summaries);
+        CommandControllerResult::default()
+    }
+
+    fn restore_backup(&mut self, number: usize) -> CommandControllerResult {
+        let restored = self.service.borrow_mut().restore_backup(number);
+        match restored {
+            Ok(backup) => {
+                self.output_manager.show_backup_restored(backup.created_at);
+                CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+            }
+            Err(err) => {
+                self.output_manager.show_todo_error(&err);
+                CommandControllerResult::default()
+            }
+        }
+    }
+
+    /// Loads a backup in place of the projects without changing the storage,
+    /// so that the dry run lists its differences.
+    fn preview_restore_backup(&mut self, number: usize) -> CommandControllerResult {
+        let mut service = self.service.borrow_mut();
+        match service.find_backup(number).and_then(|backup| backup.load()) {
+            Ok(project_manager) => service.replace_project_manager(project_manager),
+            Err(err) => self.output_manager.show_todo_error(&err),
+        }
+        CommandControllerResult::default()
+    }
+
+    fn convert(&mut self, path: &Path, kind: StorageKind) -> CommandControllerResult {
+        let target = kind.open(path);
+        let service = self.service.borrow();
+        match service.convert_to(target.as_ref()) {
+            Ok(()) => {
+                let (projects, tasks) = counts(service.project_manager());
+                self.output_manager
+                    .show_converted(&target.location(), kind, projects, tasks);
+            }
+            Err(err) => self.output_manager.show_todo_error(&err),
+        }
+        CommandControllerResult::default()
+    }
+
+    /// Describes what `convert` would write, without writing it.
+    fn preview_convert(&mut self, path: &Path, kind: StorageKind) -> CommandControllerResult {
+        let target = kind.open(path);
+        let service = self.service.borrow();
+        match service.check_convert_target(target.as_ref()) {
+            Ok(()) => {
+                let (projects, tasks) = counts(service.project_manager());
+                self.output_manager
+                    .show_convert_preview(&target.location(), kind, projects, tasks);
+            }
+            Err(err) => self.output_manager.show_todo_error(&err),
+        }
+        CommandControllerResult::with_action(CommandControllerResultAction::PreviewShown)
+    }
+
     fn rename_project(&mut self, old_name: &str, new_name: &str) -> CommandControllerResult {
-        match self
-            .project_manager
-            .borrow_mut()
-            .rename_project(old_name.to_string(), new_name.to_string())
-        {
-            Some(()) => {
+        match self.service.borrow_mut().rename_project(old_name, new_name) {
+            Ok(()) => {
                 self.output_manager.show_project_renamed(old_name, new_name);
                 CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
             }
-            None => {
-                if self
-                    .project_manager
-                    .borrow()
-                    .list_projects()
-                    .contains(&new_name.to_string())
-                {
-                    self.output_manager.show_project_already_exists(new_name);
-                } else {
-                    self.output_manager.show_project_not_found(old_name);
-                }
+            Err(err) => {
+                self.output_manager.show_todo_error(&err);
                 CommandControllerResult::default()
             }
         }
     }
 }
 
+/// Returns the number of projects and the number of tasks in all projects.
+fn counts(project_manager: &ProjectManager) -> (usize, usize) {
+    let task_count = project_manager
+        .list_projects()
+        .iter()
+        .filter_map(|name| project_manager.get_project(name))
+        .map(|project| project.todo_list.get_tasks().len())
+        .sum();
+    (project_manager.project_count(), task_count)
+}
+
 impl<O: OutputWriter> CommandController for ProjectCommandController<O> {
     fn try_execute(&mut self, input: &str) -> Option<Result<CommandControllerResult, ParseError>> {
         let parts: Vec<&str> = input.split_whitespace().collect();
@@ -161,37 +232,52 @@ impl<O: OutputWriter> CommandController for ProjectCommandController<O> {
             None => None,
         }
     }
+
+    fn try_preview(&mut self, input: &str) -> Option<Result<CommandControllerResult, ParseError>> {
+        let parts: Vec<&str> = input.split_whitespace().collect();
+        let (command, args) = parts.split_first()?;
+
+        match self.input_parser.try_parse(&command.to_lowercase(), args)? {
+            Ok(ProjectCommand::RestoreBackup(number)) => {
+                Some(Ok(self.preview_restore_backup(number)))
+            }
+            Ok(ProjectCommand::Convert(path, kind)) => Some(Ok(self.preview_convert(&path, kind))),
+            Ok(cmd) => Some(Ok(self.handle_command(&cmd))),
+            Err(err) => Some(Err(err)),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::controller::project_command::ProjectManager;
     use crate::ui::output::FileOutputWriter;
 
     #[test]
     fn test_new_project() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
         let mut buffer = Vec::new();
         let output_writer = Rc::new(RefCell::new(FileOutputWriter::new(&mut buffer)));
-        let mut controller =
-            ProjectCommandController::new(Rc::clone(&project_manager), output_writer);
+        let mut controller = ProjectCommandController::new(Rc::clone(&service), output_writer);
 
         let result = controller.new_project("Work");
         assert!(result.has_action(&CommandControllerResultAction::SaveTodoList));
-        assert_eq!(project_manager.borrow().project_count(), 2);
+        assert_eq!(service.borrow().project_manager().project_count(), 2);
     }
 
     #[test]
     fn test_new_project_duplicate() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
-        project_manager
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
+        service
             .borrow_mut()
-            .create_project("Work".to_string());
+            .project_manager_mut()
+            .create_project("Work".to_string())
+            .unwrap();
 
         let mut buffer = Vec::new();
         let output_writer = Rc::new(RefCell::new(FileOutputWriter::new(&mut buffer)));
-        let mut controller =
-            ProjectCommandController::new(Rc::clone(&project_manager), output_writer);
+        let mut controller = ProjectCommandController::new(Rc::clone(&service), output_writer);
 
         let result = controller.new_project("Work");
         assert!(!result.has_action(&CommandControllerResultAction::SaveTodoList));
@@ -199,34 +285,44 @@ mod tests {
 
     #[test]
     fn test_switch_project() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
-        project_manager
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
+        service
             .borrow_mut()
-            .create_project("Work".to_string());
+            .project_manager_mut()
+            .create_project("Work".to_string())
+            .unwrap();
 
         let mut buffer = Vec::new();
         let output_writer = Rc::new(RefCell::new(FileOutputWriter::new(&mut buffer)));
-        let mut controller =
-            ProjectCommandController::new(Rc::clone(&project_manager), output_writer);
+        let mut controller = ProjectCommandController::new(Rc::clone(&service), output_writer);
 
         controller.switch_project("Work");
-        assert_eq!(project_manager.borrow().get_current_project_name(), "Work");
+        assert_eq!(
+            service
+                .borrow()
+                .project_manager()
+                .get_current_project_name(),
+            "Work"
+        );
     }
 
     #[test]
     fn test_list_projects() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
-        project_manager
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
+        service
             .borrow_mut()
-            .create_project("Work".to_string());
-        project_manager
+            .project_manager_mut()
+            .create_project("Work".to_string())
+            .unwrap();
+        service
             .borrow_mut()
-            .create_project("Personal".to_string());
+            .project_manager_mut()
+            .create_project("Personal".to_string())
+            .unwrap();
 
         let mut buffer = Vec::new();
         let output_writer = Rc::new(RefCell::new(FileOutputWriter::new(&mut buffer)));
-        let mut controller =
-            ProjectCommandController::new(Rc::clone(&project_manager), output_writer);
+        let mut controller = ProjectCommandController::new(Rc::clone(&service), output_writer);
 
         controller.list_projects();
         // Should not crash and should display projects
@@ -234,29 +330,29 @@ mod tests {
 
     #[test]
     fn test_delete_project() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
-        project_manager
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
+        service
             .borrow_mut()
-            .create_project("Work".to_string());
+            .project_manager_mut()
+            .create_project("Work".to_string())
+            .unwrap();
 
         let mut buffer = Vec::new();
         let output_writer = Rc::new(RefCell::new(FileOutputWriter::new(&mut buffer)));
-        let mut controller =
-            ProjectCommandController::new(Rc::clone(&project_manager), output_writer);
+        let mut controller = ProjectCommandController::new(Rc::clone(&service), output_writer);
 
         let result = controller.delete_project("Work");
         assert!(result.has_action(&CommandControllerResultAction::SaveTodoList));
-        assert_eq!(project_manager.borrow().project_count(), 1);
+        assert_eq!(service.borrow().project_manager().project_count(), 1);
     }
 
     #[test]
     fn test_delete_current_project() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
 
         let mut buffer = Vec::new();
         let output_writer = Rc::new(RefCell::new(FileOutputWriter::new(&mut buffer)));
-        let mut controller =
-            ProjectCommandController::new(Rc::clone(&project_manager), output_writer);
+        let mut controller = ProjectCommandController::new(Rc::clone(&service), output_writer);
 
         let result = controller.delete_project("default");
         assert!(!result.has_action(&CommandControllerResultAction::SaveTodoList));
@@ -264,17 +360,19 @@ mod tests {
 
     #[test]
     fn test_rename_project() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
 
         let mut buffer = Vec::new();
         let output_writer = Rc::new(RefCell::new(FileOutputWriter::new(&mut buffer)));
-        let mut controller =
-            ProjectCommandController::new(Rc::clone(&project_manager), output_writer);
+        let mut controller = ProjectCommandController::new(Rc::clone(&service), output_writer);
 
         let result = controller.rename_project("default", "personal");
         assert!(result.has_action(&CommandControllerResultAction::SaveTodoList));
         assert_eq!(
-            project_manager.borrow().get_current_project_name(),
+            service
+                .borrow()
+                .project_manager()
+                .get_current_project_name(),
             "personal"
         );
     }
@@ -1,5 +1,7 @@
 use crate::controller::project_command::ProjectCommand;
 use crate::models::parse_error::ParseError;
+use crate::persistence::StorageKind;
+use std::path::PathBuf;
 
 /// Parser for project-related commands.
 pub struct ProjectCommandInputParser;
@@ -39,6 +41,9 @@ impl ProjectCommandInputParser {
                 Some(self.parse_delete_project_command(args))
             }
             "rename-project" | "mv-project" => Some(self.parse_rename_project_command(args)),
+            "restore-project" => Some(self.parse_restore_project_command(args)),
+            "restore-backup" => Some(self.parse_restore_backup_command(args)),
+            "convert" => Some(self.parse_convert_command(args)),
             _ => None,
         }
     }
@@ -94,6 +99,76 @@ impl ProjectCommandInputParser {
         }
     }
 
+    /// Parses the 'restore-project' command.
+    fn parse_restore_project_command(&self, args: &[&str]) -> Result<ProjectCommand, ParseError> {
+        if args.is_empty() {
+            Err(ParseError::MissingArguments {
+                command: "restore-project".to_string(),
+                usage: "restore-project <name>".to_string(),
+            })
+        } else {
+            let name = args.join(" ");
+            if name.trim().is_empty() {
+                Err(ParseError::EmptyInput("Project name".to_string()))
+            } else {
+                Ok(ProjectCommand::RestoreProject(name))
+            }
+        }
+    }
+
+    /// Parses the 'restore-backup' command.
+    ///
+    /// Without arguments the command lists the backups.
+    fn parse_restore_backup_command(&self, args: &[&str]) -> Result<ProjectCommand, ParseError> {
+        if args.is_empty() {
+            return Ok(ProjectCommand::ListBackups);
+        }
+
+        let number = args.join(" ");
+        number
+            .parse::<usize>()
+            .ok()
+            .filter(|&number| number > 0)
+            .map(ProjectCommand::RestoreBackup)
+            .ok_or(ParseError::InvalidValue {
+                field: "backup number".to_string(),
+                value: number,
+                allowed: "a number from the 'restore-backup' list".to_string(),
+            })
+    }
+
+    /// Parses the 'convert' command.
+    ///
+    /// The backend is taken from the last argument if it names one, and
+    /// otherwise from the file extension.
+    fn parse_convert_command(&self, args: &[&str]) -> Result<ProjectCommand, ParseError> {
+        let (path_args, kind) = match args.split_last() {
+            Some((last, rest)) if !rest.is_empty() => match StorageKind::parse(last) {
+                Some(kind) => (rest, Some(kind)),
+                None => (args, None),
+            },
+            _ => (args, None),
+        };
+
+        let path = path_args.join(" ");
+        if path.trim().is_empty() {
+            return Err(ParseError::MissingArguments {
+                command: "convert".to_string(),
+                usage: "convert <file> [json|compact-json|sqlite]".to_string(),
+            });
+        }
+
+        let kind = kind.unwrap_or_else(|| StorageKind::from_path(&path));
+        if kind == StorageKind::Memory {
+            return Err(ParseError::InvalidValue {
+                field: "backend".to_string(),
+                value: kind.as_str().to_string(),
+                allowed: "json, compact-json, sqlite".to_string(),
+            });
+        }
+        Ok(ProjectCommand::Convert(PathBuf::from(path), kind))
+    }
+
     /// Parses the 'rename-project' command.
     fn parse_rename_project_command(&self, args: &[&str]) -> Result<ProjectCommand, ParseError> {
         if args.len() < 2 {
@@ -192,6 +267,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_restore_backup() {
+        let parser = ProjectCommandInputParser::new();
+        assert_eq!(
+            parser.try_parse("restore-backup", &[]),
+            Some(Ok(ProjectCommand::ListBackups))
+        );
+        assert_eq!(
+            parser.try_parse("restore-backup", &["2"]),
+            Some(Ok(ProjectCommand::RestoreBackup(2)))
+        );
+        assert!(matches!(
+            parser.try_parse("restore-backup", &["0"]),
+            Some(Err(ParseError::InvalidValue { .. }))
+        ));
+        assert!(matches!(
+            parser.try_parse("restore-backup", &["latest"]),
+            Some(Err(ParseError::InvalidValue { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_parse_convert() {
+        let parser = ProjectCommandInputParser::new();
+        assert_eq!(
+            parser.try_parse("convert", &["tasks.db"]),
+            Some(Ok(ProjectCommand::Convert(
+                PathBuf::from("tasks.db"),
+                StorageKind::Sqlite
+            )))
+        );
+        assert_eq!(
+            parser.try_parse("convert", &["my", "tasks.json", "compact-json"]),
+            Some(Ok(ProjectCommand::Convert(
+                PathBuf::from("my tasks.json"),
+                StorageKind::CompactJson
+            )))
+        );
+        assert!(matches!(
+            parser.try_parse("convert", &[]),
+            Some(Err(ParseError::MissingArguments { .. }))
+        ));
+        assert!(matches!(
+            parser.try_parse("convert", &["copy", "memory"]),
+            Some(Err(ParseError::InvalidValue { .. }))
+        ));
+    }
+
     #[test]
     fn test_unrecognized_command() {
         let parser = ProjectCommandInputParser::new();
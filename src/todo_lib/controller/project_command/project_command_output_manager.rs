@@ -1,4 +1,8 @@
+use crate::models::TodoError;
+use crate::persistence::StorageKind;
+use crate::ui::formatters::MessageFormatter;
 use crate::OutputWriter;
+use chrono::NaiveDateTime;
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -31,7 +35,14 @@ impl<O: OutputWriter> ProjectCommandOutputManager<O> {
     pub fn show_project_deleted(&mut self, name: &str) {
         self.output_writer
             .borrow_mut()
-            .show_success(&format!("Deleted project '{}'", name));
+            .show_success(&format!("Deleted project '{}' (moved to trash)", name));
+    }
+
+    /// Displays a success message after restoring a project from the trash.
+    pub fn show_project_restored(&mut self, name: &str) {
+        self.output_writer
+            .borrow_mut()
+            .show_success(&format!("Restored project '{}'", name));
     }
 
     /// Displays a success message after renaming a project.
@@ -65,28 +76,88 @@ impl<O: OutputWriter> ProjectCommandOutputManager<O> {
         output.write_line("");
     }
 
-    /// Displays an error when a project already exists.
-    pub fn show_project_already_exists(&mut self, name: &str) {
-        self.output_writer
-            .borrow_mut()
-            .show_error(&format!("Project '{}' already exists", name));
+    /// Displays the backups, newest first, with their project and task
+    /// counts (None for a backup that cannot be read).
+    pub fn show_backups(&mut self, backups: &[(NaiveDateTime, Option<(usize, usize)>)]) {
+        let mut output = self.output_writer.borrow_mut();
+
+        output.write_line("");
+        output.write_line("--- Backups (newest first) ---");
+
+        if backups.is_empty() {
+            output
+                .write_line("No backups found. A backup is kept each time the projects are saved.");
+        } else {
+            for (index, (created_at, counts)) in backups.iter().enumerate() {
+                let contents = match counts {
+                    Some((projects, tasks)) => {
+                        format!("{} project(s), {} task(s)", projects, tasks)
+                    }
+                    None => "unreadable".to_string(),
+                };
+                output.write_line(&format!(
+                    "  {}. {}  ({})",
+                    index + 1,
+                    created_at.format("%d.%m.%Y %H:%M:%S"),
+                    contents
+                ));
+            }
+            output.write_line("\nRestore one with: restore-backup <number>");
+        }
+
+        output.write_line(&"-".repeat(40));
+        output.write_line("");
+    }
+
+    /// Displays a success message after restoring a backup.
+    pub fn show_backup_restored(&mut self, created_at: NaiveDateTime) {
+        self.output_writer.borrow_mut().show_success(&format!(
+            "Restored the backup from {} (use 'undo' to go back)",
+            created_at.format("%d.%m.%Y %H:%M:%S")
+        ));
     }
 
-    /// Displays an error when a project is not found.
-    pub fn show_project_not_found(&mut self, name: &str) {
+    /// Displays what `convert` would write during a dry run.
+    pub fn show_convert_preview(
+        &mut self,
+        location: &str,
+        kind: StorageKind,
+        projects: usize,
+        tasks: usize,
+    ) {
         self.output_writer
             .borrow_mut()
-            .show_error(&format!("Project '{}' not found", name));
+            .write_line(&MessageFormatter::warning(&format!(
+            "Dry run: would write {} project(s) with {} task(s) to {} ({}); nothing was written",
+            projects,
+            tasks,
+            location,
+            kind.as_str()
+        )));
     }
 
-    /// Displays an error when trying to delete the current project.
-    pub fn show_cannot_delete_current_project(&mut self, name: &str) {
-        self.output_writer.borrow_mut().show_error(&format!(
-            "Cannot delete current project '{}'. Switch to another project first.",
-            name
+    /// Displays a success message after copying the projects to another storage.
+    pub fn show_converted(
+        &mut self,
+        location: &str,
+        kind: StorageKind,
+        projects: usize,
+        tasks: usize,
+    ) {
+        self.output_writer.borrow_mut().show_success(&format!(
+            "Copied {} project(s) with {} task(s) to {} ({})",
+            projects,
+            tasks,
+            location,
+            kind.as_str()
         ));
     }
 
+    /// Displays why a project operation failed.
+    pub fn show_todo_error(&mut self, error: &TodoError) {
+        self.output_writer.borrow_mut().show_error(&error.message());
+    }
+
     /// Displays a generic error message.
     pub fn show_error(&mut self, message: &str) {
         self.output_writer.borrow_mut().show_error(message);
@@ -142,13 +213,54 @@ mod tests {
         assert!(output.contains("Total: 3"));
     }
 
+    #[test]
+    fn test_show_backups() {
+        let mut buffer = Vec::new();
+        let output_writer = FileOutputWriter::new(&mut buffer);
+        let mut manager = ProjectCommandOutputManager::new(Rc::new(RefCell::new(output_writer)));
+
+        let created_at = chrono::NaiveDate::from_ymd_opt(2025, 1, 17)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+        manager.show_backups(&[(created_at, Some((2, 5))), (created_at, None)]);
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("1. 17.01.2025 09:30:00  (2 project(s), 5 task(s))"));
+        assert!(output.contains("2. 17.01.2025 09:30:00  (unreadable)"));
+    }
+
+    #[test]
+    fn test_show_converted() {
+        let mut buffer = Vec::new();
+        let output_writer = FileOutputWriter::new(&mut buffer);
+        let mut manager = ProjectCommandOutputManager::new(Rc::new(RefCell::new(output_writer)));
+
+        manager.show_converted("tasks.db", StorageKind::Sqlite, 2, 5);
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("Copied 2 project(s) with 5 task(s) to tasks.db (sqlite)"));
+    }
+
+    #[test]
+    fn test_show_convert_preview() {
+        let mut buffer = Vec::new();
+        let output_writer = FileOutputWriter::new(&mut buffer);
+        let mut manager = ProjectCommandOutputManager::new(Rc::new(RefCell::new(output_writer)));
+
+        manager.show_convert_preview("tasks.db", StorageKind::Sqlite, 2, 5);
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("would write 2 project(s) with 5 task(s) to tasks.db (sqlite)"));
+    }
+
     #[test]
     fn test_show_project_already_exists() {
         let mut buffer = Vec::new();
         let output_writer = FileOutputWriter::new(&mut buffer);
         let mut manager = ProjectCommandOutputManager::new(Rc::new(RefCell::new(output_writer)));
 
-        manager.show_project_already_exists("Work");
+        manager.show_todo_error(&TodoError::ProjectExists("Work".to_string()));
 
         let output = String::from_utf8(buffer).unwrap();
         assert!(output.contains("already exists"));
@@ -160,9 +272,21 @@ mod tests {
         let output_writer = FileOutputWriter::new(&mut buffer);
         let mut manager = ProjectCommandOutputManager::new(Rc::new(RefCell::new(output_writer)));
 
-        manager.show_project_not_found("NonExistent");
+        manager.show_todo_error(&TodoError::ProjectNotFound("NonExistent".to_string()));
 
         let output = String::from_utf8(buffer).unwrap();
         assert!(output.contains("not found"));
     }
+
+    #[test]
+    fn test_show_cannot_delete_current_project() {
+        let mut buffer = Vec::new();
+        let output_writer = FileOutputWriter::new(&mut buffer);
+        let mut manager = ProjectCommandOutputManager::new(Rc::new(RefCell::new(output_writer)));
+
+        manager.show_todo_error(&TodoError::CurrentProject("Work".to_string()));
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("Cannot delete current project 'Work'"));
+    }
 }
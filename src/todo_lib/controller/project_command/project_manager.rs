@@ -1,5 +1,9 @@
 use crate::models::project::Project;
+use crate::models::saved_view::SavedView;
+use crate::models::todo_error::TodoError;
 use crate::models::todo_list::TodoList;
+use crate::models::trash::{TrashedProject, DEFAULT_TRASH_RETENTION_DAYS};
+use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -7,13 +11,29 @@ use std::collections::HashMap;
 ///
 /// The ProjectManager allows users to organize tasks into separate projects,
 /// switch between them, and manage them independently.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProjectManager {
     /// Map of project names to Project instances
     projects: HashMap<String, Project>,
 
     /// Name of the currently active project
     current_project: String,
+
+    /// Deleted projects that can still be restored
+    #[serde(default)]
+    trash: Vec<TrashedProject>,
+
+    /// Number of days after which trash entries are purged
+    #[serde(default = "default_trash_retention_days")]
+    trash_retention_days: u32,
+
+    /// Saved list filters, shared by all projects
+    #[serde(default)]
+    views: Vec<SavedView>,
+}
+
+fn default_trash_retention_days() -> u32 {
+    DEFAULT_TRASH_RETENTION_DAYS
 }
 
 impl ProjectManager {
@@ -38,13 +58,16 @@ impl ProjectManager {
         Self {
             projects,
             current_project: default_project_name,
+            trash: Vec::new(),
+            trash_retention_days: DEFAULT_TRASH_RETENTION_DAYS,
+            views: Vec::new(),
         }
     }
 
     /// Creates a new project with the given name.
     ///
-    /// Returns `Some(())` if the project was created successfully,
-    /// or `None` if a project with that name already exists.
+    /// Returns `Ok(())` if the project was created successfully,
+    /// or `TodoError::ProjectExists` if a project with that name already exists.
     ///
     /// # Arguments
     ///
@@ -56,22 +79,23 @@ impl ProjectManager {
     /// use todo_manager::controller::project_command::ProjectManager;
     ///
     /// let mut manager = ProjectManager::new();
-    /// assert!(manager.create_project("Work".to_string()).is_some());
-    /// assert!(manager.create_project("Work".to_string()).is_none()); // Already exists
+    /// assert!(manager.create_project("Work".to_string()).is_ok());
+    /// assert!(manager.create_project("Work".to_string()).is_err()); // Already exists
     /// ```
-    pub fn create_project(&mut self, name: String) -> Option<()> {
+    pub fn create_project(&mut self, name: String) -> Result<(), TodoError> {
         if self.projects.contains_key(&name) {
-            None
+            Err(TodoError::ProjectExists(name))
         } else {
             self.projects.insert(name.clone(), Project::new(name));
-            Some(())
+            Ok(())
         }
     }
 
-    /// Deletes a project by name.
+    /// Deletes a project by name and moves it to the project trash.
     ///
-    /// Returns `Some(())` if the project was deleted successfully,
-    /// or `None` if the project doesn't exist or is the current project.
+    /// Returns `Ok(())` if the project was deleted successfully,
+    /// `TodoError::CurrentProject` if it is the current project,
+    /// or `TodoError::ProjectNotFound` if the project doesn't exist.
     ///
     /// Cannot delete the current project - switch to another first.
     ///
@@ -85,29 +109,195 @@ impl ProjectManager {
     /// use todo_manager::controller::project_command::ProjectManager;
     ///
     /// let mut manager = ProjectManager::new();
-    /// manager.create_project("Work".to_string());
-    /// assert!(manager.delete_project("Work".to_string()).is_some());
-    /// assert!(manager.delete_project("Work".to_string()).is_none()); // Already deleted
+    /// manager.create_project("Work".to_string()).unwrap();
+    /// assert!(manager.delete_project("Work".to_string()).is_ok());
+    /// assert!(manager.delete_project("Work".to_string()).is_err()); // Already deleted
     /// ```
-    pub fn delete_project(&mut self, name: String) -> Option<()> {
+    pub fn delete_project(&mut self, name: String) -> Result<(), TodoError> {
         // Can't delete current project
         if name == self.current_project {
-            return None;
+            return Err(TodoError::CurrentProject(name));
         }
 
         // Can't delete if doesn't exist
-        if !self.projects.contains_key(&name) {
-            return None;
+        let Some(project) = self.projects.remove(&name) else {
+            return Err(TodoError::ProjectNotFound(name));
+        };
+
+        self.trash.push(TrashedProject {
+            project,
+            deleted_at: Local::now(),
+        });
+        Ok(())
+    }
+
+    /// Returns the deleted projects that can still be restored, oldest first.
+    pub fn get_trashed_projects(&self) -> &Vec<TrashedProject> {
+        &self.trash
+    }
+
+    /// Restores a deleted project from the project trash.
+    ///
+    /// Returns `Ok(())` if the project was restored, `TodoError::ProjectExists`
+    /// if a project with the same name exists again, or
+    /// `TodoError::ProjectNotInTrash` if it is not in the trash.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the deleted project
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::controller::project_command::ProjectManager;
+    ///
+    /// let mut manager = ProjectManager::new();
+    /// manager.create_project("Work".to_string()).unwrap();
+    /// manager.delete_project("Work".to_string()).unwrap();
+    /// assert_eq!(manager.get_trashed_projects().len(), 1);
+    ///
+    /// assert!(manager.restore_project("Work").is_ok());
+    /// assert!(manager.get_project("Work").is_some());
+    /// assert!(manager.get_trashed_projects().is_empty());
+    /// ```
+    pub fn restore_project(&mut self, name: &str) -> Result<(), TodoError> {
+        if self.projects.contains_key(name) {
+            return Err(TodoError::ProjectExists(name.to_string()));
         }
 
-        self.projects.remove(&name);
-        Some(())
+        // Restore the most recently deleted project with that name
+        let pos = self
+            .trash
+            .iter()
+            .rposition(|entry| entry.project.name == name)
+            .ok_or_else(|| TodoError::ProjectNotInTrash(name.to_string()))?;
+        let entry = self.trash.remove(pos);
+        self.projects.insert(name.to_string(), entry.project);
+        Ok(())
+    }
+
+    /// Permanently deletes all projects in the project trash.
+    ///
+    /// Returns the number of deleted projects.
+    pub fn empty_project_trash(&mut self) -> usize {
+        let count = self.trash.len();
+        self.trash.clear();
+        count
+    }
+
+    /// Returns the number of days after which trash entries are purged.
+    pub fn get_trash_retention_days(&self) -> u32 {
+        self.trash_retention_days
+    }
+
+    /// Sets the number of days after which trash entries are purged.
+    pub fn set_trash_retention_days(&mut self, days: u32) {
+        self.trash_retention_days = days;
+    }
+
+    /// Saves a view, replacing an existing view with the same name.
+    ///
+    /// View names are compared case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// `true` if an existing view was replaced
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::controller::project_command::ProjectManager;
+    /// use todo_manager::models::saved_view::SavedView;
+    ///
+    /// let mut manager = ProjectManager::new();
+    /// assert!(!manager.save_view(SavedView::new("work".to_string(), "+work".to_string())));
+    /// assert!(manager.save_view(SavedView::new("Work".to_string(), "+work high".to_string())));
+    /// assert_eq!(manager.get_view("WORK").unwrap().filter, "+work high");
+    /// assert_eq!(manager.get_views().len(), 1);
+    /// ```
+    pub fn save_view(&mut self, view: SavedView) -> bool {
+        match self
+            .views
+            .iter_mut()
+            .find(|existing| existing.name.eq_ignore_ascii_case(&view.name))
+        {
+            Some(existing) => {
+                *existing = view;
+                true
+            }
+            None => {
+                self.views.push(view);
+                false
+            }
+        }
+    }
+
+    /// Returns the saved view with the given name.
+    pub fn get_view(&self, name: &str) -> Option<&SavedView> {
+        self.views
+            .iter()
+            .find(|view| view.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns all saved views in the order they were created.
+    pub fn get_views(&self) -> &Vec<SavedView> {
+        &self.views
+    }
+
+    /// Deletes the saved view with the given name.
+    ///
+    /// Returns the deleted view, or `None` if no view has that name.
+    pub fn delete_view(&mut self, name: &str) -> Option<SavedView> {
+        let pos = self
+            .views
+            .iter()
+            .position(|view| view.name.eq_ignore_ascii_case(name))?;
+        Some(self.views.remove(pos))
+    }
+
+    /// Permanently deletes trash entries of all projects that are older than the retention period.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time
+    ///
+    /// # Returns
+    ///
+    /// The number of purged entries (tasks and projects)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::controller::project_command::ProjectManager;
+    /// use chrono::{Duration, Local};
+    ///
+    /// let mut manager = ProjectManager::new();
+    /// manager.create_project("Work".to_string()).unwrap();
+    /// manager.delete_project("Work".to_string()).unwrap();
+    /// manager.set_trash_retention_days(7);
+    ///
+    /// assert_eq!(manager.purge_expired_trash(Local::now()), 0);
+    /// assert_eq!(manager.purge_expired_trash(Local::now() + Duration::days(8)), 1);
+    /// ```
+    pub fn purge_expired_trash(&mut self, now: DateTime<Local>) -> usize {
+        let retention_days = self.trash_retention_days;
+        let mut purged = self
+            .projects
+            .values_mut()
+            .map(|project| project.todo_list.purge_trash(retention_days, now))
+            .sum();
+
+        let count = self.trash.len();
+        self.trash
+            .retain(|entry| !entry.is_expired(retention_days, now));
+        purged += count - self.trash.len();
+        purged
     }
 
     /// Switches to a different project.
     ///
-    /// Returns `Some(())` if the switch was successful,
-    /// or `None` if the project doesn't exist.
+    /// Returns `Ok(())` if the switch was successful,
+    /// or `TodoError::ProjectNotFound` if the project doesn't exist.
     ///
     /// # Arguments
     ///
@@ -119,23 +309,23 @@ impl ProjectManager {
     /// use todo_manager::controller::project_command::ProjectManager;
     ///
     /// let mut manager = ProjectManager::new();
-    /// manager.create_project("Work".to_string());
-    /// assert!(manager.switch_project("Work".to_string()).is_some());
+    /// manager.create_project("Work".to_string()).unwrap();
+    /// assert!(manager.switch_project("Work".to_string()).is_ok());
     /// assert_eq!(manager.get_current_project_name(), "Work");
     /// ```
-    pub fn switch_project(&mut self, name: String) -> Option<()> {
+    pub fn switch_project(&mut self, name: String) -> Result<(), TodoError> {
         if self.projects.contains_key(&name) {
             self.current_project = name;
-            Some(())
+            Ok(())
         } else {
-            None
+            Err(TodoError::ProjectNotFound(name))
         }
     }
 
     /// Renames a project.
     ///
-    /// Returns `Some(())` if the rename was successful,
-    /// or `None` if the old name doesn't exist or the new name already exists.
+    /// Returns `Ok(())` if the rename was successful, `TodoError::ProjectExists`
+    /// if the new name is taken, or `TodoError::ProjectNotFound` if the old name doesn't exist.
     ///
     /// # Arguments
     ///
@@ -148,34 +338,28 @@ impl ProjectManager {
     /// use todo_manager::controller::project_command::ProjectManager;
     ///
     /// let mut manager = ProjectManager::new();
-    /// assert!(manager.rename_project("default".to_string(), "personal".to_string()).is_some());
+    /// assert!(manager.rename_project("default".to_string(), "personal".to_string()).is_ok());
     /// assert_eq!(manager.get_current_project_name(), "personal");
     /// ```
-    pub fn rename_project(&mut self, old_name: String, new_name: String) -> Option<()> {
+    pub fn rename_project(&mut self, old_name: String, new_name: String) -> Result<(), TodoError> {
         // Can't rename to a name that already exists
         if self.projects.contains_key(&new_name) {
-            return None;
-        }
-
-        // Can't rename if old name doesn't exist
-        if !self.projects.contains_key(&old_name) {
-            return None;
+            return Err(TodoError::ProjectExists(new_name));
         }
 
         // Remove the old entry and create a new one with updated name
-        if let Some(mut project) = self.projects.remove(&old_name) {
-            project.name = new_name.clone();
-            self.projects.insert(new_name.clone(), project);
-
-            // Update current project if it was the renamed one
-            if self.current_project == old_name {
-                self.current_project = new_name;
-            }
-
-            Some(())
-        } else {
-            None
+        let Some(mut project) = self.projects.remove(&old_name) else {
+            return Err(TodoError::ProjectNotFound(old_name));
+        };
+        project.name = new_name.clone();
+        self.projects.insert(new_name.clone(), project);
+
+        // Update current project if it was the renamed one
+        if self.current_project == old_name {
+            self.current_project = new_name;
         }
+
+        Ok(())
     }
 
     /// Returns the name of the current project.
@@ -228,6 +412,21 @@ impl ProjectManager {
             .todo_list
     }
 
+    /// Returns a reference to the project with the given name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::controller::project_command::ProjectManager;
+    ///
+    /// let manager = ProjectManager::new();
+    /// assert!(manager.get_project("default").is_some());
+    /// assert!(manager.get_project("Work").is_none());
+    /// ```
+    pub fn get_project(&self, name: &str) -> Option<&Project> {
+        self.projects.get(name)
+    }
+
     /// Returns a list of all project names.
     ///
     /// # Examples
@@ -236,8 +435,8 @@ impl ProjectManager {
     /// use todo_manager::controller::project_command::ProjectManager;
     ///
     /// let mut manager = ProjectManager::new();
-    /// manager.create_project("Work".to_string());
-    /// manager.create_project("Personal".to_string());
+    /// manager.create_project("Work".to_string()).unwrap();
+    /// manager.create_project("Personal".to_string()).unwrap();
     ///
     /// let projects = manager.list_projects();
     /// assert_eq!(projects.len(), 3);
@@ -261,7 +460,7 @@ impl ProjectManager {
     /// let mut manager = ProjectManager::new();
     /// assert_eq!(manager.project_count(), 1); // default project
     ///
-    /// manager.create_project("Work".to_string());
+    /// manager.create_project("Work".to_string()).unwrap();
     /// assert_eq!(manager.project_count(), 2);
     /// ```
     pub fn project_count(&self) -> usize {
@@ -290,40 +489,111 @@ mod tests {
     #[test]
     fn test_create_project() {
         let mut manager = ProjectManager::new();
-        assert!(manager.create_project("Work".to_string()).is_some());
+        assert!(manager.create_project("Work".to_string()).is_ok());
         assert_eq!(manager.project_count(), 2);
 
         // Can't create duplicate
-        assert!(manager.create_project("Work".to_string()).is_none());
+        assert_eq!(
+            manager.create_project("Work".to_string()),
+            Err(TodoError::ProjectExists("Work".to_string()))
+        );
     }
 
     #[test]
     fn test_delete_project() {
         let mut manager = ProjectManager::new();
-        manager.create_project("Work".to_string());
-        manager.create_project("Personal".to_string());
+        manager.create_project("Work".to_string()).unwrap();
+        manager.create_project("Personal".to_string()).unwrap();
 
         // Can delete non-current project
-        assert!(manager.delete_project("Work".to_string()).is_some());
+        assert!(manager.delete_project("Work".to_string()).is_ok());
         assert_eq!(manager.project_count(), 2);
 
         // Can't delete current project
-        assert!(manager.delete_project("default".to_string()).is_none());
+        assert_eq!(
+            manager.delete_project("default".to_string()),
+            Err(TodoError::CurrentProject("default".to_string()))
+        );
 
         // Can't delete non-existent project
-        assert!(manager.delete_project("Work".to_string()).is_none());
+        assert_eq!(
+            manager.delete_project("Work".to_string()),
+            Err(TodoError::ProjectNotFound("Work".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_deleted_project_keeps_tasks_in_trash() {
+        let mut manager = ProjectManager::new();
+        manager.create_project("Work".to_string()).unwrap();
+        manager.switch_project("Work".to_string()).unwrap();
+        manager
+            .get_current_todo_list_mut()
+            .add_task(TaskWithoutId::new("Report".to_string()));
+        manager.switch_project("default".to_string()).unwrap();
+        manager.delete_project("Work".to_string()).unwrap();
+
+        let trashed = manager.get_trashed_projects();
+        assert_eq!(trashed.len(), 1);
+        assert_eq!(trashed[0].project.todo_list.get_tasks().len(), 1);
+
+        // Restoring fails while a project with the same name exists
+        manager.create_project("Work".to_string()).unwrap();
+        assert_eq!(
+            manager.restore_project("Work"),
+            Err(TodoError::ProjectExists("Work".to_string()))
+        );
+        manager.delete_project("Work".to_string()).unwrap();
+        assert_eq!(manager.get_trashed_projects().len(), 2);
+
+        // The most recently deleted project is restored first
+        assert!(manager.restore_project("Work").is_ok());
+        manager.switch_project("Work".to_string()).unwrap();
+        assert!(manager.get_current_todo_list().is_empty());
+        assert_eq!(manager.empty_project_trash(), 1);
+    }
+
+    #[test]
+    fn test_purge_expired_trash_covers_all_projects() {
+        let mut manager = ProjectManager::new();
+        manager.create_project("Work".to_string()).unwrap();
+        manager.switch_project("Work".to_string()).unwrap();
+        let id = manager
+            .get_current_todo_list_mut()
+            .add_task(TaskWithoutId::new("Report".to_string()));
+        manager.get_current_todo_list_mut().remove_task(id).unwrap();
+        manager.switch_project("default".to_string()).unwrap();
+        let id = manager
+            .get_current_todo_list_mut()
+            .add_task(TaskWithoutId::new("Chore".to_string()));
+        manager.get_current_todo_list_mut().remove_task(id).unwrap();
+
+        let later = chrono::Local::now() + chrono::Duration::days(31);
+        assert_eq!(manager.get_trash_retention_days(), 30);
+        assert_eq!(manager.purge_expired_trash(later), 2);
+    }
+
+    #[test]
+    fn test_load_project_manager_without_trash() {
+        let json = r#"{"projects": {"default": {"name": "default", "todo_list": {"tasks": [], "next_id": 1}}}, "current_project": "default"}"#;
+        let manager: ProjectManager = serde_json::from_str(json).unwrap();
+        assert!(manager.get_trashed_projects().is_empty());
+        assert_eq!(manager.get_trash_retention_days(), 30);
     }
 
     #[test]
     fn test_switch_project() {
         let mut manager = ProjectManager::new();
-        manager.create_project("Work".to_string());
+        manager.create_project("Work".to_string()).unwrap();
 
-        assert!(manager.switch_project("Work".to_string()).is_some());
+        assert!(manager.switch_project("Work".to_string()).is_ok());
         assert_eq!(manager.get_current_project_name(), "Work");
 
         // Can't switch to non-existent project
-        assert!(manager.switch_project("NonExistent".to_string()).is_none());
+        assert_eq!(
+            manager.switch_project("NonExistent".to_string()),
+            Err(TodoError::ProjectNotFound("NonExistent".to_string()))
+        );
     }
 
     #[test]
@@ -332,20 +602,22 @@ mod tests {
 
         assert!(manager
             .rename_project("default".to_string(), "personal".to_string())
-            .is_some());
+            .is_ok());
         assert_eq!(manager.get_current_project_name(), "personal");
         assert_eq!(manager.project_count(), 1);
 
         // Can't rename to existing name
-        manager.create_project("Work".to_string());
-        assert!(manager
-            .rename_project("personal".to_string(), "Work".to_string())
-            .is_none());
+        manager.create_project("Work".to_string()).unwrap();
+        assert_eq!(
+            manager.rename_project("personal".to_string(), "Work".to_string()),
+            Err(TodoError::ProjectExists("Work".to_string()))
+        );
 
         // Can't rename non-existent project
-        assert!(manager
-            .rename_project("NonExistent".to_string(), "New".to_string())
-            .is_none());
+        assert_eq!(
+            manager.rename_project("NonExistent".to_string(), "New".to_string()),
+            Err(TodoError::ProjectNotFound("NonExistent".to_string()))
+        );
     }
 
     #[test]
@@ -366,8 +638,8 @@ mod tests {
     #[test]
     fn test_list_projects() {
         let mut manager = ProjectManager::new();
-        manager.create_project("Work".to_string());
-        manager.create_project("Personal".to_string());
+        manager.create_project("Work".to_string()).unwrap();
+        manager.create_project("Personal".to_string()).unwrap();
 
         let projects = manager.list_projects();
         assert_eq!(projects.len(), 3);
@@ -377,14 +649,14 @@ mod tests {
     #[test]
     fn test_projects_are_independent() {
         let mut manager = ProjectManager::new();
-        manager.create_project("Work".to_string());
+        manager.create_project("Work".to_string()).unwrap();
 
         // Add task to default project
         let task1 = TaskWithoutId::new("Default Task".to_string());
         manager.get_current_todo_list_mut().add_task(task1);
 
         // Switch to Work and add task
-        manager.switch_project("Work".to_string());
+        manager.switch_project("Work".to_string()).unwrap();
         let task2 = TaskWithoutId::new("Work Task".to_string());
         manager.get_current_todo_list_mut().add_task(task2);
 
@@ -392,7 +664,31 @@ mod tests {
         assert_eq!(manager.get_current_todo_list().get_tasks().len(), 1);
 
         // Switch back to default, should still have 1 task
-        manager.switch_project("default".to_string());
+        manager.switch_project("default".to_string()).unwrap();
         assert_eq!(manager.get_current_todo_list().get_tasks().len(), 1);
     }
+
+    #[test]
+    fn test_views_roundtrip_and_default_to_empty() {
+        let mut manager = ProjectManager::new();
+        manager.save_view(SavedView::new("work".to_string(), "+work".to_string()));
+        let json = serde_json::to_string(&manager).unwrap();
+        let loaded: ProjectManager = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.get_views(), manager.get_views());
+
+        // Files written before views existed load without views
+        let legacy = json.replace(r#","views":[{"name":"work","filter":"+work"}]"#, "");
+        assert_ne!(legacy, json);
+        let loaded: ProjectManager = serde_json::from_str(&legacy).unwrap();
+        assert!(loaded.get_views().is_empty());
+    }
+
+    #[test]
+    fn test_delete_view() {
+        let mut manager = ProjectManager::new();
+        manager.save_view(SavedView::new("work".to_string(), "+work".to_string()));
+        assert!(manager.delete_view("other").is_none());
+        assert_eq!(manager.delete_view("Work").unwrap().name, "work");
+        assert!(manager.get_view("work").is_none());
+    }
 }
@@ -1,4 +1,3 @@
-pub mod recurring_task_handler;
 pub mod task;
 pub mod task_command_controller;
 pub mod task_command_input_parser;
@@ -6,7 +5,6 @@ pub mod task_command_output_manager;
 pub mod task_selection;
 pub mod task_selection_handler;
 
-pub use recurring_task_handler::RecurringTaskHandler;
 pub use task::TaskCommand;
 pub use task_command_controller::TaskCommandController;
 pub use task_command_input_parser::TaskCommandInputParser;
@@ -1,7 +1,9 @@
 use super::TaskSelection;
 use crate::models::priority::Priority;
 use crate::models::recurrence::Recurrence;
+use crate::models::task::TaskWithoutId;
 use crate::models::task_filter::TaskFilter;
+use crate::models::task_status::TaskStatus;
 use chrono::NaiveDate;
 
 /// Represents task-related commands.
@@ -10,8 +12,8 @@ use chrono::NaiveDate;
 /// filtering, searching, and statistics.
 #[derive(Debug, Clone, PartialEq)]
 pub enum TaskCommand {
-    /// Add a new task with the given description
-    Add(String),
+    /// Add a new task with the attributes parsed from its description
+    Add(TaskWithoutId),
 
     /// Add a subtask to a parent task
     AddSubtask(usize, String),
@@ -31,6 +33,9 @@ pub enum TaskCommand {
     /// Toggle task(s) completion status
     Toggle(TaskSelection),
 
+    /// Set the workflow status of task(s)
+    SetStatus(TaskSelection, TaskStatus),
+
     /// Set the priority of task(s)
     SetPriority(TaskSelection, Priority),
 
@@ -43,6 +48,12 @@ pub enum TaskCommand {
     /// Set the category of task(s)
     SetCategory(TaskSelection, Option<String>),
 
+    /// Add tags to task(s)
+    Tag(TaskSelection, Vec<String>),
+
+    /// Remove tags from task(s)
+    Untag(TaskSelection, Vec<String>),
+
     /// Set the recurrence pattern for task(s)
     SetRecurring(TaskSelection, Option<Recurrence>),
 
@@ -63,4 +74,31 @@ pub enum TaskCommand {
 
     /// Show task statistics
     ShowStatistics,
+
+    /// Show open tasks due in the next number of days, grouped by day
+    Agenda(u32),
+
+    /// Show removed tasks and deleted projects in the trash
+    ShowTrash,
+
+    /// Restore a removed task (and its subtasks) from the trash
+    Restore(usize),
+
+    /// Permanently delete everything in the trash
+    EmptyTrash,
+
+    /// Set the number of days after which trash entries are purged
+    SetTrashRetention(u32),
+
+    /// Save `list` filter arguments under a name (name, filter arguments)
+    SaveView(String, String),
+
+    /// List tasks using a saved view
+    ShowView(String),
+
+    /// List all saved views
+    ListViews,
+
+    /// Delete a saved view
+    DeleteView(String),
 }
@@ -1,18 +1,19 @@
 use crate::controller::command_controller::CommandController;
-use crate::controller::project_command::ProjectManager;
-use crate::controller::task_command::recurring_task_handler::RecurringTaskHandler;
 use crate::controller::task_command::TaskCommandInputParser;
 use crate::controller::task_command::TaskCommandOutputManager;
-use crate::controller::task_command::{TaskCommand, TaskSelection};
+use crate::controller::task_command::{TaskCommand, TaskSelection, TaskSelectionHandler};
+use crate::models::agenda::build_agenda;
 use crate::models::command_controller_result::CommandControllerResult;
 use crate::models::command_controller_result::CommandControllerResultAction;
 use crate::models::priority::Priority;
-use crate::models::task::TaskWithoutId;
+use crate::models::saved_view::SavedView;
+use crate::models::task::{Task, TaskWithoutId};
 use crate::models::task_filter::TaskFilter;
 use crate::models::task_status::TaskStatus;
 use crate::models::ParseError;
+use crate::service::{Occurrence, TodoService};
 use crate::OutputWriter;
-use chrono::NaiveDate;
+use chrono::{Local, NaiveDate};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -21,31 +22,25 @@ use std::rc::Rc;
 /// `TaskCommandController` encapsulates all operations related to individual tasks
 /// such as completing, editing, setting priority, due date, and category.
 pub struct TaskCommandController<O: OutputWriter> {
-    project_manager: Rc<RefCell<ProjectManager>>,
+    service: Rc<RefCell<TodoService>>,
     input_parser: TaskCommandInputParser,
     output_manager: TaskCommandOutputManager<O>,
-    recurring_task_handler: RecurringTaskHandler,
 }
 
 impl<O: OutputWriter> TaskCommandController<O> {
     /// Creates a new task command handler with a custom output writer.
-    pub fn new(
-        project_manager: Rc<RefCell<ProjectManager>>,
-        output_writer: Rc<RefCell<O>>,
-    ) -> Self {
-        let recurring_task_handler = RecurringTaskHandler::new(Rc::clone(&project_manager));
+    pub fn new(service: Rc<RefCell<TodoService>>, output_writer: Rc<RefCell<O>>) -> Self {
         Self {
-            project_manager,
+            service,
             input_parser: TaskCommandInputParser::new(),
             output_manager: TaskCommandOutputManager::new(output_writer),
-            recurring_task_handler,
         }
     }
 
     /// Handles a task command
     fn handle_command(&mut self, command: &TaskCommand) -> CommandControllerResult {
         match command {
-            TaskCommand::Add(description) => self.add_task(description),
+            TaskCommand::Add(task) => self.add_task(task),
             TaskCommand::AddSubtask(parent_id, description) => {
                 self.add_subtask(*parent_id, description)
             }
@@ -54,6 +49,7 @@ impl<O: OutputWriter> TaskCommandController<O> {
             TaskCommand::Complete(selection) => self.handle_complete(selection),
             TaskCommand::Uncomplete(selection) => self.handle_uncomplete(selection),
             TaskCommand::Toggle(selection) => self.handle_toggle(selection),
+            TaskCommand::SetStatus(selection, status) => self.handle_set_status(selection, *status),
             TaskCommand::SetPriority(selection, priority) => {
                 self.handle_set_priority(selection, *priority)
             }
@@ -61,6 +57,8 @@ impl<O: OutputWriter> TaskCommandController<O> {
             TaskCommand::SetCategory(selection, category) => {
                 self.handle_set_category(selection, category.clone())
             }
+            TaskCommand::Tag(selection, tags) => self.handle_tag(selection, tags, true),
+            TaskCommand::Untag(selection, tags) => self.handle_tag(selection, tags, false),
             TaskCommand::SetRecurring(selection, recurrence) => {
                 self.handle_set_recurring(selection, *recurrence)
             }
@@ -75,34 +73,46 @@ impl<O: OutputWriter> TaskCommandController<O> {
             TaskCommand::Edit(id, new_description) => self.edit_task(*id, new_description),
             TaskCommand::Search(keyword) => self.search_tasks(keyword),
             TaskCommand::ShowStatistics => self.show_statistics(),
+            TaskCommand::Agenda(days) => self.show_agenda(*days),
+            TaskCommand::ShowTrash => self.show_trash(),
+            TaskCommand::Restore(id) => self.restore_task(*id),
+            TaskCommand::EmptyTrash => self.empty_trash(),
+            TaskCommand::SetTrashRetention(days) => self.set_trash_retention(*days),
+            TaskCommand::SaveView(name, filter) => self.save_view(name, filter),
+            TaskCommand::ShowView(name) => self.show_view(name),
+            TaskCommand::ListViews => self.list_views(),
+            TaskCommand::DeleteView(name) => self.delete_view(name),
         }
     }
 
-    fn add_task(&mut self, description: &str) -> CommandControllerResult {
-        let new_task = TaskWithoutId::new(description.to_string());
-        let task_id = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .add_task(new_task);
-        self.output_manager.show_task_added(task_id, description);
-        CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+    fn add_task(&mut self, new_task: &TaskWithoutId) -> CommandControllerResult {
+        let added = self.service.borrow_mut().add_task(new_task.clone());
+        match added {
+            Ok(task_id) => {
+                self.output_manager
+                    .show_task_added(task_id, &new_task.description);
+                CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+            }
+            Err(err) => {
+                self.output_manager.show_todo_error(&err);
+                CommandControllerResult::default()
+            }
+        }
     }
 
     fn add_subtask(&mut self, parent_id: usize, description: &str) -> CommandControllerResult {
         match self
-            .project_manager
+            .service
             .borrow_mut()
-            .get_current_todo_list_mut()
-            .add_subtask(parent_id, description.to_string())
+            .add_subtask(parent_id, description)
         {
-            Some(subtask_id) => {
+            Ok(subtask_id) => {
                 self.output_manager
                     .show_subtask_added(subtask_id, parent_id, description);
                 CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
             }
-            None => {
-                self.output_manager.show_task_not_found(parent_id);
+            Err(err) => {
+                self.output_manager.show_todo_error(&err);
                 CommandControllerResult::default()
             }
         }
@@ -110,39 +120,17 @@ impl<O: OutputWriter> TaskCommandController<O> {
 
     fn add_dependency(&mut self, task_id: usize, depends_on_id: usize) -> CommandControllerResult {
         match self
-            .project_manager
+            .service
             .borrow_mut()
-            .get_current_todo_list_mut()
-            .add_task_dependency(task_id, depends_on_id)
+            .add_dependency(task_id, depends_on_id)
         {
-            Some(()) => {
+            Ok(()) => {
                 self.output_manager
                     .show_dependency_added(task_id, depends_on_id);
                 CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
             }
-            None => {
-                // Check why it failed
-                let project_manager = self.project_manager.borrow();
-                let todo_list = project_manager.get_current_todo_list();
-                if todo_list.get_tasks().iter().all(|t| t.id != task_id) {
-                    let _ = todo_list;
-                    let _ = project_manager;
-                    self.output_manager.show_task_not_found(task_id);
-                } else if todo_list.get_tasks().iter().all(|t| t.id != depends_on_id) {
-                    let _ = todo_list;
-                    let _ = project_manager;
-                    self.output_manager.show_task_not_found(depends_on_id);
-                } else if task_id == depends_on_id {
-                    let _ = todo_list;
-                    let _ = project_manager;
-                    self.output_manager
-                        .show_error("A task cannot depend on itself");
-                } else {
-                    let _ = todo_list;
-                    let _ = project_manager;
-                    self.output_manager
-                        .show_error("Adding this dependency would create a circular dependency");
-                }
+            Err(err) => {
+                self.output_manager.show_todo_error(&err);
                 CommandControllerResult::default()
             }
         }
@@ -154,33 +142,32 @@ impl<O: OutputWriter> TaskCommandController<O> {
         depends_on_id: usize,
     ) -> CommandControllerResult {
         match self
-            .project_manager
+            .service
             .borrow_mut()
-            .get_current_todo_list_mut()
-            .remove_task_dependency(task_id, depends_on_id)
+            .remove_dependency(task_id, depends_on_id)
         {
-            Some(()) => {
+            Ok(()) => {
                 self.output_manager
                     .show_dependency_removed(task_id, depends_on_id);
                 CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
             }
-            None => {
-                self.output_manager.show_task_not_found(task_id);
+            Err(err) => {
+                self.output_manager.show_todo_error(&err);
                 CommandControllerResult::default()
             }
         }
     }
 
     fn show_dependency_graph(&mut self, task_id: usize) -> CommandControllerResult {
-        let project_manager = self.project_manager.borrow();
-        let todo_list_ref = project_manager.get_current_todo_list();
+        let service = self.service.borrow();
+        let todo_list_ref = service.todo_list();
 
         // Check if task exists
         let task = match todo_list_ref.get_tasks().iter().find(|t| t.id == task_id) {
             Some(t) => t,
             None => {
                 let _ = todo_list_ref;
-                let _ = project_manager;
+                let _ = service;
                 self.output_manager.show_task_not_found(task_id);
                 return CommandControllerResult::default();
             }
@@ -233,10 +220,13 @@ impl<O: OutputWriter> TaskCommandController<O> {
         match filter {
             None => self
                 .output_manager
-                .show_all_tasks_hierarchical(self.project_manager.borrow().get_current_todo_list()),
+                .show_all_tasks_hierarchical(self.service.borrow().todo_list()),
+            Some(task_filter) if task_filter.is_sort_only() => self
+                .output_manager
+                .show_sorted_tasks_hierarchical(self.service.borrow().todo_list(), task_filter),
             Some(task_filter) => {
-                let project_manager = self.project_manager.borrow();
-                let todo_list_ref = project_manager.get_current_todo_list();
+                let service = self.service.borrow();
+                let todo_list_ref = service.todo_list();
                 let filtered_tasks = todo_list_ref.get_filtered_tasks(task_filter);
 
                 if task_filter.status == Some(TaskStatus::Completed)
@@ -261,158 +251,195 @@ impl<O: OutputWriter> TaskCommandController<O> {
 
     /// Removes a task by ID.
     fn remove_task(&mut self, id: usize) -> CommandControllerResult {
-        if let Some(task) = self
-            .project_manager
+        match self.service.borrow_mut().remove_task(id) {
+            Ok(task) => {
+                self.output_manager.show_task_removed(&task.description);
+            }
+            Err(err) => self.output_manager.show_todo_error(&err),
+        }
+        CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+    }
+
+    /// Shows the trash of the current project and the deleted projects.
+    ///
+    /// Expired entries are purged before they are shown.
+    fn show_trash(&mut self) -> CommandControllerResult {
+        let purged = self
+            .service
             .borrow_mut()
-            .get_current_todo_list_mut()
-            .remove_task(id)
-        {
-            self.output_manager.show_task_removed(&task.description);
+            .purge_expired_trash(chrono::Local::now());
+
+        let service = self.service.borrow();
+        let project_manager = service.project_manager();
+        self.output_manager.show_trash(
+            project_manager.get_current_todo_list().get_trash(),
+            project_manager.get_trashed_projects(),
+            project_manager.get_trash_retention_days(),
+        );
+
+        if purged > 0 {
+            CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
         } else {
-            self.output_manager.show_task_not_found(id);
+            CommandControllerResult::empty()
+        }
+    }
+
+    /// Restores a removed task from the trash.
+    fn restore_task(&mut self, id: usize) -> CommandControllerResult {
+        match self.service.borrow_mut().restore_task(id) {
+            Ok(task) => {
+                self.output_manager
+                    .show_task_restored(&task.description, id, task.id);
+            }
+            Err(err) => self.output_manager.show_todo_error(&err),
         }
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
-    /// Marks a task as completed.
-    fn complete_task(&mut self, id: usize) -> CommandControllerResult {
-        // Check if dependencies are completed
-        let incomplete_deps = self
-            .project_manager
+    /// Permanently deletes the trash of the current project and the deleted projects.
+    fn empty_trash(&mut self) -> CommandControllerResult {
+        let (task_count, project_count) = self.service.borrow_mut().empty_trash();
+
+        self.output_manager
+            .show_trash_emptied(task_count, project_count);
+        CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+    }
+
+    /// Sets the number of days after which trash entries are purged.
+    fn set_trash_retention(&mut self, days: u32) -> CommandControllerResult {
+        self.service.borrow_mut().set_trash_retention_days(days);
+        self.output_manager.show_trash_retention_set(days);
+        CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+    }
+
+    /// Saves `list` filter arguments under a name.
+    fn save_view(&mut self, name: &str, filter: &str) -> CommandControllerResult {
+        let replaced = self
+            .service
+            .borrow_mut()
+            .save_view(SavedView::new(name.to_string(), filter.to_string()));
+        self.output_manager.show_view_saved(name, filter, replaced);
+        CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+    }
+
+    /// Lists the tasks of the current project using a saved view.
+    fn show_view(&mut self, name: &str) -> CommandControllerResult {
+        let view = self
+            .service
             .borrow()
-            .get_current_todo_list()
-            .get_incomplete_dependencies(id);
-        if !incomplete_deps.is_empty() {
-            let deps_str = incomplete_deps
-                .iter()
-                .map(|id| id.to_string())
-                .collect::<Vec<_>>()
-                .join(", ");
-            self.output_manager.show_error(&format!(
-                "Cannot complete task {}: it depends on incomplete task(s): {}",
-                id, deps_str
-            ));
+            .project_manager()
+            .get_view(name)
+            .cloned();
+        let Some(view) = view else {
+            self.output_manager.show_view_not_found(name);
             return CommandControllerResult::default();
+        };
+
+        match view.to_filter() {
+            Ok(filter) => self.list_tasks(&filter),
+            Err(err) => {
+                self.output_manager
+                    .show_invalid_view(&view.name, &err.message());
+                CommandControllerResult::default()
+            }
         }
+    }
 
-        // Collect recurring task data before completing
-        let recurring_data = self.recurring_task_handler.collect_recurring_task_data(id);
+    /// Lists all saved views.
+    fn list_views(&mut self) -> CommandControllerResult {
+        let service = self.service.borrow();
+        self.output_manager
+            .show_views(service.project_manager().get_views());
+        CommandControllerResult::empty()
+    }
 
-        // Complete the task
-        if let Some(task) = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .complete_task(id)
-        {
-            if task.is_completed() {
-                self.output_manager.show_task_completed(&task.description);
+    /// Deletes a saved view.
+    fn delete_view(&mut self, name: &str) -> CommandControllerResult {
+        let deleted = self.service.borrow_mut().delete_view(name);
+        match deleted {
+            Some(view) => {
+                self.output_manager.show_view_deleted(&view.name);
+                CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+            }
+            None => {
+                self.output_manager.show_view_not_found(name);
+                CommandControllerResult::default()
             }
-        } else {
-            self.output_manager.show_task_not_found(id);
-            return CommandControllerResult::with_action(
-                CommandControllerResultAction::SaveTodoList,
-            );
         }
+    }
 
-        // If task was recurring, recreate it with the next due date
-        if let Some(data) = recurring_data {
-            let new_id = self.recurring_task_handler.recreate_recurring_task(&data);
-            self.output_manager
-                .show_recurring_task_created(new_id, &data.description);
+    /// Marks a task as completed.
+    ///
+    /// A recurring task gets its next occurrence.
+    fn complete_task(&mut self, id: usize) -> CommandControllerResult {
+        let completed = self.service.borrow_mut().complete_task(id);
+        match completed {
+            Ok(outcome) => {
+                self.output_manager
+                    .show_task_completed(&outcome.task.description);
+                self.show_next_occurrences(outcome.next_occurrence.as_slice());
+                CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+            }
+            Err(err) => {
+                self.output_manager.show_todo_error(&err);
+                CommandControllerResult::default()
+            }
         }
-
-        CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
     /// Marks a task as not completed.
     fn uncomplete_task(&mut self, id: usize) -> CommandControllerResult {
-        if let Some(task) = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .uncomplete_task(id)
-        {
-            if !task.is_completed() {
-                self.output_manager.show_task_uncompleted(&task.description);
+        match self.service.borrow_mut().uncomplete_task(id) {
+            Ok(task) => {
+                if !task.is_completed() {
+                    self.output_manager.show_task_uncompleted(&task.description);
+                }
             }
-        } else {
-            self.output_manager.show_task_not_found(id);
+            Err(err) => self.output_manager.show_todo_error(&err),
         }
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
     /// Completes multiple tasks by their IDs.
     fn complete_multiple_tasks(&mut self, ids: &[usize]) -> CommandControllerResult {
-        // Collect recurring task data before completing
-        let recurring_tasks_data = self
-            .recurring_task_handler
-            .collect_multiple_recurring_tasks(ids);
-
-        let (completed_count, not_found) = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .complete_tasks(ids);
+        let outcome = self.service.borrow_mut().complete_tasks(ids);
 
         self.output_manager
-            .show_multiple_tasks_completed(completed_count, &not_found);
-
-        // Recreate recurring tasks
-        for data in &recurring_tasks_data {
-            let new_id = self.recurring_task_handler.recreate_recurring_task(data);
-            self.output_manager
-                .show_recurring_task_created(new_id, &data.description);
-        }
-
+            .show_multiple_tasks_completed(outcome.updated, &outcome.not_found);
+        self.show_next_occurrences(&outcome.next_occurrences);
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
     /// Completes all tasks.
     fn complete_all_tasks(&mut self) -> CommandControllerResult {
-        // Collect recurring task data before completing
-        let recurring_tasks_data = self
-            .recurring_task_handler
-            .collect_all_pending_recurring_tasks();
-
-        let count = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .complete_all_tasks();
+        let outcome = self.service.borrow_mut().complete_all_tasks();
 
-        self.output_manager.show_all_tasks_completed(count);
+        self.output_manager
+            .show_all_tasks_completed(outcome.updated);
+        self.show_next_occurrences(&outcome.next_occurrences);
+        CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+    }
 
-        // Recreate recurring tasks
-        for data in &recurring_tasks_data {
-            let new_id = self.recurring_task_handler.recreate_recurring_task(data);
+    /// Shows the tasks created for completed recurring tasks.
+    fn show_next_occurrences(&mut self, occurrences: &[Occurrence]) {
+        for occurrence in occurrences {
             self.output_manager
-                .show_recurring_task_created(new_id, &data.description);
+                .show_recurring_task_created(occurrence.id, &occurrence.description);
         }
-
-        CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
     /// Removes multiple tasks by their IDs.
     fn remove_multiple_tasks(&mut self, ids: &[usize]) -> CommandControllerResult {
-        let (removed_count, not_found) = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .remove_tasks(ids);
+        let outcome = self.service.borrow_mut().remove_tasks(ids);
 
         self.output_manager
-            .show_multiple_tasks_removed(removed_count, &not_found);
+            .show_multiple_tasks_removed(outcome.updated, &outcome.not_found);
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
     /// Removes all tasks.
     fn remove_all_tasks(&mut self) -> CommandControllerResult {
-        let count = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .remove_all_tasks();
+        let count = self.service.borrow_mut().remove_all_tasks();
 
         self.output_manager.show_all_tasks_removed(count);
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
@@ -420,24 +447,16 @@ impl<O: OutputWriter> TaskCommandController<O> {
 
     /// Marks multiple tasks as pending (incomplete) by their IDs.
     fn uncomplete_multiple_tasks(&mut self, ids: &[usize]) -> CommandControllerResult {
-        let (uncompleted_count, not_found) = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .uncomplete_tasks(ids);
+        let outcome = self.service.borrow_mut().uncomplete_tasks(ids);
 
         self.output_manager
-            .show_multiple_tasks_uncompleted(uncompleted_count, &not_found);
+            .show_multiple_tasks_uncompleted(outcome.updated, &outcome.not_found);
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
     /// Marks all tasks as pending (incomplete).
     fn uncomplete_all_tasks(&mut self) -> CommandControllerResult {
-        let count = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .uncomplete_all_tasks();
+        let count = self.service.borrow_mut().uncomplete_all_tasks();
 
         self.output_manager.show_all_tasks_uncompleted(count);
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
@@ -445,26 +464,20 @@ impl<O: OutputWriter> TaskCommandController<O> {
 
     /// Toggles multiple tasks' completion status by their IDs.
     fn toggle_multiple_tasks(&mut self, ids: &[usize]) -> CommandControllerResult {
-        let (toggled_count, not_found) = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .toggle_tasks(ids);
+        let outcome = self.service.borrow_mut().toggle_tasks(ids);
 
         self.output_manager
-            .show_multiple_tasks_toggled(toggled_count, &not_found);
+            .show_multiple_tasks_toggled(outcome.updated, &outcome.not_found);
+        self.show_next_occurrences(&outcome.next_occurrences);
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
     /// Toggles all tasks' completion status.
     fn toggle_all_tasks(&mut self) -> CommandControllerResult {
-        let count = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .toggle_all_tasks();
+        let outcome = self.service.borrow_mut().toggle_all_tasks();
 
-        self.output_manager.show_all_tasks_toggled(count);
+        self.output_manager.show_all_tasks_toggled(outcome.updated);
+        self.show_next_occurrences(&outcome.next_occurrences);
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
@@ -474,14 +487,16 @@ impl<O: OutputWriter> TaskCommandController<O> {
         ids: &[usize],
         priority: Priority,
     ) -> CommandControllerResult {
-        let (updated_count, not_found) = self
-            .project_manager
+        let outcome = self
+            .service
             .borrow_mut()
-            .get_current_todo_list_mut()
             .set_priority_multiple(ids, priority);
 
-        self.output_manager
-            .show_multiple_priorities_set(updated_count, priority, &not_found);
+        self.output_manager.show_multiple_priorities_set(
+            outcome.updated,
+            priority,
+            &outcome.not_found,
+        );
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
@@ -491,100 +506,146 @@ impl<O: OutputWriter> TaskCommandController<O> {
         ids: &[usize],
         category: Option<String>,
     ) -> CommandControllerResult {
-        let (updated_count, not_found) = self
-            .project_manager
+        let outcome = self
+            .service
             .borrow_mut()
-            .get_current_todo_list_mut()
             .set_category_multiple(ids, category.clone());
 
         self.output_manager.show_multiple_categories_set(
-            updated_count,
+            outcome.updated,
             category.as_deref(),
-            &not_found,
+            &outcome.not_found,
         );
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
     /// Toggles a task's completion status.
     fn toggle_task(&mut self, id: usize) -> CommandControllerResult {
-        if let Some(task) = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .toggle_task(id)
-        {
-            self.output_manager
-                .show_task_toggled(&task.description, task.is_completed());
-        } else {
-            self.output_manager.show_task_not_found(id);
+        let toggled = self.service.borrow_mut().toggle_task(id);
+        match toggled {
+            Ok(outcome) => {
+                self.output_manager
+                    .show_task_toggled(&outcome.task.description, outcome.task.is_completed());
+                self.show_next_occurrences(outcome.next_occurrence.as_slice());
+            }
+            Err(err) => self.output_manager.show_todo_error(&err),
         }
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
     /// Sets the priority of a task.
     fn set_priority(&mut self, id: usize, priority: Priority) -> CommandControllerResult {
-        if let Some(task) = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .set_task_priority(id, priority)
-        {
-            self.output_manager
-                .show_priority_set(&task.description, priority);
-        } else {
-            self.output_manager.show_task_not_found(id);
+        match self.service.borrow_mut().set_priority(id, priority) {
+            Ok(task) => {
+                self.output_manager
+                    .show_priority_set(&task.description, priority);
+            }
+            Err(err) => self.output_manager.show_todo_error(&err),
         }
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
     /// Sets the due date of a task.
     fn set_due_date(&mut self, id: usize, due_date: Option<NaiveDate>) -> CommandControllerResult {
-        if let Some(task) = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .set_due_date(id, due_date)
-        {
-            self.output_manager
-                .show_due_date_set(&task.description, due_date);
-        } else {
-            self.output_manager.show_task_not_found(id);
+        match self.service.borrow_mut().set_due_date(id, due_date) {
+            Ok(task) => {
+                self.output_manager
+                    .show_due_date_set(&task.description, due_date);
+            }
+            Err(err) => self.output_manager.show_todo_error(&err),
         }
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
     /// Sets the category of a task.
     fn set_category(&mut self, id: usize, category: Option<String>) -> CommandControllerResult {
-        if let Some(task) = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .set_task_category(id, category.clone())
-        {
-            self.output_manager
-                .show_category_set(&task.description, category);
+        match self.service.borrow_mut().set_category(id, category.clone()) {
+            Ok(task) => {
+                self.output_manager
+                    .show_category_set(&task.description, category);
+            }
+            Err(err) => self.output_manager.show_todo_error(&err),
+        }
+        CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+    }
+
+    /// Adds tags to or removes tags from a task.
+    fn tag_task(&mut self, id: usize, tags: &[String], add: bool) -> CommandControllerResult {
+        let mut service = self.service.borrow_mut();
+        let task = if add {
+            service.add_tags(id, tags)
+        } else {
+            service.remove_tags(id, tags)
+        };
+
+        match task {
+            Ok(task) => self
+                .output_manager
+                .show_tags_updated(&task.description, tags, add),
+            Err(err) => self.output_manager.show_todo_error(&err),
+        }
+        CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+    }
+
+    /// Adds tags to or removes tags from multiple tasks by their IDs.
+    fn tag_multiple(
+        &mut self,
+        ids: &[usize],
+        tags: &[String],
+        add: bool,
+    ) -> CommandControllerResult {
+        let outcome = if add {
+            self.service.borrow_mut().add_tags_multiple(ids, tags)
         } else {
-            self.output_manager.show_task_not_found(id);
+            self.service.borrow_mut().remove_tags_multiple(ids, tags)
+        };
+
+        self.output_manager.show_multiple_tags_updated(
+            outcome.updated,
+            tags,
+            add,
+            &outcome.not_found,
+        );
+        CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+    }
+
+    /// Sets the workflow status of a task.
+    fn set_status(&mut self, id: usize, status: TaskStatus) -> CommandControllerResult {
+        match self.service.borrow_mut().set_status(id, status) {
+            Ok(outcome) => {
+                self.output_manager
+                    .show_status_set(&outcome.task.description, status);
+            }
+            Err(err) => self.output_manager.show_todo_error(&err),
         }
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
+    /// Sets the workflow status of multiple tasks by their IDs.
+    fn set_status_multiple(
+        &mut self,
+        ids: &[usize],
+        status: TaskStatus,
+    ) -> CommandControllerResult {
+        let outcome = self.service.borrow_mut().set_status_multiple(ids, status);
+
+        self.output_manager
+            .show_multiple_statuses_set(outcome.updated, status, &outcome.not_found);
+        CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+    }
+
     /// Sets the recurrence pattern of a task.
     fn set_recurring(
         &mut self,
         id: usize,
         recurrence: Option<crate::models::recurrence::Recurrence>,
     ) -> CommandControllerResult {
-        if let Some(task) = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .set_task_recurrence(id, recurrence)
-        {
-            self.output_manager
-                .show_recurrence_set(&task.description, recurrence);
-        } else {
-            self.output_manager.show_task_not_found(id);
+        match self.service.borrow_mut().set_recurrence(id, recurrence) {
+            Ok(task) => {
+                self.output_manager
+                    .show_recurrence_set(&task.description, recurrence);
+            }
+            Err(err) => self.output_manager.show_todo_error(&err),
         }
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
@@ -595,23 +656,43 @@ impl<O: OutputWriter> TaskCommandController<O> {
         ids: &[usize],
         recurrence: Option<crate::models::recurrence::Recurrence>,
     ) -> CommandControllerResult {
-        let (updated_count, not_found) = self
-            .project_manager
+        let outcome = self
+            .service
             .borrow_mut()
-            .get_current_todo_list_mut()
             .set_recurrence_multiple(ids, recurrence);
 
-        self.output_manager
-            .show_multiple_recurrences_set(updated_count, recurrence, &not_found);
+        self.output_manager.show_multiple_recurrences_set(
+            outcome.updated,
+            recurrence,
+            &outcome.not_found,
+        );
         CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
+    /// Runs `action` on the tasks matching a `where` filter.
+    ///
+    /// The matched IDs are shown first; nothing is done if no task matches.
+    fn for_filtered_tasks<F>(&mut self, filter: &TaskFilter, action: F) -> CommandControllerResult
+    where
+        F: FnOnce(&mut Self, &[usize]) -> CommandControllerResult,
+    {
+        let ids = TaskSelectionHandler::matching_ids(filter, self.service.borrow().todo_list());
+        self.output_manager.show_filter_matches(&ids);
+        if ids.is_empty() {
+            return CommandControllerResult::default();
+        }
+        action(self, &ids)
+    }
+
     /// Handles remove command based on TaskSelection.
     fn handle_remove(&mut self, selection: &TaskSelection) -> CommandControllerResult {
         match selection {
             TaskSelection::Single(id) => self.remove_task(*id),
             TaskSelection::Multiple(ids) => self.remove_multiple_tasks(ids),
             TaskSelection::All => self.remove_all_tasks(),
+            TaskSelection::Filter(filter) => {
+                self.for_filtered_tasks(filter, |this, ids| this.remove_multiple_tasks(ids))
+            }
         }
     }
 
@@ -621,6 +702,9 @@ impl<O: OutputWriter> TaskCommandController<O> {
             TaskSelection::Single(id) => self.complete_task(*id),
             TaskSelection::Multiple(ids) => self.complete_multiple_tasks(ids),
             TaskSelection::All => self.complete_all_tasks(),
+            TaskSelection::Filter(filter) => {
+                self.for_filtered_tasks(filter, |this, ids| this.complete_multiple_tasks(ids))
+            }
         }
     }
 
@@ -630,6 +714,9 @@ impl<O: OutputWriter> TaskCommandController<O> {
             TaskSelection::Single(id) => self.uncomplete_task(*id),
             TaskSelection::Multiple(ids) => self.uncomplete_multiple_tasks(ids),
             TaskSelection::All => self.uncomplete_all_tasks(),
+            TaskSelection::Filter(filter) => {
+                self.for_filtered_tasks(filter, |this, ids| this.uncomplete_multiple_tasks(ids))
+            }
         }
     }
 
@@ -639,6 +726,42 @@ impl<O: OutputWriter> TaskCommandController<O> {
             TaskSelection::Single(id) => self.toggle_task(*id),
             TaskSelection::Multiple(ids) => self.toggle_multiple_tasks(ids),
             TaskSelection::All => self.toggle_all_tasks(),
+            TaskSelection::Filter(filter) => {
+                self.for_filtered_tasks(filter, |this, ids| this.toggle_multiple_tasks(ids))
+            }
+        }
+    }
+
+    /// Handles status command based on TaskSelection.
+    ///
+    /// Completing goes through the regular complete command so that
+    /// dependency checks and recurring tasks are handled the same way.
+    fn handle_set_status(
+        &mut self,
+        selection: &TaskSelection,
+        status: TaskStatus,
+    ) -> CommandControllerResult {
+        if status == TaskStatus::Completed {
+            return self.handle_complete(selection);
+        }
+
+        match selection {
+            TaskSelection::Single(id) => self.set_status(*id, status),
+            TaskSelection::Multiple(ids) => self.set_status_multiple(ids, status),
+            TaskSelection::All => {
+                let all_ids: Vec<usize> = self
+                    .service
+                    .borrow()
+                    .todo_list()
+                    .get_tasks()
+                    .iter()
+                    .map(|t| t.id)
+                    .collect();
+                self.set_status_multiple(&all_ids, status)
+            }
+            TaskSelection::Filter(filter) => {
+                self.for_filtered_tasks(filter, |this, ids| this.set_status_multiple(ids, status))
+            }
         }
     }
 
@@ -653,15 +776,18 @@ impl<O: OutputWriter> TaskCommandController<O> {
             TaskSelection::Multiple(ids) => self.set_priority_multiple(ids, priority),
             TaskSelection::All => {
                 let all_ids: Vec<usize> = self
-                    .project_manager
+                    .service
                     .borrow()
-                    .get_current_todo_list()
+                    .todo_list()
                     .get_tasks()
                     .iter()
                     .map(|t| t.id)
                     .collect();
                 self.set_priority_multiple(&all_ids, priority)
             }
+            TaskSelection::Filter(filter) => self.for_filtered_tasks(filter, |this, ids| {
+                this.set_priority_multiple(ids, priority)
+            }),
         }
     }
 
@@ -676,15 +802,45 @@ impl<O: OutputWriter> TaskCommandController<O> {
             TaskSelection::Multiple(ids) => self.set_category_multiple(ids, category),
             TaskSelection::All => {
                 let all_ids: Vec<usize> = self
-                    .project_manager
+                    .service
                     .borrow()
-                    .get_current_todo_list()
+                    .todo_list()
                     .get_tasks()
                     .iter()
                     .map(|t| t.id)
                     .collect();
                 self.set_category_multiple(&all_ids, category)
             }
+            TaskSelection::Filter(filter) => self.for_filtered_tasks(filter, |this, ids| {
+                this.set_category_multiple(ids, category)
+            }),
+        }
+    }
+
+    /// Handles tag and untag commands based on TaskSelection.
+    fn handle_tag(
+        &mut self,
+        selection: &TaskSelection,
+        tags: &[String],
+        add: bool,
+    ) -> CommandControllerResult {
+        match selection {
+            TaskSelection::Single(id) => self.tag_task(*id, tags, add),
+            TaskSelection::Multiple(ids) => self.tag_multiple(ids, tags, add),
+            TaskSelection::All => {
+                let all_ids: Vec<usize> = self
+                    .service
+                    .borrow()
+                    .todo_list()
+                    .get_tasks()
+                    .iter()
+                    .map(|t| t.id)
+                    .collect();
+                self.tag_multiple(&all_ids, tags, add)
+            }
+            TaskSelection::Filter(filter) => {
+                self.for_filtered_tasks(filter, |this, ids| this.tag_multiple(ids, tags, add))
+            }
         }
     }
 
@@ -698,69 +854,60 @@ impl<O: OutputWriter> TaskCommandController<O> {
             TaskSelection::Multiple(ids) => self.set_recurring_multiple(ids, recurrence),
             TaskSelection::All => {
                 let all_ids: Vec<usize> = self
-                    .project_manager
+                    .service
                     .borrow()
-                    .get_current_todo_list()
+                    .todo_list()
                     .get_tasks()
                     .iter()
                     .map(|t| t.id)
                     .collect();
                 self.set_recurring_multiple(&all_ids, recurrence)
             }
+            TaskSelection::Filter(filter) => self.for_filtered_tasks(filter, |this, ids| {
+                this.set_recurring_multiple(ids, recurrence)
+            }),
         }
     }
 
     fn list_categories(&mut self) -> CommandControllerResult {
         self.output_manager
-            .show_categories(self.project_manager.borrow().get_current_todo_list());
+            .show_categories(self.service.borrow().todo_list());
         CommandControllerResult::empty()
     }
 
     /// Edits a task's description.
     fn edit_task(&mut self, id: usize, new_description: &str) -> CommandControllerResult {
-        if new_description.trim().is_empty() {
-            self.output_manager
-                .show_error("Task description cannot be empty.");
-            return CommandControllerResult::empty();
-        }
-
-        // Get the old description before editing
-        let old_description = self
-            .project_manager
-            .borrow()
-            .get_current_todo_list()
-            .get_tasks()
-            .iter()
-            .find(|task| task.id == id)
-            .map(|task| task.description.clone());
-
-        if let Some(_task) = self
-            .project_manager
-            .borrow_mut()
-            .get_current_todo_list_mut()
-            .edit_task(id, new_description.to_string())
-        {
-            if let Some(old_desc) = old_description {
+        let edited = self.service.borrow_mut().edit_task(id, new_description);
+        match edited {
+            Ok(old_description) => {
                 self.output_manager
-                    .show_task_edited(&old_desc, new_description);
+                    .show_task_edited(&old_description, new_description);
+                CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+            }
+            Err(err) => {
+                self.output_manager.show_todo_error(&err);
+                CommandControllerResult::empty()
             }
-        } else {
-            self.output_manager.show_task_not_found(id);
         }
-        CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
     }
 
     fn search_tasks(&mut self, keyword: &str) -> CommandControllerResult {
-        self.output_manager.show_search_results(
-            self.project_manager.borrow().get_current_todo_list(),
-            keyword,
-        );
+        self.output_manager
+            .show_search_results(self.service.borrow().todo_list(), keyword);
         CommandControllerResult::empty()
     }
 
     fn show_statistics(&mut self) -> CommandControllerResult {
         self.output_manager
-            .show_statistics(self.project_manager.borrow().get_current_todo_list());
+            .show_statistics(self.service.borrow().todo_list());
+        CommandControllerResult::empty()
+    }
+
+    fn show_agenda(&mut self, days: u32) -> CommandControllerResult {
+        let service = self.service.borrow();
+        let tasks: Vec<&Task> = service.todo_list().get_tasks().iter().collect();
+        let agenda = build_agenda(&tasks, Local::now().date_naive(), days);
+        self.output_manager.show_agenda(&agenda, days);
         CommandControllerResult::empty()
     }
 }
@@ -790,26 +937,25 @@ impl<O: OutputWriter> CommandController for TaskCommandController<O> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::controller::project_command::ProjectManager;
 
     #[test]
     fn test_remove_task_existing() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
-        let id = project_manager
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
+        let id = service
             .borrow_mut()
-            .get_current_todo_list_mut()
-            .add_task(TaskWithoutId::new("Test task".to_string()));
+            .add_task(TaskWithoutId::new("Test task".to_string()))
+            .unwrap();
         let buffer = Vec::new();
         let output_writer = crate::ui::output::FileOutputWriter::new(buffer);
-        let mut handler = TaskCommandController::new(
-            Rc::clone(&project_manager),
-            Rc::new(RefCell::new(output_writer)),
-        );
+        let mut handler =
+            TaskCommandController::new(Rc::clone(&service), Rc::new(RefCell::new(output_writer)));
 
         handler.remove_task(id);
 
-        assert!(!project_manager
+        assert!(!service
             .borrow()
-            .get_current_todo_list()
+            .todo_list()
             .get_tasks()
             .iter()
             .any(|t| t.id == id));
@@ -817,23 +963,21 @@ mod tests {
 
     #[test]
     fn test_complete_task() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
-        let id = project_manager
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
+        let id = service
             .borrow_mut()
-            .get_current_todo_list_mut()
-            .add_task(TaskWithoutId::new("Test task".to_string()));
+            .add_task(TaskWithoutId::new("Test task".to_string()))
+            .unwrap();
         let buffer = Vec::new();
         let output_writer = crate::ui::output::FileOutputWriter::new(buffer);
-        let mut handler = TaskCommandController::new(
-            Rc::clone(&project_manager),
-            Rc::new(RefCell::new(output_writer)),
-        );
+        let mut handler =
+            TaskCommandController::new(Rc::clone(&service), Rc::new(RefCell::new(output_writer)));
 
         handler.complete_task(id);
 
-        assert!(project_manager
+        assert!(service
             .borrow()
-            .get_current_todo_list()
+            .todo_list()
             .get_tasks()
             .iter()
             .find(|t| t.id == id)
@@ -843,24 +987,22 @@ mod tests {
 
     #[test]
     fn test_set_priority() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
-        let id = project_manager
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
+        let id = service
             .borrow_mut()
-            .get_current_todo_list_mut()
-            .add_task(TaskWithoutId::new("Test task".to_string()));
+            .add_task(TaskWithoutId::new("Test task".to_string()))
+            .unwrap();
         let buffer = Vec::new();
         let output_writer = crate::ui::output::FileOutputWriter::new(buffer);
-        let mut handler = TaskCommandController::new(
-            Rc::clone(&project_manager),
-            Rc::new(RefCell::new(output_writer)),
-        );
+        let mut handler =
+            TaskCommandController::new(Rc::clone(&service), Rc::new(RefCell::new(output_writer)));
 
         handler.set_priority(id, Priority::High);
 
         assert_eq!(
-            project_manager
+            service
                 .borrow()
-                .get_current_todo_list()
+                .todo_list()
                 .get_tasks()
                 .iter()
                 .find(|t| t.id == id)
@@ -872,24 +1014,22 @@ mod tests {
 
     #[test]
     fn test_edit_task() {
-        let project_manager = Rc::new(RefCell::new(ProjectManager::new()));
-        let id = project_manager
+        let service = Rc::new(RefCell::new(TodoService::new(ProjectManager::new())));
+        let id = service
             .borrow_mut()
-            .get_current_todo_list_mut()
-            .add_task(TaskWithoutId::new("Old description".to_string()));
+            .add_task(TaskWithoutId::new("Old description".to_string()))
+            .unwrap();
         let buffer = Vec::new();
         let output_writer = crate::ui::output::FileOutputWriter::new(buffer);
-        let mut handler = TaskCommandController::new(
-            Rc::clone(&project_manager),
-            Rc::new(RefCell::new(output_writer)),
-        );
+        let mut handler =
+            TaskCommandController::new(Rc::clone(&service), Rc::new(RefCell::new(output_writer)));
 
         handler.edit_task(id, "New description");
 
         assert_eq!(
-            project_manager
+            service
                 .borrow()
-                .get_current_todo_list()
+                .todo_list()
                 .get_tasks()
                 .iter()
                 .find(|t| t.id == id)
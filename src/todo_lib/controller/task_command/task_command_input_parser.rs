@@ -3,9 +3,13 @@ use std::str::FromStr;
 use crate::controller::task_command::{TaskCommand, TaskSelection};
 use crate::models::filter_builder::FilterBuilder;
 use crate::models::parse_error::ParseError;
-use crate::models::parse_ids;
-use crate::models::priority::Priority;
-use chrono::NaiveDate;
+use crate::models::saved_view::SavedView;
+use crate::models::task_field_parser::{
+    parse_category, parse_due_date, parse_priority, parse_recurrence, parse_tags,
+};
+use crate::models::task_status::TaskStatus;
+use crate::models::{parse_ids, parse_inline_task};
+use chrono::Local;
 
 /// Parser for task-related commands.
 pub struct TaskCommandInputParser;
@@ -39,11 +43,14 @@ impl TaskCommandInputParser {
             "list" => Some(self.parse_list_command(args)),
             "remove" | "delete" | "rm" => Some(self.parse_remove_command(args)),
             "complete" | "done" => Some(self.parse_complete_command(args)),
-            "uncomplete" | "undo" => Some(self.parse_uncomplete_command(args)),
+            "uncomplete" | "reopen" => Some(self.parse_uncomplete_command(args)),
             "toggle" => Some(self.parse_toggle_command(args)),
+            "status" | "set-status" => Some(self.parse_status_command(args)),
             "priority" | "pri" => Some(self.parse_priority_command(args)),
             "set-due" | "due" => Some(self.parse_set_due_command(args)),
             "set-category" | "category" | "cat" => Some(self.parse_set_category_command(args)),
+            "tag" => Some(self.parse_tag_command(args, "tag")),
+            "untag" => Some(self.parse_tag_command(args, "untag")),
             "set-recurring" | "recurring" | "recur" => Some(self.parse_set_recurring_command(args)),
             "add-dependency" | "add-dep" | "depends-on" => {
                 Some(self.parse_add_dependency_command(args))
@@ -58,24 +65,27 @@ impl TaskCommandInputParser {
             "edit" => Some(self.parse_edit_command(args)),
             "search" | "find" => Some(self.parse_search_command(args)),
             "statistics" | "stats" => Some(Ok(TaskCommand::ShowStatistics)),
+            "agenda" => Some(self.parse_agenda_command(args)),
+            "trash" | "show-trash" => Some(Ok(TaskCommand::ShowTrash)),
+            "restore" => Some(self.parse_restore_command(args)),
+            "empty-trash" => Some(Ok(TaskCommand::EmptyTrash)),
+            "trash-retention" => Some(self.parse_trash_retention_command(args)),
+            "view" => Some(self.parse_view_command(args)),
+            "views" => Some(Ok(TaskCommand::ListViews)),
             _ => None,
         }
     }
 
-    /// Parses the 'add' command and validates the task description.
+    /// Parses the 'add' command, including inline attributes such as `!high` or `due:friday`.
     fn parse_add_command(&self, args: &[&str]) -> Result<TaskCommand, ParseError> {
         if args.is_empty() {
             Err(ParseError::MissingArguments {
                 command: "add".to_string(),
-                usage: "add <task description>".to_string(),
+                usage: "add <task description> [!priority] [@tag] [due:<date>] [every:<day|week|month>] [parent:<id>] [after:<ids>]".to_string(),
             })
         } else {
-            let description = args.join(" ");
-            if description.is_empty() {
-                Err(ParseError::EmptyInput("Task description".to_string()))
-            } else {
-                Ok(TaskCommand::Add(description))
-            }
+            let task = parse_inline_task(&args.join(" "), Local::now().date_naive())?;
+            Ok(TaskCommand::Add(task))
         }
     }
 
@@ -113,52 +123,38 @@ impl TaskCommandInputParser {
 
     /// Parses the 'list' command with optional filter arguments.
     fn parse_list_command(&self, args: &[&str]) -> Result<TaskCommand, ParseError> {
-        let mut filter_builder = FilterBuilder::new();
-
-        for arg in args {
-            let lower = arg.to_lowercase();
-            filter_builder = match filter_builder.parse_argument(&lower) {
-                Ok(builder) => builder,
-                Err(err) => return Err(ParseError::InvalidFormat {
-                    field: "filter".to_string(),
-                    expected: "status (completed/pending/overdue), priority (high/medium/low), or category:name".to_string(),
-                    actual: err
-                }),
-            };
-        }
-
-        Ok(TaskCommand::List(filter_builder.build()))
+        Ok(TaskCommand::List(FilterBuilder::parse_args(args)?))
     }
 
     /// Parses the 'remove' command with task ID validation.
-    /// Supports single ID, ID ranges (1-5), lists (1,3,5), combined (1-3,7), or "all"
+    /// Supports single ID, ID ranges (1-5), lists (1,3,5), combined (1-3,7), "all" or "where <filters>"
     fn parse_remove_command(&self, args: &[&str]) -> Result<TaskCommand, ParseError> {
         let selection = self.parse_task_selection(args, "remove")?;
         Ok(TaskCommand::Remove(selection))
     }
 
     /// Parses the 'complete' command.
-    /// Supports single ID, ID ranges (1-5), lists (1,3,5), combined (1-3,7), or "all"
+    /// Supports single ID, ID ranges (1-5), lists (1,3,5), combined (1-3,7), "all" or "where <filters>"
     fn parse_complete_command(&self, args: &[&str]) -> Result<TaskCommand, ParseError> {
         let selection = self.parse_task_selection(args, "complete")?;
         Ok(TaskCommand::Complete(selection))
     }
 
     /// Parses the 'uncomplete' command.
-    /// Supports single ID, ID ranges (1-5), lists (1,3,5), combined (1-3,7), or "all"
+    /// Supports single ID, ID ranges (1-5), lists (1,3,5), combined (1-3,7), "all" or "where <filters>"
     fn parse_uncomplete_command(&self, args: &[&str]) -> Result<TaskCommand, ParseError> {
         let selection = self.parse_task_selection(args, "uncomplete")?;
         Ok(TaskCommand::Uncomplete(selection))
     }
 
     /// Parses the 'toggle' command.
-    /// Supports single ID, ID ranges (1-5), lists (1,3,5), combined (1-3,7), or "all"
+    /// Supports single ID, ID ranges (1-5), lists (1,3,5), combined (1-3,7), "all" or "where <filters>"
     fn parse_toggle_command(&self, args: &[&str]) -> Result<TaskCommand, ParseError> {
         let selection = self.parse_task_selection(args, "toggle")?;
         Ok(TaskCommand::Toggle(selection))
     }
 
-    /// Helper method to parse task selection (single, multiple, or all)
+    /// Helper method to parse task selection (single, multiple, all, or `where <filters>`)
     fn parse_task_selection(
         &self,
         args: &[&str],
@@ -167,8 +163,16 @@ impl TaskCommandInputParser {
         if args.is_empty() {
             Err(ParseError::MissingArguments {
                 command: command_name.to_string(),
-                usage: format!("{} <task id|range|all>", command_name),
+                usage: format!("{} <task id|range|all|where <filters>>", command_name),
             })
+        } else if args[0].eq_ignore_ascii_case("where") {
+            match FilterBuilder::parse_args(&args[1..])? {
+                Some(filter) => Ok(TaskSelection::Filter(filter)),
+                None => Err(ParseError::MissingArguments {
+                    command: command_name.to_string(),
+                    usage: format!("{} where <filters>", command_name),
+                }),
+            }
         } else if args[0].eq_ignore_ascii_case("all") {
             Ok(TaskSelection::All)
         } else if args[0].contains('-') || args[0].contains(',') {
@@ -180,79 +184,171 @@ impl TaskCommandInputParser {
         } else if let Ok(id) = args[0].parse::<usize>() {
             Ok(TaskSelection::Single(id))
         } else {
-            Err(ParseError::InvalidId("Invalid task ID. Please provide a number, range (e.g., 1-5), list (e.g., 1,3,5), or 'all'.".to_string()))
+            Err(ParseError::InvalidId("Invalid task ID. Please provide a number, range (e.g., 1-5), list (e.g., 1,3,5), 'all' or 'where <filters>'.".to_string()))
+        }
+    }
+
+    /// Splits the arguments of a command that takes a selection followed by a value.
+    ///
+    /// A `where` filter spans every word except the last one, which is the value;
+    /// otherwise the selection is the first word. Expects at least two arguments.
+    fn split_selection_args<'a>(&self, args: &'a [&'a str]) -> (&'a [&'a str], &'a [&'a str]) {
+        if args[0].eq_ignore_ascii_case("where") {
+            args.split_at(args.len() - 1)
+        } else {
+            args.split_at(1)
         }
     }
 
     /// Parses the 'priority' command with priority level validation.
-    /// Supports single ID, ID ranges (1-5), lists (1,3,5), combined (1-3,7), or "all"
+    /// Supports single ID, ID ranges (1-5), lists (1,3,5), combined (1-3,7), "all" or "where <filters>"
     fn parse_priority_command(&self, args: &[&str]) -> Result<TaskCommand, ParseError> {
         if args.len() < 2 {
             Err(ParseError::MissingArguments {
                 command: "priority".to_string(),
                 usage:
-                    "priority <task id|range|all> <priority level (high/h, medium/med/m, low/l)>"
+                    "priority <task id|range|all|where <filters>> <priority level (high/h, medium/med/m, low/l)>"
                         .to_string(),
             })
         } else {
-            let priority_str = args[1].to_lowercase();
-            let priority = match Priority::from_str(&priority_str) {
-                Some(p) => p,
-                None => {
-                    return Err(ParseError::InvalidValue {
-                        field: "priority level".to_string(),
-                        value: priority_str,
-                        allowed: "high/h, medium/med/m, or low/l".to_string(),
-                    })
-                }
-            };
+            let (selection_args, value_args) = self.split_selection_args(args);
+            let priority = parse_priority(value_args[0])?;
 
-            let selection = self.parse_task_selection(&args[0..1], "priority")?;
+            let selection = self.parse_task_selection(selection_args, "priority")?;
             Ok(TaskCommand::SetPriority(selection, priority))
         }
     }
 
-    /// Parses the 'set-due' command with date validation.
-    fn parse_set_due_command(&self, args: &[&str]) -> Result<TaskCommand, ParseError> {
+    /// Parses the 'status' command.
+    /// Supports single ID, ID ranges (1-5), lists (1,3,5), combined (1-3,7), "all" or "where <filters>"
+    fn parse_status_command(&self, args: &[&str]) -> Result<TaskCommand, ParseError> {
         if args.len() < 2 {
-            Err(ParseError::MissingArguments {
-                command: "set-due".to_string(),
-                usage: "set-due <task id> <date (DD.MM.YYYY) or 'none' to clear>".to_string(),
+            return Err(ParseError::MissingArguments {
+                command: "status".to_string(),
+                usage: "status <task id|range|all|where <filters>> <pending|in-progress|waiting|blocked|completed|cancelled>"
+                    .to_string(),
+            });
+        }
+
+        let (selection_args, value_args) = self.split_selection_args(args);
+        let status_str = value_args[0].to_lowercase();
+        let status = TaskStatus::from_str(&status_str).map_err(|_| ParseError::InvalidValue {
+            field: "status".to_string(),
+            value: status_str.clone(),
+            allowed: "pending, in-progress, waiting, blocked, completed, or cancelled".to_string(),
+        })?;
+
+        let selection = self.parse_task_selection(selection_args, "status")?;
+        Ok(TaskCommand::SetStatus(selection, status))
+    }
+
+    /// Parses the 'agenda' command.
+    /// The number of days defaults to 7.
+    fn parse_agenda_command(&self, args: &[&str]) -> Result<TaskCommand, ParseError> {
+        let Some(days) = args.first() else {
+            return Ok(TaskCommand::Agenda(7));
+        };
+
+        match days.parse::<u32>() {
+            Ok(days) if (1..=366).contains(&days) => Ok(TaskCommand::Agenda(days)),
+            _ => Err(ParseError::OutOfRange {
+                field: "Days".to_string(),
+                value: days.to_string(),
+                range: "Use a number of days from 1 to 366.".to_string(),
+            }),
+        }
+    }
+
+    /// Parses the 'restore' command.
+    fn parse_restore_command(&self, args: &[&str]) -> Result<TaskCommand, ParseError> {
+        if args.is_empty() {
+            return Err(ParseError::MissingArguments {
+                command: "restore".to_string(),
+                usage: "restore <task id>".to_string(),
+            });
+        }
+
+        args[0]
+            .parse::<usize>()
+            .map(TaskCommand::Restore)
+            .map_err(|_| {
+                ParseError::InvalidId("Invalid task ID. Please provide a number.".to_string())
             })
-        } else if let Ok(id) = args[0].parse::<usize>() {
-            let date_str = args[1];
+    }
 
-            if date_str.to_lowercase() == "none" {
-                return Ok(TaskCommand::SetDueDate(id, None));
-            }
+    /// Parses the 'trash-retention' command.
+    fn parse_trash_retention_command(&self, args: &[&str]) -> Result<TaskCommand, ParseError> {
+        if args.is_empty() {
+            return Err(ParseError::MissingArguments {
+                command: "trash-retention".to_string(),
+                usage: "trash-retention <days>".to_string(),
+            });
+        }
 
-            let parts: Vec<&str> = date_str.split('.').collect();
-            if parts.len() != 3 {
-                return Err(ParseError::InvalidFormat {
-                    field: "date".to_string(),
-                    expected: "DD.MM.YYYY (e.g., 31.12.2024)".to_string(),
-                    actual: date_str.to_string(),
-                });
-            }
+        args[0]
+            .parse::<u32>()
+            .map(TaskCommand::SetTrashRetention)
+            .map_err(|_| ParseError::InvalidValue {
+                field: "retention days".to_string(),
+                value: args[0].to_string(),
+                allowed: "a non-negative number of days".to_string(),
+            })
+    }
 
-            if let (Ok(day), Ok(month), Ok(year)) = (
-                parts[0].parse::<u32>(),
-                parts[1].parse::<u32>(),
-                parts[2].parse::<i32>(),
-            ) {
-                match NaiveDate::from_ymd_opt(year, month, day) {
-                    Some(date) => Ok(TaskCommand::SetDueDate(id, Some(date))),
-                    None => Err(ParseError::InvalidDate(
-                        "Invalid date. Please check the date is valid.".to_string(),
-                    )),
+    /// Parses the 'view' command and its subcommands.
+    /// Supports 'view <name>', 'view save <name> <filters>', 'view list' and 'view delete <name>'
+    fn parse_view_command(&self, args: &[&str]) -> Result<TaskCommand, ParseError> {
+        let usage = "view <name> | view save <name> <filters> | view list | view delete <name>";
+        let Some(first) = args.first() else {
+            return Err(ParseError::MissingArguments {
+                command: "view".to_string(),
+                usage: usage.to_string(),
+            });
+        };
+
+        match first.to_lowercase().as_str() {
+            "list" => Ok(TaskCommand::ListViews),
+            "save" => {
+                if args.len() < 3 {
+                    return Err(ParseError::MissingArguments {
+                        command: "view save".to_string(),
+                        usage: "view save <name> <filters>".to_string(),
+                    });
                 }
-            } else {
-                Err(ParseError::InvalidFormat {
-                    field: "date values".to_string(),
-                    expected: "DD.MM.YYYY".to_string(),
-                    actual: date_str.to_string(),
-                })
+                let name = args[1];
+                if SavedView::is_reserved_name(name) {
+                    return Err(ParseError::InvalidValue {
+                        field: "view name".to_string(),
+                        value: name.to_string(),
+                        allowed: "any name except save, list, delete, remove and rm".to_string(),
+                    });
+                }
+                // Reject filters that 'list' would reject before storing them
+                FilterBuilder::parse_args(&args[2..])?;
+                Ok(TaskCommand::SaveView(name.to_string(), args[2..].join(" ")))
             }
+            "delete" | "remove" | "rm" => match args.get(1) {
+                Some(name) => Ok(TaskCommand::DeleteView(name.to_string())),
+                None => Err(ParseError::MissingArguments {
+                    command: "view delete".to_string(),
+                    usage: "view delete <name>".to_string(),
+                }),
+            },
+            _ => Ok(TaskCommand::ShowView(first.to_string())),
+        }
+    }
+
+    /// Parses the 'set-due' command.
+    /// The date may span several words, e.g. `set-due 3 next monday`.
+    fn parse_set_due_command(&self, args: &[&str]) -> Result<TaskCommand, ParseError> {
+        if args.len() < 2 {
+            Err(ParseError::MissingArguments {
+                command: "set-due".to_string(),
+                usage: "set-due <task id> <date (DD.MM.YYYY, YYYY-MM-DD, tomorrow, fri, next monday, +3d, end-of-month) or 'none' to clear>".to_string(),
+            })
+        } else if let Ok(id) = args[0].parse::<usize>() {
+            let date = parse_due_date(&args[1..].join(" "), Local::now().date_naive())?;
+            Ok(TaskCommand::SetDueDate(id, date))
         } else {
             Err(ParseError::InvalidId(
                 "Invalid task ID. Please provide a number.".to_string(),
@@ -261,57 +357,71 @@ impl TaskCommandInputParser {
     }
 
     /// Parses the 'set-category' command.
-    /// Supports single ID, ID ranges (1-5), lists (1,3,5), combined (1-3,7), or "all"
+    /// Supports single ID, ID ranges (1-5), lists (1,3,5), combined (1-3,7), "all" or "where <filters>"
     fn parse_set_category_command(&self, args: &[&str]) -> Result<TaskCommand, ParseError> {
         if args.len() < 2 {
             Err(ParseError::MissingArguments {
                 command: "set-category".to_string(),
-                usage: "set-category <task id|range|all> <category name or 'none' to clear>"
+                usage: "set-category <task id|range|all|where <filters>> <category name or 'none' to clear>"
                     .to_string(),
             })
         } else {
-            let category_str = args[1..].join(" ");
-            let category = if category_str.to_lowercase() == "none" {
-                None
-            } else if category_str.is_empty() {
-                return Err(ParseError::EmptyInput("Category name".to_string()));
-            } else {
-                Some(category_str)
-            };
+            let (selection_args, value_args) = self.split_selection_args(args);
+            let category = parse_category(&value_args.join(" "))?;
 
-            let selection = self.parse_task_selection(&args[0..1], "set-category")?;
+            let selection = self.parse_task_selection(selection_args, "set-category")?;
             Ok(TaskCommand::SetCategory(selection, category))
         }
     }
 
+    /// Parses the 'tag' and 'untag' commands.
+    /// Supports single ID, ID ranges (1-5), lists (1,3,5), combined (1-3,7), "all" or "where <filters>"
+    /// followed by one or more tags (a leading '+' is ignored, commas also separate tags).
+    /// After a "where" filter only the last word is taken as the tags.
+    fn parse_tag_command(
+        &self,
+        args: &[&str],
+        command_name: &str,
+    ) -> Result<TaskCommand, ParseError> {
+        if args.len() < 2 {
+            return Err(ParseError::MissingArguments {
+                command: command_name.to_string(),
+                usage: format!(
+                    "{} <task id|range|all|where <filters>> <tag> [tag...]",
+                    command_name
+                ),
+            });
+        }
+
+        let (selection_args, value_args) = self.split_selection_args(args);
+        let tags = parse_tags(&value_args.join(" "));
+        if tags.is_empty() {
+            return Err(ParseError::EmptyInput("Tag name".to_string()));
+        }
+
+        let selection = self.parse_task_selection(selection_args, command_name)?;
+        if command_name == "untag" {
+            Ok(TaskCommand::Untag(selection, tags))
+        } else {
+            Ok(TaskCommand::Tag(selection, tags))
+        }
+    }
+
     /// Parses the 'set-recurring' command.
-    /// Supports single ID, ID ranges (1-5), lists (1,3,5), combined (1-3,7), or "all"
+    /// Supports single ID, ID ranges (1-5), lists (1,3,5), combined (1-3,7), "all" or "where <filters>"
     fn parse_set_recurring_command(&self, args: &[&str]) -> Result<TaskCommand, ParseError> {
-        use crate::models::recurrence::Recurrence;
-
         if args.len() < 2 {
             Err(ParseError::MissingArguments {
                 command: "set-recurring".to_string(),
-                usage: "set-recurring <task id|range|all> <daily|weekly|monthly|none>".to_string(),
+                usage:
+                    "set-recurring <task id|range|all|where <filters>> <daily|weekly|monthly|none>"
+                        .to_string(),
             })
         } else {
-            let recurrence_str = args[1].to_lowercase();
-            let recurrence = if recurrence_str == "none" {
-                None
-            } else {
-                match Recurrence::from_str(&recurrence_str) {
-                    Ok(r) => Some(r),
-                    Err(()) => {
-                        return Err(ParseError::InvalidFormat {
-                            field: "recurrence".to_string(),
-                            expected: "daily, weekly, monthly, or none".to_string(),
-                            actual: args[1].to_string(),
-                        })
-                    }
-                }
-            };
+            let (selection_args, value_args) = self.split_selection_args(args);
+            let recurrence = parse_recurrence(value_args[0])?;
 
-            let selection = self.parse_task_selection(&args[0..1], "set-recurring")?;
+            let selection = self.parse_task_selection(selection_args, "set-recurring")?;
             Ok(TaskCommand::SetRecurring(selection, recurrence))
         }
     }
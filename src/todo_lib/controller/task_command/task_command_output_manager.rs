@@ -1,8 +1,12 @@
+use crate::models::agenda::{AgendaEntry, AgendaGroup};
 use crate::models::priority::Priority;
+use crate::models::saved_view::SavedView;
 use crate::models::task::Task;
 use crate::models::task_filter::TaskFilter;
 use crate::models::task_status::TaskStatus;
+use crate::models::todo_error::TodoError;
 use crate::models::todo_list::TodoList;
+use crate::models::trash::{TrashedProject, TrashedTask};
 use crate::ui::formatters::{MessageFormatter, TaskFormatter};
 use crate::ui::output::OutputWriter;
 use chrono::NaiveDate;
@@ -110,7 +114,172 @@ impl<O: OutputWriter> TaskCommandOutputManager<O> {
     pub fn show_task_removed(&mut self, description: &str) {
         self.output_writer
             .borrow_mut()
-            .show_success(&format!("Task removed: '{}'", description));
+            .show_success(&format!("Task removed: '{}' (moved to trash)", description));
+    }
+
+    /// Displays the trash of the current project and the deleted projects.
+    pub fn show_trash(
+        &mut self,
+        tasks: &[TrashedTask],
+        projects: &[TrashedProject],
+        retention_days: u32,
+    ) {
+        if tasks.is_empty() && projects.is_empty() {
+            self.output_writer
+                .borrow_mut()
+                .write_line(&MessageFormatter::warning("Trash is empty."));
+            return;
+        }
+
+        self.output_writer
+            .borrow_mut()
+            .write_line(&format!("\n{}", MessageFormatter::section_title("Trash")));
+
+        for entry in tasks {
+            let subtasks = match entry.subtasks.len() {
+                0 => String::new(),
+                1 => " (+1 subtask)".to_string(),
+                n => format!(" (+{} subtasks)", n),
+            };
+            self.output_writer.borrow_mut().write_line(&format!(
+                "  {} {}{} {}",
+                format!("#{}", entry.task.id).bright_white().bold(),
+                entry.task.description,
+                subtasks,
+                format!(
+                    "(removed: {})",
+                    TaskFormatter::format_timestamp(entry.deleted_at)
+                )
+                .bright_black()
+            ));
+        }
+
+        if !projects.is_empty() {
+            self.output_writer
+                .borrow_mut()
+                .write_line(&format!("  {}", "Deleted projects:".bright_cyan()));
+            for entry in projects {
+                self.output_writer.borrow_mut().write_line(&format!(
+                    "    {} ({} tasks) {}",
+                    entry.project.name.bright_white().bold(),
+                    entry.project.todo_list.get_tasks().len(),
+                    format!(
+                        "(deleted: {})",
+                        TaskFormatter::format_timestamp(entry.deleted_at)
+                    )
+                    .bright_black()
+                ));
+            }
+        }
+
+        self.output_writer.borrow_mut().write_line(&format!(
+            "  {}",
+            format!("Entries are purged after {} days.", retention_days).bright_black()
+        ));
+        self.output_writer
+            .borrow_mut()
+            .write_line(&format!("{}\n", MessageFormatter::separator(13)));
+    }
+
+    /// Displays a success message after restoring a task from the trash.
+    pub fn show_task_restored(&mut self, description: &str, original_id: usize, id: usize) {
+        let message = if original_id == id {
+            format!("Task restored: '{}'", description)
+        } else {
+            format!(
+                "Task restored: '{}' (ID {} was taken, now ID {})",
+                description, original_id, id
+            )
+        };
+        self.output_writer.borrow_mut().show_success(&message);
+    }
+
+    /// Displays a success message after emptying the trash.
+    pub fn show_trash_emptied(&mut self, task_count: usize, project_count: usize) {
+        if task_count == 0 && project_count == 0 {
+            self.output_writer
+                .borrow_mut()
+                .write_line(&MessageFormatter::warning("Trash is already empty."));
+            return;
+        }
+
+        let mut message = format!("Permanently deleted {} removed task(s)", task_count);
+        if project_count > 0 {
+            message.push_str(&format!(" and {} project(s)", project_count));
+        }
+        message.push('.');
+        self.output_writer.borrow_mut().show_success(&message);
+    }
+
+    /// Displays a success message after changing the trash retention period.
+    pub fn show_trash_retention_set(&mut self, days: u32) {
+        self.output_writer.borrow_mut().show_success(&format!(
+            "Trash entries are now purged after {} days.",
+            days
+        ));
+    }
+
+    /// Displays a success message after saving a view.
+    pub fn show_view_saved(&mut self, name: &str, filter: &str, replaced: bool) {
+        let verb = if replaced { "updated" } else { "saved" };
+        self.output_writer
+            .borrow_mut()
+            .show_success(&format!("View '{}' {}: list {}", name, verb, filter));
+    }
+
+    /// Displays all saved views.
+    pub fn show_views(&mut self, views: &[SavedView]) {
+        self.output_writer.borrow_mut().write_line("");
+        if views.is_empty() {
+            self.output_writer
+                .borrow_mut()
+                .write_line(&MessageFormatter::warning(
+                    "No saved views. Use: view save <name> <filters>",
+                ));
+            self.output_writer.borrow_mut().write_line("");
+            return;
+        }
+
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::section_title("Saved Views"));
+        let name_width = views.iter().map(|view| view.name.len()).max().unwrap_or(0);
+        for view in views {
+            self.output_writer.borrow_mut().write_line(&format!(
+                "  {}  {}",
+                format!("{:<width$}", view.name, width = name_width)
+                    .bright_white()
+                    .bold(),
+                view.filter.bright_black()
+            ));
+        }
+        self.output_writer.borrow_mut().write_line(&format!(
+            "{}
+",
+            MessageFormatter::separator(19)
+        ));
+    }
+
+    /// Displays a success message after deleting a view.
+    pub fn show_view_deleted(&mut self, name: &str) {
+        self.output_writer
+            .borrow_mut()
+            .show_success(&format!("View '{}' deleted.", name));
+    }
+
+    /// Displays an error when no view with the given name exists.
+    pub fn show_view_not_found(&mut self, name: &str) {
+        self.output_writer.borrow_mut().show_error(&format!(
+            "No saved view named '{}'. Type 'view list' to see saved views.",
+            name
+        ));
+    }
+
+    /// Displays an error when a stored view can no longer be parsed.
+    pub fn show_invalid_view(&mut self, name: &str, message: &str) {
+        self.output_writer
+            .borrow_mut()
+            .show_error(&format!("View '{}' is invalid: {}", name, message));
     }
 
     /// Displays a success message after completing a task.
@@ -164,6 +333,29 @@ impl<O: OutputWriter> TaskCommandOutputManager<O> {
         }
     }
 
+    /// Displays the IDs of the tasks matched by a `where` filter before acting on them.
+    pub fn show_filter_matches(&mut self, ids: &[usize]) {
+        if ids.is_empty() {
+            self.output_writer
+                .borrow_mut()
+                .write_line(&MessageFormatter::warning("No tasks match the filter."));
+            return;
+        }
+
+        let id_list = ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let label = if ids.len() == 1 { "task" } else { "tasks" };
+        self.output_writer.borrow_mut().write_line(&format!(
+            "Filter matched {} {}: {}",
+            ids.len(),
+            label,
+            id_list
+        ));
+    }
+
     /// Displays a success message after completing all tasks.
     pub fn show_all_tasks_completed(&mut self, count: usize) {
         if count > 0 {
@@ -184,9 +376,9 @@ impl<O: OutputWriter> TaskCommandOutputManager<O> {
     pub fn show_multiple_tasks_removed(&mut self, removed_count: usize, not_found: &[usize]) {
         if removed_count > 0 {
             let message = if removed_count == 1 {
-                "Removed 1 task.".to_string()
+                "Removed 1 task (moved to trash).".to_string()
             } else {
-                format!("Removed {} tasks.", removed_count)
+                format!("Removed {} tasks (moved to trash).", removed_count)
             };
             self.output_writer.borrow_mut().show_success(&message);
         }
@@ -213,9 +405,9 @@ impl<O: OutputWriter> TaskCommandOutputManager<O> {
     pub fn show_all_tasks_removed(&mut self, count: usize) {
         if count > 0 {
             let message = if count == 1 {
-                "Removed 1 task.".to_string()
+                "Removed 1 task (moved to trash).".to_string()
             } else {
-                format!("Removed all {} tasks.", count)
+                format!("Removed all {} tasks (moved to trash).", count)
             };
             self.output_writer.borrow_mut().show_success(&message);
         } else {
@@ -399,6 +591,43 @@ impl<O: OutputWriter> TaskCommandOutputManager<O> {
         }
     }
 
+    /// Displays a success message after adding or removing tags for multiple tasks.
+    pub fn show_multiple_tags_updated(
+        &mut self,
+        updated_count: usize,
+        tags: &[String],
+        added: bool,
+        not_found: &[usize],
+    ) {
+        if updated_count > 0 {
+            let tags_str = Self::format_tag_list(tags);
+            let message = match (added, updated_count) {
+                (true, 1) => format!("Added {} to 1 task.", tags_str),
+                (true, _) => format!("Added {} to {} tasks.", tags_str, updated_count),
+                (false, 1) => format!("Removed {} from 1 task.", tags_str),
+                (false, _) => format!("Removed {} from {} tasks.", tags_str, updated_count),
+            };
+            self.output_writer.borrow_mut().show_success(&message);
+        }
+
+        if !not_found.is_empty() {
+            let ids = not_found
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            self.output_writer
+                .borrow_mut()
+                .show_error(&format!("Tasks with IDs {} not found.", ids));
+        }
+
+        if updated_count == 0 && not_found.is_empty() {
+            self.output_writer
+                .borrow_mut()
+                .show_error("No tasks to update.");
+        }
+    }
+
     /// Displays a success message after updating a task.
     pub fn show_task_updated(&mut self, old_desc: &str, new_desc: &str) {
         self.output_writer
@@ -466,6 +695,69 @@ impl<O: OutputWriter> TaskCommandOutputManager<O> {
         self.show_category_set(description, None);
     }
 
+    /// Displays a success message after changing a task's status.
+    pub fn show_status_set(&mut self, description: &str, status: TaskStatus) {
+        self.output_writer.borrow_mut().show_success(&format!(
+            "Task '{}' marked as {}.",
+            description,
+            status.as_str().to_lowercase()
+        ));
+    }
+
+    /// Displays a success message after changing the status of multiple tasks.
+    pub fn show_multiple_statuses_set(
+        &mut self,
+        updated_count: usize,
+        status: TaskStatus,
+        not_found: &[usize],
+    ) {
+        if updated_count > 0 {
+            let status_str = status.as_str().to_lowercase();
+            let message = if updated_count == 1 {
+                format!("Marked 1 task as {}.", status_str)
+            } else {
+                format!("Marked {} tasks as {}.", updated_count, status_str)
+            };
+            self.output_writer.borrow_mut().show_success(&message);
+        }
+
+        if !not_found.is_empty() {
+            let ids = not_found
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            self.output_writer
+                .borrow_mut()
+                .show_error(&format!("Tasks with IDs {} not found.", ids));
+        }
+    }
+
+    /// Displays a success message after adding or removing tags.
+    pub fn show_tags_updated(&mut self, description: &str, tags: &[String], added: bool) {
+        let tags_str = Self::format_tag_list(tags);
+        let message = if added {
+            format!("Added {} to task: '{}'", tags_str, description)
+        } else {
+            format!("Removed {} from task: '{}'", tags_str, description)
+        };
+        self.output_writer.borrow_mut().show_success(&message);
+    }
+
+    /// Formats a list of tags for messages (e.g. "tags 'work', 'urgent'").
+    fn format_tag_list(tags: &[String]) -> String {
+        let quoted = tags
+            .iter()
+            .map(|tag| format!("'{}'", tag))
+            .collect::<Vec<_>>()
+            .join(", ");
+        if tags.len() == 1 {
+            format!("tag {}", quoted)
+        } else {
+            format!("tags {}", quoted)
+        }
+    }
+
     /// Displays a success message after setting recurrence.
     pub fn show_recurrence_set(
         &mut self,
@@ -554,6 +846,11 @@ impl<O: OutputWriter> TaskCommandOutputManager<O> {
         self.output_writer.borrow_mut().show_error(message);
     }
 
+    /// Displays why a task operation failed.
+    pub fn show_todo_error(&mut self, error: &TodoError) {
+        self.output_writer.borrow_mut().show_error(&error.message());
+    }
+
     /// Displays a list of tasks.
     pub fn show_task_list(&mut self, todo_list: &TodoList, filter: &TaskFilter) {
         let tasks = todo_list.get_filtered_tasks(filter);
@@ -578,6 +875,60 @@ impl<O: OutputWriter> TaskCommandOutputManager<O> {
         self.show_statistics_internal(&stats);
     }
 
+    /// Displays the agenda with one section per group of days.
+    ///
+    /// Each task is shown with the date it is due; projected occurrences of
+    /// recurring tasks are marked as such.
+    pub fn show_agenda(&mut self, agenda: &[(AgendaGroup, Vec<AgendaEntry>)], days: u32) {
+        let title = format!(
+            "Agenda (next {} day{})",
+            days,
+            if days == 1 { "" } else { "s" }
+        );
+        self.output_writer
+            .borrow_mut()
+            .write_line(&format!("\n{}", MessageFormatter::section_title(&title)));
+
+        if agenda.is_empty() {
+            self.output_writer
+                .borrow_mut()
+                .write_line(&MessageFormatter::warning("No tasks due."));
+            return;
+        }
+
+        let tasks: Vec<&Task> = agenda
+            .iter()
+            .flat_map(|(_, entries)| entries.iter().map(|entry| entry.task))
+            .collect();
+        let max_id_width = TaskFormatter::calculate_max_id_width(&tasks);
+
+        for (group, entries) in agenda {
+            self.output_writer.borrow_mut().write_line(&format!(
+                "\n{} ({})",
+                group.as_str().bright_yellow().bold(),
+                entries.len()
+            ));
+            for entry in entries {
+                let projection = if entry.is_projection {
+                    " (next occurrence)".bright_black().to_string()
+                } else {
+                    String::new()
+                };
+                self.output_writer.borrow_mut().write_line(&format!(
+                    "  {} {}{}",
+                    entry.date.format("%a %d.%m.%Y").to_string().bright_cyan(),
+                    TaskFormatter::format_task(entry.task, max_id_width),
+                    projection
+                ));
+            }
+        }
+
+        self.output_writer.borrow_mut().write_line(&format!(
+            "{}\n",
+            MessageFormatter::separator(title.len() + 8)
+        ));
+    }
+
     /// Helper method to display a list of tasks with a given title.
     fn show_task_list_internal(&mut self, title: &str, tasks: Vec<&Task>) {
         let separator_length = title.len() + 8;
@@ -614,7 +965,16 @@ impl<O: OutputWriter> TaskCommandOutputManager<O> {
 
     /// Displays all tasks hierarchically with subtasks indented under their parents.
     pub fn show_all_tasks_hierarchical(&mut self, todo_list: &TodoList) {
+        self.show_sorted_tasks_hierarchical(todo_list, &TaskFilter::all());
+    }
+
+    /// Displays all tasks with subtasks indented under their parents.
+    ///
+    /// Top-level tasks and the subtasks of each parent are ordered by the
+    /// sort keys and sort order of `filter`; its other criteria are ignored.
+    pub fn show_sorted_tasks_hierarchical(&mut self, todo_list: &TodoList, filter: &TaskFilter) {
         let tasks = todo_list.get_tasks();
+        let sort_order = filter.sort_order.unwrap_or_default();
 
         if tasks.is_empty() {
             self.output_writer
@@ -633,32 +993,33 @@ impl<O: OutputWriter> TaskCommandOutputManager<O> {
         ));
 
         // Display only top-level tasks (tasks without a parent)
-        for task in tasks {
-            if !task.is_subtask() {
-                // Display parent task
-                let formatted_task = TaskFormatter::format_task(task, max_id_width);
-
-                // Add subtask progress if task has subtasks
-                let subtask_count = todo_list.get_subtask_count(task.id);
-                if subtask_count > 0 {
-                    let completed_count = todo_list.get_completed_subtask_count(task.id);
-                    let progress =
-                        format!(" ({}/{} subtasks)", completed_count, subtask_count).bright_cyan();
-                    self.output_writer
-                        .borrow_mut()
-                        .write_line(&format!("{}{}", formatted_task, progress));
-                } else {
-                    self.output_writer.borrow_mut().write_line(&formatted_task);
-                }
+        let mut top_level_tasks: Vec<&Task> = tasks.iter().filter(|t| !t.is_subtask()).collect();
+        todo_list.sort_tasks(&mut top_level_tasks, &filter.sort_keys, sort_order);
+        for task in top_level_tasks {
+            // Display parent task
+            let formatted_task = TaskFormatter::format_task(task, max_id_width);
 
-                // Display subtasks indented
-                let subtasks = todo_list.get_subtasks(task.id);
-                for subtask in subtasks {
-                    let formatted_subtask = TaskFormatter::format_task(subtask, max_id_width);
-                    self.output_writer
-                        .borrow_mut()
-                        .write_line(&format!("  ↳ {}", formatted_subtask));
-                }
+            // Add subtask progress if task has subtasks
+            let subtask_count = todo_list.get_subtask_count(task.id);
+            if subtask_count > 0 {
+                let completed_count = todo_list.get_completed_subtask_count(task.id);
+                let progress =
+                    format!(" ({}/{} subtasks)", completed_count, subtask_count).bright_cyan();
+                self.output_writer
+                    .borrow_mut()
+                    .write_line(&format!("{}{}", formatted_task, progress));
+            } else {
+                self.output_writer.borrow_mut().write_line(&formatted_task);
+            }
+
+            // Display subtasks indented
+            let mut subtasks = todo_list.get_subtasks(task.id);
+            todo_list.sort_tasks(&mut subtasks, &filter.sort_keys, sort_order);
+            for subtask in subtasks {
+                let formatted_subtask = TaskFormatter::format_task(subtask, max_id_width);
+                self.output_writer
+                    .borrow_mut()
+                    .write_line(&format!("  ↳ {}", formatted_subtask));
             }
         }
 
@@ -709,9 +1070,8 @@ impl<O: OutputWriter> TaskCommandOutputManager<O> {
     pub fn show_filtered_tasks(&mut self, tasks: &[&Task], filter: &TaskFilter) {
         if tasks.is_empty() {
             let status_str = match filter.status {
-                Some(TaskStatus::Completed) => "completed ",
-                Some(TaskStatus::Pending) => "pending ",
-                None => "",
+                Some(status) => format!("{} ", status.as_str().to_lowercase()),
+                None => String::new(),
             };
             let priority_str = match filter.priority {
                 Some(priority) => format!("{} priority ", priority.as_str()),
@@ -730,10 +1090,7 @@ impl<O: OutputWriter> TaskCommandOutputManager<O> {
             title_parts.push(format!("{} Priority", priority.as_str()));
         }
         if let Some(status) = filter.status {
-            match status {
-                TaskStatus::Completed => title_parts.push("Completed".to_string()),
-                TaskStatus::Pending => title_parts.push("Pending".to_string()),
-            }
+            title_parts.push(status.as_str().to_string());
         }
 
         let title = if title_parts.is_empty() {
@@ -778,6 +1135,13 @@ impl<O: OutputWriter> TaskCommandOutputManager<O> {
             "Completed".bright_white().bold(),
             stats.completed.to_string().green()
         ));
+        if stats.cancelled > 0 {
+            self.output_writer.borrow_mut().write_line(&format!(
+                "  {}: {}",
+                "Cancelled".bright_white().bold(),
+                stats.cancelled.to_string().bright_black()
+            ));
+        }
         self.output_writer.borrow_mut().write_line(&format!(
             "  {}: {}",
             "Pending".bright_white().bold(),
@@ -789,6 +1153,29 @@ impl<O: OutputWriter> TaskCommandOutputManager<O> {
             stats.completion_percentage
         ));
 
+        // Status breakdown of open tasks
+        if stats.in_progress + stats.waiting + stats.blocked > 0 {
+            self.output_writer.borrow_mut().write_line("");
+            self.output_writer
+                .borrow_mut()
+                .write_line(&format!("  {}", "By Status:".bright_cyan()));
+            self.output_writer.borrow_mut().write_line(&format!(
+                "    {} {}",
+                "• In Progress:".bright_cyan(),
+                stats.in_progress
+            ));
+            self.output_writer.borrow_mut().write_line(&format!(
+                "    {} {}",
+                "… Waiting:    ".yellow(),
+                stats.waiting
+            ));
+            self.output_writer.borrow_mut().write_line(&format!(
+                "    {} {}",
+                "! Blocked:    ".red(),
+                stats.blocked
+            ));
+        }
+
         // Priority breakdown
         if stats.total > 0 {
             self.output_writer.borrow_mut().write_line("");
@@ -812,6 +1199,21 @@ impl<O: OutputWriter> TaskCommandOutputManager<O> {
             ));
         }
 
+        // Tag breakdown
+        if !stats.tag_counts.is_empty() {
+            self.output_writer.borrow_mut().write_line("");
+            self.output_writer
+                .borrow_mut()
+                .write_line(&format!("  {}", "By Tag:".bright_cyan()));
+            for (tag, count) in &stats.tag_counts {
+                self.output_writer.borrow_mut().write_line(&format!(
+                    "    {} {}",
+                    format!("#{}:", tag).bright_magenta(),
+                    count
+                ));
+            }
+        }
+
         self.output_writer.borrow_mut().write_line("");
     }
 
@@ -869,6 +1271,53 @@ mod tests {
         assert!(output.contains("Test task"));
     }
 
+    #[test]
+    fn test_task_output_writer_todo_error() {
+        setup();
+        let mut buffer = Vec::new();
+        let output_writer = FileOutputWriter::new(&mut buffer);
+        let mut writer = TaskCommandOutputManager::new(Rc::new(RefCell::new(output_writer)));
+
+        writer.show_todo_error(&TodoError::CircularDependency {
+            task_id: 1,
+            depends_on_id: 3,
+        });
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(
+            output.contains("Making task 1 depend on task 3 would create a circular dependency.")
+        );
+    }
+
+    #[test]
+    fn test_task_output_writer_agenda() {
+        setup();
+        let mut buffer = Vec::new();
+        let output_writer = FileOutputWriter::new(&mut buffer);
+        let mut writer = TaskCommandOutputManager::new(Rc::new(RefCell::new(output_writer)));
+
+        let task = Task::new(4, "Water plants".to_string());
+        let date = NaiveDate::from_ymd_opt(2025, 1, 17).unwrap();
+        let agenda = vec![(
+            AgendaGroup::ThisWeek,
+            vec![AgendaEntry {
+                task: &task,
+                date,
+                is_projection: true,
+            }],
+        )];
+        writer.show_agenda(&agenda, 7);
+        writer.show_agenda(&[], 1);
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("Agenda (next 7 days)"));
+        assert!(output.contains("This Week (1)"));
+        assert!(output.contains("Fri 17.01.2025"));
+        assert!(output.contains("Water plants (next occurrence)"));
+        assert!(output.contains("Agenda (next 1 day)"));
+        assert!(output.contains("No tasks due."));
+    }
+
     #[test]
     fn test_task_output_writer_task_removed() {
         setup();
@@ -952,6 +1401,20 @@ mod tests {
         assert!(output.contains("marked as pending"));
     }
 
+    #[test]
+    fn test_task_output_writer_multiple_statuses_set() {
+        setup();
+        let mut buffer = Vec::new();
+        let output_writer = FileOutputWriter::new(&mut buffer);
+        let mut writer = TaskCommandOutputManager::new(Rc::new(RefCell::new(output_writer)));
+
+        writer.show_multiple_statuses_set(2, TaskStatus::InProgress, &[9]);
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("Marked 2 tasks as in progress."));
+        assert!(output.contains("Tasks with IDs 9 not found."));
+    }
+
     #[test]
     fn test_task_output_writer_priority_set() {
         setup();
@@ -1025,6 +1488,26 @@ mod tests {
         assert!(output.contains("Uncategorized task"));
     }
 
+    #[test]
+    fn test_task_output_writer_tags_updated() {
+        setup();
+        let mut buffer = Vec::new();
+        let output_writer = FileOutputWriter::new(&mut buffer);
+        let mut writer = TaskCommandOutputManager::new(Rc::new(RefCell::new(output_writer)));
+
+        writer.show_tags_updated(
+            "Tagged task",
+            &["work".to_string(), "urgent".to_string()],
+            true,
+        );
+        writer.show_multiple_tags_updated(2, &["work".to_string()], false, &[9]);
+
+        let output = String::from_utf8(buffer).unwrap();
+        assert!(output.contains("Added tags 'work', 'urgent' to task: 'Tagged task'"));
+        assert!(output.contains("Removed tag 'work' from 2 tasks."));
+        assert!(output.contains("Tasks with IDs 9 not found."));
+    }
+
     #[test]
     fn test_task_output_writer_task_not_found() {
         setup();
@@ -1135,10 +1618,15 @@ mod tests {
             total: 10,
             completed: 3,
             pending: 7,
+            in_progress: 2,
+            waiting: 0,
+            blocked: 1,
+            cancelled: 0,
             completion_percentage: 30.0,
             high_priority: 2,
             medium_priority: 5,
             low_priority: 3,
+            tag_counts: vec![("home".to_string(), 1), ("work".to_string(), 4)],
         };
 
         writer.show_statistics_internal(&stats);
@@ -1152,6 +1640,11 @@ mod tests {
         assert!(output.contains("Pending"));
         assert!(output.contains("7"));
         assert!(output.contains("30.0"));
+        assert!(output.contains("By Status:"));
+        assert!(output.contains("In Progress: 2"));
+        assert!(!output.contains("Cancelled"));
+        assert!(output.contains("By Tag:"));
+        assert!(output.contains("#work: 4"));
     }
 
     #[test]
@@ -1,3 +1,5 @@
+use crate::models::task_filter::TaskFilter;
+
 /// Represents how tasks are specified for bulk operations.
 #[derive(Debug, Clone, PartialEq)]
 pub enum TaskSelection {
@@ -9,4 +11,7 @@ pub enum TaskSelection {
 
     /// All tasks
     All,
+
+    /// Tasks matching a filter, resolved when the command is executed
+    Filter(TaskFilter),
 }
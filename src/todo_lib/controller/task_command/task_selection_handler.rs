@@ -1,23 +1,57 @@
 use crate::controller::task_command::TaskSelection;
 use crate::models::command_controller_result::CommandControllerResult;
+use crate::models::task_filter::TaskFilter;
+use crate::models::todo_list::TodoList;
 
-/// Handler for dispatching task operations based on TaskSelection (Single, Multiple, All)
+/// Handler for dispatching task operations based on TaskSelection (Single, Multiple, All, Filter)
 ///
 /// This eliminates repetitive match statements in the controller by providing
 /// a generic dispatch mechanism.
 pub struct TaskSelectionHandler;
 
 impl TaskSelectionHandler {
+    /// Returns the IDs of the tasks matching a filter, in the filter's sort order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::controller::task_command::TaskSelectionHandler;
+    /// use todo_manager::models::task::TaskWithoutId;
+    /// use todo_manager::models::task_filter::TaskFilter;
+    /// use todo_manager::models::task_status::TaskStatus;
+    /// use todo_manager::models::todo_list::TodoList;
+    ///
+    /// let mut list = TodoList::new();
+    /// list.add_task(TaskWithoutId::new("Open".to_string()));
+    /// let done = list.add_task(TaskWithoutId::new("Done".to_string()));
+    /// list.complete_task(done).unwrap();
+    ///
+    /// let filter = TaskFilter::all().with_status(TaskStatus::Completed);
+    /// assert_eq!(TaskSelectionHandler::matching_ids(&filter, &list), vec![done]);
+    /// ```
+    pub fn matching_ids(filter: &TaskFilter, todo_list: &TodoList) -> Vec<usize> {
+        todo_list
+            .get_filtered_tasks(filter)
+            .iter()
+            .map(|task| task.id)
+            .collect()
+    }
+
     /// Executes a task operation based on the selection type
     ///
+    /// A filter selection is passed to `multiple_fn` with the IDs of the tasks
+    /// of `todo_list` it matches.
+    ///
     /// # Arguments
     ///
-    /// * `selection` - The task selection (Single, Multiple, or All)
+    /// * `selection` - The task selection (Single, Multiple, All, or Filter)
+    /// * `todo_list` - The list a filter selection is applied to
     /// * `single_fn` - Function to execute for a single task
     /// * `multiple_fn` - Function to execute for multiple tasks
     /// * `all_fn` - Function to execute for all tasks
     pub fn execute<F1, F2, F3>(
         selection: &TaskSelection,
+        todo_list: &TodoList,
         mut single_fn: F1,
         mut multiple_fn: F2,
         mut all_fn: F3,
@@ -31,15 +65,17 @@ impl TaskSelectionHandler {
             TaskSelection::Single(id) => single_fn(*id),
             TaskSelection::Multiple(ids) => multiple_fn(ids),
             TaskSelection::All => all_fn(),
+            TaskSelection::Filter(filter) => multiple_fn(&Self::matching_ids(filter, todo_list)),
         }
     }
 
     /// Executes a task operation with an additional parameter based on the selection type
     ///
     /// This is useful for operations like set_priority or set_category that require
-    /// an additional parameter.
+    /// an additional parameter. Filter selections are handled as in [`TaskSelectionHandler::execute`].
     pub fn execute_with_param<P, F1, F2, F3>(
         selection: &TaskSelection,
+        todo_list: &TodoList,
         param: P,
         mut single_fn: F1,
         mut multiple_fn: F2,
@@ -55,6 +91,9 @@ impl TaskSelectionHandler {
             TaskSelection::Single(id) => single_fn(*id, param),
             TaskSelection::Multiple(ids) => multiple_fn(ids, param),
             TaskSelection::All => all_fn(param),
+            TaskSelection::Filter(filter) => {
+                multiple_fn(&Self::matching_ids(filter, todo_list), param)
+            }
         }
     }
 }
@@ -63,12 +102,15 @@ impl TaskSelectionHandler {
 mod tests {
     use super::*;
     use crate::models::command_controller_result::CommandControllerResultAction;
+    use crate::models::task::TaskWithoutId;
+    use crate::models::task_status::TaskStatus;
 
     #[test]
     fn test_execute_single() {
         let selection = TaskSelection::Single(1);
         let result = TaskSelectionHandler::execute(
             &selection,
+            &TodoList::new(),
             |id| {
                 assert_eq!(id, 1);
                 CommandControllerResult::empty()
@@ -85,6 +127,7 @@ mod tests {
         let selection = TaskSelection::Multiple(vec![1, 2, 3]);
         let result = TaskSelectionHandler::execute(
             &selection,
+            &TodoList::new(),
             |_id| panic!("Should not call single_fn"),
             |ids| {
                 assert_eq!(ids, &[1, 2, 3]);
@@ -101,6 +144,7 @@ mod tests {
         let selection = TaskSelection::All;
         let result = TaskSelectionHandler::execute(
             &selection,
+            &TodoList::new(),
             |_id| panic!("Should not call single_fn"),
             |_ids| panic!("Should not call multiple_fn"),
             CommandControllerResult::empty,
@@ -114,6 +158,7 @@ mod tests {
         let selection = TaskSelection::Single(5);
         let result = TaskSelectionHandler::execute_with_param(
             &selection,
+            &TodoList::new(),
             "test_value",
             |id, param| {
                 assert_eq!(id, 5);
@@ -132,6 +177,7 @@ mod tests {
         let selection = TaskSelection::Multiple(vec![10, 20]);
         let result = TaskSelectionHandler::execute_with_param(
             &selection,
+            &TodoList::new(),
             42,
             |_id, _param| panic!("Should not call single_fn"),
             |ids, param| {
@@ -150,6 +196,7 @@ mod tests {
         let selection = TaskSelection::All;
         let result = TaskSelectionHandler::execute_with_param(
             &selection,
+            &TodoList::new(),
             true,
             |_id, _param| panic!("Should not call single_fn"),
             |_ids, _param| panic!("Should not call multiple_fn"),
@@ -161,4 +208,27 @@ mod tests {
 
         assert!(result.has_action(&CommandControllerResultAction::SaveTodoList));
     }
+
+    #[test]
+    fn test_execute_resolves_filter() {
+        let mut list = TodoList::new();
+        list.add_task(TaskWithoutId::new("Open".to_string()));
+        let done = list.add_task(TaskWithoutId::new("Done".to_string()));
+        list.complete_task(done).unwrap();
+
+        let selection = TaskSelection::Filter(TaskFilter::all().with_status(TaskStatus::Completed));
+        let result = TaskSelectionHandler::execute_with_param(
+            &selection,
+            &list,
+            "param",
+            |_id, _param| panic!("Should not call single_fn"),
+            |ids, _param| {
+                assert_eq!(ids, &[done]);
+                CommandControllerResult::with_action(CommandControllerResultAction::SaveTodoList)
+            },
+            |_param| panic!("Should not call all_fn"),
+        );
+
+        assert!(result.has_action(&CommandControllerResultAction::SaveTodoList));
+    }
 }
@@ -0,0 +1,360 @@
+use crate::controller::project_command::ProjectManager;
+use std::collections::{HashSet, VecDeque};
+
+/// Default number of changes that can be undone.
+pub const DEFAULT_UNDO_HISTORY_CAPACITY: usize = 50;
+
+/// A recorded state of all projects together with the command that changed it.
+#[derive(Debug, Clone)]
+struct HistoryEntry {
+    /// The command that was executed after the snapshot was taken
+    command: String,
+
+    /// The state of all projects before (undo) or after (redo) the command
+    snapshot: ProjectManager,
+}
+
+/// Bounded undo/redo history of `ProjectManager` snapshots.
+///
+/// A snapshot is recorded before every command that changed the projects.
+/// Undoing restores the most recent snapshot and keeps the replaced state
+/// so it can be redone. Recording a new change clears the redo history.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::controller::project_command::ProjectManager;
+/// use todo_manager::controller::UndoHistory;
+/// use todo_manager::models::task::TaskWithoutId;
+///
+/// let mut manager = ProjectManager::new();
+/// let mut history = UndoHistory::new(10);
+///
+/// let before = manager.clone();
+/// manager.get_current_todo_list_mut().add_task(TaskWithoutId::new("Task".to_string()));
+/// history.record("add Task", before);
+///
+/// let (command, restored) = history.undo(&manager).unwrap();
+/// assert_eq!(command, "add Task");
+/// assert!(restored.get_current_todo_list().is_empty());
+/// assert_eq!(history.redo_count(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct UndoHistory {
+    undo_stack: VecDeque<HistoryEntry>,
+    redo_stack: Vec<HistoryEntry>,
+    capacity: usize,
+}
+
+impl UndoHistory {
+    /// Creates an empty history that keeps at most `capacity` undo steps.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            capacity,
+        }
+    }
+
+    /// Records the state of the projects before `command` changed them.
+    ///
+    /// The oldest entry is dropped once the capacity is exceeded and the
+    /// redo history is cleared.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command that caused the change
+    /// * `before` - The state of the projects before the command was executed
+    pub fn record(&mut self, command: &str, before: ProjectManager) {
+        self.push_undo(HistoryEntry {
+            command: command.to_string(),
+            snapshot: before,
+        });
+        self.redo_stack.clear();
+    }
+
+    /// Takes the most recent undo step.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - The current state, kept so that the step can be redone
+    ///
+    /// # Returns
+    ///
+    /// The undone command and the state to restore, or `None` if there is nothing to undo
+    pub fn undo(&mut self, current: &ProjectManager) -> Option<(String, ProjectManager)> {
+        let entry = self.undo_stack.pop_back()?;
+        self.redo_stack.push(HistoryEntry {
+            command: entry.command.clone(),
+            snapshot: current.clone(),
+        });
+        Some((entry.command, entry.snapshot))
+    }
+
+    /// Takes the most recently undone step.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - The current state, kept so that the step can be undone again
+    ///
+    /// # Returns
+    ///
+    /// The redone command and the state to restore, or `None` if there is nothing to redo
+    pub fn redo(&mut self, current: &ProjectManager) -> Option<(String, ProjectManager)> {
+        let entry = self.redo_stack.pop()?;
+        self.push_undo(HistoryEntry {
+            command: entry.command.clone(),
+            snapshot: current.clone(),
+        });
+        Some((entry.command, entry.snapshot))
+    }
+
+    /// Returns the number of steps that can be undone.
+    pub fn undo_count(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    /// Returns the number of steps that can be redone.
+    pub fn redo_count(&self) -> usize {
+        self.redo_stack.len()
+    }
+
+    fn push_undo(&mut self, entry: HistoryEntry) {
+        self.undo_stack.push_back(entry);
+        while self.undo_stack.len() > self.capacity {
+            self.undo_stack.pop_front();
+        }
+    }
+}
+
+impl Default for UndoHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_UNDO_HISTORY_CAPACITY)
+    }
+}
+
+/// Describes the differences between two project states in human-readable lines.
+///
+/// Used to summarize what an undo or redo changed.
+///
+/// # Arguments
+///
+/// * `from` - The state before the change
+/// * `to` - The state after the change
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::controller::project_command::ProjectManager;
+/// use todo_manager::controller::undo_history::describe_changes;
+/// use todo_manager::models::task::TaskWithoutId;
+///
+/// let before = ProjectManager::new();
+/// let mut after = before.clone();
+/// after.get_current_todo_list_mut().add_task(TaskWithoutId::new("Task".to_string()));
+///
+/// assert_eq!(describe_changes(&before, &after), vec!["Project 'default': 1 task added"]);
+/// ```
+pub fn describe_changes(from: &ProjectManager, to: &ProjectManager) -> Vec<String> {
+    let mut changes = Vec::new();
+    let from_names = from.list_projects();
+    let to_names = to.list_projects();
+
+    let removed: Vec<&String> = from_names
+        .iter()
+        .filter(|name| !to_names.contains(name))
+        .collect();
+    let added: Vec<&String> = to_names
+        .iter()
+        .filter(|name| !from_names.contains(name))
+        .collect();
+
+    let is_rename = removed.len() == 1
+        && added.len() == 1
+        && from.get_project(removed[0]).map(|p| &p.todo_list)
+            == to.get_project(added[0]).map(|p| &p.todo_list);
+    if is_rename {
+        changes.push(format!(
+            "Project '{}' renamed to '{}'",
+            removed[0], added[0]
+        ));
+    } else {
+        for name in &removed {
+            changes.push(format!("Project '{}' removed", name));
+        }
+        for name in &added {
+            changes.push(format!("Project '{}' added", name));
+        }
+    }
+
+    for name in from_names.iter().filter(|name| to_names.contains(name)) {
+        let (Some(old), Some(new)) = (from.get_project(name), to.get_project(name)) else {
+            continue;
+        };
+        if old.todo_list == new.todo_list {
+            continue;
+        }
+
+        let old_tasks = old.todo_list.get_tasks();
+        let new_tasks = new.todo_list.get_tasks();
+        let old_ids: HashSet<usize> = old_tasks.iter().map(|t| t.id).collect();
+        let new_ids: HashSet<usize> = new_tasks.iter().map(|t| t.id).collect();
+
+        let added_count = new_ids.difference(&old_ids).count();
+        let removed_count = old_ids.difference(&new_ids).count();
+        let changed_count = new_tasks
+            .iter()
+            .filter(|task| {
+                old_tasks
+                    .iter()
+                    .any(|old| old.id == task.id && old != *task)
+            })
+            .count();
+
+        let mut parts = Vec::new();
+        if added_count > 0 {
+            parts.push(format!("{} added", task_count_label(added_count)));
+        }
+        if removed_count > 0 {
+            parts.push(format!("{} removed", task_count_label(removed_count)));
+        }
+        if changed_count > 0 {
+            parts.push(format!("{} changed", task_count_label(changed_count)));
+        }
+        if !parts.is_empty() {
+            changes.push(format!("Project '{}': {}", name, parts.join(", ")));
+        }
+    }
+
+    if from.get_current_project_name() != to.get_current_project_name() && !is_rename {
+        changes.push(format!(
+            "Current project is now '{}'",
+            to.get_current_project_name()
+        ));
+    }
+
+    changes
+}
+
+fn task_count_label(count: usize) -> String {
+    if count == 1 {
+        "1 task".to_string()
+    } else {
+        format!("{} tasks", count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::task::TaskWithoutId;
+
+    fn manager_with_tasks(count: usize) -> ProjectManager {
+        let mut manager = ProjectManager::new();
+        for i in 0..count {
+            manager
+                .get_current_todo_list_mut()
+                .add_task(TaskWithoutId::new(format!("Task {}", i + 1)));
+        }
+        manager
+    }
+
+    #[test]
+    fn test_undo_and_redo_roundtrip() {
+        let mut history = UndoHistory::default();
+        let before = manager_with_tasks(2);
+        let mut after = before.clone();
+        after.get_current_todo_list_mut().remove_task(1).unwrap();
+        history.record("remove 1", before.clone());
+
+        let (command, restored) = history.undo(&after).unwrap();
+        assert_eq!(command, "remove 1");
+        assert_eq!(restored, before);
+        assert_eq!(history.undo_count(), 0);
+
+        let (command, redone) = history.redo(&restored).unwrap();
+        assert_eq!(command, "remove 1");
+        assert_eq!(redone, after);
+        assert_eq!(history.undo_count(), 1);
+        assert_eq!(history.redo_count(), 0);
+    }
+
+    #[test]
+    fn test_empty_history() {
+        let mut history = UndoHistory::default();
+        let manager = ProjectManager::new();
+        assert!(history.undo(&manager).is_none());
+        assert!(history.redo(&manager).is_none());
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let mut history = UndoHistory::new(3);
+        for i in 0..5 {
+            history.record(&format!("add {}", i), manager_with_tasks(i));
+        }
+        assert_eq!(history.undo_count(), 3);
+
+        let current = manager_with_tasks(5);
+        let (command, _) = history.undo(&current).unwrap();
+        assert_eq!(command, "add 4");
+    }
+
+    #[test]
+    fn test_record_clears_redo() {
+        let mut history = UndoHistory::default();
+        let manager = manager_with_tasks(1);
+        history.record("add 1", ProjectManager::new());
+        history.undo(&manager).unwrap();
+        assert_eq!(history.redo_count(), 1);
+
+        history.record("add 2", ProjectManager::new());
+        assert_eq!(history.redo_count(), 0);
+    }
+
+    #[test]
+    fn test_describe_task_changes() {
+        let before = manager_with_tasks(3);
+        let mut after = before.clone();
+        after.get_current_todo_list_mut().remove_task(1).unwrap();
+        after.get_current_todo_list_mut().remove_task(2).unwrap();
+        after.get_current_todo_list_mut().toggle_task(3).unwrap();
+
+        assert_eq!(
+            describe_changes(&before, &after),
+            vec!["Project 'default': 2 tasks removed, 1 task changed"]
+        );
+        assert_eq!(
+            describe_changes(&after, &before),
+            vec!["Project 'default': 2 tasks added, 1 task changed"]
+        );
+    }
+
+    #[test]
+    fn test_describe_project_changes() {
+        let mut before = ProjectManager::new();
+        before.create_project("Work".to_string()).unwrap();
+
+        let mut renamed = before.clone();
+        renamed
+            .rename_project("Work".to_string(), "Job".to_string())
+            .unwrap();
+        assert_eq!(
+            describe_changes(&before, &renamed),
+            vec!["Project 'Work' renamed to 'Job'"]
+        );
+
+        let mut deleted = before.clone();
+        deleted.delete_project("Work".to_string()).unwrap();
+        assert_eq!(
+            describe_changes(&deleted, &before),
+            vec!["Project 'Work' added"]
+        );
+    }
+
+    #[test]
+    fn test_describe_no_changes() {
+        let manager = manager_with_tasks(2);
+        assert!(describe_changes(&manager, &manager).is_empty());
+    }
+}
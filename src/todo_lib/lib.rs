@@ -29,6 +29,10 @@
 //! - `input_reader`: Reading and parsing user input into events
 //! - `output_writer`: Displaying messages and formatting output
 //!
+//! ### Service Layer (`service`)
+//! - `todo_service` / `TodoService`: Applies dependency checks, recurring tasks and persistence;
+//!   used by the command line, the TUI and embedding code
+//!
 //! ### Controller Layer (`controller`)
 //! - `todo_controller`: Orchestrates UI events with model operations
 //! ### Controller Layer (`controller`)
@@ -57,6 +61,7 @@
 pub mod controller;
 pub mod models;
 pub mod persistence;
+pub mod service;
 pub mod ui;
 pub mod tui;
 
@@ -64,5 +69,6 @@ pub mod tui;
 pub use controller::ApplicationController;
 pub use models::{LoopControl, Priority, Task, TaskFilter, TaskStatus, TodoList};
 pub use persistence::TodoListStorage;
+pub use service::TodoService;
 pub use ui::{FileInputStream, FileOutputWriter, InputStream, OutputWriter};
 pub use tui::{App, run_tui};
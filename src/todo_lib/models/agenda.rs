@@ -0,0 +1,249 @@
+use crate::models::task::Task;
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// A task due on a given date.
+///
+/// For recurring tasks the agenda also lists the future occurrences, which
+/// are projected from the due date with `Task::calculate_next_due_date`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AgendaEntry<'a> {
+    /// The task that is due
+    pub task: &'a Task,
+    /// The date the task (or its occurrence) is due
+    pub date: NaiveDate,
+    /// Whether this is a future occurrence of a recurring task rather than the task itself
+    pub is_projection: bool,
+}
+
+/// The sections of the agenda, in display order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgendaGroup {
+    /// Due before today
+    Overdue,
+    /// Due today
+    Today,
+    /// Due tomorrow
+    Tomorrow,
+    /// Due later this week (until Sunday)
+    ThisWeek,
+    /// Due after this week
+    Later,
+}
+
+impl AgendaGroup {
+    /// Returns the section title.
+    pub fn as_str(&self) -> &str {
+        match self {
+            AgendaGroup::Overdue => "Overdue",
+            AgendaGroup::Today => "Today",
+            AgendaGroup::Tomorrow => "Tomorrow",
+            AgendaGroup::ThisWeek => "This Week",
+            AgendaGroup::Later => "Later",
+        }
+    }
+
+    /// Returns the section a date belongs to, relative to `today`.
+    pub fn for_date(date: NaiveDate, today: NaiveDate) -> Self {
+        let end_of_week = today + Duration::days(6 - today.weekday().num_days_from_monday() as i64);
+        if date < today {
+            AgendaGroup::Overdue
+        } else if date == today {
+            AgendaGroup::Today
+        } else if date == today + Duration::days(1) {
+            AgendaGroup::Tomorrow
+        } else if date <= end_of_week {
+            AgendaGroup::ThisWeek
+        } else {
+            AgendaGroup::Later
+        }
+    }
+}
+
+/// Returns the open tasks due between `from` and `to` (both included), sorted by date.
+///
+/// Completed and cancelled tasks are left out. Recurring tasks additionally
+/// appear on each projected occurrence in the range.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::models::agenda::due_entries;
+/// use todo_manager::models::recurrence::Recurrence;
+/// use todo_manager::models::task::Task;
+/// use chrono::NaiveDate;
+///
+/// let date = |d| NaiveDate::from_ymd_opt(2025, 1, d).unwrap();
+/// let mut task = Task::new(1, "Stand-up".to_string());
+/// task.set_due_date(Some(date(6)));
+/// task.set_recurrence(Some(Recurrence::Weekly));
+///
+/// let entries = due_entries(&[&task], date(1), date(20));
+/// let dates: Vec<NaiveDate> = entries.iter().map(|entry| entry.date).collect();
+/// assert_eq!(dates, vec![date(6), date(13), date(20)]);
+/// assert!(!entries[0].is_projection);
+/// assert!(entries[1].is_projection);
+/// ```
+pub fn due_entries<'a>(tasks: &[&'a Task], from: NaiveDate, to: NaiveDate) -> Vec<AgendaEntry<'a>> {
+    let mut entries = Vec::new();
+
+    for &task in tasks {
+        if task.is_resolved() {
+            continue;
+        }
+        let Some(due_date) = task.due_date else {
+            continue;
+        };
+        if due_date >= from && due_date <= to {
+            entries.push(AgendaEntry {
+                task,
+                date: due_date,
+                is_projection: false,
+            });
+        }
+
+        // Project the next occurrences of recurring tasks
+        let mut occurrence = task.clone();
+        while let Some(next) = occurrence.calculate_next_due_date() {
+            if next > to {
+                break;
+            }
+            if next >= from {
+                entries.push(AgendaEntry {
+                    task,
+                    date: next,
+                    is_projection: true,
+                });
+            }
+            occurrence.due_date = Some(next);
+        }
+    }
+
+    entries.sort_by_key(|entry| (entry.date, entry.task.id));
+    entries
+}
+
+/// Builds the agenda for the next `days` days, grouped into sections.
+///
+/// The agenda lists the overdue open tasks and the tasks due from today
+/// through the following `days - 1` days; empty sections are left out.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::models::agenda::{build_agenda, AgendaGroup};
+/// use todo_manager::models::task::Task;
+/// use chrono::NaiveDate;
+///
+/// // A Wednesday
+/// let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+/// let mut late = Task::new(1, "Late".to_string());
+/// late.set_due_date(NaiveDate::from_ymd_opt(2025, 1, 10));
+/// let mut soon = Task::new(2, "Soon".to_string());
+/// soon.set_due_date(NaiveDate::from_ymd_opt(2025, 1, 16));
+///
+/// let agenda = build_agenda(&[&late, &soon], today, 7);
+/// assert_eq!(agenda[0].0, AgendaGroup::Overdue);
+/// assert_eq!(agenda[1].0, AgendaGroup::Tomorrow);
+/// assert_eq!(agenda[1].1[0].task.description, "Soon");
+/// ```
+pub fn build_agenda<'a>(
+    tasks: &[&'a Task],
+    today: NaiveDate,
+    days: u32,
+) -> Vec<(AgendaGroup, Vec<AgendaEntry<'a>>)> {
+    let mut groups: Vec<(AgendaGroup, Vec<AgendaEntry<'a>>)> = Vec::new();
+
+    let overdue = tasks.iter().filter_map(|&task| {
+        let date = task.due_date.filter(|_| task.is_overdue(today))?;
+        Some(AgendaEntry {
+            task,
+            date,
+            is_projection: false,
+        })
+    });
+    let mut overdue: Vec<AgendaEntry> = overdue.collect();
+    overdue.sort_by_key(|entry| (entry.date, entry.task.id));
+
+    let last_day = today + Duration::days(days.max(1) as i64 - 1);
+    let upcoming = due_entries(tasks, today, last_day);
+
+    for entry in overdue.into_iter().chain(upcoming) {
+        let group = AgendaGroup::for_date(entry.date, today);
+        match groups.last_mut() {
+            Some((last, entries)) if *last == group => entries.push(entry),
+            _ => groups.push((group, vec![entry])),
+        }
+    }
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::recurrence::Recurrence;
+    use crate::models::task_status::TaskStatus;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    fn task(id: usize, due: u32) -> Task {
+        let mut task = Task::new(id, format!("Task {}", id));
+        task.set_due_date(Some(date(due)));
+        task
+    }
+
+    #[test]
+    fn test_groups_relative_to_today() {
+        // Wednesday, 15 January 2025
+        let today = date(15);
+        assert_eq!(AgendaGroup::for_date(date(14), today), AgendaGroup::Overdue);
+        assert_eq!(AgendaGroup::for_date(date(15), today), AgendaGroup::Today);
+        assert_eq!(
+            AgendaGroup::for_date(date(16), today),
+            AgendaGroup::Tomorrow
+        );
+        assert_eq!(
+            AgendaGroup::for_date(date(19), today),
+            AgendaGroup::ThisWeek
+        );
+        assert_eq!(AgendaGroup::for_date(date(20), today), AgendaGroup::Later);
+    }
+
+    #[test]
+    fn test_agenda_skips_resolved_and_out_of_range_tasks() {
+        let mut done = task(1, 15);
+        done.set_status(TaskStatus::Completed);
+        let far = task(2, 30);
+        let today_task = task(3, 15);
+        let undated = Task::new(4, "No date".to_string());
+
+        let agenda = build_agenda(&[&done, &far, &today_task, &undated], date(15), 7);
+        assert_eq!(agenda.len(), 1);
+        assert_eq!(agenda[0].0, AgendaGroup::Today);
+        assert_eq!(agenda[0].1.len(), 1);
+        assert_eq!(agenda[0].1[0].task.id, 3);
+    }
+
+    #[test]
+    fn test_overdue_recurring_task_is_projected_forward() {
+        let mut daily = task(1, 13);
+        daily.set_recurrence(Some(Recurrence::Daily));
+
+        let agenda = build_agenda(&[&daily], date(15), 2);
+        let sections: Vec<(AgendaGroup, Vec<NaiveDate>)> = agenda
+            .iter()
+            .map(|(group, entries)| (*group, entries.iter().map(|e| e.date).collect()))
+            .collect();
+        assert_eq!(
+            sections,
+            vec![
+                (AgendaGroup::Overdue, vec![date(13)]),
+                (AgendaGroup::Today, vec![date(15)]),
+                (AgendaGroup::Tomorrow, vec![date(16)]),
+            ]
+        );
+        assert!(agenda[1].1[0].is_projection);
+    }
+}
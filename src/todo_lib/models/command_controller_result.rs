@@ -17,6 +17,15 @@ pub enum CommandControllerResultAction {
 
     /// Save the current todo list to disk
     SaveTodoList,
+
+    /// Revert the most recent change
+    UndoLastChange,
+
+    /// Reapply the most recently undone change
+    RedoLastChange,
+
+    /// The command described what a dry run would do, so its output is shown
+    PreviewShown,
 }
 
 pub struct CommandControllerResult {
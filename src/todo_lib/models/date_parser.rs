@@ -0,0 +1,319 @@
+use crate::models::parse_error::ParseError;
+use chrono::{Datelike, Duration, Months, NaiveDate, Weekday};
+
+const WEEKDAYS: [(&str, Weekday); 7] = [
+    ("monday", Weekday::Mon),
+    ("tuesday", Weekday::Tue),
+    ("wednesday", Weekday::Wed),
+    ("thursday", Weekday::Thu),
+    ("friday", Weekday::Fri),
+    ("saturday", Weekday::Sat),
+    ("sunday", Weekday::Sun),
+];
+
+const SUPPORTED_FORMATS: &str = "DD.MM.YYYY, YYYY-MM-DD, today, tomorrow, yesterday, a weekday (fri, next monday), an offset (+3d, +2w, +1m) or end-of-month";
+
+/// Parses a date expression relative to `today`.
+///
+/// Supports:
+/// - Absolute dates: `31.12.2025`, `2025-12-31`
+/// - Named days: `today`, `tomorrow`, `yesterday`
+/// - Weekdays: `fri`, `friday` (the next one, today included) and
+///   `next friday` (the one after that, one week later)
+/// - Offsets: `+3d`, `+2w`, `+1m`, `+1y` (or `-` for the past)
+/// - `end-of-month` (or `eom`)
+///
+/// Expressions that could mean more than one date, such as `03/04/2025` or
+/// `t`, are rejected with `ParseError::InvalidDate` instead of guessing.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::models::date_parser::parse_date;
+/// use chrono::NaiveDate;
+///
+/// // A Wednesday
+/// let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// assert_eq!(parse_date("31.12.2025", today), Ok(date(2025, 12, 31)));
+/// assert_eq!(parse_date("2025-12-31", today), Ok(date(2025, 12, 31)));
+/// assert_eq!(parse_date("tomorrow", today), Ok(date(2025, 1, 16)));
+/// assert_eq!(parse_date("fri", today), Ok(date(2025, 1, 17)));
+/// assert_eq!(parse_date("next monday", today), Ok(date(2025, 1, 27)));
+/// assert_eq!(parse_date("+2w", today), Ok(date(2025, 1, 29)));
+/// assert_eq!(parse_date("end-of-month", today), Ok(date(2025, 1, 31)));
+/// assert!(parse_date("03/04/2025", today).is_err());
+/// ```
+pub fn parse_date(input: &str, today: NaiveDate) -> Result<NaiveDate, ParseError> {
+    let normalized = input
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+
+    if normalized.is_empty() {
+        return Err(ParseError::InvalidDate(format!(
+            "Date cannot be empty. Use {}.",
+            SUPPORTED_FORMATS
+        )));
+    }
+
+    match normalized.as_str() {
+        "today" => return Ok(today),
+        "tomorrow" | "tmr" => return Ok(today + Duration::days(1)),
+        "yesterday" => return Ok(today - Duration::days(1)),
+        "end-of-month" | "end of month" | "eom" => return Ok(end_of_month(today)),
+        _ => {}
+    }
+
+    if let Some(rest) = normalized
+        .strip_prefix("next ")
+        .or_else(|| normalized.strip_prefix("next-"))
+    {
+        let weekday = parse_weekday(rest, input)?.ok_or_else(|| {
+            ParseError::InvalidDate(format!(
+                "Invalid date '{}'. 'next' must be followed by a weekday, e.g. 'next monday'.",
+                input
+            ))
+        })?;
+        return Ok(upcoming_weekday(today, weekday) + Duration::weeks(1));
+    }
+
+    if let Some(weekday) = parse_weekday(&normalized, input)? {
+        return Ok(upcoming_weekday(today, weekday));
+    }
+
+    if normalized.starts_with(['+', '-']) {
+        return parse_offset(&normalized, input, today);
+    }
+
+    if normalized.contains('/') {
+        return Err(ParseError::InvalidDate(format!(
+            "Date '{}' is ambiguous (day/month or month/day?). Use DD.MM.YYYY or YYYY-MM-DD.",
+            input
+        )));
+    }
+
+    parse_absolute(&normalized, input)
+}
+
+/// Parses a weekday name or an unambiguous prefix of at least two letters.
+///
+/// Returns `Ok(None)` if the text is not a weekday at all.
+fn parse_weekday(text: &str, input: &str) -> Result<Option<Weekday>, ParseError> {
+    if text.is_empty() || !text.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Ok(None);
+    }
+
+    let matches: Vec<&(&str, Weekday)> = WEEKDAYS
+        .iter()
+        .filter(|(name, _)| name.starts_with(text))
+        .collect();
+
+    match matches.as_slice() {
+        [] => Ok(None),
+        [(_, weekday)] if text.len() >= 2 => Ok(Some(*weekday)),
+        [(name, _)] => Err(ParseError::InvalidDate(format!(
+            "Date '{}' is too short: write at least two letters of the weekday, e.g. '{}' for {}.",
+            input,
+            &name[..2],
+            name
+        ))),
+        candidates => Err(ParseError::InvalidDate(format!(
+            "Date '{}' is ambiguous: could be {}.",
+            input,
+            candidates
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(" or ")
+        ))),
+    }
+}
+
+/// Returns the next date with the given weekday, today included.
+fn upcoming_weekday(today: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let days_ahead =
+        (weekday.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+    today + Duration::days(days_ahead as i64)
+}
+
+fn end_of_month(date: NaiveDate) -> NaiveDate {
+    let first_of_month = date.with_day(1).expect("every month has a first day");
+    first_of_month + Months::new(1) - Duration::days(1)
+}
+
+/// Parses offsets such as `+3d`, `-1w`, `+2m` or `+1y`.
+fn parse_offset(text: &str, input: &str, today: NaiveDate) -> Result<NaiveDate, ParseError> {
+    let invalid = || {
+        ParseError::InvalidDate(format!(
+            "Invalid date offset '{}'. Use a sign, a number and a unit: +3d, +2w, +1m or +1y.",
+            input
+        ))
+    };
+
+    let (sign, rest) = text.split_at(1);
+    let unit_start = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(invalid)?;
+    let amount: u32 = rest[..unit_start].parse().map_err(|_| invalid())?;
+    let forward = sign == "+";
+
+    let date = match &rest[unit_start..] {
+        "d" | "day" | "days" => {
+            let days = Duration::days(amount as i64);
+            if forward {
+                today.checked_add_signed(days)
+            } else {
+                today.checked_sub_signed(days)
+            }
+        }
+        "w" | "week" | "weeks" => {
+            let weeks = Duration::weeks(amount as i64);
+            if forward {
+                today.checked_add_signed(weeks)
+            } else {
+                today.checked_sub_signed(weeks)
+            }
+        }
+        // Month and year offsets keep the day, clamped to the end of shorter months
+        "m" | "month" | "months" | "y" | "year" | "years" => {
+            let months = if rest[unit_start..].starts_with('y') {
+                amount.checked_mul(12).ok_or_else(invalid)?
+            } else {
+                amount
+            };
+            if forward {
+                today.checked_add_months(Months::new(months))
+            } else {
+                today.checked_sub_months(Months::new(months))
+            }
+        }
+        _ => return Err(invalid()),
+    };
+
+    date.ok_or_else(|| ParseError::InvalidDate(format!("Date offset '{}' is out of range.", input)))
+}
+
+/// Parses `DD.MM.YYYY` and `YYYY-MM-DD` dates.
+fn parse_absolute(text: &str, input: &str) -> Result<NaiveDate, ParseError> {
+    let parts: Option<(&str, &str, &str)> = if text.contains('.') {
+        let mut parts = text.splitn(3, '.');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(day), Some(month), Some(year)) => Some((year, month, day)),
+            _ => None,
+        }
+    } else if text.contains('-') {
+        let mut parts = text.splitn(3, '-');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(year), Some(month), Some(day)) if year.len() == 4 => Some((year, month, day)),
+            _ => None,
+        }
+    } else {
+        None
+    };
+
+    let Some((year, month, day)) = parts else {
+        return Err(ParseError::InvalidDate(format!(
+            "Invalid date '{}'. Use {}.",
+            input, SUPPORTED_FORMATS
+        )));
+    };
+
+    match (
+        year.parse::<i32>(),
+        month.parse::<u32>(),
+        day.parse::<u32>(),
+    ) {
+        (Ok(year), Ok(month), Ok(day)) => {
+            NaiveDate::from_ymd_opt(year, month, day).ok_or_else(|| {
+                ParseError::InvalidDate(format!(
+                    "Invalid date '{}'. Please check the date is valid.",
+                    input
+                ))
+            })
+        }
+        _ => Err(ParseError::InvalidDate(format!(
+            "Invalid date '{}'. Use {}.",
+            input, SUPPORTED_FORMATS
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    /// A Wednesday
+    fn today() -> NaiveDate {
+        date(2025, 1, 15)
+    }
+
+    #[test]
+    fn test_weekdays() {
+        assert_eq!(parse_date("wednesday", today()), Ok(today()));
+        assert_eq!(parse_date("Tue", today()), Ok(date(2025, 1, 21)));
+        assert_eq!(parse_date("fr", today()), Ok(date(2025, 1, 17)));
+        assert_eq!(parse_date("next fri", today()), Ok(date(2025, 1, 24)));
+        assert_eq!(parse_date("next-wed", today()), Ok(date(2025, 1, 22)));
+    }
+
+    #[test]
+    fn test_ambiguous_weekday_prefix() {
+        let err = parse_date("t", today()).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "Date 't' is ambiguous: could be tuesday or thursday."
+        );
+        assert!(parse_date("s", today()).is_err());
+        assert!(parse_date("next", today()).is_err());
+
+        // A single letter is rejected even when only one weekday starts with it
+        assert_eq!(
+            parse_date("m", today()).unwrap_err().message(),
+            "Date 'm' is too short: write at least two letters of the weekday, e.g. 'mo' for monday."
+        );
+    }
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(parse_date("+3d", today()), Ok(date(2025, 1, 18)));
+        assert_eq!(parse_date("-1w", today()), Ok(date(2025, 1, 8)));
+        assert_eq!(parse_date("+1y", today()), Ok(date(2026, 1, 15)));
+        // Month offsets are clamped to the end of shorter months
+        assert_eq!(parse_date("+1m", date(2025, 1, 31)), Ok(date(2025, 2, 28)));
+        assert!(parse_date("+3", today()).is_err());
+        assert!(parse_date("+d", today()).is_err());
+        assert!(parse_date("+3x", today()).is_err());
+    }
+
+    #[test]
+    fn test_end_of_month() {
+        assert_eq!(parse_date("eom", date(2024, 2, 10)), Ok(date(2024, 2, 29)));
+        assert_eq!(
+            parse_date("end-of-month", date(2025, 12, 31)),
+            Ok(date(2025, 12, 31))
+        );
+    }
+
+    #[test]
+    fn test_absolute_dates() {
+        assert_eq!(parse_date("1.2.2025", today()), Ok(date(2025, 2, 1)));
+        assert_eq!(parse_date("2025-02-01", today()), Ok(date(2025, 2, 1)));
+        assert!(parse_date("32.13.2025", today()).is_err());
+        assert!(parse_date("01-02-2025", today()).is_err());
+        assert!(parse_date("someday", today()).is_err());
+    }
+
+    #[test]
+    fn test_slash_dates_are_ambiguous() {
+        let err = parse_date("03/04/2025", today()).unwrap_err();
+        assert!(matches!(err, ParseError::InvalidDate(_)));
+        assert!(err.message().contains("ambiguous"));
+    }
+}
@@ -1,7 +1,9 @@
+use crate::models::filter_expression::FilterExpression;
 use crate::models::overdue_filter::OverdueFilter;
+use crate::models::parse_error::ParseError;
 use crate::models::priority::Priority;
 use crate::models::task_filter::TaskFilter;
-use crate::models::task_sort::{SortBy, SortOrder};
+use crate::models::task_sort::{SortKey, SortOrder};
 use crate::models::task_status::TaskStatus;
 use std::str::FromStr;
 
@@ -14,8 +16,9 @@ pub struct FilterBuilder {
     status_set: bool,
     priority_set: bool,
     category_set: bool,
-    sort_by_set: bool,
+    sort_keys_set: bool,
     sort_order_set: bool,
+    expression_set: bool,
 }
 
 impl FilterBuilder {
@@ -26,8 +29,9 @@ impl FilterBuilder {
             status_set: false,
             priority_set: false,
             category_set: false,
-            sort_by_set: false,
+            sort_keys_set: false,
             sort_order_set: false,
+            expression_set: false,
         }
     }
 
@@ -36,7 +40,9 @@ impl FilterBuilder {
     /// Returns an error if a status filter was already set.
     pub fn with_status(mut self, status: TaskStatus) -> Result<Self, String> {
         if self.status_set {
-            return Err("Cannot specify multiple status filters (done/todo).".to_string());
+            return Err(
+                "Cannot specify multiple status filters (done/todo/in-progress/...).".to_string(),
+            );
         }
         self.filter = self.filter.with_status(status);
         self.status_set = true;
@@ -70,21 +76,64 @@ impl FilterBuilder {
         Ok(self)
     }
 
+    /// Attempts to add a tag that tasks must have.
+    ///
+    /// Returns an error if the tag is empty or already excluded.
+    pub fn with_tag(mut self, tag: String) -> Result<Self, String> {
+        if tag.trim().is_empty() {
+            return Err("Tag name cannot be empty. Use: list +tag or list -tag".to_string());
+        }
+        if self
+            .filter
+            .excluded_tags
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(&tag))
+        {
+            return Err(format!("Cannot both include and exclude tag '{}'.", tag));
+        }
+        self.filter = self.filter.with_tag(tag);
+        Ok(self)
+    }
+
+    /// Attempts to add a tag that tasks must not have.
+    ///
+    /// Returns an error if the tag is empty or already included.
+    pub fn without_tag(mut self, tag: String) -> Result<Self, String> {
+        if tag.trim().is_empty() {
+            return Err("Tag name cannot be empty. Use: list +tag or list -tag".to_string());
+        }
+        if self
+            .filter
+            .tags
+            .iter()
+            .any(|t| t.eq_ignore_ascii_case(&tag))
+        {
+            return Err(format!("Cannot both include and exclude tag '{}'.", tag));
+        }
+        self.filter = self.filter.without_tag(tag);
+        Ok(self)
+    }
+
     /// Adds an overdue filter.
     pub fn with_overdue(mut self) -> Self {
         self.filter = self.filter.with_overdue(OverdueFilter::OnlyOverdue);
         self
     }
 
-    /// Attempts to add a sort field.
+    /// Attempts to set the sort keys.
     ///
-    /// Returns an error if a sort field was already set.
-    pub fn with_sort_by(mut self, sort_by: SortBy) -> Result<Self, String> {
-        if self.sort_by_set {
-            return Err("Cannot specify multiple sort fields.".to_string());
+    /// Returns an error if sort keys were already set.
+    pub fn with_sort_keys(mut self, sort_keys: Vec<SortKey>) -> Result<Self, String> {
+        if self.sort_keys_set {
+            return Err(
+                "Cannot specify multiple sort options. Combine fields instead: sort:due,-priority"
+                    .to_string(),
+            );
         }
-        self.filter = self.filter.with_sort_by(sort_by);
-        self.sort_by_set = true;
+        for sort_key in sort_keys {
+            self.filter = self.filter.with_sort_key(sort_key);
+        }
+        self.sort_keys_set = true;
         Ok(self)
     }
 
@@ -98,6 +147,13 @@ impl FilterBuilder {
         Ok(self)
     }
 
+    /// Adds a boolean filter expression, replacing any previous one.
+    pub fn with_expression(mut self, expression: FilterExpression) -> Self {
+        self.filter = self.filter.with_expression(expression);
+        self.expression_set = true;
+        self
+    }
+
     /// Parses a filter argument and adds it to the builder.
     ///
     /// Returns an error if the argument is invalid or conflicts with existing filters.
@@ -114,10 +170,18 @@ impl FilterBuilder {
             return self.with_category(category);
         }
 
-        // Check for sort option (format: sort:field or --sort field)
-        if let Some(sort_field) = arg_lower.strip_prefix("sort:") {
-            return match SortBy::from_str(sort_field) {
-                Ok(sort_by) => self.with_sort_by(sort_by),
+        // Check for status filter (format: status:state)
+        if let Some(status) = arg_lower.strip_prefix("status:") {
+            return match TaskStatus::from_str(status) {
+                Ok(status) => self.with_status(status),
+                Err(err) => Err(err),
+            };
+        }
+
+        // Check for sort option (format: sort:field or sort:field,-field,...)
+        if let Some(sort_fields) = arg_lower.strip_prefix("sort:") {
+            return match SortKey::parse_list(sort_fields) {
+                Ok(sort_keys) => self.with_sort_keys(sort_keys),
                 Err(err) => Err(err),
             };
         }
@@ -127,20 +191,110 @@ impl FilterBuilder {
             return self.with_sort_order(SortOrder::Descending);
         }
 
+        // Check for tag include/exclude filters (format: +tag or -tag)
+        if let Some(tag) = arg.strip_prefix('+') {
+            return self.with_tag(tag.to_string());
+        }
+        if let Some(tag) = arg.strip_prefix('-') {
+            if !tag.starts_with('-') {
+                return self.without_tag(tag.to_string());
+            }
+        }
+
         match arg_lower.as_str() {
             "completed" | "done" => self.with_status(TaskStatus::Completed),
             "pending" | "todo" => self.with_status(TaskStatus::Pending),
+            "in-progress" | "wip" => self.with_status(TaskStatus::InProgress),
+            "waiting" => self.with_status(TaskStatus::Waiting),
+            "blocked" => self.with_status(TaskStatus::Blocked),
+            "cancelled" | "canceled" => self.with_status(TaskStatus::Cancelled),
             "high" | "h" => self.with_priority(Priority::High),
             "medium" | "med" | "m" => self.with_priority(Priority::Medium),
             "low" | "l" => self.with_priority(Priority::Low),
             "overdue" => Ok(self.with_overdue()),
             _ => Err(format!(
-                "Unknown filter: '{}'. Valid filters: done, todo, high, medium, low, overdue, category:name, sort:field, --reverse",
+                "Unknown filter: '{}'. Valid filters: done, todo, in-progress, waiting, blocked, cancelled, status:state, high, medium, low, overdue, category:name, +tag, -tag, sort:field, --reverse",
                 arg
             )),
         }
     }
 
+    /// Parses the arguments of a `list` command into a filter.
+    ///
+    /// Arguments are first read as simple filters (`pending high +work`).
+    /// If they cannot be read that way and use expression syntax (see
+    /// `FilterExpression::has_expression_syntax`), they are parsed as a
+    /// boolean filter expression, with sort options allowed anywhere.
+    ///
+    /// # Returns
+    ///
+    /// The filter, or `None` if the arguments select all tasks in default order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::filter_builder::FilterBuilder;
+    ///
+    /// let filter = FilterBuilder::parse_args(&["pending", "high"]).unwrap().unwrap();
+    /// assert!(filter.expression.is_none());
+    ///
+    /// let filter = FilterBuilder::parse_args(&["high", "or", "overdue"]).unwrap().unwrap();
+    /// assert!(filter.expression.is_some());
+    ///
+    /// assert!(FilterBuilder::parse_args(&[]).unwrap().is_none());
+    /// ```
+    pub fn parse_args(args: &[&str]) -> Result<Option<TaskFilter>, ParseError> {
+        let mut filter_builder = FilterBuilder::new();
+
+        for arg in args {
+            let lower = arg.to_lowercase();
+            filter_builder = match filter_builder.parse_argument(&lower) {
+                Ok(builder) => builder,
+                Err(_)
+                    if args
+                        .iter()
+                        .any(|arg| FilterExpression::has_expression_syntax(arg)) =>
+                {
+                    return Self::parse_expression_args(args)
+                }
+                Err(err) => {
+                    return Err(ParseError::InvalidFormat {
+                        field: "filter".to_string(),
+                        expected: "status (completed/pending/overdue), priority (high/medium/low), category:name, +tag/-tag or a filter expression".to_string(),
+                        actual: err,
+                    })
+                }
+            };
+        }
+
+        Ok(filter_builder.build())
+    }
+
+    /// Parses arguments as a boolean filter expression plus sort options.
+    fn parse_expression_args(args: &[&str]) -> Result<Option<TaskFilter>, ParseError> {
+        let mut filter_builder = FilterBuilder::new();
+        let mut expression_args = Vec::new();
+
+        for arg in args {
+            let lower = arg.to_lowercase();
+            if lower.starts_with("sort:") || matches!(lower.as_str(), "--reverse" | "--desc" | "-r")
+            {
+                filter_builder = filter_builder.parse_argument(&lower).map_err(|err| {
+                    ParseError::InvalidFormat {
+                        field: "sort option".to_string(),
+                        expected: "sort:field or --reverse".to_string(),
+                        actual: err,
+                    }
+                })?;
+            } else {
+                expression_args.push(*arg);
+            }
+        }
+
+        let expression = FilterExpression::parse(&expression_args.join(" "))?;
+        Ok(filter_builder.with_expression(expression).build())
+    }
+
     /// Builds the final filter.
     ///
     /// Returns None if no filters or sorting were applied (meaning show all tasks with default sort).
@@ -148,8 +302,11 @@ impl FilterBuilder {
         if !self.status_set
             && !self.priority_set
             && !self.category_set
-            && !self.sort_by_set
+            && self.filter.tags.is_empty()
+            && self.filter.excluded_tags.is_empty()
+            && !self.sort_keys_set
             && !self.sort_order_set
+            && !self.expression_set
             && self.filter.overdue == OverdueFilter::All
         {
             None
@@ -169,6 +326,65 @@ impl Default for FilterBuilder {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_filter_builder_parse_argument_tags() {
+        let filter = FilterBuilder::new()
+            .parse_argument("+work")
+            .unwrap()
+            .parse_argument("+urgent")
+            .unwrap()
+            .parse_argument("-someday")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(filter.tags, vec!["work".to_string(), "urgent".to_string()]);
+        assert_eq!(filter.excluded_tags, vec!["someday".to_string()]);
+    }
+
+    #[test]
+    fn test_filter_builder_conflicting_tags() {
+        let result = FilterBuilder::new()
+            .parse_argument("+work")
+            .unwrap()
+            .parse_argument("-Work");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_filter_builder_empty_tag() {
+        assert!(FilterBuilder::new().parse_argument("+").is_err());
+    }
+
+    #[test]
+    fn test_filter_builder_reverse_is_not_a_tag() {
+        let filter = FilterBuilder::new()
+            .parse_argument("-r")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert!(filter.excluded_tags.is_empty());
+        assert_eq!(filter.sort_order, Some(SortOrder::Descending));
+    }
+
+    #[test]
+    fn test_filter_builder_parse_argument_status() {
+        let filter = FilterBuilder::new()
+            .parse_argument("blocked")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(filter.status, Some(TaskStatus::Blocked));
+
+        let filter = FilterBuilder::new()
+            .parse_argument("status:in-progress")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(filter.status, Some(TaskStatus::InProgress));
+
+        assert!(FilterBuilder::new().parse_argument("status:later").is_err());
+    }
+
     #[test]
     fn test_filter_builder_no_filters() {
         let builder = FilterBuilder::new();
@@ -260,6 +476,17 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_args_keeps_simple_filter_errors() {
+        let error = FilterBuilder::parse_args(&["high", "medium"]).unwrap_err();
+        assert!(matches!(error, ParseError::InvalidFormat { .. }));
+
+        let filter = FilterBuilder::parse_args(&["high", "or", "medium"])
+            .unwrap()
+            .unwrap();
+        assert!(filter.expression.is_some());
+    }
+
     #[test]
     fn test_filter_builder_combined_filters() {
         let builder = FilterBuilder::new()
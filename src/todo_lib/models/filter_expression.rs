@@ -0,0 +1,845 @@
+use crate::models::date_parser::parse_date;
+use crate::models::parse_error::ParseError;
+use crate::models::priority::Priority;
+use crate::models::task::Task;
+use crate::models::task_status::TaskStatus;
+use crate::models::todo_list::TodoList;
+use chrono::NaiveDate;
+use std::str::FromStr;
+
+/// Comparison operator used by field predicates such as `due<friday`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    /// `=` or `:`
+    Equal,
+    /// `!=`
+    NotEqual,
+    /// `<`
+    Less,
+    /// `<=`
+    LessOrEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    /// Compares `value` against `reference` with this operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::filter_expression::Comparison;
+    ///
+    /// assert!(Comparison::Less.compare(1, 2));
+    /// assert!(Comparison::GreaterOrEqual.compare(2, 2));
+    /// assert!(!Comparison::NotEqual.compare(2, 2));
+    /// ```
+    pub fn compare<T: Ord>(self, value: T, reference: T) -> bool {
+        match self {
+            Comparison::Equal => value == reference,
+            Comparison::NotEqual => value != reference,
+            Comparison::Less => value < reference,
+            Comparison::LessOrEqual => value <= reference,
+            Comparison::Greater => value > reference,
+            Comparison::GreaterOrEqual => value >= reference,
+        }
+    }
+
+    fn from_operator(operator: &str) -> Option<Self> {
+        match operator {
+            "=" | ":" => Some(Comparison::Equal),
+            "!=" => Some(Comparison::NotEqual),
+            "<" => Some(Comparison::Less),
+            "<=" => Some(Comparison::LessOrEqual),
+            ">" => Some(Comparison::Greater),
+            ">=" => Some(Comparison::GreaterOrEqual),
+            _ => None,
+        }
+    }
+}
+
+/// A single condition of a filter expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterPredicate {
+    /// Task status matches (`done`, `status:waiting`, `is:in-progress`)
+    Status(TaskStatus),
+
+    /// Task priority compares to the given level (`high`, `priority>=medium`)
+    Priority(Comparison, Priority),
+
+    /// Task due date compares to the given date (`due<friday`)
+    Due(Comparison, NaiveDate),
+
+    /// Task has a due date (`has:due`)
+    HasDueDate,
+
+    /// Task is overdue (`overdue`, `is:overdue`)
+    Overdue,
+
+    /// Task has the tag (`+work`, `tag:work`, `cat:work`)
+    Tag(String),
+
+    /// Task description contains the text, case-insensitively (`desc~milk`)
+    DescriptionContains(String),
+
+    /// Task has at least one tag (`has:tags`)
+    HasTags,
+
+    /// Task has subtasks (`has:subtasks`)
+    HasSubtasks,
+
+    /// Task depends on other tasks (`has:deps`)
+    HasDependencies,
+
+    /// Task is a subtask (`is:subtask`, `has:parent`)
+    IsSubtask,
+
+    /// Task is blocked by status or by unresolved dependencies (`is:blocked`)
+    IsBlocked,
+
+    /// Task recurs (`is:recurring`)
+    IsRecurring,
+
+    /// Task is completed or cancelled (`is:resolved`)
+    IsResolved,
+
+    /// Task ID compares to the given number (`id<=10`)
+    Id(Comparison, usize),
+}
+
+impl FilterPredicate {
+    /// Evaluates the predicate for a task of the given list.
+    pub fn matches(&self, task: &Task, todo_list: &TodoList, today: NaiveDate) -> bool {
+        match self {
+            FilterPredicate::Status(status) => status.matches(task.status),
+            FilterPredicate::Priority(comparison, priority) => {
+                comparison.compare(task.priority, *priority)
+            }
+            FilterPredicate::Due(comparison, date) => task
+                .due_date
+                .is_some_and(|due| comparison.compare(due, *date)),
+            FilterPredicate::HasDueDate => task.due_date.is_some(),
+            FilterPredicate::Overdue => task.is_overdue(today),
+            FilterPredicate::Tag(tag) => task.has_tag(tag),
+            FilterPredicate::DescriptionContains(text) => task
+                .description
+                .to_lowercase()
+                .contains(&text.to_lowercase()),
+            FilterPredicate::HasTags => !task.tags.is_empty(),
+            FilterPredicate::HasSubtasks => !todo_list.get_subtasks(task.id).is_empty(),
+            FilterPredicate::HasDependencies => task.has_dependencies(),
+            FilterPredicate::IsSubtask => task.parent_id.is_some(),
+            FilterPredicate::IsBlocked => {
+                task.status == TaskStatus::Blocked
+                    || !todo_list.get_incomplete_dependencies(task.id).is_empty()
+            }
+            FilterPredicate::IsRecurring => task.recurrence.is_some(),
+            FilterPredicate::IsResolved => task.is_resolved(),
+            FilterPredicate::Id(comparison, id) => comparison.compare(task.id, *id),
+        }
+    }
+}
+
+/// A boolean filter expression over tasks.
+///
+/// Expressions combine predicates with `and`, `or`, `not` and parentheses.
+/// Predicates next to each other are implicitly combined with `and`, so the
+/// classic `list pending high` syntax is a valid expression as well.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::models::filter_expression::FilterExpression;
+/// use todo_manager::models::todo_list::TodoList;
+/// use todo_manager::models::task::TaskWithoutId;
+/// use todo_manager::models::priority::Priority;
+///
+/// let mut list = TodoList::new();
+/// let id = list.add_task(TaskWithoutId::new("Write report".to_string()));
+/// list.set_task_priority(id, Priority::High).unwrap();
+/// list.add_task_tags(id, &["work".to_string()]).unwrap();
+///
+/// let expression = FilterExpression::parse("(high or medium) and +work and not is:recurring").unwrap();
+/// let today = chrono::Local::now().date_naive();
+/// assert!(expression.matches(&list.get_tasks()[0], &list, today));
+///
+/// let error = FilterExpression::parse("high or (medium").unwrap_err();
+/// assert!(error.message().contains("column 16"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterExpression {
+    /// Both sub-expressions must match
+    And(Box<FilterExpression>, Box<FilterExpression>),
+
+    /// At least one sub-expression must match
+    Or(Box<FilterExpression>, Box<FilterExpression>),
+
+    /// The sub-expression must not match
+    Not(Box<FilterExpression>),
+
+    /// A single condition
+    Predicate(FilterPredicate),
+}
+
+impl FilterExpression {
+    /// Parses a filter expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The expression text
+    ///
+    /// # Returns
+    ///
+    /// The parsed expression, or a `ParseError::InvalidQuery` pointing at the offending column.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let today = chrono::Local::now().date_naive();
+        Self::parse_with_today(input, today)
+    }
+
+    /// Returns true if `word` uses syntax that only filter expressions have:
+    /// operators, parentheses, quotes or the `and`/`or`/`not` keywords.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::filter_expression::FilterExpression;
+    ///
+    /// assert!(FilterExpression::has_expression_syntax("due<today"));
+    /// assert!(FilterExpression::has_expression_syntax("OR"));
+    /// assert!(!FilterExpression::has_expression_syntax("high"));
+    /// ```
+    pub fn has_expression_syntax(word: &str) -> bool {
+        word.contains(OPERATOR_CHARS)
+            || word.contains(['(', ')', '"', '&', '|'])
+            || matches!(word.to_lowercase().as_str(), "and" | "or" | "not")
+    }
+
+    /// Parses a filter expression, resolving relative dates against `today`.
+    pub fn parse_with_today(input: &str, today: NaiveDate) -> Result<Self, ParseError> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser {
+            input,
+            tokens,
+            position: 0,
+            today,
+        };
+
+        if parser.tokens.is_empty() {
+            return Err(parser.error(1, "empty filter expression"));
+        }
+
+        let expression = parser.parse_or()?;
+        if let Some(token) = parser.peek() {
+            let column = token.column;
+            return Err(match token.kind {
+                TokenKind::RightParen => parser.error(column, "unmatched ')'"),
+                _ => parser.error(column, "expected 'and', 'or' or end of expression"),
+            });
+        }
+        Ok(expression)
+    }
+
+    /// Evaluates the expression for a task of the given list.
+    ///
+    /// # Arguments
+    ///
+    /// * `task` - The task to check
+    /// * `todo_list` - The list the task belongs to (used for subtask and dependency predicates)
+    /// * `today` - The current date (used for overdue checks)
+    pub fn matches(&self, task: &Task, todo_list: &TodoList, today: NaiveDate) -> bool {
+        match self {
+            FilterExpression::And(left, right) => {
+                left.matches(task, todo_list, today) && right.matches(task, todo_list, today)
+            }
+            FilterExpression::Or(left, right) => {
+                left.matches(task, todo_list, today) || right.matches(task, todo_list, today)
+            }
+            FilterExpression::Not(inner) => !inner.matches(task, todo_list, today),
+            FilterExpression::Predicate(predicate) => predicate.matches(task, todo_list, today),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    LeftParen,
+    RightParen,
+    And,
+    Or,
+    Not,
+    Word(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Token {
+    kind: TokenKind,
+    /// 1-based column of the first character of the token
+    column: usize,
+    /// Whether the word contained quoted text
+    quoted: bool,
+}
+
+fn query_error(input: &str, column: usize, message: impl Into<String>) -> ParseError {
+    ParseError::InvalidQuery {
+        query: input.to_string(),
+        column,
+        message: message.into(),
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let column = i + 1;
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let simple = match c {
+            '(' => Some(TokenKind::LeftParen),
+            ')' => Some(TokenKind::RightParen),
+            '!' if chars.get(i + 1) != Some(&'=') => Some(TokenKind::Not),
+            '&' if chars.get(i + 1) == Some(&'&') => Some(TokenKind::And),
+            '|' if chars.get(i + 1) == Some(&'|') => Some(TokenKind::Or),
+            _ => None,
+        };
+        if let Some(kind) = simple {
+            i += if matches!(kind, TokenKind::And | TokenKind::Or) {
+                2
+            } else {
+                1
+            };
+            tokens.push(Token {
+                kind,
+                column,
+                quoted: false,
+            });
+            continue;
+        }
+
+        let mut word = String::new();
+        let mut quoted = false;
+        while i < chars.len() && !chars[i].is_whitespace() && chars[i] != '(' && chars[i] != ')' {
+            if chars[i] == '"' {
+                let quote_column = i + 1;
+                i += 1;
+                while i < chars.len() && chars[i] != '"' {
+                    word.push(chars[i]);
+                    i += 1;
+                }
+                if i == chars.len() {
+                    return Err(query_error(input, quote_column, "unterminated quote"));
+                }
+                quoted = true;
+            } else {
+                word.push(chars[i]);
+            }
+            i += 1;
+        }
+
+        let kind = if quoted {
+            TokenKind::Word(word)
+        } else {
+            match word.to_lowercase().as_str() {
+                "and" => TokenKind::And,
+                "or" => TokenKind::Or,
+                "not" => TokenKind::Not,
+                _ => TokenKind::Word(word),
+            }
+        };
+        tokens.push(Token {
+            kind,
+            column,
+            quoted,
+        });
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Vec<Token>,
+    position: usize,
+    today: NaiveDate,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn end_column(&self) -> usize {
+        self.input.chars().count() + 1
+    }
+
+    fn error(&self, column: usize, message: impl Into<String>) -> ParseError {
+        query_error(self.input, column, message)
+    }
+
+    fn parse_or(&mut self) -> Result<FilterExpression, ParseError> {
+        let mut left = self.parse_and()?;
+        while matches!(self.peek(), Some(token) if token.kind == TokenKind::Or) {
+            self.next();
+            let right = self.parse_and()?;
+            left = FilterExpression::Or(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<FilterExpression, ParseError> {
+        let mut left = self.parse_not()?;
+        loop {
+            match self.peek().map(|token| &token.kind) {
+                Some(TokenKind::And) => {
+                    self.next();
+                }
+                // Juxtaposed predicates are combined with 'and'
+                Some(TokenKind::Word(_)) | Some(TokenKind::Not) | Some(TokenKind::LeftParen) => {}
+                _ => break,
+            }
+            let right = self.parse_not()?;
+            left = FilterExpression::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_not(&mut self) -> Result<FilterExpression, ParseError> {
+        if matches!(self.peek(), Some(token) if token.kind == TokenKind::Not) {
+            self.next();
+            let inner = self.parse_not()?;
+            return Ok(FilterExpression::Not(Box::new(inner)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<FilterExpression, ParseError> {
+        let end_column = self.end_column();
+        let token = match self.next() {
+            Some(token) => token,
+            None => return Err(self.error(end_column, "unexpected end of expression")),
+        };
+
+        match token.kind {
+            TokenKind::LeftParen => {
+                let inner = self.parse_or()?;
+                match self.next() {
+                    Some(Token {
+                        kind: TokenKind::RightParen,
+                        ..
+                    }) => Ok(inner),
+                    Some(other) => Err(self.error(other.column, "expected ')'")),
+                    None => Err(self.error(end_column, "expected ')'")),
+                }
+            }
+            TokenKind::RightParen => Err(self.error(token.column, "unexpected ')'")),
+            TokenKind::And | TokenKind::Or => {
+                Err(self.error(token.column, "expected a filter before 'and'/'or'"))
+            }
+            TokenKind::Not => unreachable!("handled by parse_not"),
+            TokenKind::Word(word) => {
+                if token.quoted && !word.contains(['~', ':', '=', '<', '>']) {
+                    return Ok(FilterExpression::Predicate(
+                        FilterPredicate::DescriptionContains(word),
+                    ));
+                }
+                let word = self.merge_spaced_comparison(word);
+                self.parse_word(&word, token.column)
+            }
+        }
+    }
+
+    /// Joins `due < friday` written with spaces into a single word.
+    fn merge_spaced_comparison(&mut self, mut word: String) -> String {
+        if !FIELDS.contains(&word.to_lowercase().as_str()) {
+            return word;
+        }
+        if let Some(Token {
+            kind: TokenKind::Word(operator),
+            ..
+        }) = self.peek().cloned()
+        {
+            if operator.starts_with(OPERATOR_CHARS) {
+                self.next();
+                word.push_str(&operator);
+                if OPERATORS.contains(&operator.as_str()) {
+                    if let Some(Token {
+                        kind: TokenKind::Word(value),
+                        ..
+                    }) = self.peek().cloned()
+                    {
+                        self.next();
+                        word.push_str(&value);
+                    }
+                }
+            }
+        }
+        word
+    }
+
+    fn parse_word(&self, word: &str, column: usize) -> Result<FilterExpression, ParseError> {
+        let lower = word.to_lowercase();
+        let predicate = |p| Ok(FilterExpression::Predicate(p));
+
+        if let Some(tag) = word.strip_prefix('+') {
+            if tag.is_empty() {
+                return Err(self.error(column + 1, "expected a tag name after '+'"));
+            }
+            return predicate(FilterPredicate::Tag(tag.to_string()));
+        }
+        if let Some(tag) = word.strip_prefix('-') {
+            if tag.is_empty() {
+                return Err(self.error(column + 1, "expected a tag name after '-'"));
+            }
+            return Ok(FilterExpression::Not(Box::new(
+                FilterExpression::Predicate(FilterPredicate::Tag(tag.to_string())),
+            )));
+        }
+
+        match lower.as_str() {
+            "completed" | "done" => {
+                return predicate(FilterPredicate::Status(TaskStatus::Completed))
+            }
+            "pending" | "todo" | "open" => {
+                return predicate(FilterPredicate::Status(TaskStatus::Pending))
+            }
+            "in-progress" | "wip" => {
+                return predicate(FilterPredicate::Status(TaskStatus::InProgress))
+            }
+            "waiting" => return predicate(FilterPredicate::Status(TaskStatus::Waiting)),
+            "blocked" => return predicate(FilterPredicate::Status(TaskStatus::Blocked)),
+            "cancelled" | "canceled" => {
+                return predicate(FilterPredicate::Status(TaskStatus::Cancelled))
+            }
+            "high" | "h" => {
+                return predicate(FilterPredicate::Priority(Comparison::Equal, Priority::High))
+            }
+            "medium" | "med" | "m" => {
+                return predicate(FilterPredicate::Priority(
+                    Comparison::Equal,
+                    Priority::Medium,
+                ))
+            }
+            "low" | "l" => {
+                return predicate(FilterPredicate::Priority(Comparison::Equal, Priority::Low))
+            }
+            "overdue" => return predicate(FilterPredicate::Overdue),
+            _ => {}
+        }
+
+        let Some(operator_start) = word.find(OPERATOR_CHARS) else {
+            return Err(self.error(column, format!("unknown filter '{}'", word)));
+        };
+        // Lowercasing can change byte lengths, so slice the original word first
+        let field = word[..operator_start].to_lowercase();
+        let field = field.as_str();
+        let rest = &word[operator_start..];
+        let operator = OPERATORS
+            .iter()
+            .find(|op| rest.starts_with(**op))
+            .copied()
+            .unwrap_or(&rest[..1]);
+        let value = &rest[operator.len()..];
+        let operator_column = column + word[..operator_start].chars().count();
+        let value_column = operator_column + operator.chars().count();
+
+        if field.is_empty() {
+            return Err(self.error(column, "expected a field name before the operator"));
+        }
+        if value.is_empty() {
+            return Err(self.error(
+                value_column,
+                format!("expected a value after '{}'", operator),
+            ));
+        }
+
+        let field_error = |allowed: &str| {
+            self.error(
+                operator_column,
+                format!(
+                    "operator '{}' is not supported for '{}' (use {})",
+                    operator, field, allowed
+                ),
+            )
+        };
+        let comparison = Comparison::from_operator(operator);
+        let value_lower = value.to_lowercase();
+
+        match field {
+            "status" | "s" => {
+                let status = TaskStatus::from_str(value)
+                    .map_err(|err| self.error(value_column, err))?;
+                match comparison {
+                    Some(Comparison::Equal) => predicate(FilterPredicate::Status(status)),
+                    Some(Comparison::NotEqual) => Ok(FilterExpression::Not(Box::new(
+                        FilterExpression::Predicate(FilterPredicate::Status(status)),
+                    ))),
+                    _ => Err(field_error("':' or '!='")),
+                }
+            }
+            "priority" | "pri" | "p" => {
+                let priority = Priority::from_str(&value_lower).ok_or_else(|| {
+                    self.error(
+                        value_column,
+                        format!("invalid priority '{}' (use high, medium or low)", value),
+                    )
+                })?;
+                match comparison {
+                    Some(comparison) => predicate(FilterPredicate::Priority(comparison, priority)),
+                    None => Err(field_error("':', '=', '!=', '<', '<=', '>' or '>='")),
+                }
+            }
+            "due" => {
+                let comparison =
+                    comparison.ok_or_else(|| field_error("':', '=', '!=', '<', '<=', '>' or '>='"))?;
+                if value_lower == "none" {
+                    return match comparison {
+                        Comparison::Equal => Ok(FilterExpression::Not(Box::new(
+                            FilterExpression::Predicate(FilterPredicate::HasDueDate),
+                        ))),
+                        Comparison::NotEqual => predicate(FilterPredicate::HasDueDate),
+                        _ => Err(self.error(
+                            value_column,
+                            "'none' can only be used with ':' or '!='",
+                        )),
+                    };
+                }
+                let date = parse_date(value, self.today)
+                    .map_err(|err| self.error(value_column, err.message()))?;
+                predicate(FilterPredicate::Due(comparison, date))
+            }
+            "id" => {
+                let id = value.parse::<usize>().map_err(|_| {
+                    self.error(value_column, format!("invalid task ID '{}'", value))
+                })?;
+                match comparison {
+                    Some(comparison) => predicate(FilterPredicate::Id(comparison, id)),
+                    None => Err(field_error("':', '=', '!=', '<', '<=', '>' or '>='")),
+                }
+            }
+            "tag" | "tags" | "category" | "cat" => {
+                let tag = FilterExpression::Predicate(FilterPredicate::Tag(value.to_string()));
+                match comparison {
+                    Some(Comparison::Equal) => Ok(tag),
+                    Some(Comparison::NotEqual) => Ok(FilterExpression::Not(Box::new(tag))),
+                    _ => Err(field_error("':' or '!='")),
+                }
+            }
+            "desc" | "description" | "text" => match operator {
+                "~" | ":" => predicate(FilterPredicate::DescriptionContains(value.to_string())),
+                _ => Err(field_error("'~' or ':'")),
+            },
+            "has" | "is" if operator != ":" => Err(field_error("':'")),
+            "has" => match value_lower.as_str() {
+                "subtasks" | "subtask" | "children" => predicate(FilterPredicate::HasSubtasks),
+                "due" | "due-date" => predicate(FilterPredicate::HasDueDate),
+                "deps" | "dependencies" | "dependency" => {
+                    predicate(FilterPredicate::HasDependencies)
+                }
+                "tags" | "tag" => predicate(FilterPredicate::HasTags),
+                "parent" => predicate(FilterPredicate::IsSubtask),
+                _ => Err(self.error(
+                    value_column,
+                    format!(
+                        "unknown property 'has:{}' (use subtasks, due, deps, tags or parent)",
+                        value
+                    ),
+                )),
+            },
+            "is" => match value_lower.as_str() {
+                "blocked" => predicate(FilterPredicate::IsBlocked),
+                "recurring" => predicate(FilterPredicate::IsRecurring),
+                "overdue" => predicate(FilterPredicate::Overdue),
+                "subtask" => predicate(FilterPredicate::IsSubtask),
+                "resolved" => predicate(FilterPredicate::IsResolved),
+                _ => match TaskStatus::from_str(value) {
+                    Ok(status) => predicate(FilterPredicate::Status(status)),
+                    Err(_) => Err(self.error(
+                        value_column,
+                        format!(
+                            "unknown property 'is:{}' (use blocked, recurring, overdue, subtask, resolved or a status)",
+                            value
+                        ),
+                    )),
+                },
+            },
+            _ => Err(self.error(
+                column,
+                format!(
+                    "unknown field '{}' (use status, priority, due, id, tag, desc, has or is)",
+                    &word[..operator_start]
+                ),
+            )),
+        }
+    }
+}
+
+/// Field names that may be followed by a separately written operator.
+const FIELDS: &[&str] = &[
+    "status",
+    "s",
+    "priority",
+    "pri",
+    "p",
+    "due",
+    "id",
+    "tag",
+    "tags",
+    "category",
+    "cat",
+    "desc",
+    "description",
+    "text",
+    "has",
+    "is",
+];
+
+/// Supported operators, longest first.
+const OPERATORS: &[&str] = &["!=", "<=", ">=", "=", "<", ">", "~", ":"];
+
+const OPERATOR_CHARS: &[char] = &['!', '<', '>', '=', '~', ':'];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::recurrence::Recurrence;
+    use crate::models::task::TaskWithoutId;
+
+    fn today() -> NaiveDate {
+        // A Wednesday
+        NaiveDate::from_ymd_opt(2025, 1, 15).unwrap()
+    }
+
+    fn parse(input: &str) -> Result<FilterExpression, ParseError> {
+        FilterExpression::parse_with_today(input, today())
+    }
+
+    fn column_of(error: ParseError) -> usize {
+        match error {
+            ParseError::InvalidQuery { column, .. } => column,
+            other => panic!("Expected InvalidQuery, got {:?}", other),
+        }
+    }
+
+    fn sample_list() -> TodoList {
+        let mut list = TodoList::new();
+        let report = list.add_task(TaskWithoutId::new("Write report".to_string()));
+        list.set_task_priority(report, Priority::High).unwrap();
+        list.add_task_tags(report, &["work".to_string()]).unwrap();
+        list.set_due_date(report, Some(NaiveDate::from_ymd_opt(2025, 1, 16).unwrap()))
+            .unwrap();
+
+        let review = list.add_task(TaskWithoutId::new("Review slides".to_string()));
+        list.add_task_tags(review, &["work".to_string()]).unwrap();
+        list.set_task_recurrence(review, Some(Recurrence::Weekly))
+            .unwrap();
+        list.set_due_date(review, Some(NaiveDate::from_ymd_opt(2025, 1, 20).unwrap()))
+            .unwrap();
+
+        let groceries = list.add_task(TaskWithoutId::new("Buy milk".to_string()));
+        list.set_task_priority(groceries, Priority::Low).unwrap();
+        list.add_subtask(groceries, "Find store".to_string())
+            .unwrap();
+        list.add_task_dependency(review, report).unwrap();
+        list
+    }
+
+    fn matching_ids(list: &TodoList, input: &str) -> Vec<usize> {
+        let expression = parse(input).unwrap();
+        list.get_tasks()
+            .iter()
+            .filter(|task| expression.matches(task, list, today()))
+            .map(|task| task.id)
+            .collect()
+    }
+
+    #[test]
+    fn test_or_and_not_with_parentheses() {
+        let list = sample_list();
+        assert_eq!(
+            matching_ids(&list, "(high or medium) and +work and not is:recurring"),
+            vec![1]
+        );
+        assert_eq!(matching_ids(&list, "high || low"), vec![1, 3]);
+        assert_eq!(matching_ids(&list, "!+work not has:parent"), vec![3]);
+    }
+
+    #[test]
+    fn test_implicit_and_keeps_classic_syntax() {
+        let list = sample_list();
+        assert_eq!(matching_ids(&list, "pending medium"), vec![2, 4]);
+    }
+
+    #[test]
+    fn test_comparisons() {
+        let list = sample_list();
+        assert_eq!(matching_ids(&list, "priority>=medium"), vec![1, 2, 4]);
+        assert_eq!(matching_ids(&list, "due<friday"), vec![1]);
+        assert_eq!(matching_ids(&list, "due <= 20.01.2025"), vec![1, 2]);
+        assert_eq!(matching_ids(&list, "due:none"), vec![3, 4]);
+        assert_eq!(matching_ids(&list, "due<=+5d"), vec![1, 2]);
+        assert_eq!(matching_ids(&list, "due<\"next monday\""), vec![1, 2]);
+        assert_eq!(matching_ids(&list, "id>2"), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_has_and_is_predicates() {
+        let list = sample_list();
+        assert_eq!(matching_ids(&list, "has:subtasks"), vec![3]);
+        assert_eq!(matching_ids(&list, "is:blocked"), vec![2]);
+        assert_eq!(matching_ids(&list, "is:recurring"), vec![2]);
+        assert_eq!(matching_ids(&list, "is:subtask"), vec![4]);
+        assert_eq!(matching_ids(&list, "has:deps"), vec![2]);
+    }
+
+    #[test]
+    fn test_description_contains() {
+        let list = sample_list();
+        assert_eq!(matching_ids(&list, "desc~MILK"), vec![3]);
+        assert_eq!(matching_ids(&list, "desc~\"write rep\""), vec![1]);
+        assert_eq!(matching_ids(&list, "\"slides\""), vec![2]);
+    }
+
+    #[test]
+    fn test_error_columns() {
+        assert_eq!(column_of(parse("high or foo").unwrap_err()), 9);
+        assert_eq!(column_of(parse("high or (medium").unwrap_err()), 16);
+        assert_eq!(column_of(parse("high )").unwrap_err()), 6);
+        assert_eq!(column_of(parse("due<someday").unwrap_err()), 5);
+        assert_eq!(column_of(parse("desc<milk").unwrap_err()), 5);
+        assert_eq!(column_of(parse("weight>3").unwrap_err()), 1);
+        assert_eq!(column_of(parse("high and").unwrap_err()), 9);
+        assert_eq!(column_of(parse("desc~\"open").unwrap_err()), 6);
+        assert_eq!(column_of(parse("").unwrap_err()), 1);
+    }
+
+    #[test]
+    fn test_non_ascii_field_name_is_an_unknown_field() {
+        for input in ["İ:x", "Ⱥ:x"] {
+            let message = parse(input).unwrap_err().message();
+            assert!(message.contains("unknown field"), "{}", message);
+        }
+    }
+
+    #[test]
+    fn test_error_message_points_at_column() {
+        let message = parse("high or foo").unwrap_err().message();
+        assert!(message.contains("column 9"));
+        assert!(message.contains("unknown filter 'foo'"));
+        assert!(message.ends_with("        ^"));
+    }
+}
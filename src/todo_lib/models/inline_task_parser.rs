@@ -0,0 +1,210 @@
+use crate::models::date_parser::parse_date;
+use crate::models::id_parser::parse_ids;
+use crate::models::parse_error::ParseError;
+use crate::models::recurrence::Recurrence;
+use crate::models::task::TaskWithoutId;
+use chrono::NaiveDate;
+
+/// Parses a task description with inline attribute markers.
+///
+/// Recognized markers are removed from the description:
+/// - `!high`, `!medium`, `!low` (or `!h`, `!m`, `!l`) set the priority
+/// - `@name` adds a tag; the first one is the task's category
+/// - `due:<date>` sets the due date (see `parse_date`, e.g. `due:friday`, `due:+3d`)
+/// - `every:<day|week|month>` sets the recurrence
+/// - `parent:<id>` makes the task a subtask
+/// - `after:<ids>` adds dependencies (`after:7` or `after:3,5`)
+///
+/// A word starting with a backslash is kept literally without the backslash,
+/// so `\!important` and `\@home` end up as `!important` and `@home`.
+/// A lone `!` or `@` is kept as text as well.
+///
+/// # Arguments
+///
+/// * `input` - The description including markers
+/// * `today` - The date relative dates are resolved against
+///
+/// # Returns
+///
+/// The task data, or a `ParseError` for an invalid marker value or an empty description
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::models::inline_task_parser::parse_inline_task;
+/// use todo_manager::models::priority::Priority;
+/// use todo_manager::models::recurrence::Recurrence;
+/// use chrono::NaiveDate;
+///
+/// // A Wednesday
+/// let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+/// let task = parse_inline_task(
+///     "Pay invoice !high @finance due:friday every:month parent:12 after:7",
+///     today,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(task.description, "Pay invoice");
+/// assert_eq!(task.priority, Priority::High);
+/// assert_eq!(task.tags, vec!["finance".to_string()]);
+/// assert_eq!(task.due_date, NaiveDate::from_ymd_opt(2025, 1, 17));
+/// assert_eq!(task.recurrence, Some(Recurrence::Monthly));
+/// assert_eq!(task.parent_id, Some(12));
+/// assert_eq!(task.depends_on, vec![7]);
+///
+/// let task = parse_inline_task(r"Say \@hello \!now", today).unwrap();
+/// assert_eq!(task.description, "Say @hello !now");
+/// ```
+pub fn parse_inline_task(input: &str, today: NaiveDate) -> Result<TaskWithoutId, ParseError> {
+    let mut task = TaskWithoutId::new(String::new());
+    let mut words: Vec<&str> = Vec::new();
+
+    for word in input.split_whitespace() {
+        if let Some(literal) = word.strip_prefix('\\') {
+            if !literal.is_empty() {
+                words.push(literal);
+                continue;
+            }
+        }
+
+        if let Some(value) = word.strip_prefix('!').filter(|value| !value.is_empty()) {
+            task.priority = value.parse().map_err(|_| ParseError::InvalidValue {
+                field: "priority".to_string(),
+                value: value.to_string(),
+                allowed: "!high, !medium, !low (write \\! for a literal '!')".to_string(),
+            })?;
+        } else if let Some(tag) = word.strip_prefix('@').filter(|tag| !tag.is_empty()) {
+            if !task.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                task.tags.push(tag.to_string());
+            }
+        } else if let Some((key, value)) = word.split_once(':') {
+            if !apply_attribute(&mut task, key, value, today)? {
+                words.push(word);
+            }
+        } else {
+            words.push(word);
+        }
+    }
+
+    if words.is_empty() {
+        return Err(ParseError::EmptyInput("Task description".to_string()));
+    }
+
+    task.description = words.join(" ");
+    Ok(task)
+}
+
+/// Applies a `key:value` marker to the task.
+///
+/// Returns `Ok(false)` if `key` is not a marker, so the word stays in the description.
+fn apply_attribute(
+    task: &mut TaskWithoutId,
+    key: &str,
+    value: &str,
+    today: NaiveDate,
+) -> Result<bool, ParseError> {
+    let missing_value = |example: &str| ParseError::InvalidFormat {
+        field: key.to_string(),
+        expected: example.to_string(),
+        actual: format!("{}:", key),
+    };
+
+    match key.to_lowercase().as_str() {
+        "due" => {
+            if value.is_empty() {
+                return Err(missing_value("due:<date>, e.g. due:friday"));
+            }
+            task.due_date = Some(parse_date(value, today)?);
+        }
+        "every" => {
+            let recurrence = value
+                .parse::<Recurrence>()
+                .map_err(|_| ParseError::InvalidValue {
+                    field: "recurrence".to_string(),
+                    value: value.to_string(),
+                    allowed: "day, week, month".to_string(),
+                })?;
+            task.recurrence = Some(recurrence);
+        }
+        "parent" => {
+            let parent_id = value.parse::<usize>().map_err(|_| {
+                ParseError::InvalidId(format!(
+                    "Invalid parent ID '{}'. Please provide a number.",
+                    value
+                ))
+            })?;
+            task.parent_id = Some(parent_id);
+        }
+        "after" => {
+            if value.is_empty() {
+                return Err(missing_value("after:<ids>, e.g. after:7"));
+            }
+            for id in parse_ids(value).map_err(ParseError::InvalidId)? {
+                if !task.depends_on.contains(&id) {
+                    task.depends_on.push(id);
+                }
+            }
+        }
+        _ => return Ok(false),
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::priority::Priority;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, 15).unwrap()
+    }
+
+    #[test]
+    fn test_plain_description_is_unchanged() {
+        let task = parse_inline_task("Buy milk at 10:30 !", today()).unwrap();
+        assert_eq!(task, TaskWithoutId::new("Buy milk at 10:30 !".to_string()));
+    }
+
+    #[test]
+    fn test_markers_anywhere_in_description() {
+        let task = parse_inline_task("!low Call @home @Work @home mom after:2-3", today()).unwrap();
+        assert_eq!(task.description, "Call mom");
+        assert_eq!(task.priority, Priority::Low);
+        assert_eq!(task.tags, vec!["home".to_string(), "Work".to_string()]);
+        assert_eq!(task.depends_on, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_invalid_marker_values() {
+        assert!(matches!(
+            parse_inline_task("Task !urgent", today()),
+            Err(ParseError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            parse_inline_task("Task due:someday", today()),
+            Err(ParseError::InvalidDate(_))
+        ));
+        assert!(matches!(
+            parse_inline_task("Task parent:x", today()),
+            Err(ParseError::InvalidId(_))
+        ));
+        assert!(parse_inline_task("Task every:year", today()).is_err());
+        assert!(parse_inline_task("Task after:", today()).is_err());
+    }
+
+    #[test]
+    fn test_description_required() {
+        assert_eq!(
+            parse_inline_task("!high @work", today()),
+            Err(ParseError::EmptyInput("Task description".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_escaped_markers() {
+        let task = parse_inline_task(r"\due:tomorrow \\ back\slash", today()).unwrap();
+        assert_eq!(task.description, r"due:tomorrow \ back\slash");
+        assert_eq!(task.due_date, None);
+    }
+}
@@ -3,32 +3,46 @@
 //! This module contains all data structures, events, and business logic
 //! related to tasks, priorities, filtering, and control flow.
 
+pub mod agenda;
 pub mod command_controller_result;
+pub mod date_parser;
 pub mod filter_builder;
+pub mod filter_expression;
 pub mod id_parser;
+pub mod inline_task_parser;
 pub mod loop_control;
 pub mod overdue_filter;
 pub mod parse_error;
 pub mod priority;
 pub mod project;
 pub mod recurrence;
+pub mod saved_view;
 pub mod task;
+pub mod task_field_parser;
 pub mod task_filter;
 pub mod task_sort;
 pub mod task_statistics;
 pub mod task_status;
+pub mod todo_error;
 pub mod todo_list;
+pub mod trash;
 
 // Re-export commonly used types
+pub use date_parser::parse_date;
 pub use filter_builder::FilterBuilder;
+pub use filter_expression::FilterExpression;
 pub use id_parser::parse_ids;
+pub use inline_task_parser::parse_inline_task;
 pub use loop_control::LoopControl;
 pub use parse_error::ParseError;
 pub use priority::Priority;
 pub use recurrence::Recurrence;
+pub use saved_view::SavedView;
 pub use task::Task;
 pub use task_filter::TaskFilter;
 pub use task_sort::{SortBy, SortOrder};
 pub use task_statistics::TaskStatistics;
 pub use task_status::TaskStatus;
+pub use todo_error::TodoError;
 pub use todo_list::TodoList;
+pub use trash::{TrashedProject, TrashedTask};
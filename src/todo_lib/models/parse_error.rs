@@ -33,6 +33,13 @@ pub enum ParseError {
     EmptyCommand(String),
     /// Command is not recognized
     UnknownCommand(String),
+    /// Filter expression could not be parsed
+    InvalidQuery {
+        query: String,
+        /// 1-based character column where the error was detected
+        column: usize,
+        message: String,
+    },
 }
 
 impl ParseError {
@@ -66,6 +73,17 @@ impl ParseError {
                 "Unknown command '{}'. Type 'help' for available commands.",
                 cmd
             ),
+            ParseError::InvalidQuery {
+                query,
+                column,
+                message,
+            } => format!(
+                "Invalid filter at column {}: {}\n  {}\n  {}^",
+                column,
+                message,
+                query,
+                " ".repeat(column.saturating_sub(1))
+            ),
         }
     }
 }
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 /// Projects allow organizing tasks into separate lists,
 /// making it easier to manage tasks for different contexts
 /// (e.g., work, personal, specific projects).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Project {
     /// The name of the project
     pub name: String,
@@ -50,13 +50,14 @@ impl FromStr for Recurrence {
     /// assert_eq!(Recurrence::from_str("daily"), Ok(Recurrence::Daily));
     /// assert_eq!(Recurrence::from_str("WEEKLY"), Ok(Recurrence::Weekly));
     /// assert_eq!(Recurrence::from_str("monthly"), Ok(Recurrence::Monthly));
+    /// assert_eq!(Recurrence::from_str("month"), Ok(Recurrence::Monthly));
     /// assert!(Recurrence::from_str("invalid").is_err());
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
-            "daily" | "d" => Ok(Recurrence::Daily),
-            "weekly" | "w" => Ok(Recurrence::Weekly),
-            "monthly" | "m" => Ok(Recurrence::Monthly),
+            "daily" | "day" | "d" => Ok(Recurrence::Daily),
+            "weekly" | "week" | "w" => Ok(Recurrence::Weekly),
+            "monthly" | "month" | "m" => Ok(Recurrence::Monthly),
             _ => Err(()),
         }
     }
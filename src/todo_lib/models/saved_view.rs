@@ -0,0 +1,100 @@
+use crate::models::filter_builder::FilterBuilder;
+use crate::models::parse_error::ParseError;
+use crate::models::task_filter::TaskFilter;
+use serde::{Deserialize, Serialize};
+
+/// Names that cannot be used for saved views because they are `view` subcommands.
+pub const RESERVED_VIEW_NAMES: [&str; 5] = ["save", "list", "delete", "remove", "rm"];
+
+/// A named set of `list` filter arguments.
+///
+/// Views store the arguments as typed so that they are parsed with the same
+/// rules as `list` every time they are shown.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::models::saved_view::SavedView;
+/// use todo_manager::models::priority::Priority;
+///
+/// let view = SavedView::new("work".to_string(), "pending high +work sort:due".to_string());
+/// let filter = view.to_filter().unwrap().unwrap();
+/// assert_eq!(filter.priority, Some(Priority::High));
+/// assert_eq!(filter.tags, vec!["work".to_string()]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedView {
+    /// Name used to show the view
+    pub name: String,
+
+    /// The filter arguments, exactly as they would follow `list`
+    pub filter: String,
+}
+
+impl SavedView {
+    /// Creates a new saved view.
+    pub fn new(name: String, filter: String) -> Self {
+        Self { name, filter }
+    }
+
+    /// Parses the stored arguments into a task filter.
+    ///
+    /// # Returns
+    ///
+    /// The filter, `None` if the view shows all tasks, or the parse error
+    pub fn to_filter(&self) -> Result<Option<TaskFilter>, ParseError> {
+        let args: Vec<&str> = self.filter.split_whitespace().collect();
+        FilterBuilder::parse_args(&args)
+    }
+
+    /// Returns the views that are always available in the TUI.
+    pub fn builtin() -> Vec<SavedView> {
+        vec![
+            SavedView::new("all".to_string(), String::new()),
+            SavedView::new("pending".to_string(), "pending".to_string()),
+            SavedView::new("completed".to_string(), "completed".to_string()),
+        ]
+    }
+
+    /// Returns whether `name` is a `view` subcommand and cannot name a view.
+    pub fn is_reserved_name(name: &str) -> bool {
+        RESERVED_VIEW_NAMES
+            .iter()
+            .any(|reserved| reserved.eq_ignore_ascii_case(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::task_status::TaskStatus;
+
+    #[test]
+    fn test_empty_view_shows_all_tasks() {
+        let view = SavedView::new("all".to_string(), String::new());
+        assert_eq!(view.to_filter().unwrap(), None);
+    }
+
+    #[test]
+    fn test_view_with_expression() {
+        let view = SavedView::new("urgent".to_string(), "high or overdue".to_string());
+        let filter = view.to_filter().unwrap().unwrap();
+        assert!(filter.expression.is_some());
+    }
+
+    #[test]
+    fn test_builtin_views_parse() {
+        let views = SavedView::builtin();
+        assert_eq!(
+            views[1].to_filter().unwrap().unwrap().status,
+            Some(TaskStatus::Pending)
+        );
+        assert!(views.iter().all(|view| view.to_filter().is_ok()));
+    }
+
+    #[test]
+    fn test_reserved_names() {
+        assert!(SavedView::is_reserved_name("Save"));
+        assert!(!SavedView::is_reserved_name("work"));
+    }
+}
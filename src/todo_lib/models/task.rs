@@ -1,7 +1,51 @@
 use super::priority::Priority;
 use super::recurrence::Recurrence;
-use chrono::{Datelike, NaiveDate};
-use serde::{Deserialize, Serialize};
+use super::task_status::TaskStatus;
+use chrono::{DateTime, Datelike, Local, NaiveDate};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Deserializes the tags of a task.
+///
+/// Older files store a single `category` string (or `null`) instead of a list of tags,
+/// so both representations are accepted and converted into a tag list.
+fn deserialize_tags<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum TagsOrCategory {
+        Tags(Vec<String>),
+        Category(Option<String>),
+    }
+
+    Ok(match TagsOrCategory::deserialize(deserializer)? {
+        TagsOrCategory::Tags(tags) => tags,
+        TagsOrCategory::Category(category) => category.into_iter().collect(),
+    })
+}
+
+/// Deserializes the status of a task.
+///
+/// Older files only store a `completed` flag instead of a status, so both
+/// representations are accepted and the flag is converted into a status.
+fn deserialize_status<'de, D>(deserializer: D) -> Result<TaskStatus, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StatusOrCompleted {
+        Status(TaskStatus),
+        Completed(bool),
+    }
+
+    Ok(match StatusOrCompleted::deserialize(deserializer)? {
+        StatusOrCompleted::Status(status) => status,
+        StatusOrCompleted::Completed(true) => TaskStatus::Completed,
+        StatusOrCompleted::Completed(false) => TaskStatus::Pending,
+    })
+}
 
 /// Represents task data without an ID.
 ///
@@ -11,36 +55,47 @@ use serde::{Deserialize, Serialize};
 ///
 /// ```
 /// use todo_manager::models::task::TaskWithoutId;
-/// use todo_manager::models::priority::Priority;
+/// use todo_manager::models::task_status::TaskStatus;
 ///
 /// let task = TaskWithoutId::new("Write documentation".to_string());
 /// assert_eq!(task.description, "Write documentation");
-/// assert_eq!(task.completed, false);
+/// assert_eq!(task.status, TaskStatus::Pending);
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaskWithoutId {
     /// A textual description of the task
     pub description: String,
-    /// Whether the task has been completed
-    pub completed: bool,
+    /// The workflow status of the task (legacy `completed` flags are migrated on load)
+    #[serde(default, alias = "completed", deserialize_with = "deserialize_status")]
+    pub status: TaskStatus,
     /// The priority level of the task
     pub priority: Priority,
     /// The optional due date for the task
     pub due_date: Option<NaiveDate>,
-    /// The optional category/tag for the task
-    pub category: Option<String>,
+    /// The tags assigned to the task (legacy `category` values are migrated on load)
+    #[serde(default, alias = "category", deserialize_with = "deserialize_tags")]
+    pub tags: Vec<String>,
     /// The optional parent task ID for subtasks
     pub parent_id: Option<usize>,
     /// The optional recurrence pattern for the task
     pub recurrence: Option<Recurrence>,
     /// The list of task IDs that this task depends on
     pub depends_on: Vec<usize>,
+    /// When the task was added to a todo list
+    #[serde(default)]
+    pub created_at: Option<DateTime<Local>>,
+    /// When the task was last modified
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Local>>,
+    /// When the task was last completed
+    #[serde(default)]
+    pub completed_at: Option<DateTime<Local>>,
 }
 
 impl TaskWithoutId {
     /// Creates a new task data with the given description.
     ///
-    /// The task is initialized with `completed` set to `false` and priority set to `Medium`.
+    /// The task is initialized with status `Pending` and priority set to `Medium`.
     ///
     /// # Arguments
     ///
@@ -50,21 +105,25 @@ impl TaskWithoutId {
     ///
     /// ```
     /// use todo_manager::models::task::TaskWithoutId;
+    /// use todo_manager::models::task_status::TaskStatus;
     ///
     /// let task = TaskWithoutId::new("Buy milk".to_string());
     /// assert_eq!(task.description, "Buy milk");
-    /// assert!(!task.completed);
+    /// assert_eq!(task.status, TaskStatus::Pending);
     /// ```
     pub fn new(description: String) -> Self {
         TaskWithoutId {
             description,
-            completed: false,
+            status: TaskStatus::Pending,
             priority: Priority::default(),
             due_date: None,
-            category: None,
+            tags: Vec::new(),
             parent_id: None,
             recurrence: None,
             depends_on: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            completed_at: None,
         }
     }
 
@@ -88,13 +147,16 @@ impl TaskWithoutId {
         Task {
             id,
             description: self.description,
-            completed: self.completed,
+            status: self.status,
             priority: self.priority,
             due_date: self.due_date,
-            category: self.category,
+            tags: self.tags,
             parent_id: self.parent_id,
             recurrence: self.recurrence,
             depends_on: self.depends_on,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            completed_at: self.completed_at,
         }
     }
 }
@@ -119,26 +181,37 @@ pub struct Task {
     pub id: usize,
     /// A textual description of the task
     pub description: String,
-    /// Whether the task has been completed
-    pub completed: bool,
+    /// The workflow status of the task (legacy `completed` flags are migrated on load)
+    #[serde(default, alias = "completed", deserialize_with = "deserialize_status")]
+    pub status: TaskStatus,
     /// The priority level of the task
     pub priority: Priority,
     /// The optional due date for the task
     pub due_date: Option<NaiveDate>,
-    /// The optional category/tag for the task
-    pub category: Option<String>,
+    /// The tags assigned to the task (legacy `category` values are migrated on load)
+    #[serde(default, alias = "category", deserialize_with = "deserialize_tags")]
+    pub tags: Vec<String>,
     /// The optional parent task ID for subtasks
     pub parent_id: Option<usize>,
     /// The optional recurrence pattern for the task
     pub recurrence: Option<Recurrence>,
     /// The list of task IDs that this task depends on
     pub depends_on: Vec<usize>,
+    /// When the task was added to a todo list
+    #[serde(default)]
+    pub created_at: Option<DateTime<Local>>,
+    /// When the task was last modified
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Local>>,
+    /// When the task was last completed
+    #[serde(default)]
+    pub completed_at: Option<DateTime<Local>>,
 }
 
 impl Task {
     /// Creates a new task with the given ID and description.
     ///
-    /// The task is initialized with `completed` set to `false` and priority set to `Medium`.
+    /// The task is initialized with status `Pending` and priority set to `Medium`.
     ///
     /// # Arguments
     ///
@@ -153,19 +226,22 @@ impl Task {
     /// let task = Task::new(1, "Buy milk".to_string());
     /// assert_eq!(task.id, 1);
     /// assert_eq!(task.description, "Buy milk");
-    /// assert!(!task.completed);
+    /// assert!(!task.is_completed());
     /// ```
     pub fn new(id: usize, description: String) -> Self {
         Task {
             id,
             description,
-            completed: false,
+            status: TaskStatus::Pending,
             priority: Priority::default(),
             due_date: None,
-            category: None,
+            tags: Vec::new(),
             parent_id: None,
             recurrence: None,
             depends_on: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            completed_at: None,
         }
     }
 
@@ -205,7 +281,8 @@ impl Task {
     }
     /// Toggles the completion status of the task.
     ///
-    /// If the task is completed, it becomes pending. If it's pending, it becomes completed.
+    /// If the task is completed, it becomes pending. Otherwise it becomes completed.
+    /// Completing a task records the completion time, reopening it clears that time again.
     ///
     /// # Examples
     ///
@@ -222,7 +299,77 @@ impl Task {
     /// assert!(!task.is_completed());
     /// ```
     pub fn toggle_completion(&mut self) {
-        self.completed = !self.completed;
+        if self.is_completed() {
+            self.set_status(TaskStatus::Pending);
+        } else {
+            self.set_status(TaskStatus::Completed);
+        }
+    }
+
+    /// Sets the workflow status of the task.
+    ///
+    /// Entering `Completed` records the completion time; any other status clears it.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The new status
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::task::Task;
+    /// use todo_manager::models::task_status::TaskStatus;
+    ///
+    /// let mut task = Task::new(1, "Write report".to_string());
+    /// task.set_status(TaskStatus::InProgress);
+    /// assert_eq!(task.get_status(), TaskStatus::InProgress);
+    /// assert!(!task.is_completed());
+    ///
+    /// task.set_status(TaskStatus::Completed);
+    /// assert!(task.is_completed());
+    /// assert!(task.completed_at.is_some());
+    /// ```
+    pub fn set_status(&mut self, status: TaskStatus) {
+        if status == TaskStatus::Completed {
+            if self.status != TaskStatus::Completed {
+                self.completed_at = Some(Local::now());
+            }
+        } else {
+            self.completed_at = None;
+        }
+        self.status = status;
+    }
+
+    /// Gets the workflow status of the task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::task::Task;
+    /// use todo_manager::models::task_status::TaskStatus;
+    ///
+    /// let task = Task::new(1, "Task".to_string());
+    /// assert_eq!(task.get_status(), TaskStatus::Pending);
+    /// ```
+    pub fn get_status(&self) -> TaskStatus {
+        self.status
+    }
+
+    /// Returns whether the task is resolved, i.e. completed or cancelled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::task::Task;
+    /// use todo_manager::models::task_status::TaskStatus;
+    ///
+    /// let mut task = Task::new(1, "Task".to_string());
+    /// task.set_status(TaskStatus::Cancelled);
+    /// assert!(task.is_resolved());
+    /// assert!(!task.is_completed());
+    /// ```
+    pub fn is_resolved(&self) -> bool {
+        self.status.is_resolved()
     }
 
     /// Returns whether the task is completed.
@@ -236,12 +383,13 @@ impl Task {
     /// assert_eq!(task.is_completed(), false);
     /// ```
     pub fn is_completed(&self) -> bool {
-        self.completed
+        self.status == TaskStatus::Completed
     }
 
-    /// Returns a visual symbol representing the task's completion status.
+    /// Returns a visual symbol representing the task's status.
     ///
-    /// Returns "✓" for completed tasks and " " (space) for pending tasks.
+    /// Returns "✓" for completed tasks, " " (space) for pending tasks and
+    /// the status symbol for all other states.
     ///
     /// # Examples
     ///
@@ -255,11 +403,7 @@ impl Task {
     /// assert_eq!(task.get_status_symbol(), "✓");
     /// ```
     pub fn get_status_symbol(&self) -> &str {
-        if self.completed {
-            "✓"
-        } else {
-            " "
-        }
+        self.status.symbol()
     }
 
     /// Sets the due date for the task.
@@ -329,9 +473,11 @@ impl Task {
 
     /// Sets the category of the task.
     ///
+    /// A category is a single tag: setting it replaces all existing tags.
+    ///
     /// # Arguments
     ///
-    /// * `category` - The category/tag to assign (None to clear)
+    /// * `category` - The category/tag to assign (None to clear all tags)
     ///
     /// # Examples
     ///
@@ -339,18 +485,20 @@ impl Task {
     /// use todo_manager::models::task::Task;
     ///
     /// let mut task = Task::new(1, "Task".to_string());
+    /// task.add_tag("urgent");
     /// task.set_category(Some("work".to_string()));
     /// assert_eq!(task.get_category(), Some(&"work".to_string()));
+    /// assert_eq!(task.get_tags(), &vec!["work".to_string()]);
     /// ```
     pub fn set_category(&mut self, category: Option<String>) {
-        self.category = category;
+        self.tags = category.into_iter().collect();
     }
 
     /// Gets the category of the task.
     ///
     /// # Returns
     ///
-    /// A reference to the optional category string
+    /// A reference to the first tag of the task, if any
     ///
     /// # Examples
     ///
@@ -361,7 +509,98 @@ impl Task {
     /// assert_eq!(task.get_category(), None);
     /// ```
     pub fn get_category(&self) -> Option<&String> {
-        self.category.as_ref()
+        self.tags.first()
+    }
+
+    /// Adds a tag to the task.
+    ///
+    /// Tags are compared case-insensitively, so a tag is only added once.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The tag to add
+    ///
+    /// # Returns
+    ///
+    /// `true` if the tag was added, `false` if the task already had it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::task::Task;
+    ///
+    /// let mut task = Task::new(1, "Task".to_string());
+    /// assert!(task.add_tag("work"));
+    /// assert!(task.add_tag("urgent"));
+    /// assert!(!task.add_tag("Work"));
+    /// assert_eq!(task.get_tags().len(), 2);
+    /// ```
+    pub fn add_tag(&mut self, tag: &str) -> bool {
+        if self.has_tag(tag) {
+            return false;
+        }
+        self.tags.push(tag.to_string());
+        true
+    }
+
+    /// Removes a tag from the task.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The tag to remove (case-insensitive)
+    ///
+    /// # Returns
+    ///
+    /// `true` if the tag was removed, `false` if the task did not have it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::task::Task;
+    ///
+    /// let mut task = Task::new(1, "Task".to_string());
+    /// task.add_tag("work");
+    /// assert!(task.remove_tag("WORK"));
+    /// assert!(!task.remove_tag("work"));
+    /// ```
+    pub fn remove_tag(&mut self, tag: &str) -> bool {
+        let count = self.tags.len();
+        self.tags.retain(|t| !t.eq_ignore_ascii_case(tag));
+        self.tags.len() != count
+    }
+
+    /// Checks if the task has a specific tag.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The tag to look for (case-insensitive)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::task::Task;
+    ///
+    /// let mut task = Task::new(1, "Task".to_string());
+    /// task.add_tag("work");
+    /// assert!(task.has_tag("Work"));
+    /// assert!(!task.has_tag("home"));
+    /// ```
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+
+    /// Gets the tags of the task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::task::Task;
+    ///
+    /// let task = Task::new(1, "Task".to_string());
+    /// assert!(task.get_tags().is_empty());
+    /// ```
+    pub fn get_tags(&self) -> &Vec<String> {
+        &self.tags
     }
 
     /// Sets the parent task ID, making this task a subtask.
@@ -666,6 +905,28 @@ impl Task {
     pub fn has_dependencies(&self) -> bool {
         !self.depends_on.is_empty()
     }
+
+    /// Records the current time as the last modification time of the task.
+    ///
+    /// If the task has no creation time yet, it is set as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::task::Task;
+    ///
+    /// let mut task = Task::new(1, "Task".to_string());
+    /// assert!(task.updated_at.is_none());
+    ///
+    /// task.touch();
+    /// assert!(task.updated_at.is_some());
+    /// assert_eq!(task.created_at, task.updated_at);
+    /// ```
+    pub fn touch(&mut self) {
+        let now = Local::now();
+        self.created_at.get_or_insert(now);
+        self.updated_at = Some(now);
+    }
 }
 
 #[cfg(test)]
@@ -677,7 +938,7 @@ mod tests {
         let task = Task::new(1, "Test task".to_string());
         assert_eq!(task.id, 1);
         assert_eq!(task.description, "Test task");
-        assert!(!task.completed);
+        assert!(!task.is_completed());
     }
 
     #[test]
@@ -692,6 +953,96 @@ mod tests {
         assert!(!task.is_completed());
     }
 
+    #[test]
+    fn test_toggle_completion_sets_completed_at() {
+        let mut task = Task::new(1, "Test task".to_string());
+        assert!(task.completed_at.is_none());
+
+        task.toggle_completion();
+        assert!(task.completed_at.is_some());
+
+        task.toggle_completion();
+        assert!(task.completed_at.is_none());
+    }
+
+    #[test]
+    fn test_touch_keeps_created_at() {
+        let mut task = Task::new(1, "Test task".to_string());
+        task.touch();
+        let created_at = task.created_at;
+
+        task.touch();
+        assert_eq!(task.created_at, created_at);
+        assert!(task.updated_at >= created_at);
+    }
+
+    #[test]
+    fn test_deserialize_without_timestamps() {
+        let json = r#"{"id":1,"description":"Old task","completed":false,"priority":"Medium","due_date":null,"category":null,"parent_id":null,"recurrence":null,"depends_on":[]}"#;
+        let task: Task = serde_json::from_str(json).unwrap();
+        assert!(task.created_at.is_none());
+        assert!(task.updated_at.is_none());
+        assert!(task.completed_at.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_legacy_category_as_tag() {
+        let json = r#"{"id":1,"description":"Old task","completed":false,"priority":"Medium","due_date":null,"category":"work","parent_id":null,"recurrence":null,"depends_on":[]}"#;
+        let task: Task = serde_json::from_str(json).unwrap();
+        assert_eq!(task.tags, vec!["work".to_string()]);
+    }
+
+    #[test]
+    fn test_deserialize_legacy_completed_flag() {
+        let json = r#"{"id":1,"description":"Old task","completed":true,"priority":"Medium","due_date":null,"category":null,"parent_id":null,"recurrence":null,"depends_on":[]}"#;
+        let task: Task = serde_json::from_str(json).unwrap();
+        assert_eq!(task.status, TaskStatus::Completed);
+
+        let json = r#"{"id":2,"description":"Old task","completed":false,"priority":"Medium","due_date":null,"category":null,"parent_id":null,"recurrence":null,"depends_on":[]}"#;
+        let task: Task = serde_json::from_str(json).unwrap();
+        assert_eq!(task.status, TaskStatus::Pending);
+    }
+
+    #[test]
+    fn test_status_roundtrip() {
+        let mut task = Task::new(1, "Task".to_string());
+        task.set_status(TaskStatus::Blocked);
+
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains(r#""status":"Blocked""#));
+        assert!(!json.contains(r#""completed""#));
+
+        let loaded: Task = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.status, TaskStatus::Blocked);
+    }
+
+    #[test]
+    fn test_set_status_keeps_completion_time() {
+        let mut task = Task::new(1, "Task".to_string());
+        task.set_status(TaskStatus::Completed);
+        let completed_at = task.completed_at;
+
+        task.set_status(TaskStatus::Completed);
+        assert_eq!(task.completed_at, completed_at);
+
+        task.set_status(TaskStatus::Cancelled);
+        assert!(task.completed_at.is_none());
+    }
+
+    #[test]
+    fn test_serialize_tags() {
+        let mut task = Task::new(1, "Task".to_string());
+        task.add_tag("work");
+        task.add_tag("urgent");
+
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains(r#""tags":["work","urgent"]"#));
+        assert!(!json.contains("category"));
+
+        let loaded: Task = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.tags, task.tags);
+    }
+
     #[test]
     fn test_status_symbol() {
         let mut task = Task::new(1, "Test task".to_string());
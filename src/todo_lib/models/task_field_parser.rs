@@ -0,0 +1,185 @@
+use crate::models::date_parser::parse_date;
+use crate::models::parse_error::ParseError;
+use crate::models::priority::Priority;
+use crate::models::recurrence::Recurrence;
+use chrono::NaiveDate;
+
+/// Parses a priority level such as `high`, `med` or `l`.
+///
+/// The command line (`priority`) and the TUI task form use this parser,
+/// so both accept the same values.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::models::task_field_parser::parse_priority;
+/// use todo_manager::models::priority::Priority;
+///
+/// assert_eq!(parse_priority("High"), Ok(Priority::High));
+/// assert_eq!(parse_priority("m"), Ok(Priority::Medium));
+/// assert!(parse_priority("urgent").is_err());
+/// ```
+pub fn parse_priority(input: &str) -> Result<Priority, ParseError> {
+    let value = input.trim().to_lowercase();
+    Priority::from_str(&value).ok_or(ParseError::InvalidValue {
+        field: "priority level".to_string(),
+        value,
+        allowed: "high/h, medium/med/m, or low/l".to_string(),
+    })
+}
+
+/// Parses a due date, where `none` clears it.
+///
+/// Dates are parsed with `parse_date` relative to `today`.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::models::task_field_parser::parse_due_date;
+/// use chrono::NaiveDate;
+///
+/// let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+/// assert_eq!(parse_due_date("tomorrow", today), Ok(NaiveDate::from_ymd_opt(2025, 1, 16)));
+/// assert_eq!(parse_due_date("none", today), Ok(None));
+/// ```
+pub fn parse_due_date(input: &str, today: NaiveDate) -> Result<Option<NaiveDate>, ParseError> {
+    if input.trim().eq_ignore_ascii_case("none") {
+        return Ok(None);
+    }
+    parse_date(input, today).map(Some)
+}
+
+/// Parses a category name, where `none` clears it.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::models::task_field_parser::parse_category;
+///
+/// assert_eq!(parse_category(" work "), Ok(Some("work".to_string())));
+/// assert_eq!(parse_category("None"), Ok(None));
+/// assert!(parse_category("").is_err());
+/// ```
+pub fn parse_category(input: &str) -> Result<Option<String>, ParseError> {
+    let category = input.trim();
+    if category.eq_ignore_ascii_case("none") {
+        Ok(None)
+    } else if category.is_empty() {
+        Err(ParseError::EmptyInput("Category name".to_string()))
+    } else {
+        Ok(Some(category.to_string()))
+    }
+}
+
+/// Parses a list of tags separated by commas or spaces.
+///
+/// A leading `+` is ignored and a tag that is given twice (compared
+/// case-insensitively) is kept once. Empty input gives no tags.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::models::task_field_parser::parse_tags;
+///
+/// assert_eq!(parse_tags("work, +urgent"), vec!["work", "urgent"]);
+/// assert_eq!(parse_tags("home Home"), vec!["home"]);
+/// assert!(parse_tags(" , ").is_empty());
+/// ```
+pub fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in input.split(|c: char| c == ',' || c.is_whitespace()) {
+        let tag = tag.trim_start_matches('+');
+        if !tag.is_empty() && !tags.iter().any(|other| other.eq_ignore_ascii_case(tag)) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+/// Parses a recurrence such as `daily` or `week`, where `none` clears it.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::models::task_field_parser::parse_recurrence;
+/// use todo_manager::models::recurrence::Recurrence;
+///
+/// assert_eq!(parse_recurrence("weekly"), Ok(Some(Recurrence::Weekly)));
+/// assert_eq!(parse_recurrence("none"), Ok(None));
+/// assert!(parse_recurrence("yearly").is_err());
+/// ```
+pub fn parse_recurrence(input: &str) -> Result<Option<Recurrence>, ParseError> {
+    let value = input.trim();
+    if value.eq_ignore_ascii_case("none") {
+        return Ok(None);
+    }
+    value
+        .parse::<Recurrence>()
+        .map(Some)
+        .map_err(|_| ParseError::InvalidFormat {
+            field: "recurrence".to_string(),
+            expected: "daily, weekly, monthly, or none".to_string(),
+            actual: value.to_string(),
+        })
+}
+
+/// Parses a task ID.
+///
+/// # Arguments
+///
+/// * `input` - The ID to parse
+/// * `name` - What the ID refers to, used in the error message (e.g. "parent ID")
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::models::task_field_parser::parse_task_id;
+///
+/// assert_eq!(parse_task_id("12", "parent ID"), Ok(12));
+/// assert!(parse_task_id("x", "parent ID").is_err());
+/// ```
+pub fn parse_task_id(input: &str, name: &str) -> Result<usize, ParseError> {
+    input.trim().parse::<usize>().map_err(|_| {
+        ParseError::InvalidId(format!(
+            "Invalid {} '{}'. Please provide a number.",
+            name,
+            input.trim()
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_priority_error_lists_allowed_values() {
+        assert_eq!(
+            parse_priority("Urgent").unwrap_err().message(),
+            "Invalid priority level 'urgent'. Allowed values: high/h, medium/med/m, or low/l"
+        );
+    }
+
+    #[test]
+    fn test_parse_due_date_uses_date_parser() {
+        let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+        assert_eq!(
+            parse_due_date("+2w", today),
+            Ok(NaiveDate::from_ymd_opt(2025, 1, 29))
+        );
+        assert!(matches!(
+            parse_due_date("someday", today),
+            Err(ParseError::InvalidDate(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_recurrence_accepts_short_forms() {
+        assert_eq!(parse_recurrence("d"), Ok(Some(Recurrence::Daily)));
+        assert_eq!(parse_recurrence("MONTH"), Ok(Some(Recurrence::Monthly)));
+        assert_eq!(
+            parse_recurrence("yearly").unwrap_err().message(),
+            "Invalid recurrence format. Expected: daily, weekly, monthly, or none, got: yearly"
+        );
+    }
+}
@@ -1,6 +1,7 @@
+use super::filter_expression::FilterExpression;
 use super::overdue_filter::OverdueFilter;
 use super::priority::Priority;
-use super::task_sort::{SortBy, SortOrder};
+use super::task_sort::{SortBy, SortKey, SortOrder};
 use super::task_status::TaskStatus;
 
 /// Filter options for listing tasks.
@@ -20,10 +21,19 @@ pub struct TaskFilter {
     /// Filter by category (None means all categories)
     pub category: Option<String>,
 
-    /// Sort tasks by this field (None means sort by ID)
-    pub sort_by: Option<SortBy>,
+    /// Tags a task must all have to be included
+    pub tags: Vec<String>,
 
-    /// Sort order (None means ascending)
+    /// Tags that exclude a task when it has any of them
+    pub excluded_tags: Vec<String>,
+
+    /// Boolean filter expression tasks must also match (None means no expression)
+    pub expression: Option<FilterExpression>,
+
+    /// Sort keys in order of precedence (empty means sort by ID)
+    pub sort_keys: Vec<SortKey>,
+
+    /// Overall sort order, reversing the complete ordering when descending (None means ascending)
     pub sort_order: Option<SortOrder>,
 }
 
@@ -70,7 +80,10 @@ impl TaskFilter {
             priority,
             overdue,
             category: None,
-            sort_by: None,
+            tags: Vec::new(),
+            excluded_tags: Vec::new(),
+            expression: None,
+            sort_keys: Vec::new(),
             sort_order: None,
         }
     }
@@ -104,12 +117,59 @@ impl TaskFilter {
         self
     }
 
-    /// Sets the sort field.
+    /// Adds a tag that tasks must have.
+    pub fn with_tag(mut self, tag: String) -> Self {
+        self.tags.push(tag);
+        self
+    }
+
+    /// Adds a tag that tasks must not have.
+    pub fn without_tag(mut self, tag: String) -> Self {
+        self.excluded_tags.push(tag);
+        self
+    }
+
+    /// Sets the boolean filter expression.
+    pub fn with_expression(mut self, expression: FilterExpression) -> Self {
+        self.expression = Some(expression);
+        self
+    }
+
+    /// Adds a sort field in its default direction.
     pub fn with_sort_by(mut self, sort_by: SortBy) -> Self {
-        self.sort_by = Some(sort_by);
+        self.sort_keys
+            .push(SortKey::new(sort_by, SortOrder::Ascending));
         self
     }
 
+    /// Adds a sort key.
+    pub fn with_sort_key(mut self, sort_key: SortKey) -> Self {
+        self.sort_keys.push(sort_key);
+        self
+    }
+
+    /// Returns whether the filter only changes the sort order and selects all tasks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::task_filter::TaskFilter;
+    /// use todo_manager::models::task_sort::SortBy;
+    /// use todo_manager::models::priority::Priority;
+    ///
+    /// assert!(TaskFilter::all().with_sort_by(SortBy::DueDate).is_sort_only());
+    /// assert!(!TaskFilter::all().with_priority(Priority::High).is_sort_only());
+    /// ```
+    pub fn is_sort_only(&self) -> bool {
+        self.status.is_none()
+            && self.priority.is_none()
+            && self.overdue == OverdueFilter::All
+            && self.category.is_none()
+            && self.tags.is_empty()
+            && self.excluded_tags.is_empty()
+            && self.expression.is_none()
+    }
+
     /// Sets the sort order.
     pub fn with_sort_order(mut self, sort_order: SortOrder) -> Self {
         self.sort_order = Some(sort_order);
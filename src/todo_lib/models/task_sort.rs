@@ -1,3 +1,5 @@
+use crate::models::task::Task;
+use crate::models::todo_list::TodoList;
 use std::cmp::Ordering;
 use std::str::FromStr;
 
@@ -14,6 +16,12 @@ pub enum SortBy {
     Category,
     /// Sort by completion status (pending first, completed last)
     Status,
+    /// Sort by description (alphabetically, case-insensitive)
+    Description,
+    /// Sort by subtask progress (least complete first, tasks without subtasks last)
+    Progress,
+    /// Sort by number of dependencies (fewest first)
+    Dependencies,
 }
 
 impl Default for SortBy {
@@ -33,8 +41,11 @@ impl FromStr for SortBy {
             "due" | "due-date" | "duedate" => Ok(SortBy::DueDate),
             "category" | "cat" => Ok(SortBy::Category),
             "status" => Ok(SortBy::Status),
+            "description" | "desc" | "name" => Ok(SortBy::Description),
+            "progress" | "subtasks" => Ok(SortBy::Progress),
+            "dependencies" | "deps" => Ok(SortBy::Dependencies),
             _ => Err(format!(
-                "Invalid sort option: '{}'. Valid options: id, priority, due, category, status",
+                "Invalid sort option: '{}'. Valid options: id, priority, due, category, status, description, progress, dependencies",
                 s
             )),
         }
@@ -67,6 +78,128 @@ impl SortOrder {
     }
 }
 
+/// A sort field together with its direction.
+///
+/// Written as `key` or `+key` for the field's default direction and `-key`
+/// for the reverse, e.g. `-priority` lists low priority tasks first.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::models::task_sort::{SortBy, SortKey, SortOrder};
+///
+/// let keys = SortKey::parse_list("due,-priority,id").unwrap();
+/// assert_eq!(keys[0], SortKey::new(SortBy::DueDate, SortOrder::Ascending));
+/// assert_eq!(keys[1], SortKey::new(SortBy::Priority, SortOrder::Descending));
+/// assert_eq!(keys[2], SortKey::new(SortBy::Id, SortOrder::Ascending));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortKey {
+    /// The field to compare
+    pub by: SortBy,
+    /// The direction of the comparison
+    pub order: SortOrder,
+}
+
+impl SortKey {
+    /// Creates a new sort key.
+    pub fn new(by: SortBy, order: SortOrder) -> Self {
+        Self { by, order }
+    }
+
+    /// Parses a comma-separated list of sort keys such as `due,-priority,id`.
+    pub fn parse_list(s: &str) -> Result<Vec<SortKey>, String> {
+        let mut keys: Vec<SortKey> = Vec::new();
+        for field in s.split(',') {
+            let key = SortKey::from_str(field)?;
+            if keys.iter().any(|other| other.by == key.by) {
+                return Err(format!(
+                    "Sort field '{}' is given more than once.",
+                    field.trim_start_matches(['+', '-'])
+                ));
+            }
+            keys.push(key);
+        }
+        Ok(keys)
+    }
+
+    /// Compares two tasks of `todo_list` by this key.
+    pub fn compare(&self, a: &Task, b: &Task, todo_list: &TodoList) -> Ordering {
+        let ordering = match self.by {
+            SortBy::Id => a.id.cmp(&b.id),
+            // High > Medium > Low (reverse natural order for priority)
+            SortBy::Priority => b.priority.cmp(&a.priority),
+            // Tasks with due dates come first, sorted by date
+            SortBy::DueDate => compare_present_first(a.due_date, b.due_date),
+            // Tasks with categories come first, sorted alphabetically
+            SortBy::Category => compare_present_first(a.get_category(), b.get_category()),
+            // Workflow order: open states first, then completed and cancelled
+            SortBy::Status => a.status.cmp(&b.status),
+            SortBy::Description => a
+                .description
+                .to_lowercase()
+                .cmp(&b.description.to_lowercase()),
+            SortBy::Progress => {
+                let progress = |task: &Task| {
+                    let total = todo_list.get_subtask_count(task.id);
+                    (total > 0).then(|| (todo_list.get_completed_subtask_count(task.id), total))
+                };
+                // Compare completed/total ratios without floating point
+                match (progress(a), progress(b)) {
+                    (Some((done_a, total_a)), Some((done_b, total_b))) => {
+                        (done_a * total_b).cmp(&(done_b * total_a))
+                    }
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                }
+            }
+            SortBy::Dependencies => a.depends_on.len().cmp(&b.depends_on.len()),
+        };
+
+        self.order.apply(ordering)
+    }
+}
+
+impl FromStr for SortKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some(field) = s.strip_prefix('-') {
+            Ok(SortKey::new(
+                SortBy::from_str(field)?,
+                SortOrder::Descending,
+            ))
+        } else {
+            let field = s.strip_prefix('+').unwrap_or(s);
+            Ok(SortKey::new(SortBy::from_str(field)?, SortOrder::Ascending))
+        }
+    }
+}
+
+/// Compares optional values so that present values come first, in ascending order.
+fn compare_present_first<T: Ord>(a: Option<T>, b: Option<T>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Compares two tasks by a list of sort keys.
+///
+/// Later keys only decide when all earlier keys are equal. Tasks that are
+/// equal for all keys are ordered by ID, so the result never depends on the
+/// order in which tasks were stored.
+pub fn compare_tasks(a: &Task, b: &Task, keys: &[SortKey], todo_list: &TodoList) -> Ordering {
+    keys.iter()
+        .map(|key| key.compare(a, b, todo_list))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.id.cmp(&b.id))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,6 +246,33 @@ mod tests {
         assert_eq!(SortBy::default(), SortBy::Id);
     }
 
+    #[test]
+    fn test_sort_by_from_str_new_fields() {
+        assert_eq!(SortBy::from_str("desc").unwrap(), SortBy::Description);
+        assert_eq!(SortBy::from_str("progress").unwrap(), SortBy::Progress);
+        assert_eq!(SortBy::from_str("deps").unwrap(), SortBy::Dependencies);
+    }
+
+    #[test]
+    fn test_sort_key_from_str() {
+        assert_eq!(
+            SortKey::from_str("-priority").unwrap(),
+            SortKey::new(SortBy::Priority, SortOrder::Descending)
+        );
+        assert_eq!(
+            SortKey::from_str("+due").unwrap(),
+            SortKey::new(SortBy::DueDate, SortOrder::Ascending)
+        );
+        assert!(SortKey::from_str("-").is_err());
+    }
+
+    #[test]
+    fn test_sort_key_parse_list_errors() {
+        assert!(SortKey::parse_list("due,,id").is_err());
+        assert!(SortKey::parse_list("due,-due").is_err());
+        assert!(SortKey::parse_list("due,weight").is_err());
+    }
+
     #[test]
     fn test_sort_order_default() {
         assert_eq!(SortOrder::default(), SortOrder::Ascending);
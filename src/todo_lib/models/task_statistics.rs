@@ -9,9 +9,17 @@ pub struct TaskStatistics {
     pub total: usize,
     /// Number of completed tasks
     pub completed: usize,
-    /// Number of pending tasks
+    /// Number of open tasks (neither completed nor cancelled)
     pub pending: usize,
-    /// Completion percentage (0.0 to 100.0)
+    /// Number of open tasks that are in progress
+    pub in_progress: usize,
+    /// Number of open tasks that are waiting
+    pub waiting: usize,
+    /// Number of open tasks that are blocked
+    pub blocked: usize,
+    /// Number of cancelled tasks
+    pub cancelled: usize,
+    /// Completion percentage (0.0 to 100.0), not counting cancelled tasks
     pub completion_percentage: f64,
     /// Number of high priority tasks
     pub high_priority: usize,
@@ -19,4 +27,6 @@ pub struct TaskStatistics {
     pub medium_priority: usize,
     /// Number of low priority tasks
     pub low_priority: usize,
+    /// Number of tasks per tag, sorted alphabetically by tag
+    pub tag_counts: Vec<(String, usize)>,
 }
@@ -1,11 +1,163 @@
-/// Task completion status for filtering.
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Workflow status of a task.
+///
+/// Every task is in exactly one of these states. `Completed` and `Cancelled`
+/// are the resolved states; all others are open.
+///
+/// When used as a filter, `Pending` matches every open task, while all other
+/// states only match tasks in exactly that state.
+///
+/// # Examples
 ///
-/// Represents whether a task is completed or still pending.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// ```
+/// use todo_manager::models::task_status::TaskStatus;
+///
+/// let status: TaskStatus = "in-progress".parse().unwrap();
+/// assert_eq!(status, TaskStatus::InProgress);
+/// assert!(!status.is_resolved());
+/// assert!(TaskStatus::Cancelled.is_resolved());
+/// ```
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
 pub enum TaskStatus {
-    /// Show only completed tasks
+    /// Not started yet
+    #[default]
+    Pending,
+
+    /// Currently being worked on
+    InProgress,
+
+    /// Waiting for someone or something else
+    Waiting,
+
+    /// Cannot continue until an obstacle is removed
+    Blocked,
+
+    /// Done
     Completed,
 
-    /// Show only pending (incomplete) tasks
-    Pending,
+    /// Will not be done
+    Cancelled,
+}
+
+impl TaskStatus {
+    /// Returns all statuses in workflow order.
+    pub fn all() -> [TaskStatus; 6] {
+        [
+            TaskStatus::Pending,
+            TaskStatus::InProgress,
+            TaskStatus::Waiting,
+            TaskStatus::Blocked,
+            TaskStatus::Completed,
+            TaskStatus::Cancelled,
+        ]
+    }
+
+    /// Returns whether the status is final (completed or cancelled).
+    pub fn is_resolved(self) -> bool {
+        matches!(self, TaskStatus::Completed | TaskStatus::Cancelled)
+    }
+
+    /// Checks whether a task with status `status` matches this status used as a filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::task_status::TaskStatus;
+    ///
+    /// assert!(TaskStatus::Pending.matches(TaskStatus::Blocked));
+    /// assert!(!TaskStatus::Pending.matches(TaskStatus::Cancelled));
+    /// assert!(TaskStatus::Waiting.matches(TaskStatus::Waiting));
+    /// assert!(!TaskStatus::Waiting.matches(TaskStatus::Pending));
+    /// ```
+    pub fn matches(self, status: TaskStatus) -> bool {
+        match self {
+            TaskStatus::Pending => !status.is_resolved(),
+            _ => self == status,
+        }
+    }
+
+    /// Returns the display name of the status.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaskStatus::Pending => "Pending",
+            TaskStatus::InProgress => "In Progress",
+            TaskStatus::Waiting => "Waiting",
+            TaskStatus::Blocked => "Blocked",
+            TaskStatus::Completed => "Completed",
+            TaskStatus::Cancelled => "Cancelled",
+        }
+    }
+
+    /// Returns the single-character symbol shown in the status checkbox.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            TaskStatus::Pending => " ",
+            TaskStatus::InProgress => "•",
+            TaskStatus::Waiting => "…",
+            TaskStatus::Blocked => "!",
+            TaskStatus::Completed => "✓",
+            TaskStatus::Cancelled => "✗",
+        }
+    }
+}
+
+impl FromStr for TaskStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" | "todo" | "open" => Ok(TaskStatus::Pending),
+            "in-progress" | "inprogress" | "progress" | "started" | "wip" => {
+                Ok(TaskStatus::InProgress)
+            }
+            "waiting" | "wait" => Ok(TaskStatus::Waiting),
+            "blocked" => Ok(TaskStatus::Blocked),
+            "completed" | "done" => Ok(TaskStatus::Completed),
+            "cancelled" | "canceled" | "cancel" => Ok(TaskStatus::Cancelled),
+            _ => Err(format!(
+                "Invalid status: '{}'. Valid options: pending, in-progress, waiting, blocked, completed, cancelled",
+                s
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(TaskStatus::from_str("todo").unwrap(), TaskStatus::Pending);
+        assert_eq!(TaskStatus::from_str("WIP").unwrap(), TaskStatus::InProgress);
+        assert_eq!(TaskStatus::from_str("wait").unwrap(), TaskStatus::Waiting);
+        assert_eq!(
+            TaskStatus::from_str("blocked").unwrap(),
+            TaskStatus::Blocked
+        );
+        assert_eq!(TaskStatus::from_str("done").unwrap(), TaskStatus::Completed);
+        assert_eq!(
+            TaskStatus::from_str("canceled").unwrap(),
+            TaskStatus::Cancelled
+        );
+        assert!(TaskStatus::from_str("later").is_err());
+    }
+
+    #[test]
+    fn test_is_resolved() {
+        let resolved: Vec<TaskStatus> = TaskStatus::all()
+            .into_iter()
+            .filter(|s| s.is_resolved())
+            .collect();
+        assert_eq!(resolved, vec![TaskStatus::Completed, TaskStatus::Cancelled]);
+    }
+
+    #[test]
+    fn test_default_is_pending() {
+        assert_eq!(TaskStatus::default(), TaskStatus::Pending);
+    }
 }
@@ -0,0 +1,181 @@
+use std::fmt;
+
+/// Represents errors returned by operations on tasks, projects and storage.
+///
+/// Each variant carries the data needed to explain the failure, so callers
+/// can report the exact cause without inspecting the list again.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::models::todo_error::TodoError;
+/// use todo_manager::models::todo_list::TodoList;
+/// use todo_manager::models::task::TaskWithoutId;
+///
+/// let mut list = TodoList::new();
+/// let id = list.add_task(TaskWithoutId::new("Task".to_string()));
+///
+/// assert_eq!(list.complete_task(42).unwrap_err(), TodoError::TaskNotFound(42));
+/// assert_eq!(
+///     list.add_task_dependency(id, id).unwrap_err().message(),
+///     "Task with ID 1 cannot depend on itself."
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TodoError {
+    /// No task with the given ID exists
+    TaskNotFound(usize),
+    /// The trash has no task with the given ID
+    TaskNotInTrash(usize),
+    /// The parent task for a new subtask does not exist
+    InvalidParent(usize),
+    /// A task was asked to depend on itself
+    SelfDependency(usize),
+    /// The dependency would close a cycle
+    CircularDependency {
+        task_id: usize,
+        depends_on_id: usize,
+    },
+    /// The new parent is the task itself or one of its subtasks
+    CircularParent { task_id: usize, parent_id: usize },
+    /// The task cannot be completed before the tasks it depends on
+    IncompleteDependencies {
+        task_id: usize,
+        dependencies: Vec<usize>,
+    },
+    /// A task description was empty or only whitespace
+    EmptyDescription,
+    /// No project with the given name exists
+    ProjectNotFound(String),
+    /// A project with the given name already exists
+    ProjectExists(String),
+    /// The project trash has no project with the given name
+    ProjectNotInTrash(String),
+    /// The current project cannot be deleted
+    CurrentProject(String),
+    /// No backup with the given number (1 is the newest) exists
+    BackupNotFound(usize),
+    /// The storage to convert to already holds data
+    StorageNotEmpty(String),
+    /// Reading or writing a file failed
+    Io(String),
+    /// Data could not be serialized or deserialized
+    Serialization(String),
+}
+
+impl TodoError {
+    /// Returns the error message.
+    pub fn message(&self) -> String {
+        match self {
+            TodoError::TaskNotFound(id) => format!("Task with ID {} not found.", id),
+            TodoError::TaskNotInTrash(id) => {
+                format!("No removed task with ID {} in the trash.", id)
+            }
+            TodoError::InvalidParent(id) => format!("Parent task with ID {} not found.", id),
+            TodoError::SelfDependency(id) => {
+                format!("Task with ID {} cannot depend on itself.", id)
+            }
+            TodoError::CircularDependency {
+                task_id,
+                depends_on_id,
+            } => format!(
+                "Making task {} depend on task {} would create a circular dependency.",
+                task_id, depends_on_id
+            ),
+            TodoError::CircularParent { task_id, parent_id } => format!(
+                "Task {} cannot become a subtask of task {}: it would be its own parent.",
+                task_id, parent_id
+            ),
+            TodoError::IncompleteDependencies {
+                task_id,
+                dependencies,
+            } => format!(
+                "Cannot complete task {}: it depends on incomplete task(s): {}",
+                task_id,
+                dependencies
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            TodoError::EmptyDescription => "Task description cannot be empty.".to_string(),
+            TodoError::ProjectNotFound(name) => format!("Project '{}' not found", name),
+            TodoError::ProjectExists(name) => format!("Project '{}' already exists", name),
+            TodoError::ProjectNotInTrash(name) => {
+                format!("No deleted project '{}' in the trash", name)
+            }
+            TodoError::CurrentProject(name) => format!(
+                "Cannot delete current project '{}'. Switch to another project first.",
+                name
+            ),
+            TodoError::BackupNotFound(number) => format!(
+                "Backup {} not found. Use 'restore-backup' to list the backups.",
+                number
+            ),
+            TodoError::StorageNotEmpty(location) => format!(
+                "{} already exists. Convert to a new file to avoid overwriting it.",
+                location
+            ),
+            TodoError::Io(msg) => msg.clone(),
+            TodoError::Serialization(msg) => msg.clone(),
+        }
+    }
+}
+
+impl fmt::Display for TodoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for TodoError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_matches_message() {
+        let err = TodoError::CircularDependency {
+            task_id: 1,
+            depends_on_id: 2,
+        };
+        assert_eq!(err.to_string(), err.message());
+        assert!(err.message().contains("circular dependency"));
+    }
+
+    #[test]
+    fn test_incomplete_dependencies_message() {
+        let err = TodoError::IncompleteDependencies {
+            task_id: 3,
+            dependencies: vec![1, 2],
+        };
+        assert_eq!(
+            err.message(),
+            "Cannot complete task 3: it depends on incomplete task(s): 1, 2"
+        );
+    }
+
+    #[test]
+    fn test_circular_parent_message() {
+        let err = TodoError::CircularParent {
+            task_id: 1,
+            parent_id: 4,
+        };
+        assert_eq!(
+            err.message(),
+            "Task 1 cannot become a subtask of task 4: it would be its own parent."
+        );
+    }
+
+    #[test]
+    fn test_project_messages() {
+        assert_eq!(
+            TodoError::ProjectExists("Work".to_string()).message(),
+            "Project 'Work' already exists"
+        );
+        assert!(TodoError::CurrentProject("default".to_string())
+            .message()
+            .contains("Switch to another project"));
+    }
+}
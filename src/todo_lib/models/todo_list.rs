@@ -2,9 +2,14 @@ use super::overdue_filter::OverdueFilter;
 use super::priority::Priority;
 use super::task::{Task, TaskWithoutId};
 use super::task_filter::TaskFilter;
+use super::task_sort::{compare_tasks, SortKey, SortOrder};
 use super::task_statistics::TaskStatistics;
 use super::task_status::TaskStatus;
+use super::todo_error::TodoError;
+use super::trash::TrashedTask;
+use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// A collection of tasks with methods to manage them.
 ///
@@ -29,6 +34,9 @@ pub struct TodoList {
     /// The next available ID to be assigned to a new task.
     /// This value is incremented each time a task is added.
     next_id: usize,
+    /// Removed tasks that can still be restored.
+    #[serde(default)]
+    trash: Vec<TrashedTask>,
 }
 
 impl TodoList {
@@ -47,6 +55,7 @@ impl TodoList {
         TodoList {
             tasks: Vec::new(),
             next_id: 1,
+            trash: Vec::new(),
         }
     }
 }
@@ -57,6 +66,7 @@ impl TodoList {
     /// Adds a task to the list.
     ///
     /// Creates a Task from the provided TaskWithoutId data and assigns it the next available ID.
+    /// The creation and modification timestamps of the new task are set to the current time.
     ///
     /// # Arguments
     ///
@@ -82,7 +92,11 @@ impl TodoList {
     /// assert_eq!(id2, 2);
     /// ```
     pub fn add_task(&mut self, new_task: TaskWithoutId) -> usize {
-        let task = new_task.to_task(self.next_id);
+        let mut task = new_task.to_task(self.next_id);
+        task.touch();
+        if task.is_completed() && task.completed_at.is_none() {
+            task.completed_at = task.updated_at;
+        }
         let task_id = task.id;
         self.tasks.push(task);
         self.next_id += 1;
@@ -98,37 +112,43 @@ impl TodoList {
     ///
     /// # Returns
     ///
-    /// * `Some(usize)` - The ID of the newly created subtask if parent exists
-    /// * `None` - If the parent task doesn't exist
+    /// * `Ok(usize)` - The ID of the newly created subtask if parent exists
+    /// * `Err(TodoError::InvalidParent)` - If the parent task doesn't exist
     ///
     /// # Examples
     ///
     /// ```
     /// use todo_manager::models::todo_list::TodoList;
+    /// use todo_manager::models::todo_error::TodoError;
     /// use todo_manager::models::task::TaskWithoutId;
     ///
     /// let mut list = TodoList::new();
     /// let parent_id = list.add_task(TaskWithoutId::new("Main task".to_string()));
     ///
     /// let subtask_id = list.add_subtask(parent_id, "Subtask 1".to_string());
-    /// assert!(subtask_id.is_some());
+    /// assert!(subtask_id.is_ok());
     ///
     /// let invalid = list.add_subtask(999, "Invalid".to_string());
-    /// assert!(invalid.is_none());
+    /// assert_eq!(invalid, Err(TodoError::InvalidParent(999)));
     /// ```
-    pub fn add_subtask(&mut self, parent_id: usize, description: String) -> Option<usize> {
+    pub fn add_subtask(
+        &mut self,
+        parent_id: usize,
+        description: String,
+    ) -> Result<usize, TodoError> {
         // Check if parent exists
         if !self.tasks.iter().any(|t| t.id == parent_id) {
-            return None;
+            return Err(TodoError::InvalidParent(parent_id));
         }
 
         let mut new_task = TaskWithoutId::new(description);
         new_task.parent_id = Some(parent_id);
-        let task = new_task.to_task(self.next_id);
+        let mut task = new_task.to_task(self.next_id);
+        task.touch();
         let task_id = task.id;
         self.tasks.push(task);
         self.next_id += 1;
-        Some(task_id)
+        Ok(task_id)
     }
 
     /// Returns a reference to the vector of all tasks.
@@ -167,8 +187,8 @@ impl TodoList {
     ///
     /// let mut list = TodoList::new();
     /// let parent_id = list.add_task(TaskWithoutId::new("Main task".to_string()));
-    /// list.add_subtask(parent_id, "Subtask 1".to_string());
-    /// list.add_subtask(parent_id, "Subtask 2".to_string());
+    /// list.add_subtask(parent_id, "Subtask 1".to_string()).unwrap();
+    /// list.add_subtask(parent_id, "Subtask 2".to_string()).unwrap();
     ///
     /// let subtasks = list.get_subtasks(parent_id);
     /// assert_eq!(subtasks.len(), 2);
@@ -198,8 +218,8 @@ impl TodoList {
     ///
     /// let mut list = TodoList::new();
     /// let parent_id = list.add_task(TaskWithoutId::new("Main task".to_string()));
-    /// list.add_subtask(parent_id, "Subtask 1".to_string());
-    /// list.add_subtask(parent_id, "Subtask 2".to_string());
+    /// list.add_subtask(parent_id, "Subtask 1".to_string()).unwrap();
+    /// list.add_subtask(parent_id, "Subtask 2".to_string()).unwrap();
     ///
     /// assert_eq!(list.get_subtask_count(parent_id), 2);
     /// ```
@@ -231,13 +251,13 @@ impl TodoList {
     /// let sub1 = list.add_subtask(parent_id, "Subtask 1".to_string()).unwrap();
     /// let sub2 = list.add_subtask(parent_id, "Subtask 2".to_string()).unwrap();
     ///
-    /// list.complete_task(sub1);
+    /// list.complete_task(sub1).unwrap();
     /// assert_eq!(list.get_completed_subtask_count(parent_id), 1);
     /// ```
     pub fn get_completed_subtask_count(&self, parent_id: usize) -> usize {
         self.tasks
             .iter()
-            .filter(|t| t.parent_id == Some(parent_id) && t.completed)
+            .filter(|t| t.parent_id == Some(parent_id) && t.is_completed())
             .count()
     }
 
@@ -280,7 +300,11 @@ impl TodoList {
         self.tasks.clear();
     }
 
-    /// Removes a task from the list by its ID.
+    /// Removes a task from the list by its ID and moves it to the trash.
+    ///
+    /// Subtasks of the task are removed with it, and dependency edges other
+    /// tasks had on the removed tasks are dropped. Both are remembered in the
+    /// trash entry so that [`TodoList::restore_task`] can put them back.
     ///
     /// # Arguments
     ///
@@ -288,7 +312,7 @@ impl TodoList {
     ///
     /// # Returns
     ///
-    /// `Some(Task)` containing the removed task if found, or `None` if no task with the given ID exists.
+    /// `Ok(Task)` containing the removed task if found, or `TodoError::TaskNotFound` if no task with the given ID exists.
     ///
     /// # Examples
     ///
@@ -300,29 +324,207 @@ impl TodoList {
     /// let id = list.add_task(TaskWithoutId::new("Remove this".to_string()));
     ///
     /// let removed = list.remove_task(id);
-    /// assert!(removed.is_some());
+    /// assert!(removed.is_ok());
     /// assert_eq!(removed.unwrap().description, "Remove this");
     /// ```
-    pub fn remove_task(&mut self, id: usize) -> Option<Task> {
-        if let Some(pos) = self.tasks.iter().position(|task| task.id == id) {
-            let removed_task = self.tasks.remove(pos);
+    pub fn remove_task(&mut self, id: usize) -> Result<Task, TodoError> {
+        let pos = self
+            .tasks
+            .iter()
+            .position(|task| task.id == id)
+            .ok_or(TodoError::TaskNotFound(id))?;
+        let removed_task = self.tasks.remove(pos);
+
+        // If the removed task is a parent, also remove all its subtasks
+        let (subtasks, remaining): (Vec<Task>, Vec<Task>) = std::mem::take(&mut self.tasks)
+            .into_iter()
+            .partition(|task| task.get_parent_id() == Some(id));
+        self.tasks = remaining;
+
+        // Drop dependency edges on the removed tasks
+        let removed_ids: Vec<usize> = std::iter::once(id)
+            .chain(subtasks.iter().map(|task| task.id))
+            .collect();
+        let mut dependents = Vec::new();
+        for task in &mut self.tasks {
+            for &removed_id in &removed_ids {
+                if task.has_dependency(removed_id) {
+                    task.remove_dependency(removed_id);
+                    dependents.push((task.id, removed_id));
+                }
+            }
+        }
 
-            // If the removed task is a parent, also remove all its subtasks
-            let subtask_ids: Vec<usize> = self
-                .tasks
-                .iter()
-                .filter(|task| task.get_parent_id() == Some(id))
-                .map(|task| task.id)
-                .collect();
+        self.trash.push(TrashedTask {
+            task: removed_task.clone(),
+            subtasks,
+            dependents,
+            deleted_at: Local::now(),
+        });
+        Ok(removed_task)
+    }
 
-            for subtask_id in subtask_ids {
-                self.tasks.retain(|task| task.id != subtask_id);
-            }
+    /// Returns the removed tasks that can still be restored, oldest first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::todo_list::TodoList;
+    /// use todo_manager::models::task::TaskWithoutId;
+    ///
+    /// let mut list = TodoList::new();
+    /// let id = list.add_task(TaskWithoutId::new("Remove this".to_string()));
+    /// list.remove_task(id).unwrap();
+    ///
+    /// assert_eq!(list.get_trash().len(), 1);
+    /// assert_eq!(list.get_trash()[0].task.id, id);
+    /// ```
+    pub fn get_trash(&self) -> &Vec<TrashedTask> {
+        &self.trash
+    }
 
-            Some(removed_task)
-        } else {
-            None
+    /// Returns the ID the next added task will get.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::todo_list::TodoList;
+    /// use todo_manager::models::task::TaskWithoutId;
+    ///
+    /// let mut list = TodoList::new();
+    /// list.add_task(TaskWithoutId::new("First".to_string()));
+    ///
+    /// assert_eq!(list.next_id(), 2);
+    /// ```
+    pub fn next_id(&self) -> usize {
+        self.next_id
+    }
+
+    /// Restores a removed task together with its subtasks from the trash.
+    ///
+    /// Original IDs are kept unless they have been reused in the meantime.
+    /// Parent links and dependency edges are restored where the other task
+    /// still exists and dropped otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The original ID of the removed task
+    ///
+    /// # Returns
+    ///
+    /// `Ok(&Task)` with the restored task, or `TodoError::TaskNotInTrash` if the trash has no task with that ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::todo_list::TodoList;
+    /// use todo_manager::models::task::TaskWithoutId;
+    ///
+    /// let mut list = TodoList::new();
+    /// let parent = list.add_task(TaskWithoutId::new("Parent".to_string()));
+    /// let child = list.add_subtask(parent, "Child".to_string()).unwrap();
+    /// list.remove_task(parent).unwrap();
+    /// assert!(list.is_empty());
+    ///
+    /// let restored = list.restore_task(parent).unwrap();
+    /// assert_eq!(restored.id, parent);
+    /// assert_eq!(list.get_tasks().len(), 2);
+    /// assert!(list.get_trash().is_empty());
+    /// ```
+    pub fn restore_task(&mut self, id: usize) -> Result<&Task, TodoError> {
+        let pos = self
+            .trash
+            .iter()
+            .position(|entry| entry.task.id == id)
+            .ok_or(TodoError::TaskNotInTrash(id))?;
+        let entry = self.trash.remove(pos);
+
+        let mut restored: Vec<Task> = std::iter::once(entry.task).chain(entry.subtasks).collect();
+
+        // Keep the original IDs unless they are taken
+        let mut id_map = HashMap::new();
+        for task in &mut restored {
+            let new_id = if self.tasks.iter().any(|t| t.id == task.id) {
+                self.next_id
+            } else {
+                task.id
+            };
+            self.next_id = self.next_id.max(new_id + 1);
+            id_map.insert(task.id, new_id);
+            task.id = new_id;
         }
+
+        // Re-link parents and dependencies that still exist
+        let existing_ids: Vec<usize> = self.tasks.iter().map(|t| t.id).collect();
+        let resolve = |old_id: usize| {
+            id_map
+                .get(&old_id)
+                .copied()
+                .or_else(|| existing_ids.contains(&old_id).then_some(old_id))
+        };
+        for task in &mut restored {
+            task.parent_id = task.parent_id.and_then(resolve);
+            task.depends_on = task.depends_on.iter().filter_map(|&d| resolve(d)).collect();
+            task.touch();
+        }
+        let restored_id = restored[0].id;
+        self.tasks.extend(restored);
+
+        for (dependent_id, removed_id) in entry.dependents {
+            if let (Some(task), Some(&new_id)) = (
+                self.tasks.iter_mut().find(|t| t.id == dependent_id),
+                id_map.get(&removed_id),
+            ) {
+                task.add_dependency(new_id);
+            }
+        }
+
+        self.tasks.sort_by_key(|task| task.id);
+        self.task(restored_id)
+    }
+
+    /// Permanently deletes all tasks in the trash.
+    ///
+    /// # Returns
+    ///
+    /// The number of trash entries that were deleted
+    pub fn empty_trash(&mut self) -> usize {
+        let count = self.trash.len();
+        self.trash.clear();
+        count
+    }
+
+    /// Permanently deletes trash entries older than the given number of days.
+    ///
+    /// # Arguments
+    ///
+    /// * `retention_days` - How many days removed tasks are kept
+    /// * `now` - The current time
+    ///
+    /// # Returns
+    ///
+    /// The number of trash entries that were deleted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::todo_list::TodoList;
+    /// use todo_manager::models::task::TaskWithoutId;
+    /// use chrono::{Duration, Local};
+    ///
+    /// let mut list = TodoList::new();
+    /// let id = list.add_task(TaskWithoutId::new("Old".to_string()));
+    /// list.remove_task(id).unwrap();
+    ///
+    /// assert_eq!(list.purge_trash(30, Local::now()), 0);
+    /// assert_eq!(list.purge_trash(30, Local::now() + Duration::days(31)), 1);
+    /// assert!(list.get_trash().is_empty());
+    /// ```
+    pub fn purge_trash(&mut self, retention_days: u32, now: DateTime<Local>) -> usize {
+        let count = self.trash.len();
+        self.trash
+            .retain(|entry| !entry.is_expired(retention_days, now));
+        count - self.trash.len()
     }
 
     /// Toggles the completion status of a task by its ID.
@@ -333,7 +535,7 @@ impl TodoList {
     ///
     /// # Returns
     ///
-    /// `Some(&Task)` containing a reference to the modified task if found, or `None` if no task with the given ID exists.
+    /// `Ok(&Task)` containing a reference to the modified task if found, or `TodoError::TaskNotFound` if no task with the given ID exists.
     ///
     /// # Examples
     ///
@@ -344,17 +546,15 @@ impl TodoList {
     /// let mut list = TodoList::new();
     /// let id = list.add_task(TaskWithoutId::new("Toggle me".to_string()));
     ///
-    /// list.toggle_task(id);
+    /// list.toggle_task(id).unwrap();
     /// let task = list.get_tasks().iter().find(|t| t.id == id).unwrap();
     /// assert!(task.is_completed());
     /// ```
-    pub fn toggle_task(&mut self, id: usize) -> Option<&Task> {
-        if let Some(task) = self.tasks.iter_mut().find(|task| task.id == id) {
-            task.toggle_completion();
-            Some(task)
-        } else {
-            None
-        }
+    pub fn toggle_task(&mut self, id: usize) -> Result<&Task, TodoError> {
+        let task = self.task_mut(id)?;
+        task.toggle_completion();
+        task.touch();
+        Ok(task)
     }
 
     /// Returns a vector of references to all completed tasks.
@@ -369,7 +569,7 @@ impl TodoList {
     /// let id1 = list.add_task(TaskWithoutId::new("Completed task".to_string()));
     /// let id2 = list.add_task(TaskWithoutId::new("Pending task".to_string()));
     ///
-    /// list.toggle_task(id1);
+    /// list.toggle_task(id1).unwrap();
     /// let completed = list.get_completed_tasks();
     /// assert_eq!(completed.len(), 1);
     /// ```
@@ -380,7 +580,9 @@ impl TodoList {
             .collect()
     }
 
-    /// Returns a vector of references to all pending (incomplete) tasks.
+    /// Returns a vector of references to all pending (open) tasks.
+    ///
+    /// A task is pending as long as it is neither completed nor cancelled.
     ///
     /// # Examples
     ///
@@ -392,14 +594,14 @@ impl TodoList {
     /// let id1 = list.add_task(TaskWithoutId::new("Completed task".to_string()));
     /// let id2 = list.add_task(TaskWithoutId::new("Pending task".to_string()));
     ///
-    /// list.toggle_task(id1);
+    /// list.toggle_task(id1).unwrap();
     /// let pending = list.get_pending_tasks();
     /// assert_eq!(pending.len(), 1);
     /// ```
     pub fn get_pending_tasks(&self) -> Vec<&Task> {
         self.tasks
             .iter()
-            .filter(|task| !task.is_completed())
+            .filter(|task| !task.is_resolved())
             .collect()
     }
 
@@ -420,7 +622,7 @@ impl TodoList {
     /// let id1 = list.add_task(TaskWithoutId::new("High priority task".to_string()));
     /// let id2 = list.add_task(TaskWithoutId::new("Low priority task".to_string()));
     ///
-    /// list.set_task_priority(id1, Priority::High);
+    /// list.set_task_priority(id1, Priority::High).unwrap();
     /// let high_tasks = list.get_tasks_by_priority(Priority::High);
     /// assert_eq!(high_tasks.len(), 1);
     /// ```
@@ -452,7 +654,7 @@ impl TodoList {
     ///
     /// let mut list = TodoList::new();
     /// let id = list.add_task(TaskWithoutId::new("High priority task".to_string()));
-    /// list.set_task_priority(id, Priority::High);
+    /// list.set_task_priority(id, Priority::High).unwrap();
     ///
     /// let filter = TaskFilter::all().with_status(TaskStatus::Pending).with_priority(Priority::High);
     /// let tasks = list.get_filtered_tasks(&filter);
@@ -466,8 +668,7 @@ impl TodoList {
             .iter()
             .filter(|task| {
                 let status_matches = match filter.status {
-                    Some(TaskStatus::Completed) => task.is_completed(),
-                    Some(TaskStatus::Pending) => !task.is_completed(),
+                    Some(status) => status.matches(task.status),
                     None => true,
                 };
 
@@ -483,66 +684,67 @@ impl TodoList {
                 };
 
                 let category_matches = match &filter.category {
-                    Some(category) => task
-                        .category
-                        .as_ref()
-                        .map(|c| c == category)
-                        .unwrap_or(false),
+                    Some(category) => task.has_tag(category),
                     None => true,
                 };
 
-                status_matches && priority_matches && overdue_matches && category_matches
-            })
-            .collect();
-
-        // Apply sorting if specified
-        use crate::models::task_sort::{SortBy, SortOrder};
-        let sort_by = filter.sort_by.unwrap_or(SortBy::Id);
-        let sort_order = filter.sort_order.unwrap_or(SortOrder::Ascending);
+                let tags_match = filter.tags.iter().all(|tag| task.has_tag(tag))
+                    && !filter.excluded_tags.iter().any(|tag| task.has_tag(tag));
 
-        tasks.sort_by(|a, b| {
-            use std::cmp::Ordering;
+                let expression_matches = filter
+                    .expression
+                    .as_ref()
+                    .is_none_or(|expression| expression.matches(task, self, today));
 
-            let ordering = match sort_by {
-                SortBy::Id => a.id.cmp(&b.id),
-                SortBy::Priority => {
-                    // High > Medium > Low (reverse natural order for priority)
-                    b.priority.cmp(&a.priority)
-                }
-                SortBy::DueDate => {
-                    // Tasks with due dates come first, sorted by date
-                    // Tasks without due dates come last
-                    match (&a.due_date, &b.due_date) {
-                        (Some(date_a), Some(date_b)) => date_a.cmp(date_b),
-                        (Some(_), None) => Ordering::Less,
-                        (None, Some(_)) => Ordering::Greater,
-                        (None, None) => a.id.cmp(&b.id), // Secondary sort by ID
-                    }
-                }
-                SortBy::Category => {
-                    // Tasks with categories come first, sorted alphabetically
-                    // Tasks without categories come last
-                    match (&a.category, &b.category) {
-                        (Some(cat_a), Some(cat_b)) => cat_a.cmp(cat_b),
-                        (Some(_), None) => Ordering::Less,
-                        (None, Some(_)) => Ordering::Greater,
-                        (None, None) => a.id.cmp(&b.id), // Secondary sort by ID
-                    }
-                }
-                SortBy::Status => {
-                    // Pending tasks first, then completed
-                    let status_a = if a.is_completed() { 1 } else { 0 };
-                    let status_b = if b.is_completed() { 1 } else { 0 };
-                    status_a.cmp(&status_b)
-                }
-            };
+                status_matches
+                    && priority_matches
+                    && overdue_matches
+                    && category_matches
+                    && tags_match
+                    && expression_matches
+            })
+            .collect();
 
-            sort_order.apply(ordering)
-        });
+        self.sort_tasks(
+            &mut tasks,
+            &filter.sort_keys,
+            filter.sort_order.unwrap_or_default(),
+        );
 
         tasks
     }
 
+    /// Sorts tasks of this list by a list of sort keys.
+    ///
+    /// Ties are broken by ID. A descending `order` reverses the complete
+    /// ordering, including the ID tie-break.
+    ///
+    /// # Arguments
+    ///
+    /// * `tasks` - The tasks to sort (must belong to this list)
+    /// * `keys` - Sort keys in order of precedence
+    /// * `order` - The overall sort order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::todo_list::TodoList;
+    /// use todo_manager::models::task::TaskWithoutId;
+    /// use todo_manager::models::task_sort::{SortKey, SortOrder};
+    ///
+    /// let mut list = TodoList::new();
+    /// list.add_task(TaskWithoutId::new("Buy milk".to_string()));
+    /// list.add_task(TaskWithoutId::new("Answer mail".to_string()));
+    ///
+    /// let mut tasks: Vec<_> = list.get_tasks().iter().collect();
+    /// let keys = SortKey::parse_list("description").unwrap();
+    /// list.sort_tasks(&mut tasks, &keys, SortOrder::Ascending);
+    /// assert_eq!(tasks[0].description, "Answer mail");
+    /// ```
+    pub fn sort_tasks(&self, tasks: &mut [&Task], keys: &[SortKey], order: SortOrder) {
+        tasks.sort_by(|a, b| order.apply(compare_tasks(a, b, keys, self)));
+    }
+
     /// Sets the priority of a task by its ID.
     ///
     /// # Arguments
@@ -552,7 +754,7 @@ impl TodoList {
     ///
     /// # Returns
     ///
-    /// `Some(&Task)` containing a reference to the task if found, or `None` if no task with the given ID exists.
+    /// `Ok(&Task)` containing a reference to the task if found, or `TodoError::TaskNotFound` if no task with the given ID exists.
     ///
     /// # Examples
     ///
@@ -565,15 +767,14 @@ impl TodoList {
     /// let id = list.add_task(TaskWithoutId::new("Important task".to_string()));
     ///
     /// let task = list.set_task_priority(id, Priority::High);
-    /// assert!(task.is_some());
+    /// assert!(task.is_ok());
     /// assert_eq!(task.unwrap().priority, Priority::High);
     /// ```
-    pub fn set_task_priority(&mut self, id: usize, priority: Priority) -> Option<&Task> {
-        if let Some(task) = self.tasks.iter_mut().find(|t| t.id == id) {
-            task.set_priority(priority);
-            return self.tasks.iter().find(|t| t.id == id);
-        }
-        None
+    pub fn set_task_priority(&mut self, id: usize, priority: Priority) -> Result<&Task, TodoError> {
+        let task = self.task_mut(id)?;
+        task.set_priority(priority);
+        task.touch();
+        Ok(task)
     }
 
     /// Searches for tasks containing the given keyword in their description.
@@ -621,8 +822,8 @@ impl TodoList {
     ///
     /// # Returns
     ///
-    /// `Some(&Task)` containing a reference to the edited task if found,
-    /// or `None` if no task with the given ID exists.
+    /// `Ok(&Task)` containing a reference to the edited task if found,
+    /// or `TodoError::TaskNotFound` if no task with the given ID exists.
     ///
     /// # Examples
     ///
@@ -633,16 +834,14 @@ impl TodoList {
     /// let mut list = TodoList::new();
     /// let id = list.add_task(TaskWithoutId::new("Old description".to_string()));
     ///
-    /// list.edit_task(id, "New description".to_string());
+    /// list.edit_task(id, "New description".to_string()).unwrap();
     /// assert_eq!(list.get_tasks()[0].description, "New description");
     /// ```
-    pub fn edit_task(&mut self, id: usize, new_description: String) -> Option<&Task> {
-        if let Some(task) = self.tasks.iter_mut().find(|task| task.id == id) {
-            task.description = new_description;
-            Some(task)
-        } else {
-            None
-        }
+    pub fn edit_task(&mut self, id: usize, new_description: String) -> Result<&Task, TodoError> {
+        let task = self.task_mut(id)?;
+        task.description = new_description;
+        task.touch();
+        Ok(task)
     }
 
     /// Sets the due date for a task by its ID.
@@ -654,7 +853,7 @@ impl TodoList {
     ///
     /// # Returns
     ///
-    /// `Some(&Task)` containing a reference to the updated task if found, or `None` if no task with the given ID exists.
+    /// `Ok(&Task)` containing a reference to the updated task if found, or `TodoError::TaskNotFound` if no task with the given ID exists.
     ///
     /// # Examples
     ///
@@ -667,23 +866,21 @@ impl TodoList {
     /// let id = list.add_task(TaskWithoutId::new("Submit report".to_string()));
     /// let due = NaiveDate::from_ymd_opt(2025, 12, 31).unwrap();
     ///
-    /// list.set_due_date(id, Some(due));
+    /// list.set_due_date(id, Some(due)).unwrap();
     /// assert_eq!(list.get_tasks()[0].due_date, Some(due));
     ///
-    /// list.set_due_date(id, None);
+    /// list.set_due_date(id, None).unwrap();
     /// assert_eq!(list.get_tasks()[0].due_date, None);
     /// ```
     pub fn set_due_date(
         &mut self,
         id: usize,
         due_date: Option<chrono::NaiveDate>,
-    ) -> Option<&Task> {
-        if let Some(task) = self.tasks.iter_mut().find(|task| task.id == id) {
-            task.set_due_date(due_date);
-            Some(task)
-        } else {
-            None
-        }
+    ) -> Result<&Task, TodoError> {
+        let task = self.task_mut(id)?;
+        task.set_due_date(due_date);
+        task.touch();
+        Ok(task)
     }
 
     /// Sets the category of a task by its ID.
@@ -695,7 +892,7 @@ impl TodoList {
     ///
     /// # Returns
     ///
-    /// `Some(&Task)` containing a reference to the updated task if found, or `None` if no task with the given ID exists.
+    /// `Ok(&Task)` containing a reference to the updated task if found, or `TodoError::TaskNotFound` if no task with the given ID exists.
     ///
     /// # Examples
     ///
@@ -706,19 +903,21 @@ impl TodoList {
     /// let mut list = TodoList::new();
     /// let id = list.add_task(TaskWithoutId::new("Write code".to_string()));
     ///
-    /// list.set_task_category(id, Some("work".to_string()));
-    /// assert_eq!(list.get_tasks()[0].category, Some("work".to_string()));
+    /// list.set_task_category(id, Some("work".to_string())).unwrap();
+    /// assert_eq!(list.get_tasks()[0].tags, vec!["work".to_string()]);
     ///
-    /// list.set_task_category(id, None);
-    /// assert_eq!(list.get_tasks()[0].category, None);
+    /// list.set_task_category(id, None).unwrap();
+    /// assert!(list.get_tasks()[0].tags.is_empty());
     /// ```
-    pub fn set_task_category(&mut self, id: usize, category: Option<String>) -> Option<&Task> {
-        if let Some(task) = self.tasks.iter_mut().find(|task| task.id == id) {
-            task.set_category(category);
-            Some(task)
-        } else {
-            None
-        }
+    pub fn set_task_category(
+        &mut self,
+        id: usize,
+        category: Option<String>,
+    ) -> Result<&Task, TodoError> {
+        let task = self.task_mut(id)?;
+        task.set_category(category);
+        task.touch();
+        Ok(task)
     }
 
     /// Sets the recurrence pattern of a task with the given ID.
@@ -730,7 +929,7 @@ impl TodoList {
     ///
     /// # Returns
     ///
-    /// A reference to the updated task, or `None` if the task doesn't exist
+    /// A reference to the updated task, or `TodoError::TaskNotFound` if the task doesn't exist
     ///
     /// # Examples
     ///
@@ -742,23 +941,21 @@ impl TodoList {
     /// let mut list = TodoList::new();
     /// let id = list.add_task(TaskWithoutId::new("Daily task".to_string()));
     ///
-    /// list.set_task_recurrence(id, Some(Recurrence::Daily));
+    /// list.set_task_recurrence(id, Some(Recurrence::Daily)).unwrap();
     /// assert_eq!(list.get_tasks()[0].get_recurrence(), Some(Recurrence::Daily));
     ///
-    /// list.set_task_recurrence(id, None);
+    /// list.set_task_recurrence(id, None).unwrap();
     /// assert_eq!(list.get_tasks()[0].get_recurrence(), None);
     /// ```
     pub fn set_task_recurrence(
         &mut self,
         id: usize,
         recurrence: Option<crate::models::recurrence::Recurrence>,
-    ) -> Option<&Task> {
-        if let Some(task) = self.tasks.iter_mut().find(|task| task.id == id) {
-            task.set_recurrence(recurrence);
-            Some(task)
-        } else {
-            None
-        }
+    ) -> Result<&Task, TodoError> {
+        let task = self.task_mut(id)?;
+        task.set_recurrence(recurrence);
+        task.touch();
+        Ok(task)
     }
 
     /// Sets the recurrence pattern for multiple tasks by their IDs.
@@ -796,7 +993,7 @@ impl TodoList {
         let mut not_found = Vec::new();
 
         for &id in ids {
-            if self.set_task_recurrence(id, recurrence).is_some() {
+            if self.set_task_recurrence(id, recurrence).is_ok() {
                 updated_count += 1;
             } else {
                 not_found.push(id);
@@ -806,11 +1003,13 @@ impl TodoList {
         (updated_count, not_found)
     }
 
-    /// Gets all unique categories from all tasks.
+    /// Gets all unique categories (tags) from all tasks.
+    ///
+    /// Every tag of a task counts as a category.
     ///
     /// # Returns
     ///
-    /// A vector of unique category names sorted alphabetically.
+    /// A vector of unique tag names sorted alphabetically.
     ///
     /// # Examples
     ///
@@ -823,18 +1022,20 @@ impl TodoList {
     /// let id2 = list.add_task(TaskWithoutId::new("Task 2".to_string()));
     /// let id3 = list.add_task(TaskWithoutId::new("Task 3".to_string()));
     ///
-    /// list.set_task_category(id1, Some("work".to_string()));
-    /// list.set_task_category(id2, Some("personal".to_string()));
-    /// list.set_task_category(id3, Some("work".to_string()));
+    /// list.set_task_category(id1, Some("work".to_string())).unwrap();
+    /// list.set_task_category(id2, Some("personal".to_string())).unwrap();
+    /// list.set_task_category(id3, Some("work".to_string())).unwrap();
+    ///
+    /// list.add_task_tags(id1, &["urgent".to_string()]).unwrap();
     ///
     /// let categories = list.get_all_categories();
-    /// assert_eq!(categories, vec!["personal", "work"]);
+    /// assert_eq!(categories, vec!["personal", "urgent", "work"]);
     /// ```
     pub fn get_all_categories(&self) -> Vec<String> {
         let mut categories: Vec<String> = self
             .tasks
             .iter()
-            .filter_map(|task| task.category.clone())
+            .flat_map(|task| task.tags.iter().cloned())
             .collect();
 
         // Remove duplicates and sort
@@ -855,10 +1056,10 @@ impl TodoList {
     ///
     /// # Returns
     ///
-    /// `Some(())` if the dependency was added successfully, or `None` if:
-    /// - Either task doesn't exist
-    /// - The dependency would create a circular dependency
-    /// - The task is trying to depend on itself
+    /// `Ok(())` if the dependency was added successfully, or an error naming the cause:
+    /// - `TodoError::SelfDependency` if the task is trying to depend on itself
+    /// - `TodoError::TaskNotFound` if either task doesn't exist
+    /// - `TodoError::CircularDependency` if the dependency would create a cycle
     ///
     /// # Examples
     ///
@@ -871,33 +1072,35 @@ impl TodoList {
     /// let id2 = list.add_task(TaskWithoutId::new("Task 2".to_string()));
     ///
     /// let result = list.add_task_dependency(id2, id1);
-    /// assert!(result.is_some());
+    /// assert!(result.is_ok());
     /// ```
-    pub fn add_task_dependency(&mut self, task_id: usize, depends_on_id: usize) -> Option<()> {
+    pub fn add_task_dependency(
+        &mut self,
+        task_id: usize,
+        depends_on_id: usize,
+    ) -> Result<(), TodoError> {
         // Can't depend on itself
         if task_id == depends_on_id {
-            return None;
+            return Err(TodoError::SelfDependency(task_id));
         }
 
         // Check both tasks exist
-        if !self.tasks.iter().any(|t| t.id == task_id)
-            || !self.tasks.iter().any(|t| t.id == depends_on_id)
-        {
-            return None;
-        }
+        self.task(task_id)?;
+        self.task(depends_on_id)?;
 
         // Check for circular dependency
         if self.would_create_circular_dependency(task_id, depends_on_id) {
-            return None;
+            return Err(TodoError::CircularDependency {
+                task_id,
+                depends_on_id,
+            });
         }
 
         // Add the dependency
-        if let Some(task) = self.tasks.iter_mut().find(|t| t.id == task_id) {
-            task.add_dependency(depends_on_id);
-            Some(())
-        } else {
-            None
-        }
+        let task = self.task_mut(task_id)?;
+        task.add_dependency(depends_on_id);
+        task.touch();
+        Ok(())
     }
 
     /// Removes a dependency relationship between two tasks.
@@ -909,7 +1112,7 @@ impl TodoList {
     ///
     /// # Returns
     ///
-    /// `Some(())` if the dependency was removed successfully, or `None` if the task doesn't exist.
+    /// `Ok(())` if the dependency was removed successfully, or `TodoError::TaskNotFound` if the task doesn't exist.
     ///
     /// # Examples
     ///
@@ -921,17 +1124,108 @@ impl TodoList {
     /// let id1 = list.add_task(TaskWithoutId::new("Task 1".to_string()));
     /// let id2 = list.add_task(TaskWithoutId::new("Task 2".to_string()));
     ///
-    /// list.add_task_dependency(id2, id1);
+    /// list.add_task_dependency(id2, id1).unwrap();
     /// let result = list.remove_task_dependency(id2, id1);
-    /// assert!(result.is_some());
+    /// assert!(result.is_ok());
     /// ```
-    pub fn remove_task_dependency(&mut self, task_id: usize, depends_on_id: usize) -> Option<()> {
-        if let Some(task) = self.tasks.iter_mut().find(|t| t.id == task_id) {
-            task.remove_dependency(depends_on_id);
-            Some(())
-        } else {
-            None
+    pub fn remove_task_dependency(
+        &mut self,
+        task_id: usize,
+        depends_on_id: usize,
+    ) -> Result<(), TodoError> {
+        let task = self.task_mut(task_id)?;
+        task.remove_dependency(depends_on_id);
+        task.touch();
+        Ok(())
+    }
+
+    /// Makes a task a subtask of another task, or a top-level task again.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the task to move
+    /// * `parent_id` - The ID of the new parent (or None for a top-level task)
+    ///
+    /// # Returns
+    ///
+    /// A reference to the updated task, or an error naming the cause:
+    /// - `TodoError::TaskNotFound` if the task doesn't exist
+    /// - `TodoError::InvalidParent` if the parent doesn't exist
+    /// - `TodoError::CircularParent` if the parent is the task itself or one of its subtasks
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::todo_list::TodoList;
+    /// use todo_manager::models::task::TaskWithoutId;
+    ///
+    /// let mut list = TodoList::new();
+    /// let parent = list.add_task(TaskWithoutId::new("Parent".to_string()));
+    /// let child = list.add_task(TaskWithoutId::new("Child".to_string()));
+    ///
+    /// list.set_task_parent(child, Some(parent)).unwrap();
+    /// assert_eq!(list.get_subtasks(parent).len(), 1);
+    /// assert!(list.set_task_parent(parent, Some(child)).is_err());
+    ///
+    /// list.set_task_parent(child, None).unwrap();
+    /// assert!(list.get_subtasks(parent).is_empty());
+    /// ```
+    pub fn set_task_parent(
+        &mut self,
+        id: usize,
+        parent_id: Option<usize>,
+    ) -> Result<&Task, TodoError> {
+        self.task(id)?;
+
+        if let Some(parent_id) = parent_id {
+            if self.task(parent_id).is_err() {
+                return Err(TodoError::InvalidParent(parent_id));
+            }
+            if self.is_descendant_or_self(parent_id, id) {
+                return Err(TodoError::CircularParent {
+                    task_id: id,
+                    parent_id,
+                });
+            }
+        }
+
+        let task = self.task_mut(id)?;
+        match parent_id {
+            Some(parent_id) => task.set_parent_id(parent_id),
+            None => task.clear_parent(),
+        }
+        task.touch();
+        Ok(task)
+    }
+
+    /// Checks if `task_id` is `ancestor_id` or one of its (nested) subtasks.
+    fn is_descendant_or_self(&self, task_id: usize, ancestor_id: usize) -> bool {
+        let mut current = Some(task_id);
+        // Stop after visiting every task once in case the stored parents form a cycle
+        for _ in 0..=self.tasks.len() {
+            match current {
+                Some(id) if id == ancestor_id => return true,
+                Some(id) => current = self.task(id).ok().and_then(|task| task.parent_id),
+                None => return false,
+            }
         }
+        false
+    }
+
+    /// Returns the task with the given ID.
+    fn task(&self, id: usize) -> Result<&Task, TodoError> {
+        self.tasks
+            .iter()
+            .find(|task| task.id == id)
+            .ok_or(TodoError::TaskNotFound(id))
+    }
+
+    /// Returns the task with the given ID for modification.
+    fn task_mut(&mut self, id: usize) -> Result<&mut Task, TodoError> {
+        self.tasks
+            .iter_mut()
+            .find(|task| task.id == id)
+            .ok_or(TodoError::TaskNotFound(id))
     }
 
     /// Checks if adding a dependency would create a circular dependency chain.
@@ -988,6 +1282,8 @@ impl TodoList {
 
     /// Checks if all dependencies of a task are completed.
     ///
+    /// Cancelled dependencies count as resolved and do not block the task.
+    ///
     /// # Arguments
     ///
     /// * `task_id` - The ID of the task to check
@@ -1007,10 +1303,10 @@ impl TodoList {
     /// let id1 = list.add_task(TaskWithoutId::new("Task 1".to_string()));
     /// let id2 = list.add_task(TaskWithoutId::new("Task 2".to_string()));
     ///
-    /// list.add_task_dependency(id2, id1);
+    /// list.add_task_dependency(id2, id1).unwrap();
     /// assert!(!list.are_dependencies_completed(id2));
     ///
-    /// list.complete_task(id1);
+    /// list.complete_task(id1).unwrap();
     /// assert!(list.are_dependencies_completed(id2));
     /// ```
     pub fn are_dependencies_completed(&self, task_id: usize) -> bool {
@@ -1019,10 +1315,10 @@ impl TodoList {
             None => return false,
         };
 
-        // Check if all dependencies are completed
+        // Check if all dependencies are resolved (completed or cancelled)
         for &dep_id in task.get_dependencies() {
             if let Some(dep_task) = self.tasks.iter().find(|t| t.id == dep_id) {
-                if !dep_task.is_completed() {
+                if !dep_task.is_resolved() {
                     return false;
                 }
             } else {
@@ -1042,7 +1338,7 @@ impl TodoList {
     ///
     /// # Returns
     ///
-    /// A vector of task IDs that are dependencies of this task and are not completed.
+    /// A vector of task IDs that are dependencies of this task and are neither completed nor cancelled.
     /// Returns an empty vector if the task doesn't exist or has no incomplete dependencies.
     ///
     /// # Examples
@@ -1056,13 +1352,13 @@ impl TodoList {
     /// let id2 = list.add_task(TaskWithoutId::new("Task 2".to_string()));
     /// let id3 = list.add_task(TaskWithoutId::new("Task 3".to_string()));
     ///
-    /// list.add_task_dependency(id3, id1);
-    /// list.add_task_dependency(id3, id2);
+    /// list.add_task_dependency(id3, id1).unwrap();
+    /// list.add_task_dependency(id3, id2).unwrap();
     ///
     /// let incomplete = list.get_incomplete_dependencies(id3);
     /// assert_eq!(incomplete.len(), 2);
     ///
-    /// list.complete_task(id1);
+    /// list.complete_task(id1).unwrap();
     /// let incomplete = list.get_incomplete_dependencies(id3);
     /// assert_eq!(incomplete.len(), 1);
     /// assert_eq!(incomplete[0], id2);
@@ -1078,7 +1374,7 @@ impl TodoList {
         // Check each dependency
         for &dep_id in task.get_dependencies() {
             if let Some(dep_task) = self.tasks.iter().find(|t| t.id == dep_id) {
-                if !dep_task.is_completed() {
+                if !dep_task.is_resolved() {
                     incomplete.push(dep_id);
                 }
             } else {
@@ -1114,8 +1410,8 @@ impl TodoList {
     /// let id2 = list.add_task(TaskWithoutId::new("Task 2".to_string()));
     /// let id3 = list.add_task(TaskWithoutId::new("Task 3".to_string()));
     ///
-    /// list.add_task_dependency(id2, id1);
-    /// list.add_task_dependency(id3, id1);
+    /// list.add_task_dependency(id2, id1).unwrap();
+    /// list.add_task_dependency(id3, id1).unwrap();
     ///
     /// let dependents = list.get_dependent_tasks(id1);
     /// assert_eq!(dependents.len(), 2);
@@ -1140,7 +1436,7 @@ impl TodoList {
     ///
     /// # Returns
     ///
-    /// `Some(&Task)` containing a reference to the task if found, or `None` if no task with the given ID exists.
+    /// `Ok(&Task)` containing a reference to the task if found, or `TodoError::TaskNotFound` if no task with the given ID exists.
     ///
     /// # Examples
     ///
@@ -1151,24 +1447,23 @@ impl TodoList {
     /// let mut list = TodoList::new();
     /// let id = list.add_task(TaskWithoutId::new("Finish this".to_string()));
     ///
-    /// list.complete_task(id);
+    /// list.complete_task(id).unwrap();
     /// let task = list.get_tasks().iter().find(|t| t.id == id).unwrap();
     /// assert!(task.is_completed());
     /// ```
-    pub fn complete_task(&mut self, id: usize) -> Option<&Task> {
-        if let Some(task) = self.tasks.iter_mut().find(|task| task.id == id) {
-            if !task.is_completed() {
-                task.toggle_completion();
-            }
-            Some(task)
-        } else {
-            None
+    pub fn complete_task(&mut self, id: usize) -> Result<&Task, TodoError> {
+        let task = self.task_mut(id)?;
+        if !task.is_completed() {
+            task.toggle_completion();
+            task.touch();
         }
+        Ok(task)
     }
 
     /// Marks a task as pending (incomplete) by its ID.
     ///
-    /// If the task is already pending, this method has no effect but still returns the task.
+    /// Completed and cancelled tasks are reopened. If the task is already open,
+    /// this method has no effect but still returns the task.
     ///
     /// # Arguments
     ///
@@ -1176,7 +1471,7 @@ impl TodoList {
     ///
     /// # Returns
     ///
-    /// `Some(&Task)` containing a reference to the task if found, or `None` if no task with the given ID exists.
+    /// `Ok(&Task)` containing a reference to the task if found, or `TodoError::TaskNotFound` if no task with the given ID exists.
     ///
     /// # Examples
     ///
@@ -1187,21 +1482,97 @@ impl TodoList {
     /// let mut list = TodoList::new();
     /// let id = list.add_task(TaskWithoutId::new("Do this again".to_string()));
     ///
-    /// list.complete_task(id);
+    /// list.complete_task(id).unwrap();
     /// assert!(list.get_tasks()[0].is_completed());
     ///
-    /// list.uncomplete_task(id);
+    /// list.uncomplete_task(id).unwrap();
     /// assert!(!list.get_tasks()[0].is_completed());
     /// ```
-    pub fn uncomplete_task(&mut self, id: usize) -> Option<&Task> {
-        if let Some(task) = self.tasks.iter_mut().find(|task| task.id == id) {
-            if task.is_completed() {
-                task.toggle_completion();
+    pub fn uncomplete_task(&mut self, id: usize) -> Result<&Task, TodoError> {
+        let task = self.task_mut(id)?;
+        if task.is_resolved() {
+            task.set_status(TaskStatus::Pending);
+            task.touch();
+        }
+        Ok(task)
+    }
+
+    /// Sets the workflow status of a task by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the task
+    /// * `status` - The new status
+    ///
+    /// # Returns
+    ///
+    /// `Ok(&Task)` containing a reference to the updated task if found, or `TodoError::TaskNotFound` if no task with the given ID exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::todo_list::TodoList;
+    /// use todo_manager::models::task::TaskWithoutId;
+    /// use todo_manager::models::task_status::TaskStatus;
+    ///
+    /// let mut list = TodoList::new();
+    /// let id = list.add_task(TaskWithoutId::new("Write report".to_string()));
+    ///
+    /// list.set_task_status(id, TaskStatus::InProgress).unwrap();
+    /// assert_eq!(list.get_tasks()[0].status, TaskStatus::InProgress);
+    /// ```
+    pub fn set_task_status(&mut self, id: usize, status: TaskStatus) -> Result<&Task, TodoError> {
+        let task = self.task_mut(id)?;
+        task.set_status(status);
+        task.touch();
+        Ok(task)
+    }
+
+    /// Sets the workflow status of multiple tasks by their IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - A slice of task IDs
+    /// * `status` - The new status
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - Number of tasks successfully updated
+    /// - Vector of IDs that were not found
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::todo_list::TodoList;
+    /// use todo_manager::models::task::TaskWithoutId;
+    /// use todo_manager::models::task_status::TaskStatus;
+    ///
+    /// let mut list = TodoList::new();
+    /// let id1 = list.add_task(TaskWithoutId::new("Task 1".to_string()));
+    /// let id2 = list.add_task(TaskWithoutId::new("Task 2".to_string()));
+    ///
+    /// let (updated, not_found) = list.set_status_multiple(&[id1, id2, 42], TaskStatus::Waiting);
+    /// assert_eq!(updated, 2);
+    /// assert_eq!(not_found, vec![42]);
+    /// ```
+    pub fn set_status_multiple(
+        &mut self,
+        ids: &[usize],
+        status: TaskStatus,
+    ) -> (usize, Vec<usize>) {
+        let mut updated_count = 0;
+        let mut not_found = Vec::new();
+
+        for &id in ids {
+            if self.set_task_status(id, status).is_ok() {
+                updated_count += 1;
+            } else {
+                not_found.push(id);
             }
-            Some(task)
-        } else {
-            None
         }
+
+        (updated_count, not_found)
     }
 
     /// Completes multiple tasks specified by their IDs.
@@ -1235,7 +1606,7 @@ impl TodoList {
         let mut not_found = Vec::new();
 
         for &id in ids {
-            if self.complete_task(id).is_some() {
+            if self.complete_task(id).is_ok() {
                 completed_count += 1;
             } else {
                 not_found.push(id);
@@ -1282,7 +1653,7 @@ impl TodoList {
         sorted_ids.dedup();
 
         for id in sorted_ids {
-            if self.remove_task(id).is_some() {
+            if self.remove_task(id).is_ok() {
                 removed_count += 1;
             } else {
                 not_found.push(id);
@@ -1340,7 +1711,27 @@ impl TodoList {
     /// ```
     pub fn remove_all_tasks(&mut self) -> usize {
         let count = self.tasks.len();
-        self.tasks.clear();
+
+        // Remove top-level tasks first so subtasks go to the trash with their parent
+        while !self.tasks.is_empty() {
+            let mut ids: Vec<usize> = self
+                .tasks
+                .iter()
+                .filter(|task| {
+                    task.get_parent_id()
+                        .is_none_or(|parent_id| !self.tasks.iter().any(|t| t.id == parent_id))
+                })
+                .map(|task| task.id)
+                .collect();
+            if ids.is_empty() {
+                ids.push(self.tasks[0].id);
+            }
+            for id in ids {
+                // The IDs were just taken from the list, so the removal cannot fail
+                let _ = self.remove_task(id);
+            }
+        }
+
         count
     }
 
@@ -1365,8 +1756,8 @@ impl TodoList {
     /// let mut list = TodoList::new();
     /// let id1 = list.add_task(TaskWithoutId::new("Task 1".to_string()));
     /// let id2 = list.add_task(TaskWithoutId::new("Task 2".to_string()));
-    /// list.complete_task(id1);
-    /// list.complete_task(id2);
+    /// list.complete_task(id1).unwrap();
+    /// list.complete_task(id2).unwrap();
     ///
     /// let (uncompleted, not_found) = list.uncomplete_tasks(&[id1, id2]);
     /// assert_eq!(uncompleted, 2);
@@ -1377,7 +1768,7 @@ impl TodoList {
         let mut not_found = Vec::new();
 
         for &id in ids {
-            if self.uncomplete_task(id).is_some() {
+            if self.uncomplete_task(id).is_ok() {
                 uncompleted_count += 1;
             } else {
                 not_found.push(id);
@@ -1446,7 +1837,7 @@ impl TodoList {
         let mut not_found = Vec::new();
 
         for &id in ids {
-            if self.toggle_task(id).is_some() {
+            if self.toggle_task(id).is_ok() {
                 toggled_count += 1;
             } else {
                 not_found.push(id);
@@ -1519,7 +1910,7 @@ impl TodoList {
         let mut not_found = Vec::new();
 
         for &id in ids {
-            if self.set_task_priority(id, priority).is_some() {
+            if self.set_task_priority(id, priority).is_ok() {
                 updated_count += 1;
             } else {
                 not_found.push(id);
@@ -1565,7 +1956,194 @@ impl TodoList {
         let mut not_found = Vec::new();
 
         for &id in ids {
-            if self.set_task_category(id, category.clone()).is_some() {
+            if self.set_task_category(id, category.clone()).is_ok() {
+                updated_count += 1;
+            } else {
+                not_found.push(id);
+            }
+        }
+
+        (updated_count, not_found)
+    }
+
+    /// Adds tags to a task by its ID.
+    ///
+    /// Tags the task already has are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the task
+    /// * `tags` - The tags to add
+    ///
+    /// # Returns
+    ///
+    /// `Ok(&Task)` containing a reference to the updated task if found, or `TodoError::TaskNotFound` if no task with the given ID exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::todo_list::TodoList;
+    /// use todo_manager::models::task::TaskWithoutId;
+    ///
+    /// let mut list = TodoList::new();
+    /// let id = list.add_task(TaskWithoutId::new("Task".to_string()));
+    ///
+    /// list.add_task_tags(id, &["work".to_string(), "urgent".to_string()]).unwrap();
+    /// assert_eq!(list.get_tasks()[0].tags, vec!["work", "urgent"]);
+    /// ```
+    pub fn add_task_tags(&mut self, id: usize, tags: &[String]) -> Result<&Task, TodoError> {
+        let task = self.task_mut(id)?;
+        for tag in tags {
+            task.add_tag(tag);
+        }
+        task.touch();
+        Ok(task)
+    }
+
+    /// Replaces the tags of a task by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the task
+    /// * `tags` - The new tags, the first one being the category
+    ///
+    /// # Returns
+    ///
+    /// `Ok(&Task)` containing a reference to the updated task if found, or `TodoError::TaskNotFound` if no task with the given ID exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::todo_list::TodoList;
+    /// use todo_manager::models::task::TaskWithoutId;
+    ///
+    /// let mut list = TodoList::new();
+    /// let id = list.add_task(TaskWithoutId::new("Task".to_string()));
+    /// list.add_task_tags(id, &["work".to_string()]).unwrap();
+    ///
+    /// list.set_task_tags(id, &["home".to_string(), "urgent".to_string()]).unwrap();
+    /// assert_eq!(list.get_tasks()[0].tags, vec!["home", "urgent"]);
+    /// ```
+    pub fn set_task_tags(&mut self, id: usize, tags: &[String]) -> Result<&Task, TodoError> {
+        let task = self.task_mut(id)?;
+        task.tags.clear();
+        for tag in tags {
+            task.add_tag(tag);
+        }
+        task.touch();
+        Ok(task)
+    }
+
+    /// Removes tags from a task by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the task
+    /// * `tags` - The tags to remove
+    ///
+    /// # Returns
+    ///
+    /// `Ok(&Task)` containing a reference to the updated task if found, or `TodoError::TaskNotFound` if no task with the given ID exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::todo_list::TodoList;
+    /// use todo_manager::models::task::TaskWithoutId;
+    ///
+    /// let mut list = TodoList::new();
+    /// let id = list.add_task(TaskWithoutId::new("Task".to_string()));
+    /// list.add_task_tags(id, &["work".to_string(), "urgent".to_string()]).unwrap();
+    ///
+    /// list.remove_task_tags(id, &["urgent".to_string()]).unwrap();
+    /// assert_eq!(list.get_tasks()[0].tags, vec!["work"]);
+    /// ```
+    pub fn remove_task_tags(&mut self, id: usize, tags: &[String]) -> Result<&Task, TodoError> {
+        let task = self.task_mut(id)?;
+        for tag in tags {
+            task.remove_tag(tag);
+        }
+        task.touch();
+        Ok(task)
+    }
+
+    /// Adds tags to multiple tasks by their IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - A slice of task IDs
+    /// * `tags` - The tags to add
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - Number of tasks successfully updated
+    /// - Vector of IDs that were not found
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::todo_list::TodoList;
+    /// use todo_manager::models::task::TaskWithoutId;
+    ///
+    /// let mut list = TodoList::new();
+    /// let id1 = list.add_task(TaskWithoutId::new("Task 1".to_string()));
+    /// let id2 = list.add_task(TaskWithoutId::new("Task 2".to_string()));
+    ///
+    /// let (updated, not_found) = list.add_tags_multiple(&[id1, id2, 99], &["work".to_string()]);
+    /// assert_eq!(updated, 2);
+    /// assert_eq!(not_found, vec![99]);
+    /// ```
+    pub fn add_tags_multiple(&mut self, ids: &[usize], tags: &[String]) -> (usize, Vec<usize>) {
+        let mut updated_count = 0;
+        let mut not_found = Vec::new();
+
+        for &id in ids {
+            if self.add_task_tags(id, tags).is_ok() {
+                updated_count += 1;
+            } else {
+                not_found.push(id);
+            }
+        }
+
+        (updated_count, not_found)
+    }
+
+    /// Removes tags from multiple tasks by their IDs.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - A slice of task IDs
+    /// * `tags` - The tags to remove
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - Number of tasks successfully updated
+    /// - Vector of IDs that were not found
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::todo_list::TodoList;
+    /// use todo_manager::models::task::TaskWithoutId;
+    ///
+    /// let mut list = TodoList::new();
+    /// let id1 = list.add_task(TaskWithoutId::new("Task 1".to_string()));
+    /// let id2 = list.add_task(TaskWithoutId::new("Task 2".to_string()));
+    /// list.add_tags_multiple(&[id1, id2], &["work".to_string()]);
+    ///
+    /// let (updated, not_found) = list.remove_tags_multiple(&[id1, id2], &["work".to_string()]);
+    /// assert_eq!(updated, 2);
+    /// assert!(not_found.is_empty());
+    /// assert!(list.get_all_categories().is_empty());
+    /// ```
+    pub fn remove_tags_multiple(&mut self, ids: &[usize], tags: &[String]) -> (usize, Vec<usize>) {
+        let mut updated_count = 0;
+        let mut not_found = Vec::new();
+
+        for &id in ids {
+            if self.remove_task_tags(id, tags).is_ok() {
                 updated_count += 1;
             } else {
                 not_found.push(id);
@@ -1579,9 +2157,12 @@ impl TodoList {
     ///
     /// Returns a `TaskStatistics` struct containing:
     /// - Total number of tasks
-    /// - Number of completed and pending tasks
+    /// - Number of completed and pending (open) tasks
+    /// - Number of in-progress, waiting and blocked tasks (included in pending)
+    /// - Number of cancelled tasks, which are excluded from the completion percentage
     /// - Completion percentage
     /// - Task counts by priority level
+    /// - Task counts by tag
     ///
     /// # Examples
     ///
@@ -1593,8 +2174,8 @@ impl TodoList {
     /// let mut list = TodoList::new();
     /// let id1 = list.add_task(TaskWithoutId::new("Task 1".to_string()));
     /// let id2 = list.add_task(TaskWithoutId::new("Task 2".to_string()));
-    /// list.set_task_priority(id1, Priority::High);
-    /// list.complete_task(id1);
+    /// list.set_task_priority(id1, Priority::High).unwrap();
+    /// list.complete_task(id1).unwrap();
     ///
     /// let stats = list.get_statistics();
     /// assert_eq!(stats.total, 2);
@@ -1605,11 +2186,19 @@ impl TodoList {
     /// ```
     pub fn get_statistics(&self) -> TaskStatistics {
         let total = self.tasks.len();
-        let completed = self.tasks.iter().filter(|t| t.is_completed()).count();
-        let pending = total - completed;
-
-        let completion_percentage = if total > 0 {
-            (completed as f64 / total as f64) * 100.0
+        let count_status =
+            |status: TaskStatus| self.tasks.iter().filter(|t| t.status == status).count();
+        let completed = count_status(TaskStatus::Completed);
+        let cancelled = count_status(TaskStatus::Cancelled);
+        let in_progress = count_status(TaskStatus::InProgress);
+        let waiting = count_status(TaskStatus::Waiting);
+        let blocked = count_status(TaskStatus::Blocked);
+        let pending = total - completed - cancelled;
+
+        // Cancelled tasks will never be done, so they don't count towards completion
+        let relevant = total - cancelled;
+        let completion_percentage = if relevant > 0 {
+            (completed as f64 / relevant as f64) * 100.0
         } else {
             0.0
         };
@@ -1630,14 +2219,31 @@ impl TodoList {
             .filter(|t| t.get_priority() == Priority::Low)
             .count();
 
+        let mut tag_counts: Vec<(String, usize)> = Vec::new();
+        for tag in self.tasks.iter().flat_map(|t| t.tags.iter()) {
+            match tag_counts
+                .iter_mut()
+                .find(|(name, _)| name.eq_ignore_ascii_case(tag))
+            {
+                Some((_, count)) => *count += 1,
+                None => tag_counts.push((tag.clone(), 1)),
+            }
+        }
+        tag_counts.sort_by(|a, b| a.0.cmp(&b.0));
+
         TaskStatistics {
             total,
             completed,
             pending,
+            in_progress,
+            waiting,
+            blocked,
+            cancelled,
             completion_percentage,
             high_priority,
             medium_priority,
             low_priority,
+            tag_counts,
         }
     }
 }
@@ -1670,7 +2276,38 @@ mod tests {
 
         let task = &todo_list.get_tasks()[0];
         assert_eq!(task.description, "Test task");
-        assert!(!task.completed);
+        assert!(!task.is_completed());
+    }
+
+    #[test]
+    fn test_add_task_sets_timestamps() {
+        let mut todo_list = TodoList::new();
+        todo_list.add_task(TaskWithoutId::new("Test task".to_string()));
+
+        let task = &todo_list.get_tasks()[0];
+        assert!(task.created_at.is_some());
+        assert_eq!(task.created_at, task.updated_at);
+        assert!(task.completed_at.is_none());
+    }
+
+    #[test]
+    fn test_mutators_update_timestamps() {
+        let mut todo_list = TodoList::new();
+        let task_id = todo_list.add_task(TaskWithoutId::new("Test task".to_string()));
+        let created_at = todo_list.get_tasks()[0].created_at;
+
+        todo_list.edit_task(task_id, "Edited".to_string()).unwrap();
+        let updated_at = todo_list.get_tasks()[0].updated_at;
+        assert!(updated_at >= created_at);
+
+        todo_list.complete_task(task_id).unwrap();
+        let task = &todo_list.get_tasks()[0];
+        assert_eq!(task.created_at, created_at);
+        assert!(task.completed_at.is_some());
+        assert!(task.updated_at >= updated_at);
+
+        todo_list.uncomplete_task(task_id).unwrap();
+        assert!(todo_list.get_tasks()[0].completed_at.is_none());
     }
 
     #[test]
@@ -1690,7 +2327,7 @@ mod tests {
         let task_id = todo_list.add_task(TaskWithoutId::new("Test task".to_string()));
 
         let removed_task = todo_list.remove_task(task_id);
-        assert!(removed_task.is_some());
+        assert!(removed_task.is_ok());
         assert_eq!(removed_task.unwrap().description, "Test task");
         assert!(todo_list.is_empty());
     }
@@ -1699,7 +2336,143 @@ mod tests {
     fn test_remove_nonexistent_task() {
         let mut todo_list = TodoList::new();
         let removed_task = todo_list.remove_task(999);
-        assert!(removed_task.is_none());
+        assert_eq!(removed_task, Err(TodoError::TaskNotFound(999)));
+        assert!(todo_list.get_trash().is_empty());
+    }
+
+    #[test]
+    fn test_remove_and_restore_keeps_ids_parents_and_dependencies() {
+        let mut todo_list = TodoList::new();
+        let blocker = todo_list.add_task(TaskWithoutId::new("Blocker".to_string()));
+        let parent = todo_list.add_task(TaskWithoutId::new("Parent".to_string()));
+        let child = todo_list.add_subtask(parent, "Child".to_string()).unwrap();
+        let dependent = todo_list.add_task(TaskWithoutId::new("Dependent".to_string()));
+        todo_list.add_task_dependency(parent, blocker).unwrap();
+        todo_list.add_task_dependency(dependent, child).unwrap();
+
+        todo_list.remove_task(parent).unwrap();
+        assert_eq!(todo_list.get_tasks().len(), 2);
+        let trashed = &todo_list.get_trash()[0];
+        assert_eq!(trashed.subtasks.len(), 1);
+        assert_eq!(trashed.dependents, vec![(dependent, child)]);
+        // Removed tasks no longer block their dependents
+        assert!(todo_list.are_dependencies_completed(dependent));
+
+        let restored = todo_list.restore_task(parent).unwrap();
+        assert_eq!(restored.id, parent);
+        assert_eq!(restored.get_dependencies(), &vec![blocker]);
+
+        let child_task = todo_list
+            .get_tasks()
+            .iter()
+            .find(|t| t.id == child)
+            .unwrap();
+        assert_eq!(child_task.get_parent_id(), Some(parent));
+        let dependent_task = todo_list
+            .get_tasks()
+            .iter()
+            .find(|t| t.id == dependent)
+            .unwrap();
+        assert_eq!(dependent_task.get_dependencies(), &vec![child]);
+        assert!(todo_list.get_trash().is_empty());
+    }
+
+    #[test]
+    fn test_restore_drops_links_to_missing_tasks() {
+        let mut todo_list = TodoList::new();
+        let parent = todo_list.add_task(TaskWithoutId::new("Parent".to_string()));
+        let child = todo_list.add_subtask(parent, "Child".to_string()).unwrap();
+        let other = todo_list.add_task(TaskWithoutId::new("Other".to_string()));
+        todo_list.add_task_dependency(child, other).unwrap();
+
+        todo_list.remove_task(child).unwrap();
+        todo_list.remove_task(other).unwrap();
+        todo_list.restore_task(child).unwrap();
+
+        let restored = todo_list
+            .get_tasks()
+            .iter()
+            .find(|t| t.id == child)
+            .unwrap();
+        assert_eq!(restored.get_parent_id(), Some(parent));
+        assert!(restored.get_dependencies().is_empty());
+
+        assert_eq!(todo_list.empty_trash(), 1);
+        assert_eq!(
+            todo_list.restore_task(other),
+            Err(TodoError::TaskNotInTrash(other))
+        );
+    }
+
+    #[test]
+    fn test_set_task_parent_rejects_cycles() {
+        let mut todo_list = TodoList::new();
+        let root = todo_list.add_task(TaskWithoutId::new("Root".to_string()));
+        let child = todo_list.add_subtask(root, "Child".to_string()).unwrap();
+        let grandchild = todo_list
+            .add_subtask(child, "Grandchild".to_string())
+            .unwrap();
+
+        assert_eq!(
+            todo_list
+                .set_task_parent(root, Some(grandchild))
+                .unwrap_err(),
+            TodoError::CircularParent {
+                task_id: root,
+                parent_id: grandchild
+            }
+        );
+        assert_eq!(
+            todo_list.set_task_parent(root, Some(root)).unwrap_err(),
+            TodoError::CircularParent {
+                task_id: root,
+                parent_id: root
+            }
+        );
+        assert_eq!(
+            todo_list.set_task_parent(root, Some(99)).unwrap_err(),
+            TodoError::InvalidParent(99)
+        );
+
+        todo_list.set_task_parent(grandchild, Some(root)).unwrap();
+        assert_eq!(todo_list.get_subtasks(root).len(), 2);
+    }
+
+    #[test]
+    fn test_restore_subtask_after_parent_was_removed() {
+        let mut todo_list = TodoList::new();
+        let parent = todo_list.add_task(TaskWithoutId::new("Parent".to_string()));
+        let child = todo_list.add_subtask(parent, "Child".to_string()).unwrap();
+
+        todo_list.remove_task(child).unwrap();
+        todo_list.remove_task(parent).unwrap();
+        todo_list.restore_task(child).unwrap();
+
+        let restored = &todo_list.get_tasks()[0];
+        assert_eq!(restored.id, child);
+        assert_eq!(restored.get_parent_id(), None);
+    }
+
+    #[test]
+    fn test_remove_all_tasks_moves_everything_to_trash() {
+        let mut todo_list = TodoList::new();
+        let parent = todo_list.add_task(TaskWithoutId::new("Parent".to_string()));
+        todo_list.add_subtask(parent, "Child".to_string()).unwrap();
+        todo_list.add_task(TaskWithoutId::new("Other".to_string()));
+
+        assert_eq!(todo_list.remove_all_tasks(), 3);
+        assert!(todo_list.is_empty());
+        assert_eq!(todo_list.get_trash().len(), 2);
+
+        todo_list.restore_task(parent).unwrap();
+        assert_eq!(todo_list.get_tasks().len(), 2);
+    }
+
+    #[test]
+    fn test_load_todo_list_without_trash() {
+        let json = r#"{"tasks": [], "next_id": 1}"#;
+        let todo_list: TodoList = serde_json::from_str(json).unwrap();
+        assert!(todo_list.get_trash().is_empty());
     }
 
     #[test]
@@ -1708,11 +2481,11 @@ mod tests {
         let task_id = todo_list.add_task(TaskWithoutId::new("Test task".to_string()));
 
         let task = todo_list.toggle_task(task_id);
-        assert!(task.is_some());
+        assert!(task.is_ok());
         assert!(task.unwrap().is_completed());
 
         let task = todo_list.toggle_task(task_id);
-        assert!(task.is_some());
+        assert!(task.is_ok());
         assert!(!task.unwrap().is_completed());
     }
 
@@ -1720,7 +2493,7 @@ mod tests {
     fn test_toggle_nonexistent_task() {
         let mut todo_list = TodoList::new();
         let result = todo_list.toggle_task(999);
-        assert!(result.is_none());
+        assert!(result.is_err());
     }
 
     #[test]
@@ -1729,7 +2502,7 @@ mod tests {
         let task1_id = todo_list.add_task(TaskWithoutId::new("Completed task".to_string()));
         let _task2_id = todo_list.add_task(TaskWithoutId::new("Pending task".to_string()));
 
-        todo_list.toggle_task(task1_id);
+        todo_list.toggle_task(task1_id).unwrap();
 
         let completed_tasks = todo_list.get_completed_tasks();
         let pending_tasks = todo_list.get_pending_tasks();
@@ -1740,6 +2513,71 @@ mod tests {
         assert_eq!(pending_tasks[0].description, "Pending task");
     }
 
+    #[test]
+    fn test_cancelled_dependency_counts_as_resolved() {
+        let mut todo_list = TodoList::new();
+        let id1 = todo_list.add_task(TaskWithoutId::new("Dependency".to_string()));
+        let id2 = todo_list.add_task(TaskWithoutId::new("Dependent".to_string()));
+        todo_list.add_task_dependency(id2, id1).unwrap();
+
+        todo_list.set_task_status(id1, TaskStatus::Blocked).unwrap();
+        assert!(!todo_list.are_dependencies_completed(id2));
+
+        todo_list
+            .set_task_status(id1, TaskStatus::Cancelled)
+            .unwrap();
+        assert!(todo_list.are_dependencies_completed(id2));
+        assert!(todo_list.get_incomplete_dependencies(id2).is_empty());
+    }
+
+    #[test]
+    fn test_statistics_report_cancelled_separately() {
+        let mut todo_list = TodoList::new();
+        let id1 = todo_list.add_task(TaskWithoutId::new("Done".to_string()));
+        let id2 = todo_list.add_task(TaskWithoutId::new("Dropped".to_string()));
+        let id3 = todo_list.add_task(TaskWithoutId::new("Working".to_string()));
+        todo_list.add_task(TaskWithoutId::new("Open".to_string()));
+
+        todo_list.complete_task(id1).unwrap();
+        todo_list
+            .set_task_status(id2, TaskStatus::Cancelled)
+            .unwrap();
+        todo_list
+            .set_task_status(id3, TaskStatus::InProgress)
+            .unwrap();
+
+        let stats = todo_list.get_statistics();
+        assert_eq!(stats.total, 4);
+        assert_eq!(stats.completed, 1);
+        assert_eq!(stats.cancelled, 1);
+        assert_eq!(stats.pending, 2);
+        assert_eq!(stats.in_progress, 1);
+        assert!((stats.completion_percentage - 100.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_status_filter() {
+        let mut todo_list = TodoList::new();
+        let id1 = todo_list.add_task(TaskWithoutId::new("Waiting".to_string()));
+        let id2 = todo_list.add_task(TaskWithoutId::new("Open".to_string()));
+        let id3 = todo_list.add_task(TaskWithoutId::new("Cancelled".to_string()));
+        todo_list.set_task_status(id1, TaskStatus::Waiting).unwrap();
+        todo_list
+            .set_task_status(id3, TaskStatus::Cancelled)
+            .unwrap();
+
+        let waiting =
+            todo_list.get_filtered_tasks(&TaskFilter::all().with_status(TaskStatus::Waiting));
+        assert_eq!(waiting.iter().map(|t| t.id).collect::<Vec<_>>(), vec![id1]);
+
+        let pending =
+            todo_list.get_filtered_tasks(&TaskFilter::all().with_status(TaskStatus::Pending));
+        assert_eq!(
+            pending.iter().map(|t| t.id).collect::<Vec<_>>(),
+            vec![id1, id2]
+        );
+    }
+
     #[test]
     fn test_search_tasks_case_insensitive() {
         let mut todo_list = TodoList::new();
@@ -1793,7 +2631,7 @@ mod tests {
         todo_list.add_task(TaskWithoutId::new("Buy bread".to_string()));
         todo_list.add_task(TaskWithoutId::new("Sell old laptop".to_string()));
 
-        todo_list.complete_task(task1_id);
+        todo_list.complete_task(task1_id).unwrap();
 
         let results = todo_list.search_tasks("buy");
         assert_eq!(results.len(), 2);
@@ -0,0 +1,102 @@
+use crate::models::project::Project;
+use crate::models::task::Task;
+use chrono::{DateTime, Duration, Local};
+use serde::{Deserialize, Serialize};
+
+/// Default number of days after which trash entries are purged.
+pub const DEFAULT_TRASH_RETENTION_DAYS: u32 = 30;
+
+/// A removed task kept in the trash of its project.
+///
+/// Besides the task itself, the entry keeps everything needed to put it back
+/// where it was: the subtasks that were removed with it and the dependency
+/// edges other tasks had on the removed tasks.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrashedTask {
+    /// The removed task
+    pub task: Task,
+
+    /// Subtasks that were removed together with the task
+    #[serde(default)]
+    pub subtasks: Vec<Task>,
+
+    /// Dependency edges `(dependent_id, removed_id)` that pointed at the removed tasks
+    #[serde(default)]
+    pub dependents: Vec<(usize, usize)>,
+
+    /// When the task was removed
+    pub deleted_at: DateTime<Local>,
+}
+
+impl TrashedTask {
+    /// Returns whether the entry is older than the given number of days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::models::task::Task;
+    /// use todo_manager::models::trash::TrashedTask;
+    /// use chrono::{Duration, Local};
+    ///
+    /// let entry = TrashedTask {
+    ///     task: Task::new(1, "Old".to_string()),
+    ///     subtasks: Vec::new(),
+    ///     dependents: Vec::new(),
+    ///     deleted_at: Local::now() - Duration::days(40),
+    /// };
+    /// assert!(entry.is_expired(30, Local::now()));
+    /// assert!(!entry.is_expired(60, Local::now()));
+    /// ```
+    pub fn is_expired(&self, retention_days: u32, now: DateTime<Local>) -> bool {
+        is_expired(self.deleted_at, retention_days, now)
+    }
+}
+
+/// A deleted project kept in the project trash.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrashedProject {
+    /// The deleted project including its tasks
+    pub project: Project,
+
+    /// When the project was deleted
+    pub deleted_at: DateTime<Local>,
+}
+
+impl TrashedProject {
+    /// Returns whether the entry is older than the given number of days.
+    pub fn is_expired(&self, retention_days: u32, now: DateTime<Local>) -> bool {
+        is_expired(self.deleted_at, retention_days, now)
+    }
+}
+
+fn is_expired(deleted_at: DateTime<Local>, retention_days: u32, now: DateTime<Local>) -> bool {
+    now - deleted_at > Duration::days(retention_days as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_entry_expiry() {
+        let now = Local::now();
+        let entry = TrashedProject {
+            project: Project::new("Work".to_string()),
+            deleted_at: now - Duration::days(3),
+        };
+        assert!(entry.is_expired(2, now));
+        assert!(!entry.is_expired(3, now));
+    }
+
+    #[test]
+    fn test_zero_retention_expires_immediately_after_deletion() {
+        let now = Local::now();
+        let entry = TrashedTask {
+            task: Task::new(1, "Task".to_string()),
+            subtasks: Vec::new(),
+            dependents: Vec::new(),
+            deleted_at: now - Duration::seconds(1),
+        };
+        assert!(entry.is_expired(0, now));
+    }
+}
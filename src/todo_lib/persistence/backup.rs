@@ -0,0 +1,255 @@
+use crate::controller::project_command::ProjectManager;
+use crate::models::TodoError;
+use crate::persistence::storage::StorageKind;
+use chrono::{Local, NaiveDateTime};
+use std::cell::Cell;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Format of the timestamp in backup file names, e.g. `20250117-093000250`.
+const TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S%3f";
+
+/// A timestamped copy of a storage file, taken before the file was overwritten.
+///
+/// Backups live in a directory next to the storage file: the backups of
+/// `tasks.json` are `tasks.json.backups/tasks-<timestamp>.json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Backup {
+    /// Path of the backup file
+    pub path: PathBuf,
+    /// When the backup was taken
+    pub created_at: NaiveDateTime,
+    /// The backend that wrote the storage file, and so the backup
+    pub kind: StorageKind,
+}
+
+impl Backup {
+    /// Loads the projects stored in the backup.
+    ///
+    /// # Returns
+    ///
+    /// The projects, or a `TodoError` if the backup cannot be read or parsed
+    pub fn load(&self) -> Result<ProjectManager, TodoError> {
+        self.kind.open(&self.path).load_projects()
+    }
+}
+
+/// Takes the backups of a storage file for one storage instance.
+///
+/// The command line saves after every command, so a backup on every save
+/// would rotate the file as it was before the session out within a few
+/// commands. Only the first save that replaces the file takes a backup;
+/// later ones are skipped unless a backup is asked for with `create`.
+#[derive(Debug)]
+pub(crate) struct SessionBackups {
+    keep: usize,
+    taken: Cell<bool>,
+}
+
+impl SessionBackups {
+    /// Keeps up to `keep` backups; 0 turns backups off.
+    pub(crate) fn new(keep: usize) -> Self {
+        Self {
+            keep,
+            taken: Cell::new(false),
+        }
+    }
+
+    /// Backs up the storage file before it is replaced, unless this
+    /// instance has already taken a backup.
+    pub(crate) fn before_save(&self, storage_path: &Path) -> Result<(), TodoError> {
+        if self.taken.get() {
+            return Ok(());
+        }
+        self.create(storage_path)
+    }
+
+    /// Backs up the storage file now.
+    pub(crate) fn create(&self, storage_path: &Path) -> Result<(), TodoError> {
+        if create_backup(storage_path, Local::now().naive_local(), self.keep)? {
+            self.taken.set(true);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the directory holding the backups of a storage file.
+pub fn backup_dir(storage_path: &Path) -> PathBuf {
+    let mut dir_name = storage_path.file_name().unwrap_or_default().to_os_string();
+    dir_name.push(".backups");
+    storage_path.with_file_name(dir_name)
+}
+
+/// Returns the file name prefix and extension of the backups of a storage file.
+fn name_parts(storage_path: &Path) -> (String, String) {
+    let stem = storage_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let extension = storage_path
+        .extension()
+        .map(|extension| format!(".{}", extension.to_string_lossy()))
+        .unwrap_or_default();
+    (format!("{}-", stem), extension)
+}
+
+/// Copies the storage file into its backup directory and removes the oldest
+/// backups beyond `keep`.
+///
+/// Does nothing if the storage file does not exist yet or `keep` is 0.
+///
+/// # Returns
+///
+/// Whether a backup was taken, or a `TodoError::Io` if the copy failed
+pub fn create_backup(
+    storage_path: &Path,
+    created_at: NaiveDateTime,
+    keep: usize,
+) -> Result<bool, TodoError> {
+    if keep == 0 || !storage_path.is_file() {
+        return Ok(false);
+    }
+
+    let dir = backup_dir(storage_path);
+    fs::create_dir_all(&dir)
+        .map_err(|e| TodoError::Io(format!("Failed to create backup directory: {}", e)))?;
+
+    let (prefix, extension) = name_parts(storage_path);
+    let backup_path = dir.join(format!(
+        "{}{}{}",
+        prefix,
+        created_at.format(TIMESTAMP_FORMAT),
+        extension
+    ));
+    fs::copy(storage_path, &backup_path)
+        .map_err(|e| TodoError::Io(format!("Failed to create backup: {}", e)))?;
+
+    for (old_backup, _) in backup_files(storage_path)?.iter().skip(keep) {
+        fs::remove_file(old_backup)
+            .map_err(|e| TodoError::Io(format!("Failed to remove old backup: {}", e)))?;
+    }
+    Ok(true)
+}
+
+/// Lists the backups of a storage file written by the `kind` backend, newest first.
+///
+/// Files in the backup directory that do not follow the backup naming are
+/// ignored.
+///
+/// # Returns
+///
+/// The backups (empty if there are none), or a `TodoError::Io` if the backup
+/// directory cannot be read
+pub fn list_backups(storage_path: &Path, kind: StorageKind) -> Result<Vec<Backup>, TodoError> {
+    let backups = backup_files(storage_path)?
+        .into_iter()
+        .map(|(path, created_at)| Backup {
+            path,
+            created_at,
+            kind,
+        })
+        .collect();
+    Ok(backups)
+}
+
+/// Returns the paths and times of the backups of a storage file, newest first.
+fn backup_files(storage_path: &Path) -> Result<Vec<(PathBuf, NaiveDateTime)>, TodoError> {
+    let dir = backup_dir(storage_path);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let (prefix, extension) = name_parts(storage_path);
+    let entries = fs::read_dir(&dir)
+        .map_err(|e| TodoError::Io(format!("Failed to read backup directory: {}", e)))?;
+
+    let mut backups: Vec<(PathBuf, NaiveDateTime)> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            let timestamp = name.strip_prefix(&prefix)?.strip_suffix(&extension)?;
+            let created_at = NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT).ok()?;
+            Some((path, created_at))
+        })
+        .collect();
+    backups.sort_by_key(|(_, created_at)| std::cmp::Reverse(*created_at));
+    Ok(backups)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn time(second: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, 17)
+            .unwrap()
+            .and_hms_milli_opt(9, 30, second, 250)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_backup_dir_is_next_to_storage_file() {
+        assert_eq!(
+            backup_dir(Path::new("data/tasks.json")),
+            Path::new("data/tasks.json.backups")
+        );
+    }
+
+    #[test]
+    fn test_backups_rotate_and_list_newest_first() {
+        let dir = std::env::temp_dir().join("todo_backup_rotation_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let storage_path = dir.join("tasks.json");
+
+        // Nothing to back up before the first save
+        assert_eq!(create_backup(&storage_path, time(0), 2), Ok(false));
+        assert!(list_backups(&storage_path, StorageKind::Json)
+            .unwrap()
+            .is_empty());
+
+        for second in 1..=3 {
+            fs::write(&storage_path, format!("version {}", second)).unwrap();
+            create_backup(&storage_path, time(second), 2).unwrap();
+        }
+        fs::write(backup_dir(&storage_path).join("notes.txt"), "not a backup").unwrap();
+
+        let backups = list_backups(&storage_path, StorageKind::Json).unwrap();
+        let times: Vec<NaiveDateTime> = backups.iter().map(|backup| backup.created_at).collect();
+        assert_eq!(times, vec![time(3), time(2)]);
+        assert_eq!(fs::read_to_string(&backups[0].path).unwrap(), "version 3");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_session_backups_back_up_the_first_save_only() {
+        let dir = std::env::temp_dir().join("todo_session_backups_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let storage_path = dir.join("tasks.json");
+        let backups = SessionBackups::new(5);
+        let count = || {
+            list_backups(&storage_path, StorageKind::Json)
+                .unwrap()
+                .len()
+        };
+
+        // A new file is not backed up, so its first replacement is
+        backups.before_save(&storage_path).unwrap();
+        fs::write(&storage_path, "before").unwrap();
+        backups.before_save(&storage_path).unwrap();
+        assert_eq!(count(), 1);
+
+        backups.before_save(&storage_path).unwrap();
+        assert_eq!(count(), 1);
+
+        // Asking for a backup always takes one
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        backups.create(&storage_path).unwrap();
+        assert_eq!(count(), 2);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,201 @@
+use crate::controller::project_command::ProjectManager;
+use crate::models::todo_list::TodoList;
+use std::collections::HashMap;
+
+/// Row-level differences between two states of the projects.
+///
+/// Backends that write incrementally (see `Storage::save_changes`) use it
+/// to touch only the rows that changed instead of rewriting all projects.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::controller::project_command::ProjectManager;
+/// use todo_manager::models::task::TaskWithoutId;
+/// use todo_manager::persistence::StorageChanges;
+///
+/// let before = ProjectManager::new();
+/// let mut after = before.clone();
+/// let id = after
+///     .get_current_todo_list_mut()
+///     .add_task(TaskWithoutId::new("Write report".to_string()));
+///
+/// let changes = StorageChanges::between(&before, &after);
+/// assert_eq!(changes.saved_tasks, vec![("default".to_string(), id)]);
+/// assert_eq!(changes.saved_projects, vec!["default".to_string()]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StorageChanges {
+    /// Whether anything outside the projects changed: the current project,
+    /// the project trash, the saved views or the trash retention
+    pub settings: bool,
+    /// Projects that were deleted or renamed, with all their tasks
+    pub removed_projects: Vec<String>,
+    /// Projects that were added or whose next ID or task trash changed
+    pub saved_projects: Vec<String>,
+    /// Added or changed tasks, by project name and task ID
+    pub saved_tasks: Vec<(String, usize)>,
+    /// Removed tasks, by project name and task ID
+    pub removed_tasks: Vec<(String, usize)>,
+    /// Projects whose task order changed other than by appending tasks
+    pub reordered_projects: Vec<String>,
+}
+
+impl StorageChanges {
+    /// Compares two states of the projects.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The state that is stored
+    /// * `to` - The state to store
+    pub fn between(from: &ProjectManager, to: &ProjectManager) -> Self {
+        let mut changes = StorageChanges {
+            settings: from.get_current_project_name() != to.get_current_project_name()
+                || from.get_trashed_projects() != to.get_trashed_projects()
+                || from.get_views() != to.get_views()
+                || from.get_trash_retention_days() != to.get_trash_retention_days(),
+            ..StorageChanges::default()
+        };
+
+        for name in from.list_projects() {
+            if to.get_project(&name).is_none() {
+                changes.removed_projects.push(name);
+            }
+        }
+
+        for name in to.list_projects() {
+            let Some(new) = to.get_project(&name) else {
+                continue;
+            };
+            match from.get_project(&name) {
+                Some(old) => changes.add_todo_list_changes(&name, &old.todo_list, &new.todo_list),
+                None => {
+                    changes.saved_projects.push(name.clone());
+                    for task in new.todo_list.get_tasks() {
+                        changes.saved_tasks.push((name.clone(), task.id));
+                    }
+                }
+            }
+        }
+        changes
+    }
+
+    /// Returns true if nothing changed.
+    pub fn is_empty(&self) -> bool {
+        *self == StorageChanges::default()
+    }
+
+    fn add_todo_list_changes(&mut self, name: &str, old: &TodoList, new: &TodoList) {
+        if old.next_id() != new.next_id() || old.get_trash() != new.get_trash() {
+            self.saved_projects.push(name.to_string());
+        }
+
+        let old_tasks: HashMap<usize, _> =
+            old.get_tasks().iter().map(|task| (task.id, task)).collect();
+        let new_tasks: HashMap<usize, _> =
+            new.get_tasks().iter().map(|task| (task.id, task)).collect();
+
+        for task in old.get_tasks() {
+            if !new_tasks.contains_key(&task.id) {
+                self.removed_tasks.push((name.to_string(), task.id));
+            }
+        }
+        for task in new.get_tasks() {
+            if old_tasks.get(&task.id) != Some(&task) {
+                self.saved_tasks.push((name.to_string(), task.id));
+            }
+        }
+
+        // Kept tasks must stay in order, and added tasks must come after them
+        let kept_in_old: Vec<usize> = old
+            .get_tasks()
+            .iter()
+            .map(|task| task.id)
+            .filter(|id| new_tasks.contains_key(id))
+            .collect();
+        let kept_in_new: Vec<usize> = new
+            .get_tasks()
+            .iter()
+            .map(|task| task.id)
+            .take_while(|id| old_tasks.contains_key(id))
+            .collect();
+        if kept_in_old != kept_in_new {
+            self.reordered_projects.push(name.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::saved_view::SavedView;
+    use crate::models::task::TaskWithoutId;
+
+    fn manager_with_tasks(count: usize) -> ProjectManager {
+        let mut manager = ProjectManager::new();
+        for index in 1..=count {
+            manager
+                .get_current_todo_list_mut()
+                .add_task(TaskWithoutId::new(format!("Task {}", index)));
+        }
+        manager
+    }
+
+    fn default_ids(ids: &[usize]) -> Vec<(String, usize)> {
+        ids.iter().map(|&id| ("default".to_string(), id)).collect()
+    }
+
+    #[test]
+    fn test_no_changes() {
+        let manager = manager_with_tasks(2);
+        assert!(StorageChanges::between(&manager, &manager).is_empty());
+    }
+
+    #[test]
+    fn test_changed_and_removed_tasks() {
+        let before = manager_with_tasks(3);
+        let mut after = before.clone();
+        after.get_current_todo_list_mut().complete_task(1).unwrap();
+        after.get_current_todo_list_mut().remove_task(2).unwrap();
+
+        let changes = StorageChanges::between(&before, &after);
+        assert_eq!(changes.saved_tasks, default_ids(&[1]));
+        assert_eq!(changes.removed_tasks, default_ids(&[2]));
+        // The removed task went to the trash of the project
+        assert_eq!(changes.saved_projects, vec!["default".to_string()]);
+        assert!(changes.reordered_projects.is_empty());
+        assert!(!changes.settings);
+    }
+
+    #[test]
+    fn test_restored_task_in_the_middle_reorders() {
+        let mut before = manager_with_tasks(3);
+        before.get_current_todo_list_mut().remove_task(2).unwrap();
+        let mut after = before.clone();
+        after.get_current_todo_list_mut().restore_task(2).unwrap();
+
+        let changes = StorageChanges::between(&before, &after);
+        assert_eq!(changes.saved_tasks, default_ids(&[2]));
+        assert_eq!(changes.reordered_projects, vec!["default".to_string()]);
+    }
+
+    #[test]
+    fn test_project_changes() {
+        let mut before = manager_with_tasks(1);
+        before.create_project("Old".to_string()).unwrap();
+        let mut after = before.clone();
+        after
+            .rename_project("Old".to_string(), "New".to_string())
+            .unwrap();
+        after.switch_project("default".to_string()).unwrap();
+
+        let changes = StorageChanges::between(&before, &after);
+        assert_eq!(changes.removed_projects, vec!["Old".to_string()]);
+        assert_eq!(changes.saved_projects, vec!["New".to_string()]);
+        assert!(changes.saved_tasks.is_empty());
+
+        let mut with_view = after.clone();
+        with_view.save_view(SavedView::new("Mine".to_string(), "pending".to_string()));
+        assert!(StorageChanges::between(&after, &with_view).settings);
+    }
+}
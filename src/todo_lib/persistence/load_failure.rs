@@ -0,0 +1,93 @@
+use crate::models::TodoError;
+use chrono::NaiveDateTime;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Why a projects file could not be loaded.
+///
+/// The kinds call for different handling: a missing file simply starts a new
+/// session, a corrupt file can be moved aside and salvaged, while an
+/// unreadable file or one from a newer version must be left alone so that it
+/// is not overwritten.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadFailure {
+    /// The file does not exist yet
+    Missing,
+    /// The file exists but cannot be read, e.g. because of its permissions
+    Unreadable(String),
+    /// The file is not valid JSON or does not hold projects
+    Corrupt(String),
+    /// The file was written by a newer version with a schema this version does not know
+    UnsupportedVersion { found: u64, supported: u64 },
+}
+
+impl LoadFailure {
+    /// Returns the failure message.
+    pub fn message(&self) -> String {
+        match self {
+            LoadFailure::Missing => "Failed to read file: the file does not exist".to_string(),
+            LoadFailure::Unreadable(msg) | LoadFailure::Corrupt(msg) => msg.clone(),
+            LoadFailure::UnsupportedVersion { found, supported } => format!(
+                "The file has schema version {}, but this version only supports up to {}. Please update the program.",
+                found, supported
+            ),
+        }
+    }
+}
+
+impl fmt::Display for LoadFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl From<LoadFailure> for TodoError {
+    fn from(failure: LoadFailure) -> Self {
+        match failure {
+            LoadFailure::Missing | LoadFailure::Unreadable(_) => TodoError::Io(failure.message()),
+            LoadFailure::Corrupt(_) | LoadFailure::UnsupportedVersion { .. } => {
+                TodoError::Serialization(failure.message())
+            }
+        }
+    }
+}
+
+/// A corrupt projects file that was moved aside before starting a new session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quarantine {
+    /// Where the corrupt file was moved to
+    pub path: PathBuf,
+    /// Why the file could not be loaded
+    pub reason: String,
+}
+
+impl Quarantine {
+    /// Returns the warning shown to the user.
+    pub fn warning(&self) -> String {
+        format!(
+            "The projects file could not be loaded ({}). It was moved to {} and a new session was started. Run with --recover {} to see which tasks can be salvaged, or use 'restore-backup'.",
+            self.reason,
+            self.path.display(),
+            self.path.display()
+        )
+    }
+}
+
+/// Moves a corrupt file aside to `<file>.corrupt-<timestamp>`.
+///
+/// The file is kept unchanged, so its tasks can still be salvaged, while
+/// the next save starts a new file.
+///
+/// # Returns
+///
+/// The path the file was moved to, or a `TodoError::Io` if it could not be moved
+pub(crate) fn quarantine_file(path: &Path, at: NaiveDateTime) -> Result<PathBuf, TodoError> {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(format!(".corrupt-{}", at.format("%Y%m%d-%H%M%S")));
+    let quarantine_path = path.with_file_name(file_name);
+
+    fs::rename(path, &quarantine_path)
+        .map_err(|e| TodoError::Io(format!("Failed to move corrupt file aside: {}", e)))?;
+    Ok(quarantine_path)
+}
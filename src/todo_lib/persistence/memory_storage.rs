@@ -0,0 +1,67 @@
+use crate::controller::project_command::ProjectManager;
+use crate::models::TodoError;
+use crate::persistence::load_failure::LoadFailure;
+use crate::persistence::storage::{Storage, StorageKind};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Storage that keeps the projects in memory, for tests and embedding.
+///
+/// Clones share the same projects, so a clone kept by the caller sees what
+/// a `TodoService` saved.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::controller::project_command::ProjectManager;
+/// use todo_manager::persistence::{MemoryStorage, Storage};
+/// use todo_manager::TodoService;
+///
+/// let storage = MemoryStorage::new();
+/// let service = TodoService::with_storage(ProjectManager::new(), Box::new(storage.clone()));
+/// service.save().unwrap();
+///
+/// assert_eq!(storage.projects(), Some(ProjectManager::new()));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStorage {
+    projects: Rc<RefCell<Option<ProjectManager>>>,
+}
+
+impl MemoryStorage {
+    /// Creates an empty storage; loading from it reports `LoadFailure::Missing`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a storage holding `project_manager`.
+    pub fn with_projects(project_manager: ProjectManager) -> Self {
+        Self {
+            projects: Rc::new(RefCell::new(Some(project_manager))),
+        }
+    }
+
+    /// Returns the saved projects, if any.
+    pub fn projects(&self) -> Option<ProjectManager> {
+        self.projects.borrow().clone()
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn kind(&self) -> StorageKind {
+        StorageKind::Memory
+    }
+
+    fn location(&self) -> String {
+        "memory".to_string()
+    }
+
+    fn try_load_projects(&self) -> Result<ProjectManager, LoadFailure> {
+        self.projects().ok_or(LoadFailure::Missing)
+    }
+
+    fn save_projects(&self, project_manager: &ProjectManager) -> Result<(), TodoError> {
+        *self.projects.borrow_mut() = Some(project_manager.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,333 @@
+use crate::persistence::load_failure::LoadFailure;
+use serde_json::{json, Map, Value};
+
+/// Schema version written by this version.
+///
+/// Files are upgraded to it step by step when loaded:
+///
+/// * 0 - a bare task list, from before projects existed
+/// * 1 - projects, without a `schema_version` field
+/// * 2 - projects with a `schema_version` field; tasks store `status` and
+///   `tags` instead of the legacy `completed` and `category` fields
+pub const SCHEMA_VERSION: u64 = 2;
+
+/// One step of the migration chain, upgrading a file from `from` to `from + 1`.
+pub struct Migration {
+    /// Schema version the step upgrades from
+    pub from: u64,
+    /// What the step changes
+    pub description: &'static str,
+    /// Upgrades the file contents; the error says why the file cannot be upgraded
+    pub apply: fn(Value) -> Result<Value, String>,
+}
+
+/// The migration steps, in order. Each schema change adds a step to the end.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        from: 0,
+        description: "Move the bare task list into the default project",
+        apply: task_list_to_projects,
+    },
+    Migration {
+        from: 1,
+        description: "Replace the legacy completed and category task fields",
+        apply: legacy_task_fields,
+    },
+];
+
+/// Determines the schema version of the file contents.
+///
+/// Files without a `schema_version` are recognised by their shape.
+///
+/// # Returns
+///
+/// The version, or a message saying why it cannot be determined
+pub fn schema_version(value: &Value) -> Result<u64, String> {
+    let object = value
+        .as_object()
+        .ok_or("the file does not hold a JSON object")?;
+    match object.get("schema_version") {
+        Some(version) => version
+            .as_u64()
+            .ok_or_else(|| format!("invalid schema_version: {}", version)),
+        None if object.contains_key("projects") => Ok(1),
+        None if object.contains_key("tasks") => Ok(0),
+        None => Err("the file holds neither projects nor a task list".to_string()),
+    }
+}
+
+/// Upgrades file contents of any older schema version to [`SCHEMA_VERSION`].
+///
+/// # Returns
+///
+/// The upgraded contents together with the version the file had, or a
+/// `LoadFailure::UnsupportedVersion` for files from a newer version and a
+/// `LoadFailure::Corrupt` for contents that cannot be upgraded
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use todo_manager::persistence::migration::{migrate, SCHEMA_VERSION};
+///
+/// let legacy = json!({"tasks": [], "next_id": 1});
+/// let (upgraded, found) = migrate(legacy).unwrap();
+///
+/// assert_eq!(found, 0);
+/// assert_eq!(upgraded["schema_version"], SCHEMA_VERSION);
+/// assert_eq!(upgraded["current_project"], "default");
+/// ```
+pub fn migrate(mut value: Value) -> Result<(Value, u64), LoadFailure> {
+    let found = schema_version(&value).map_err(LoadFailure::Corrupt)?;
+    if found > SCHEMA_VERSION {
+        return Err(LoadFailure::UnsupportedVersion {
+            found,
+            supported: SCHEMA_VERSION,
+        });
+    }
+
+    for migration in MIGRATIONS
+        .iter()
+        .filter(|migration| migration.from >= found)
+    {
+        value = (migration.apply)(value).map_err(|e| {
+            LoadFailure::Corrupt(format!(
+                "Failed to upgrade from schema version {}: {}",
+                migration.from, e
+            ))
+        })?;
+        if let Some(object) = value.as_object_mut() {
+            object.insert("schema_version".to_string(), json!(migration.from + 1));
+        }
+    }
+    Ok((value, found))
+}
+
+/// 0 → 1: wraps a bare task list into a `default` project.
+fn task_list_to_projects(task_list: Value) -> Result<Value, String> {
+    Ok(json!({
+        "projects": {
+            "default": {
+                "name": "default",
+                "todo_list": task_list,
+            }
+        },
+        "current_project": "default",
+    }))
+}
+
+/// 1 → 2: replaces `completed` with `status` and `category` with `tags` in
+/// every task, including the tasks in the trash.
+fn legacy_task_fields(mut value: Value) -> Result<Value, String> {
+    let object = value
+        .as_object_mut()
+        .ok_or("the file does not hold a JSON object")?;
+
+    if let Some(projects) = object.get_mut("projects").and_then(Value::as_object_mut) {
+        for project in projects.values_mut() {
+            upgrade_project(project);
+        }
+    }
+    if let Some(trash) = object.get_mut("trash").and_then(Value::as_array_mut) {
+        for entry in trash {
+            if let Some(project) = entry.get_mut("project") {
+                upgrade_project(project);
+            }
+        }
+    }
+    Ok(value)
+}
+
+fn upgrade_project(project: &mut Value) {
+    let Some(todo_list) = project.get_mut("todo_list") else {
+        return;
+    };
+    for task in tasks_mut(todo_list, "tasks") {
+        upgrade_task(task);
+    }
+    if let Some(trash) = todo_list.get_mut("trash").and_then(Value::as_array_mut) {
+        for entry in trash {
+            if let Some(Value::Object(task)) = entry.get_mut("task") {
+                upgrade_task(task);
+            }
+            for subtask in tasks_mut(entry, "subtasks") {
+                upgrade_task(subtask);
+            }
+        }
+    }
+}
+
+/// Returns the task objects in the array `key` of `value`.
+fn tasks_mut<'a>(
+    value: &'a mut Value,
+    key: &str,
+) -> impl Iterator<Item = &'a mut Map<String, Value>> {
+    value
+        .get_mut(key)
+        .and_then(Value::as_array_mut)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_object_mut)
+}
+
+fn upgrade_task(task: &mut Map<String, Value>) {
+    if !task.contains_key("status") {
+        if let Some(Value::Bool(completed)) = task.get("completed") {
+            let status = if *completed { "Completed" } else { "Pending" };
+            task.insert("status".to_string(), json!(status));
+            task.remove("completed");
+        }
+    }
+    if !task.contains_key("tags") {
+        match task.get("category") {
+            Some(Value::String(category)) => {
+                let tags = json!([category]);
+                task.insert("tags".to_string(), tags);
+                task.remove("category");
+            }
+            Some(Value::Null) => {
+                task.insert("tags".to_string(), json!([]));
+                task.remove("category");
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::project_command::ProjectManager;
+    use serde::Deserialize;
+
+    fn legacy_task(id: usize, completed: bool, category: Option<&str>) -> Value {
+        json!({
+            "id": id,
+            "description": format!("Task {}", id),
+            "completed": completed,
+            "priority": "Medium",
+            "due_date": null,
+            "category": category,
+            "parent_id": null,
+            "recurrence": null,
+            "depends_on": []
+        })
+    }
+
+    fn legacy_task_list() -> Value {
+        json!({
+            "tasks": [legacy_task(1, true, Some("work")), legacy_task(2, false, None)],
+            "next_id": 3
+        })
+    }
+
+    #[test]
+    fn test_schema_version_is_recognised_by_shape() {
+        assert_eq!(schema_version(&legacy_task_list()), Ok(0));
+        assert_eq!(schema_version(&json!({"projects": {}})), Ok(1));
+        assert_eq!(schema_version(&json!({"schema_version": 2})), Ok(2));
+        assert!(schema_version(&json!({"schema_version": "two"})).is_err());
+        assert!(schema_version(&json!({"notes": []})).is_err());
+        assert!(schema_version(&json!([1, 2])).is_err());
+    }
+
+    #[test]
+    fn test_migrations_form_a_chain_to_the_current_version() {
+        let versions: Vec<u64> = MIGRATIONS.iter().map(|migration| migration.from).collect();
+        let expected: Vec<u64> = (0..SCHEMA_VERSION).collect();
+        assert_eq!(versions, expected);
+    }
+
+    #[test]
+    fn test_task_list_to_projects() {
+        let projects = task_list_to_projects(legacy_task_list()).unwrap();
+
+        assert_eq!(projects["current_project"], "default");
+        assert_eq!(projects["projects"]["default"]["name"], "default");
+        assert_eq!(
+            projects["projects"]["default"]["todo_list"],
+            legacy_task_list()
+        );
+    }
+
+    #[test]
+    fn test_legacy_task_fields() {
+        let trashed = json!({
+            "task": legacy_task(3, true, None),
+            "subtasks": [legacy_task(4, false, Some("home"))],
+            "dependents": [],
+            "deleted_at": "2025-01-17T09:30:00+01:00"
+        });
+        let mut todo_list = legacy_task_list();
+        todo_list["trash"] = json!([trashed]);
+        let file = json!({
+            "projects": {"default": {"name": "default", "todo_list": todo_list.clone()}},
+            "current_project": "default",
+            "trash": [{
+                "project": {"name": "Old", "todo_list": todo_list},
+                "deleted_at": "2025-01-17T09:30:00+01:00"
+            }]
+        });
+
+        let upgraded = legacy_task_fields(file).unwrap();
+        for todo_list in [
+            &upgraded["projects"]["default"]["todo_list"],
+            &upgraded["trash"][0]["project"]["todo_list"],
+        ] {
+            let first = &todo_list["tasks"][0];
+            assert_eq!(first["status"], "Completed");
+            assert_eq!(first["tags"], json!(["work"]));
+            assert!(first.get("completed").is_none());
+            assert!(first.get("category").is_none());
+
+            let second = &todo_list["tasks"][1];
+            assert_eq!(second["status"], "Pending");
+            assert_eq!(second["tags"], json!([]));
+
+            assert_eq!(todo_list["trash"][0]["task"]["status"], "Completed");
+            assert_eq!(
+                todo_list["trash"][0]["subtasks"][0]["tags"],
+                json!(["home"])
+            );
+        }
+    }
+
+    #[test]
+    fn test_migrate_legacy_task_list_to_current_version() {
+        let (upgraded, found) = migrate(legacy_task_list()).unwrap();
+        assert_eq!(found, 0);
+        assert_eq!(upgraded["schema_version"], SCHEMA_VERSION);
+
+        let manager = ProjectManager::deserialize(&upgraded).unwrap();
+        let tasks = manager.get_current_todo_list().get_tasks();
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0].tags, vec!["work".to_string()]);
+    }
+
+    #[test]
+    fn test_migrate_current_version_is_unchanged() {
+        let current = serde_json::to_value(ProjectManager::new()).unwrap();
+        let mut versioned = current.clone();
+        versioned["schema_version"] = json!(SCHEMA_VERSION);
+
+        assert_eq!(
+            migrate(versioned.clone()).unwrap(),
+            (versioned, SCHEMA_VERSION)
+        );
+    }
+
+    #[test]
+    fn test_migrate_rejects_newer_and_unknown_files() {
+        assert_eq!(
+            migrate(json!({"schema_version": SCHEMA_VERSION + 1})),
+            Err(LoadFailure::UnsupportedVersion {
+                found: SCHEMA_VERSION + 1,
+                supported: SCHEMA_VERSION
+            })
+        );
+        assert!(matches!(
+            migrate(json!({"notes": []})),
+            Err(LoadFailure::Corrupt(_))
+        ));
+    }
+}
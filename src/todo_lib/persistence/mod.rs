@@ -2,7 +2,22 @@
 //!
 //! This module provides functionality to persist TodoList data to disk
 //! and load it back, decoupling storage concerns from business logic.
+//! Backends implement the [`Storage`] trait.
 
+pub mod backup;
+pub mod changes;
+pub mod load_failure;
+pub mod memory_storage;
+pub mod migration;
+pub mod recovery;
+pub mod sqlite_storage;
+pub mod storage;
 pub mod todo_list_storage;
 
+pub use backup::Backup;
+pub use changes::StorageChanges;
+pub use load_failure::{LoadFailure, Quarantine};
+pub use memory_storage::MemoryStorage;
+pub use sqlite_storage::SqliteStorage;
+pub use storage::{open_storage, Storage, StorageKind};
 pub use todo_list_storage::TodoListStorage;
@@ -0,0 +1,234 @@
+use crate::models::project::Project;
+use crate::models::task::Task;
+use crate::models::trash::{TrashedProject, TrashedTask};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Tasks salvaged from a damaged projects file.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Salvage {
+    /// Salvaged tasks by project; `None` holds tasks whose project could not be read
+    pub projects: Vec<(Option<String>, Vec<Task>)>,
+    /// Number of task entries that could not be read
+    pub skipped: usize,
+}
+
+impl Salvage {
+    /// Returns the number of salvaged tasks.
+    pub fn task_count(&self) -> usize {
+        self.projects.iter().map(|(_, tasks)| tasks.len()).sum()
+    }
+
+    fn push(&mut self, project: Option<String>, task: Task) {
+        match self.projects.iter_mut().find(|(name, _)| *name == project) {
+            Some((_, tasks)) => tasks.push(task),
+            None => self.projects.push((project, vec![task])),
+        }
+    }
+}
+
+/// Salvages the tasks that can still be read from the contents of a
+/// damaged projects file.
+///
+/// Nothing is written. If the file is valid JSON, every task entry of every
+/// project is read on its own, so one bad field only loses that task. If the
+/// JSON itself is broken (e.g. a truncated file), the text is scanned for
+/// complete projects and tasks. Removed tasks and deleted projects in the
+/// trash are left out.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::persistence::recovery::salvage_tasks;
+///
+/// // A file cut off in the middle of the second task
+/// let contents = r#"{"projects": {"default": {"name": "default", "todo_list": {"tasks": [
+///     {"id": 1, "description": "Saved", "status": "Pending", "priority": "High",
+///      "due_date": null, "tags": [], "parent_id": null, "recurrence": null, "depends_on": []},
+///     {"id": 2, "description": "Lost", "sta"#;
+///
+/// let salvage = salvage_tasks(contents);
+/// assert_eq!(salvage.task_count(), 1);
+/// assert_eq!(salvage.projects[0].1[0].description, "Saved");
+/// assert_eq!(salvage.skipped, 1);
+/// ```
+pub fn salvage_tasks(contents: &str) -> Salvage {
+    match serde_json::from_str::<Value>(contents) {
+        Ok(value) if value.get("projects").is_some_and(Value::is_object) => {
+            salvage_projects(&value["projects"])
+        }
+        Ok(value) if value.get("tasks").is_some_and(Value::is_array) => {
+            let mut salvage = Salvage::default();
+            salvage_task_values(&mut salvage, None, &value["tasks"]);
+            salvage
+        }
+        _ => scan_for_tasks(contents),
+    }
+}
+
+/// Reads each project, and each task of a project that cannot be read as a whole.
+fn salvage_projects(projects: &Value) -> Salvage {
+    let mut salvage = Salvage::default();
+    let mut entries: Vec<(&String, &Value)> = projects.as_object().into_iter().flatten().collect();
+    entries.sort_by_key(|(name, _)| name.as_str());
+
+    for (key, project) in entries {
+        let name = project
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or(key)
+            .to_string();
+        match Project::deserialize(project) {
+            Ok(project) => {
+                for task in project.todo_list.get_tasks() {
+                    salvage.push(Some(name.clone()), task.clone());
+                }
+            }
+            Err(_) => salvage_task_values(&mut salvage, Some(name), &project["todo_list"]["tasks"]),
+        }
+    }
+    salvage
+}
+
+fn salvage_task_values(salvage: &mut Salvage, project: Option<String>, tasks: &Value) {
+    for task in tasks.as_array().into_iter().flatten() {
+        match Task::deserialize(task) {
+            Ok(task) => salvage.push(project.clone(), task),
+            Err(_) => salvage.skipped += 1,
+        }
+    }
+}
+
+/// Scans broken JSON for complete projects and tasks.
+///
+/// Task entries in the text that are not part of anything read are counted
+/// as skipped.
+fn scan_for_tasks(contents: &str) -> Salvage {
+    let mut salvage = Salvage::default();
+    let mut position = 0;
+    let mut unread_text = String::new();
+
+    while let Some(offset) = contents[position..].find('{') {
+        let start = position + offset;
+        let rest = &contents[start..];
+
+        let end = if let Some(length) =
+            parse_prefix::<TrashedProject>(rest).or_else(|| parse_prefix::<TrashedTask>(rest))
+        {
+            Some(start + length)
+        } else if let Some((project, length)) = parse_value::<Project>(rest) {
+            for task in project.todo_list.get_tasks() {
+                salvage.push(Some(project.name.clone()), task.clone());
+            }
+            Some(start + length)
+        } else if let Some((task, length)) = parse_value::<Task>(rest) {
+            salvage.push(None, task);
+            Some(start + length)
+        } else {
+            None
+        };
+
+        match end {
+            Some(end) => {
+                unread_text.push_str(&contents[position..start]);
+                position = end;
+            }
+            None => {
+                unread_text.push_str(&contents[position..=start]);
+                position = start + 1;
+            }
+        }
+    }
+    unread_text.push_str(&contents[position..]);
+
+    salvage.skipped = unread_text.matches("\"description\"").count();
+    salvage
+}
+
+/// Parses a value at the start of `text`, returning it with its length in bytes.
+fn parse_value<T: DeserializeOwned>(text: &str) -> Option<(T, usize)> {
+    let mut values = serde_json::Deserializer::from_str(text).into_iter::<T>();
+    let value = values.next()?.ok()?;
+    Some((value, values.byte_offset()))
+}
+
+fn parse_prefix<T: DeserializeOwned>(text: &str) -> Option<usize> {
+    parse_value::<T>(text).map(|(_, length)| length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::project_command::ProjectManager;
+    use crate::models::task::TaskWithoutId;
+
+    fn projects_json() -> String {
+        let mut manager = ProjectManager::new();
+        manager
+            .get_current_todo_list_mut()
+            .add_task(TaskWithoutId::new("First".to_string()));
+        manager
+            .get_current_todo_list_mut()
+            .add_task(TaskWithoutId::new("Removed".to_string()));
+        manager.get_current_todo_list_mut().remove_task(2).unwrap();
+        manager.create_project("Work".to_string()).unwrap();
+        manager.switch_project("Work".to_string()).unwrap();
+        manager
+            .get_current_todo_list_mut()
+            .add_task(TaskWithoutId::new("Report".to_string()));
+        serde_json::to_string_pretty(&manager).unwrap()
+    }
+
+    fn descriptions(salvage: &Salvage) -> Vec<(Option<String>, Vec<String>)> {
+        let mut projects: Vec<(Option<String>, Vec<String>)> = salvage
+            .projects
+            .iter()
+            .map(|(name, tasks)| {
+                let descriptions = tasks.iter().map(|task| task.description.clone()).collect();
+                (name.clone(), descriptions)
+            })
+            .collect();
+        projects.sort();
+        projects
+    }
+
+    #[test]
+    fn test_valid_json_with_a_bad_task_keeps_the_other_tasks() {
+        let json =
+            projects_json().replacen("\"priority\": \"Medium\"", "\"priority\": \"Urgent\"", 1);
+
+        let salvage = salvage_tasks(&json);
+        assert_eq!(salvage.skipped, 1);
+        assert_eq!(salvage.task_count(), 1);
+    }
+
+    #[test]
+    fn test_truncated_json_recovers_complete_projects() {
+        let json = projects_json();
+        let work = json.find("\"Work\"").unwrap();
+        let default = json.find("\"default\"").unwrap();
+        let (first, second) = if work < default {
+            ("Work", "default")
+        } else {
+            ("default", "Work")
+        };
+
+        // Cut the file inside the second project, after its name
+        let second_start = json.find(&format!("\"name\": \"{}\"", second)).unwrap();
+        let truncated = &json[..second_start + 20];
+
+        let salvage = salvage_tasks(truncated);
+        let expected_first = if first == "Work" { "Report" } else { "First" };
+        assert_eq!(
+            descriptions(&salvage),
+            vec![(Some(first.to_string()), vec![expected_first.to_string()])]
+        );
+    }
+
+    #[test]
+    fn test_garbage_salvages_nothing() {
+        let salvage = salvage_tasks("not json at all {");
+        assert_eq!(salvage, Salvage::default());
+    }
+}
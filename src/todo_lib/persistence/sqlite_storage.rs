@@ -0,0 +1,793 @@
+use crate::controller::project_command::ProjectManager;
+use crate::models::task::Task;
+use crate::models::todo_list::TodoList;
+use crate::models::TodoError;
+use crate::persistence::backup::{self, Backup, SessionBackups};
+use crate::persistence::changes::StorageChanges;
+use crate::persistence::load_failure::{self, LoadFailure};
+use crate::persistence::migration::SCHEMA_VERSION;
+use crate::persistence::storage::{self, Storage, StorageKind};
+use crate::persistence::todo_list_storage::DEFAULT_BACKUP_COUNT;
+use chrono::NaiveDateTime;
+use rusqlite::{named_params, Connection, OpenFlags, Transaction};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Layout of the database, kept in `PRAGMA user_version`:
+///
+/// * 1 - one JSON document per project (written with `user_version` 0)
+/// * 2 - tasks and dependencies in tables of their own
+const DATABASE_VERSION: u64 = 2;
+
+/// Tables of the current layout.
+///
+/// The top-level fields other than the projects (schema version, current
+/// project, project trash, views) are kept as one JSON value per key in
+/// `settings`. The `history` table is reserved for a change history.
+const SCHEMA: &str = "
+    CREATE TABLE settings (
+        key   TEXT PRIMARY KEY,
+        value TEXT NOT NULL
+    );
+    CREATE TABLE projects (
+        name    TEXT PRIMARY KEY,
+        next_id INTEGER NOT NULL,
+        trash   TEXT NOT NULL
+    );
+    CREATE TABLE tasks (
+        project      TEXT NOT NULL REFERENCES projects (name) ON DELETE CASCADE,
+        id           INTEGER NOT NULL,
+        position     INTEGER NOT NULL,
+        description  TEXT NOT NULL,
+        status       TEXT NOT NULL,
+        priority     TEXT NOT NULL,
+        due_date     TEXT,
+        parent_id    INTEGER,
+        recurrence   TEXT,
+        tags         TEXT NOT NULL,
+        created_at   TEXT,
+        updated_at   TEXT,
+        completed_at TEXT,
+        PRIMARY KEY (project, id)
+    );
+    CREATE TABLE dependencies (
+        project    TEXT NOT NULL,
+        task_id    INTEGER NOT NULL,
+        depends_on INTEGER NOT NULL,
+        PRIMARY KEY (project, task_id, depends_on),
+        FOREIGN KEY (project, task_id) REFERENCES tasks (project, id) ON DELETE CASCADE
+    );
+    CREATE TABLE history (
+        id      INTEGER PRIMARY KEY AUTOINCREMENT,
+        at      TEXT NOT NULL,
+        project TEXT NOT NULL,
+        task_id INTEGER,
+        change  TEXT NOT NULL
+    );
+";
+
+/// Inserts or updates a task. New tasks without a position go to the end of
+/// their project; existing tasks keep their position unless one is given.
+const UPSERT_TASK: &str = "
+    INSERT INTO tasks (
+        project, id, position, description, status, priority, due_date,
+        parent_id, recurrence, tags, created_at, updated_at, completed_at
+    ) VALUES (
+        :project, :id,
+        COALESCE(:position, (SELECT COALESCE(MAX(position), -1) + 1 FROM tasks WHERE project = :project)),
+        :description, :status, :priority, :due_date,
+        :parent_id, :recurrence, :tags, :created_at, :updated_at, :completed_at
+    )
+    ON CONFLICT (project, id) DO UPDATE SET
+        position = COALESCE(:position, position),
+        description = excluded.description,
+        status = excluded.status,
+        priority = excluded.priority,
+        due_date = excluded.due_date,
+        parent_id = excluded.parent_id,
+        recurrence = excluded.recurrence,
+        tags = excluded.tags,
+        created_at = excluded.created_at,
+        updated_at = excluded.updated_at,
+        completed_at = excluded.completed_at
+";
+
+/// Storage in a single-file SQLite database (bundled, no server needed).
+///
+/// Projects, tasks and dependencies are kept in tables of their own, so a
+/// save after a command only writes the rows that changed (see
+/// [`Storage::save_changes`]). Full saves, e.g. the first save or a
+/// conversion, run in one transaction and, like the JSON storage, the first
+/// one keeps the previous file as a timestamped backup. Every operation opens the
+/// database file on its own.
+///
+/// # Examples
+///
+/// ```no_run
+/// use todo_manager::controller::project_command::ProjectManager;
+/// use todo_manager::persistence::{SqliteStorage, Storage};
+///
+/// let storage = SqliteStorage::new("tasks.db");
+/// storage.save_projects(&ProjectManager::new()).unwrap();
+/// let projects = storage.load_projects().unwrap();
+/// ```
+pub struct SqliteStorage {
+    storage_path: PathBuf,
+    backups: SessionBackups,
+}
+
+impl SqliteStorage {
+    /// Creates a storage for the database file at `path`.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        SqliteStorage {
+            storage_path: path.as_ref().to_path_buf(),
+            backups: SessionBackups::new(DEFAULT_BACKUP_COUNT),
+        }
+    }
+
+    /// Sets how many backups are kept; 0 turns backups off.
+    pub fn with_backup_count(mut self, backup_count: usize) -> Self {
+        self.backups = SessionBackups::new(backup_count);
+        self
+    }
+
+    /// Returns the path of the database file.
+    pub fn path(&self) -> &Path {
+        &self.storage_path
+    }
+
+    /// Opens the database for writing, with foreign keys enforced.
+    fn open_for_writing(&self) -> Result<Connection, rusqlite::Error> {
+        let connection = Connection::open(&self.storage_path)?;
+        connection.execute_batch("PRAGMA foreign_keys = ON")?;
+        Ok(connection)
+    }
+
+    /// Reads the projects, returning them with the schema version of the
+    /// stored document and the layout version of the database.
+    fn read_projects(&self) -> Result<(ProjectManager, u64, u64), LoadFailure> {
+        match fs::metadata(&self.storage_path) {
+            Ok(_) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Err(LoadFailure::Missing),
+            Err(e) => {
+                return Err(LoadFailure::Unreadable(format!(
+                    "Failed to read file: {}",
+                    e
+                )))
+            }
+        }
+
+        let corrupt = |e: rusqlite::Error| {
+            LoadFailure::Corrupt(format!("Failed to read SQLite database: {}", e))
+        };
+        let connection =
+            Connection::open_with_flags(&self.storage_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+                .map_err(corrupt)?;
+        let version = database_version(&connection).map_err(corrupt)?;
+        let document = match version {
+            // An empty database holds no projects yet
+            0 => return Err(LoadFailure::Missing),
+            1 => read_document(&connection, read_project_documents),
+            DATABASE_VERSION => read_document(&connection, read_project_tables),
+            found => {
+                return Err(LoadFailure::UnsupportedVersion {
+                    found,
+                    supported: DATABASE_VERSION,
+                })
+            }
+        }
+        .map_err(corrupt)?;
+
+        let (project_manager, found) = storage::projects_from_value(document)?;
+        Ok((project_manager, found, version))
+    }
+}
+
+impl Storage for SqliteStorage {
+    fn kind(&self) -> StorageKind {
+        StorageKind::Sqlite
+    }
+
+    fn location(&self) -> String {
+        self.storage_path.display().to_string()
+    }
+
+    fn try_load_projects(&self) -> Result<ProjectManager, LoadFailure> {
+        self.read_projects()
+            .map(|(project_manager, _, _)| project_manager)
+    }
+
+    fn save_projects(&self, project_manager: &ProjectManager) -> Result<(), TodoError> {
+        let settings = settings_of(project_manager)?;
+
+        if let Some(parent) = self
+            .storage_path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)
+                .map_err(|e| TodoError::Io(format!("Failed to create parent directory: {}", e)))?;
+        }
+        self.backups.before_save(&self.storage_path)?;
+
+        let mut connection = self.open_for_writing().map_err(write_error)?;
+        write_all(&mut connection, project_manager, &settings).map_err(write_error)
+    }
+
+    fn list_backups(&self) -> Result<Vec<Backup>, TodoError> {
+        backup::list_backups(&self.storage_path, self.kind())
+    }
+
+    fn create_backup(&self) -> Result<(), TodoError> {
+        self.backups.create(&self.storage_path)
+    }
+
+    fn quarantine(&self, at: NaiveDateTime) -> Result<PathBuf, TodoError> {
+        load_failure::quarantine_file(&self.storage_path, at)
+    }
+
+    /// Databases in an older layout are rewritten as well; a current
+    /// database is left untouched.
+    fn migrate(&self) -> Result<u64, TodoError> {
+        let (project_manager, found, version) = self.read_projects()?;
+        if found < SCHEMA_VERSION || version < DATABASE_VERSION {
+            self.create_backup()?;
+            self.save_projects(&project_manager)?;
+        }
+        Ok(found)
+    }
+
+    fn is_incremental(&self) -> bool {
+        true
+    }
+
+    /// Writes the changed rows in one transaction, without taking a backup.
+    ///
+    /// Databases that are missing or use the first layout need a full save.
+    fn save_changes(
+        &self,
+        project_manager: &ProjectManager,
+        changes: &StorageChanges,
+    ) -> Result<bool, TodoError> {
+        if !self.storage_path.is_file() {
+            return Ok(false);
+        }
+        let settings = if changes.settings {
+            Some(settings_of(project_manager)?)
+        } else {
+            None
+        };
+
+        let mut connection = self.open_for_writing().map_err(write_error)?;
+        if database_version(&connection).map_err(write_error)? != DATABASE_VERSION {
+            return Ok(false);
+        }
+        write_changes(&mut connection, project_manager, changes, settings.as_ref())
+            .map_err(write_error)?;
+        Ok(true)
+    }
+}
+
+fn write_error(e: rusqlite::Error) -> TodoError {
+    TodoError::Io(format!("Failed to write SQLite database: {}", e))
+}
+
+/// Returns the layout version of an open database, or 0 for an empty one.
+fn database_version(connection: &Connection) -> Result<u64, rusqlite::Error> {
+    let version: u64 = connection.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    if version != 0 {
+        return Ok(version);
+    }
+    // The first layout did not set a version
+    let tables: u64 = connection.query_row(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'projects'",
+        [],
+        |row| row.get(0),
+    )?;
+    Ok(if tables > 0 { 1 } else { 0 })
+}
+
+/// Reads the stored document, as it would look in a JSON file, using
+/// `read_projects` for the projects of the database's layout.
+fn read_document(
+    connection: &Connection,
+    read_projects: fn(&Connection) -> Result<Map<String, Value>, rusqlite::Error>,
+) -> Result<Value, rusqlite::Error> {
+    let mut document = Map::new();
+    let mut statement = connection.prepare("SELECT key, value FROM settings")?;
+    let rows = statement.query_map([], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+    })?;
+    for row in rows {
+        let (key, value) = row?;
+        document.insert(key, parse_json(&value)?);
+    }
+    document.insert(
+        "projects".to_string(),
+        Value::Object(read_projects(connection)?),
+    );
+    Ok(Value::Object(document))
+}
+
+/// Reads the projects of the first layout, one JSON document each.
+fn read_project_documents(connection: &Connection) -> Result<Map<String, Value>, rusqlite::Error> {
+    let mut projects = Map::new();
+    let mut statement = connection.prepare("SELECT name, data FROM projects")?;
+    let rows = statement.query_map([], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+    })?;
+    for row in rows {
+        let (name, data) = row?;
+        projects.insert(name, parse_json(&data)?);
+    }
+    Ok(projects)
+}
+
+/// Reads the projects from the project, task and dependency tables.
+fn read_project_tables(connection: &Connection) -> Result<Map<String, Value>, rusqlite::Error> {
+    let mut dependencies: HashMap<(String, i64), Vec<i64>> = HashMap::new();
+    let mut statement = connection
+        .prepare("SELECT project, task_id, depends_on FROM dependencies ORDER BY rowid")?;
+    let rows = statement.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, i64>(1)?,
+            row.get::<_, i64>(2)?,
+        ))
+    })?;
+    for row in rows {
+        let (project, task_id, depends_on) = row?;
+        dependencies
+            .entry((project, task_id))
+            .or_default()
+            .push(depends_on);
+    }
+
+    let mut tasks: HashMap<String, Vec<Value>> = HashMap::new();
+    let mut statement = connection.prepare(
+        "SELECT project, id, description, status, priority, due_date, parent_id,
+                recurrence, tags, created_at, updated_at, completed_at
+         FROM tasks ORDER BY project, position",
+    )?;
+    let mut rows = statement.query([])?;
+    while let Some(row) = rows.next()? {
+        let project: String = row.get(0)?;
+        let id: i64 = row.get(1)?;
+        let recurrence = match row.get::<_, Option<String>>(7)? {
+            Some(recurrence) => parse_json(&recurrence)?,
+            None => Value::Null,
+        };
+        let task = json!({
+            "id": id,
+            "description": row.get::<_, String>(2)?,
+            "status": row.get::<_, String>(3)?,
+            "priority": row.get::<_, String>(4)?,
+            "due_date": row.get::<_, Option<String>>(5)?,
+            "tags": parse_json(&row.get::<_, String>(8)?)?,
+            "parent_id": row.get::<_, Option<i64>>(6)?,
+            "recurrence": recurrence,
+            "depends_on": dependencies.remove(&(project.clone(), id)).unwrap_or_default(),
+            "created_at": row.get::<_, Option<String>>(9)?,
+            "updated_at": row.get::<_, Option<String>>(10)?,
+            "completed_at": row.get::<_, Option<String>>(11)?,
+        });
+        tasks.entry(project).or_default().push(task);
+    }
+
+    let mut projects = Map::new();
+    let mut statement = connection.prepare("SELECT name, next_id, trash FROM projects")?;
+    let mut rows = statement.query([])?;
+    while let Some(row) = rows.next()? {
+        let name: String = row.get(0)?;
+        let project = json!({
+            "name": name,
+            "todo_list": {
+                "tasks": tasks.remove(&name).unwrap_or_default(),
+                "next_id": row.get::<_, i64>(1)?,
+                "trash": parse_json(&row.get::<_, String>(2)?)?,
+            },
+        });
+        projects.insert(name, project);
+    }
+    Ok(projects)
+}
+
+/// Returns the top-level fields of the stored document other than the projects.
+fn settings_of(project_manager: &ProjectManager) -> Result<Map<String, Value>, TodoError> {
+    match storage::projects_to_value(project_manager)? {
+        Value::Object(mut document) => {
+            document.remove("projects");
+            Ok(document)
+        }
+        _ => Err(TodoError::Serialization(
+            "Projects are not stored as an object".to_string(),
+        )),
+    }
+}
+
+/// Replaces the whole database, including the tables of the first layout.
+fn write_all(
+    connection: &mut Connection,
+    project_manager: &ProjectManager,
+    settings: &Map<String, Value>,
+) -> Result<(), rusqlite::Error> {
+    let transaction = connection.transaction()?;
+    transaction.execute_batch(
+        "DROP TABLE IF EXISTS history;
+         DROP TABLE IF EXISTS dependencies;
+         DROP TABLE IF EXISTS tasks;
+         DROP TABLE IF EXISTS projects;
+         DROP TABLE IF EXISTS settings;",
+    )?;
+    transaction.execute_batch(SCHEMA)?;
+    transaction.pragma_update(None, "user_version", DATABASE_VERSION)?;
+
+    write_settings(&transaction, settings)?;
+    for name in project_manager.list_projects() {
+        if let Some(project) = project_manager.get_project(&name) {
+            write_project(&transaction, &name, &project.todo_list)?;
+            for (position, task) in project.todo_list.get_tasks().iter().enumerate() {
+                write_task(&transaction, &name, task, Some(position))?;
+            }
+        }
+    }
+    transaction.commit()
+}
+
+/// Writes the rows in `changes`, taking their contents from `project_manager`.
+fn write_changes(
+    connection: &mut Connection,
+    project_manager: &ProjectManager,
+    changes: &StorageChanges,
+    settings: Option<&Map<String, Value>>,
+) -> Result<(), rusqlite::Error> {
+    let todo_list = |name: &str| {
+        project_manager
+            .get_project(name)
+            .map(|project| &project.todo_list)
+    };
+    let transaction = connection.transaction()?;
+
+    if let Some(settings) = settings {
+        write_settings(&transaction, settings)?;
+    }
+    for name in &changes.removed_projects {
+        transaction.execute("DELETE FROM projects WHERE name = ?1", [name])?;
+    }
+    for name in &changes.saved_projects {
+        if let Some(todo_list) = todo_list(name) {
+            write_project(&transaction, name, todo_list)?;
+        }
+    }
+    for (name, id) in &changes.removed_tasks {
+        transaction.execute(
+            "DELETE FROM tasks WHERE project = ?1 AND id = ?2",
+            (name, *id as i64),
+        )?;
+    }
+    for (name, id) in &changes.saved_tasks {
+        let task = todo_list(name)
+            .and_then(|todo_list| todo_list.get_tasks().iter().find(|task| task.id == *id));
+        if let Some(task) = task {
+            write_task(&transaction, name, task, None)?;
+        }
+    }
+    for name in &changes.reordered_projects {
+        let tasks = todo_list(name).map(TodoList::get_tasks);
+        for (position, task) in tasks.into_iter().flatten().enumerate() {
+            transaction.execute(
+                "UPDATE tasks SET position = ?1 WHERE project = ?2 AND id = ?3",
+                (position as i64, name, task.id as i64),
+            )?;
+        }
+    }
+    transaction.commit()
+}
+
+fn write_settings(
+    transaction: &Transaction,
+    settings: &Map<String, Value>,
+) -> Result<(), rusqlite::Error> {
+    transaction.execute("DELETE FROM settings", [])?;
+    for (key, value) in settings {
+        transaction.execute(
+            "INSERT INTO settings (key, value) VALUES (?1, ?2)",
+            (key, value.to_string()),
+        )?;
+    }
+    Ok(())
+}
+
+fn write_project(
+    transaction: &Transaction,
+    name: &str,
+    todo_list: &TodoList,
+) -> Result<(), rusqlite::Error> {
+    let trash = serde_json::to_string(todo_list.get_trash()).map_err(conversion_error)?;
+    transaction.execute(
+        "INSERT INTO projects (name, next_id, trash) VALUES (?1, ?2, ?3)
+         ON CONFLICT (name) DO UPDATE SET next_id = excluded.next_id, trash = excluded.trash",
+        (name, todo_list.next_id() as i64, trash),
+    )?;
+    Ok(())
+}
+
+/// Writes a task and its dependencies; see `UPSERT_TASK` for the position.
+fn write_task(
+    transaction: &Transaction,
+    project: &str,
+    task: &Task,
+    position: Option<usize>,
+) -> Result<(), rusqlite::Error> {
+    // Columns hold the values as they are written to a JSON file
+    let value = serde_json::to_value(task).map_err(conversion_error)?;
+    let text = |key: &str| value.get(key).and_then(Value::as_str);
+    let json = |key: &str| {
+        value
+            .get(key)
+            .filter(|value| !value.is_null())
+            .map(Value::to_string)
+    };
+
+    transaction.execute(
+        UPSERT_TASK,
+        named_params! {
+            ":project": project,
+            ":id": task.id as i64,
+            ":position": position.map(|position| position as i64),
+            ":description": &task.description,
+            ":status": text("status"),
+            ":priority": text("priority"),
+            ":due_date": text("due_date"),
+            ":parent_id": task.parent_id.map(|id| id as i64),
+            ":recurrence": json("recurrence"),
+            ":tags": json("tags"),
+            ":created_at": text("created_at"),
+            ":updated_at": text("updated_at"),
+            ":completed_at": text("completed_at"),
+        },
+    )?;
+
+    transaction.execute(
+        "DELETE FROM dependencies WHERE project = ?1 AND task_id = ?2",
+        (project, task.id as i64),
+    )?;
+    for depends_on in &task.depends_on {
+        transaction.execute(
+            "INSERT OR IGNORE INTO dependencies (project, task_id, depends_on) VALUES (?1, ?2, ?3)",
+            (project, task.id as i64, *depends_on as i64),
+        )?;
+    }
+    Ok(())
+}
+
+fn parse_json(text: &str) -> Result<Value, rusqlite::Error> {
+    serde_json::from_str(text).map_err(|e| {
+        rusqlite::Error::FromSqlConversionFailure(0, rusqlite::types::Type::Text, Box::new(e))
+    })
+}
+
+fn conversion_error(e: serde_json::Error) -> rusqlite::Error {
+    rusqlite::Error::ToSqlConversionFailure(Box::new(e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::priority::Priority;
+    use crate::models::recurrence::Recurrence;
+    use crate::models::task::TaskWithoutId;
+
+    fn temp_storage(name: &str) -> (PathBuf, SqliteStorage) {
+        let dir = std::env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&dir);
+        let storage = SqliteStorage::new(dir.join("tasks.db"));
+        (dir, storage)
+    }
+
+    fn sample_projects() -> ProjectManager {
+        let mut manager = ProjectManager::new();
+        let todo_list = manager.get_current_todo_list_mut();
+        let mut first = TaskWithoutId::new("Water plants".to_string());
+        first.priority = Priority::High;
+        first.due_date = chrono::NaiveDate::from_ymd_opt(2025, 1, 17);
+        first.tags = vec!["home".to_string(), "garden".to_string()];
+        first.recurrence = Some(Recurrence::Weekly);
+        let first = todo_list.add_task(first);
+        let mut second = TaskWithoutId::new("Buy soil".to_string());
+        second.parent_id = Some(first);
+        let second = todo_list.add_task(second);
+        todo_list.add_task_dependency(first, second).unwrap();
+        todo_list.complete_task(second).unwrap();
+        let removed = todo_list.add_task(TaskWithoutId::new("Removed".to_string()));
+        todo_list.remove_task(removed).unwrap();
+        manager.create_project("Work".to_string()).unwrap();
+        manager.delete_project("Work".to_string()).unwrap();
+        manager
+    }
+
+    fn row_count(storage: &SqliteStorage, table: &str) -> i64 {
+        let connection = Connection::open(storage.path()).unwrap();
+        connection
+            .query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| {
+                row.get(0)
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_tasks_and_dependencies_are_stored_in_tables() {
+        let (dir, storage) = temp_storage("todo_sqlite_tables_test");
+        let projects = sample_projects();
+        storage.save_projects(&projects).unwrap();
+
+        assert_eq!(storage.load_projects().unwrap(), projects);
+        assert_eq!(row_count(&storage, "projects"), 1);
+        assert_eq!(row_count(&storage, "tasks"), 2);
+        assert_eq!(row_count(&storage, "dependencies"), 1);
+        assert_eq!(row_count(&storage, "history"), 0);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_save_changes_writes_changed_rows_without_backup() {
+        let (dir, storage) = temp_storage("todo_sqlite_changes_test");
+        let before = sample_projects();
+        storage.save_projects(&before).unwrap();
+
+        let mut after = before.clone();
+        after.create_project("Home".to_string()).unwrap();
+        let todo_list = after.get_current_todo_list_mut();
+        todo_list.remove_task(2).unwrap();
+        todo_list.restore_task(3).unwrap();
+        let added = todo_list.add_task(TaskWithoutId::new("Added".to_string()));
+        todo_list.add_task_dependency(added, 1).unwrap();
+
+        let changes = StorageChanges::between(&before, &after);
+        assert!(!changes.is_empty());
+        assert_eq!(storage.save_changes(&after, &changes), Ok(true));
+        assert_eq!(storage.load_projects().unwrap(), after);
+        assert!(storage.list_backups().unwrap().is_empty());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_save_changes_needs_an_existing_database() {
+        let (dir, storage) = temp_storage("todo_sqlite_missing_test");
+        let projects = sample_projects();
+        let changes = StorageChanges::between(&ProjectManager::new(), &projects);
+
+        assert_eq!(storage.save_changes(&projects, &changes), Ok(false));
+        assert!(!storage.path().exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_first_layout_is_read_and_replaced_on_save() {
+        let (dir, storage) = temp_storage("todo_sqlite_first_layout_test");
+        fs::create_dir_all(&dir).unwrap();
+        let projects = sample_projects();
+
+        // Write the projects the way the first layout stored them
+        let settings = settings_of(&projects).unwrap();
+        let document = storage::projects_to_value(&projects).unwrap();
+        let connection = Connection::open(storage.path()).unwrap();
+        connection
+            .execute_batch(
+                "CREATE TABLE settings (key TEXT PRIMARY KEY, value TEXT NOT NULL);
+                 CREATE TABLE projects (name TEXT PRIMARY KEY, data TEXT NOT NULL);",
+            )
+            .unwrap();
+        for (key, value) in &settings {
+            connection
+                .execute(
+                    "INSERT INTO settings VALUES (?1, ?2)",
+                    (key, value.to_string()),
+                )
+                .unwrap();
+        }
+        for (name, data) in document["projects"].as_object().unwrap() {
+            connection
+                .execute(
+                    "INSERT INTO projects VALUES (?1, ?2)",
+                    (name, data.to_string()),
+                )
+                .unwrap();
+        }
+        drop(connection);
+
+        assert_eq!(storage.load_projects().unwrap(), projects);
+        let changes = StorageChanges::between(&ProjectManager::new(), &projects);
+        assert_eq!(storage.save_changes(&projects, &changes), Ok(false));
+
+        assert_eq!(storage.migrate(), Ok(SCHEMA_VERSION));
+        assert_eq!(row_count(&storage, "tasks"), 2);
+        assert_eq!(storage.load_projects().unwrap(), projects);
+        assert_eq!(storage.list_backups().unwrap().len(), 1);
+
+        // A current database is left untouched
+        assert_eq!(storage.migrate(), Ok(SCHEMA_VERSION));
+        assert_eq!(storage.list_backups().unwrap().len(), 1);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_newer_layout_is_unsupported() {
+        let (dir, storage) = temp_storage("todo_sqlite_newer_test");
+        fs::create_dir_all(&dir).unwrap();
+        let connection = Connection::open(storage.path()).unwrap();
+        connection
+            .pragma_update(None, "user_version", DATABASE_VERSION + 1)
+            .unwrap();
+        drop(connection);
+
+        assert_eq!(
+            storage.try_load_projects(),
+            Err(LoadFailure::UnsupportedVersion {
+                found: DATABASE_VERSION + 1,
+                supported: DATABASE_VERSION
+            })
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_save_keeps_backup_and_replaces_projects() {
+        let (dir, storage) = temp_storage("todo_sqlite_storage_test");
+
+        let mut project_manager = ProjectManager::new();
+        project_manager.create_project("Work".to_string()).unwrap();
+        storage.save_projects(&project_manager).unwrap();
+
+        project_manager.delete_project("Work".to_string()).unwrap();
+        project_manager
+            .get_current_todo_list_mut()
+            .add_task(TaskWithoutId::new("Task 1".to_string()));
+        storage.save_projects(&project_manager).unwrap();
+
+        assert_eq!(storage.load_projects().unwrap(), project_manager);
+        let backups = storage.list_backups().unwrap();
+        assert_eq!(backups.len(), 1);
+        assert_eq!(backups[0].load().unwrap().project_count(), 2);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_backups_are_read_as_sqlite_whatever_their_extension() {
+        let (dir, _) = temp_storage("todo_sqlite_json_name_test");
+        let storage = SqliteStorage::new(dir.join("tasks.json"));
+
+        let mut project_manager = ProjectManager::new();
+        storage.save_projects(&project_manager).unwrap();
+        project_manager.create_project("Work".to_string()).unwrap();
+        storage.save_projects(&project_manager).unwrap();
+
+        let backups = storage.list_backups().unwrap();
+        assert_eq!(backups[0].kind, StorageKind::Sqlite);
+        assert_eq!(backups[0].load().unwrap(), ProjectManager::new());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_file_that_is_not_a_database_is_corrupt() {
+        let (dir, storage) = temp_storage("todo_sqlite_corrupt_test");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(storage.path(), "not a database").unwrap();
+
+        assert!(matches!(
+            storage.try_load_projects(),
+            Err(LoadFailure::Corrupt(_))
+        ));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
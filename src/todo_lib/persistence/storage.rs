@@ -0,0 +1,332 @@
+use crate::controller::project_command::ProjectManager;
+use crate::models::TodoError;
+use crate::persistence::backup::Backup;
+use crate::persistence::changes::StorageChanges;
+use crate::persistence::load_failure::LoadFailure;
+use crate::persistence::memory_storage::MemoryStorage;
+use crate::persistence::migration::{self, SCHEMA_VERSION};
+use crate::persistence::sqlite_storage::SqliteStorage;
+use crate::persistence::todo_list_storage::{JsonFormat, TodoListStorage};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// Where the projects are kept between sessions.
+///
+/// A backend only has to load and save all projects at once. Backends that
+/// can write single rows override `is_incremental` and `save_changes`, so
+/// that a save only writes what changed since the last load or save.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::controller::project_command::ProjectManager;
+/// use todo_manager::persistence::{LoadFailure, MemoryStorage, Storage};
+///
+/// let storage = MemoryStorage::new();
+/// assert_eq!(storage.try_load_projects(), Err(LoadFailure::Missing));
+///
+/// storage.save_projects(&ProjectManager::new()).unwrap();
+/// assert_eq!(storage.load_projects().unwrap().project_count(), 1);
+/// ```
+pub trait Storage {
+    /// Returns the kind of backend.
+    fn kind(&self) -> StorageKind;
+
+    /// Describes where the projects are stored, e.g. the file path.
+    fn location(&self) -> String;
+
+    /// Loads all projects, telling apart why loading failed.
+    ///
+    /// # Returns
+    ///
+    /// The projects, or the `LoadFailure` saying whether the data is
+    /// missing, unreadable, corrupt or from a newer version
+    fn try_load_projects(&self) -> Result<ProjectManager, LoadFailure>;
+
+    /// Replaces the stored projects with `project_manager`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or a `TodoError::Io` or `TodoError::Serialization` describing the failure
+    fn save_projects(&self, project_manager: &ProjectManager) -> Result<(), TodoError>;
+
+    /// Loads all projects like `try_load_projects`, as a `TodoError`.
+    fn load_projects(&self) -> Result<ProjectManager, TodoError> {
+        self.try_load_projects().map_err(TodoError::from)
+    }
+
+    /// Lists the backups of the stored data, newest first.
+    fn list_backups(&self) -> Result<Vec<Backup>, TodoError> {
+        Ok(Vec::new())
+    }
+
+    /// Backs up the stored data now, even if a save already took a backup.
+    ///
+    /// Backends without backups do nothing.
+    fn create_backup(&self) -> Result<(), TodoError> {
+        Ok(())
+    }
+
+    /// Moves corrupt data aside, so that the next save starts afresh.
+    ///
+    /// # Returns
+    ///
+    /// Where the data was moved to, or a `TodoError::Io` if it could not be moved
+    fn quarantine(&self, _at: NaiveDateTime) -> Result<PathBuf, TodoError> {
+        Err(TodoError::Io(format!(
+            "{} cannot be moved aside",
+            self.location()
+        )))
+    }
+
+    /// Upgrades the stored data in place to the current [`SCHEMA_VERSION`],
+    /// keeping the old data as a backup.
+    ///
+    /// # Returns
+    ///
+    /// The schema version the data had, or a `TodoError` if it cannot be
+    /// loaded or saved, or the backend keeps nothing to migrate
+    fn migrate(&self) -> Result<u64, TodoError> {
+        Err(TodoError::Io(format!(
+            "{} has no stored data to migrate",
+            self.location()
+        )))
+    }
+
+    /// Whether the backend can write single rows with `save_changes`.
+    fn is_incremental(&self) -> bool {
+        false
+    }
+
+    /// Writes only the rows in `changes`, taking their contents from
+    /// `project_manager`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(true)` if the changes were written, `Ok(false)` if the backend
+    /// cannot write them on their own and all projects must be saved instead
+    fn save_changes(
+        &self,
+        _project_manager: &ProjectManager,
+        _changes: &StorageChanges,
+    ) -> Result<bool, TodoError> {
+        Ok(false)
+    }
+}
+
+/// The available storage backends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageKind {
+    /// Pretty-printed JSON file
+    Json,
+    /// JSON file without whitespace
+    CompactJson,
+    /// Single-file SQLite database
+    Sqlite,
+    /// Kept in memory only, nothing is written
+    Memory,
+}
+
+impl StorageKind {
+    /// All backends, in the order they are listed to the user.
+    pub const ALL: [StorageKind; 4] = [
+        StorageKind::Json,
+        StorageKind::CompactJson,
+        StorageKind::Sqlite,
+        StorageKind::Memory,
+    ];
+
+    /// Returns the name of the backend, as accepted by `parse`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageKind::Json => "json",
+            StorageKind::CompactJson => "compact-json",
+            StorageKind::Sqlite => "sqlite",
+            StorageKind::Memory => "memory",
+        }
+    }
+
+    /// Parses a backend name (case-insensitive).
+    pub fn parse(name: &str) -> Option<StorageKind> {
+        let name = name.to_lowercase();
+        StorageKind::ALL
+            .into_iter()
+            .find(|kind| kind.as_str() == name)
+    }
+
+    /// Chooses the backend for a file by its extension.
+    ///
+    /// `.db`, `.sqlite` and `.sqlite3` files are SQLite databases, all other
+    /// files are pretty-printed JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::persistence::StorageKind;
+    ///
+    /// assert_eq!(StorageKind::from_path("tasks.db"), StorageKind::Sqlite);
+    /// assert_eq!(StorageKind::from_path("tasks.json"), StorageKind::Json);
+    /// ```
+    pub fn from_path<P: AsRef<Path>>(path: P) -> StorageKind {
+        let extension = path
+            .as_ref()
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        match extension.as_deref() {
+            Some("db" | "sqlite" | "sqlite3") => StorageKind::Sqlite,
+            _ => StorageKind::Json,
+        }
+    }
+
+    /// Creates a backend of this kind for the file at `path`.
+    ///
+    /// Nothing is read or written until the backend is used; the memory
+    /// backend ignores the path.
+    pub fn open<P: AsRef<Path>>(&self, path: P) -> Box<dyn Storage> {
+        match self {
+            StorageKind::Json => Box::new(TodoListStorage::new(path)),
+            StorageKind::CompactJson => {
+                Box::new(TodoListStorage::new(path).with_format(JsonFormat::Compact))
+            }
+            StorageKind::Sqlite => Box::new(SqliteStorage::new(path)),
+            StorageKind::Memory => Box::new(MemoryStorage::new()),
+        }
+    }
+}
+
+/// Creates the backend for the file at `path`, chosen by its extension.
+pub fn open_storage<P: AsRef<Path>>(path: P) -> Box<dyn Storage> {
+    StorageKind::from_path(&path).open(path)
+}
+
+/// A ProjectManager as it is saved, with the current [`SCHEMA_VERSION`] first.
+#[derive(Serialize)]
+pub(crate) struct VersionedProjects<'a> {
+    schema_version: u64,
+    #[serde(flatten)]
+    projects: &'a ProjectManager,
+}
+
+impl<'a> VersionedProjects<'a> {
+    pub(crate) fn new(projects: &'a ProjectManager) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            projects,
+        }
+    }
+}
+
+/// Converts projects into the stored document.
+pub(crate) fn projects_to_value(project_manager: &ProjectManager) -> Result<Value, TodoError> {
+    serde_json::to_value(VersionedProjects::new(project_manager))
+        .map_err(|e| TodoError::Serialization(format!("Failed to serialize ProjectManager: {}", e)))
+}
+
+/// Reads projects from a stored document of any supported schema version.
+///
+/// A document whose current project is not one of its projects is corrupt.
+///
+/// # Returns
+///
+/// The projects together with the schema version the document had
+pub(crate) fn projects_from_value(value: Value) -> Result<(ProjectManager, u64), LoadFailure> {
+    let (value, found) = migration::migrate(value)?;
+    let project_manager = ProjectManager::deserialize(&value)
+        .map_err(|e| LoadFailure::Corrupt(format!("Failed to read projects: {}", e)))?;
+    let current_project = project_manager.get_current_project_name();
+    if project_manager.get_project(current_project).is_none() {
+        return Err(LoadFailure::Corrupt(format!(
+            "Failed to read projects: current project '{}' does not exist",
+            current_project
+        )));
+    }
+    Ok((project_manager, found))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::task::TaskWithoutId;
+    use std::fs;
+
+    fn sample_projects() -> ProjectManager {
+        let mut manager = ProjectManager::new();
+        manager.create_project("Work".to_string()).unwrap();
+        manager.switch_project("Work".to_string()).unwrap();
+        manager
+            .get_current_todo_list_mut()
+            .add_task(TaskWithoutId::new("Report".to_string()));
+        manager
+    }
+
+    #[test]
+    fn test_parse_and_name_round_trip() {
+        for kind in StorageKind::ALL {
+            assert_eq!(StorageKind::parse(kind.as_str()), Some(kind));
+        }
+        assert_eq!(StorageKind::parse("SQLite"), Some(StorageKind::Sqlite));
+        assert_eq!(StorageKind::parse("xml"), None);
+    }
+
+    #[test]
+    fn test_kind_from_extension() {
+        assert_eq!(
+            StorageKind::from_path("a/tasks.sqlite3"),
+            StorageKind::Sqlite
+        );
+        assert_eq!(StorageKind::from_path("tasks.DB"), StorageKind::Sqlite);
+        assert_eq!(StorageKind::from_path("tasks"), StorageKind::Json);
+    }
+
+    #[test]
+    fn test_every_file_backend_round_trips_projects() {
+        let dir = std::env::temp_dir().join("todo_storage_backends_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let projects = sample_projects();
+
+        for (kind, file_name) in [
+            (StorageKind::Json, "tasks.json"),
+            (StorageKind::CompactJson, "compact.json"),
+            (StorageKind::Sqlite, "tasks.db"),
+        ] {
+            let storage = kind.open(dir.join(file_name));
+            assert_eq!(storage.kind(), kind);
+            assert_eq!(storage.try_load_projects(), Err(LoadFailure::Missing));
+
+            storage.save_projects(&projects).unwrap();
+            assert_eq!(storage.load_projects().unwrap(), projects, "{:?}", kind);
+        }
+
+        let compact = fs::read_to_string(dir.join("compact.json")).unwrap();
+        assert!(!compact.contains('\n'));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_unknown_current_project_is_corrupt() {
+        let mut value = projects_to_value(&sample_projects()).unwrap();
+        value["current_project"] = Value::from("Missing");
+
+        assert_eq!(
+            projects_from_value(value),
+            Err(LoadFailure::Corrupt(
+                "Failed to read projects: current project 'Missing' does not exist".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_backends_do_not_support_incremental_writes_by_default() {
+        let storage = MemoryStorage::new();
+        let projects = sample_projects();
+        let changes = StorageChanges::between(&ProjectManager::new(), &projects);
+        assert!(!storage.is_incremental());
+        assert_eq!(storage.save_changes(&projects, &changes), Ok(false));
+        assert!(storage.quarantine(NaiveDateTime::default()).is_err());
+        assert!(storage.migrate().is_err());
+    }
+}
@@ -1,13 +1,38 @@
 use crate::controller::project_command::ProjectManager;
-use crate::models::TodoList;
+use crate::models::{TodoError, TodoList};
+use crate::persistence::backup::{self, Backup, SessionBackups};
+use crate::persistence::load_failure::{self, LoadFailure};
+use crate::persistence::migration::SCHEMA_VERSION;
+use crate::persistence::storage::{self, Storage, StorageKind, VersionedProjects};
+use chrono::NaiveDateTime;
+use serde_json::Value;
 use std::fs;
-use std::io::Write;
+use std::io::{ErrorKind, Write};
 use std::path::{Path, PathBuf};
 
+/// Number of backups kept by default.
+pub const DEFAULT_BACKUP_COUNT: usize = 10;
+
+/// How projects are written to the JSON file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JsonFormat {
+    /// Indented, one field per line
+    #[default]
+    Pretty,
+    /// Without whitespace, for smaller files
+    Compact,
+}
+
 /// Handles persistence operations for TodoList.
 ///
 /// This struct encapsulates all file I/O operations for saving and loading
-/// todo lists, keeping storage concerns separate from business logic.
+/// todo lists, keeping storage concerns separate from business logic. It is
+/// the [`Storage`] backend for pretty or compact JSON files.
+///
+/// Saves are crash-safe: the data is written to a temporary file next to the
+/// target, flushed to disk and then renamed over the target, so the file is
+/// never left half-written. Before the first save of a storage instance the
+/// previous file is kept as a timestamped backup (see [`Backup`]).
 ///
 /// # Examples
 ///
@@ -26,6 +51,8 @@ use std::path::{Path, PathBuf};
 /// ```
 pub struct TodoListStorage {
     storage_path: PathBuf,
+    backups: SessionBackups,
+    format: JsonFormat,
 }
 
 impl TodoListStorage {
@@ -45,12 +72,69 @@ impl TodoListStorage {
     pub fn new<P: AsRef<Path>>(path: P) -> Self {
         TodoListStorage {
             storage_path: path.as_ref().to_path_buf(),
+            backups: SessionBackups::new(DEFAULT_BACKUP_COUNT),
+            format: JsonFormat::default(),
+        }
+    }
+
+    /// Sets how many backups are kept; 0 turns backups off.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::persistence::TodoListStorage;
+    ///
+    /// let storage = TodoListStorage::new("tasks.json").with_backup_count(3);
+    /// ```
+    pub fn with_backup_count(mut self, backup_count: usize) -> Self {
+        self.backups = SessionBackups::new(backup_count);
+        self
+    }
+
+    /// Sets how projects are written; files of either format can be loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::persistence::todo_list_storage::JsonFormat;
+    /// use todo_manager::persistence::TodoListStorage;
+    ///
+    /// let storage = TodoListStorage::new("tasks.json").with_format(JsonFormat::Compact);
+    /// ```
+    pub fn with_format(mut self, format: JsonFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Returns the path of the storage file.
+    pub fn path(&self) -> &Path {
+        &self.storage_path
+    }
+
+    /// Backs up the current file on the first save, then replaces it with `json`.
+    fn write(&self, json: &str) -> Result<(), TodoError> {
+        let path = &self.storage_path;
+
+        // Create parent directories if they don't exist
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            if !parent.exists() {
+                fs::create_dir_all(parent).map_err(|e| {
+                    TodoError::Io(format!("Failed to create parent directory: {}", e))
+                })?;
+            }
         }
+
+        self.backups.before_save(path)?;
+        write_atomically(path, json.as_bytes())
     }
 
     /// Saves a TodoList to a JSON file at the configured storage path.
     ///
-    /// Creates parent directories if they don't exist.
+    /// Creates parent directories if they don't exist, and keeps the previous
+    /// file as a backup.
     ///
     /// # Arguments
     ///
@@ -58,7 +142,7 @@ impl TodoListStorage {
     ///
     /// # Returns
     ///
-    /// `Ok(())` on success, or `Err(String)` with an error message on failure.
+    /// `Ok(())` on success, or a `TodoError::Io` or `TodoError::Serialization` describing the failure.
     ///
     /// # Examples
     ///
@@ -70,36 +154,20 @@ impl TodoListStorage {
     /// let todo_list = TodoList::new();
     /// storage.save(&todo_list).unwrap();
     /// ```
-    pub fn save(&self, todo_list: &TodoList) -> Result<(), String> {
-        let path = &self.storage_path;
-
-        // Create parent directories if they don't exist
-        if let Some(parent) = path.parent() {
-            if !parent.exists() {
-                fs::create_dir_all(parent)
-                    .map_err(|e| format!("Failed to create parent directory: {}", e))?;
-            }
-        }
-
+    pub fn save(&self, todo_list: &TodoList) -> Result<(), TodoError> {
         // Serialize to pretty JSON
-        let json = serde_json::to_string_pretty(&todo_list)
-            .map_err(|e| format!("Failed to serialize TodoList: {}", e))?;
-
-        // Write to file
-        let mut file =
-            fs::File::create(path).map_err(|e| format!("Failed to create file: {}", e))?;
-
-        file.write_all(json.as_bytes())
-            .map_err(|e| format!("Failed to write to file: {}", e))?;
+        let json = serde_json::to_string_pretty(&todo_list).map_err(|e| {
+            TodoError::Serialization(format!("Failed to serialize TodoList: {}", e))
+        })?;
 
-        Ok(())
+        self.write(&json)
     }
 
     /// Loads a TodoList from a JSON file at the configured storage path.
     ///
     /// # Returns
     ///
-    /// `Ok(TodoList)` on success, or `Err(String)` with an error message on failure.
+    /// `Ok(TodoList)` on success, or a `TodoError::Io` or `TodoError::Serialization` describing the failure.
     ///
     /// # Examples
     ///
@@ -109,86 +177,141 @@ impl TodoListStorage {
     /// let storage = TodoListStorage::new("tasks.json");
     /// let todo_list = storage.load().unwrap();
     /// ```
-    pub fn load(&self) -> Result<TodoList, String> {
+    pub fn load(&self) -> Result<TodoList, TodoError> {
         let path = &self.storage_path;
 
         // Read file contents
-        let contents =
-            fs::read_to_string(path).map_err(|e| format!("Failed to read file: {}", e))?;
+        let contents = fs::read_to_string(path)
+            .map_err(|e| TodoError::Io(format!("Failed to read file: {}", e)))?;
 
         // Deserialize from JSON
-        let todo_list: TodoList =
-            serde_json::from_str(&contents).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+        let todo_list: TodoList = serde_json::from_str(&contents)
+            .map_err(|e| TodoError::Serialization(format!("Failed to parse JSON: {}", e)))?;
 
         Ok(todo_list)
     }
 
-    /// Saves a ProjectManager to a JSON file at the configured storage path.
-    ///
-    /// Creates parent directories if they don't exist.
-    ///
-    /// # Arguments
-    ///
-    /// * `project_manager` - The ProjectManager to save
-    ///
-    /// # Returns
-    ///
-    /// `Ok(())` on success, or `Err(String)` with an error message on failure.
-    pub fn save_projects(&self, project_manager: &ProjectManager) -> Result<(), String> {
-        let path = &self.storage_path;
-
-        // Create parent directories if they don't exist
-        if let Some(parent) = path.parent() {
-            if !parent.exists() {
-                fs::create_dir_all(parent)
-                    .map_err(|e| format!("Failed to create parent directory: {}", e))?;
+    /// Reads and upgrades the storage file, returning the projects with the
+    /// schema version the file had.
+    fn read_projects(&self) -> Result<(ProjectManager, u64), LoadFailure> {
+        let contents = match fs::read_to_string(&self.storage_path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Err(LoadFailure::Missing),
+            Err(e) => {
+                return Err(LoadFailure::Unreadable(format!(
+                    "Failed to read file: {}",
+                    e
+                )))
             }
-        }
+        };
 
-        // Serialize to pretty JSON
-        let json = serde_json::to_string_pretty(&project_manager)
-            .map_err(|e| format!("Failed to serialize ProjectManager: {}", e))?;
-
-        // Write to file
-        let mut file =
-            fs::File::create(path).map_err(|e| format!("Failed to create file: {}", e))?;
+        let value: Value = serde_json::from_str(&contents)
+            .map_err(|e| LoadFailure::Corrupt(format!("Failed to parse JSON: {}", e)))?;
+        storage::projects_from_value(value)
+    }
+}
 
-        file.write_all(json.as_bytes())
-            .map_err(|e| format!("Failed to write to file: {}", e))?;
+impl Storage for TodoListStorage {
+    fn kind(&self) -> StorageKind {
+        match self.format {
+            JsonFormat::Pretty => StorageKind::Json,
+            JsonFormat::Compact => StorageKind::CompactJson,
+        }
+    }
 
-        Ok(())
+    fn location(&self) -> String {
+        self.storage_path.display().to_string()
     }
 
-    /// Loads a ProjectManager from a JSON file at the configured storage path.
+    /// Loads a ProjectManager from the JSON file.
     ///
-    /// For backward compatibility, if the file contains an old TodoList format,
-    /// it will be loaded into the default project of a new ProjectManager.
-    ///
-    /// # Returns
+    /// Files of an older schema version, including the old TodoList format,
+    /// are upgraded while loading (see [`migration`](crate::persistence::migration));
+    /// the file itself is only rewritten by the next save.
+    fn try_load_projects(&self) -> Result<ProjectManager, LoadFailure> {
+        self.read_projects()
+            .map(|(project_manager, _)| project_manager)
+    }
+
+    /// Saves a ProjectManager to the JSON file.
     ///
-    /// `Ok(ProjectManager)` on success, or `Err(String)` with an error message on failure.
-    pub fn load_projects(&self) -> Result<ProjectManager, String> {
-        let path = &self.storage_path;
+    /// The file records the current [`SCHEMA_VERSION`]. Creates parent
+    /// directories if they don't exist, and keeps the previous file as a backup.
+    fn save_projects(&self, project_manager: &ProjectManager) -> Result<(), TodoError> {
+        let versioned = VersionedProjects::new(project_manager);
+        let json = match self.format {
+            JsonFormat::Pretty => serde_json::to_string_pretty(&versioned),
+            JsonFormat::Compact => serde_json::to_string(&versioned),
+        }
+        .map_err(|e| {
+            TodoError::Serialization(format!("Failed to serialize ProjectManager: {}", e))
+        })?;
 
-        // Read file contents
-        let contents =
-            fs::read_to_string(path).map_err(|e| format!("Failed to read file: {}", e))?;
+        self.write(&json)
+    }
 
-        // Try to deserialize as ProjectManager first
-        if let Ok(project_manager) = serde_json::from_str::<ProjectManager>(&contents) {
-            return Ok(project_manager);
-        }
+    fn list_backups(&self) -> Result<Vec<Backup>, TodoError> {
+        backup::list_backups(&self.storage_path, self.kind())
+    }
+
+    fn create_backup(&self) -> Result<(), TodoError> {
+        self.backups.create(&self.storage_path)
+    }
 
-        // Fall back to old TodoList format for backward compatibility
-        if let Ok(todo_list) = serde_json::from_str::<TodoList>(&contents) {
-            let mut project_manager = ProjectManager::new();
-            // Replace the default project's todo list with the loaded one
-            *project_manager.get_current_todo_list_mut() = todo_list;
-            return Ok(project_manager);
+    /// Moves a corrupt storage file aside to `<file>.corrupt-<timestamp>`.
+    fn quarantine(&self, at: NaiveDateTime) -> Result<PathBuf, TodoError> {
+        load_failure::quarantine_file(&self.storage_path, at)
+    }
+
+    /// A file that is already current is left untouched.
+    fn migrate(&self) -> Result<u64, TodoError> {
+        let (project_manager, found) = self.read_projects()?;
+        if found < SCHEMA_VERSION {
+            self.create_backup()?;
+            self.save_projects(&project_manager)?;
         }
+        Ok(found)
+    }
+}
 
-        Err("Failed to parse file as either ProjectManager or TodoList".to_string())
+/// Writes `contents` to a temporary file next to `path`, flushes it to disk
+/// and renames it over `path`.
+///
+/// The rename replaces the file in one step, so a crash or a full disk
+/// leaves either the old or the new file, never a truncated one.
+fn write_atomically(path: &Path, contents: &[u8]) -> Result<(), TodoError> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| TodoError::Io(format!("Invalid storage path: {}", path.display())))?;
+    let temp_path = path.with_file_name(format!(
+        ".{}.tmp-{}",
+        file_name.to_string_lossy(),
+        std::process::id()
+    ));
+
+    let written = fs::File::create(&temp_path)
+        .and_then(|mut file| {
+            file.write_all(contents)?;
+            file.sync_all()
+        })
+        .and_then(|()| fs::rename(&temp_path, path));
+    if let Err(e) = written {
+        let _ = fs::remove_file(&temp_path);
+        return Err(TodoError::Io(format!("Failed to write to file: {}", e)));
     }
+
+    // Make the rename itself durable
+    #[cfg(unix)]
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        if let Ok(dir) = fs::File::open(parent) {
+            let _ = dir.sync_all();
+        }
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -241,11 +364,51 @@ mod tests {
         let _ = fs::remove_file(temp_path);
     }
 
+    #[test]
+    fn test_save_replaces_file_and_keeps_backup() {
+        let dir = std::env::temp_dir().join("todo_storage_backup_test");
+        let _ = fs::remove_dir_all(&dir);
+        let storage = TodoListStorage::new(dir.join("tasks.json")).with_backup_count(5);
+
+        let mut project_manager = ProjectManager::new();
+        storage.save_projects(&project_manager).unwrap();
+        assert!(storage.list_backups().unwrap().is_empty());
+
+        project_manager
+            .get_current_todo_list_mut()
+            .add_task(TaskWithoutId::new("Task 1".to_string()));
+        storage.save_projects(&project_manager).unwrap();
+
+        // The new version is saved and the previous one is kept as a backup
+        let loaded = storage.load_projects().unwrap();
+        assert_eq!(loaded.get_current_todo_list().get_tasks().len(), 1);
+        let backups = storage.list_backups().unwrap();
+        assert_eq!(backups.len(), 1);
+        let backed_up = backups[0].load().unwrap();
+        assert!(backed_up.get_current_todo_list().get_tasks().is_empty());
+
+        // Later saves of the same storage keep that backup instead of rotating it out
+        project_manager
+            .get_current_todo_list_mut()
+            .add_task(TaskWithoutId::new("Task 2".to_string()));
+        storage.save_projects(&project_manager).unwrap();
+        assert_eq!(storage.list_backups().unwrap(), backups);
+
+        // No temporary files are left behind
+        let files: Vec<String> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(files.len(), 2, "unexpected files: {:?}", files);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn test_load_nonexistent_file() {
         let storage = TodoListStorage::new("nonexistent_file.json");
         let result = storage.load();
-        assert!(result.is_err());
+        assert!(matches!(result, Err(TodoError::Io(_))));
     }
 
     #[test]
@@ -391,4 +554,134 @@ mod tests {
         // Cleanup
         let _ = fs::remove_file(temp_path);
     }
+
+    #[test]
+    fn test_save_projects_records_schema_version() {
+        let temp_path = "test_projects_schema_version.json";
+        let storage = TodoListStorage::new(temp_path).with_backup_count(0);
+        storage.save_projects(&ProjectManager::new()).unwrap();
+
+        let contents = fs::read_to_string(temp_path).unwrap();
+        let value: Value = serde_json::from_str(&contents).unwrap();
+        assert_eq!(value["schema_version"], SCHEMA_VERSION);
+        assert!(contents
+            .trim_start_matches(['{', '\n', ' '])
+            .starts_with("\"schema_version\""));
+
+        // Cleanup
+        let _ = fs::remove_file(temp_path);
+    }
+
+    #[test]
+    fn test_migrate_rewrites_old_file_in_place() {
+        let dir = std::env::temp_dir().join("todo_storage_migrate_test");
+        let _ = fs::remove_dir_all(&dir);
+        let storage = TodoListStorage::new(dir.join("tasks.json"));
+
+        let mut old_todo_list = TodoList::new();
+        old_todo_list.add_task(TaskWithoutId::new("Old task".to_string()));
+        storage.save(&old_todo_list).unwrap();
+
+        assert_eq!(storage.migrate().unwrap(), 0);
+        let value: Value =
+            serde_json::from_str(&fs::read_to_string(storage.path()).unwrap()).unwrap();
+        assert_eq!(value["schema_version"], SCHEMA_VERSION);
+        assert_eq!(
+            value["projects"]["default"]["todo_list"]["tasks"][0]["description"],
+            "Old task"
+        );
+        assert_eq!(storage.list_backups().unwrap().len(), 1);
+
+        // A current file is left alone
+        assert_eq!(storage.migrate().unwrap(), SCHEMA_VERSION);
+        assert_eq!(storage.list_backups().unwrap().len(), 1);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_load_failures_are_classified() {
+        let dir = std::env::temp_dir().join("todo_storage_load_failure_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let storage = TodoListStorage::new(dir.join("tasks.json"));
+
+        assert_eq!(storage.try_load_projects(), Err(LoadFailure::Missing));
+
+        fs::write(storage.path(), r#"{"schema_version": 99, "projects": 1}"#).unwrap();
+        assert_eq!(
+            storage.try_load_projects(),
+            Err(LoadFailure::UnsupportedVersion {
+                found: 99,
+                supported: SCHEMA_VERSION
+            })
+        );
+
+        fs::write(storage.path(), r#"{"projects": {"#).unwrap();
+        assert!(matches!(
+            storage.try_load_projects(),
+            Err(LoadFailure::Corrupt(_))
+        ));
+
+        // Quarantining moves the file aside unchanged
+        let at = chrono::NaiveDate::from_ymd_opt(2025, 1, 17)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+        let quarantined = storage.quarantine(at).unwrap();
+        assert_eq!(
+            quarantined.file_name().unwrap(),
+            "tasks.json.corrupt-20250117-093000"
+        );
+        assert_eq!(
+            fs::read_to_string(&quarantined).unwrap(),
+            r#"{"projects": {"#
+        );
+        assert!(!storage.path().exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_load_projects_without_timestamps() {
+        let temp_path = "test_load_without_timestamps.json";
+        let storage = TodoListStorage::new(temp_path);
+
+        // Project file written before tasks carried timestamps
+        let json = r#"{
+  "projects": {
+    "default": {
+      "name": "default",
+      "todo_list": {
+        "tasks": [
+          {
+            "id": 1,
+            "description": "Old task",
+            "completed": true,
+            "priority": "High",
+            "due_date": null,
+            "category": null,
+            "parent_id": null,
+            "recurrence": null,
+            "depends_on": []
+          }
+        ],
+        "next_id": 2
+      }
+    }
+  },
+  "current_project": "default"
+}"#;
+        fs::write(temp_path, json).unwrap();
+
+        let loaded_manager = storage.load_projects().unwrap();
+        let task = &loaded_manager.get_current_todo_list().get_tasks()[0];
+        assert_eq!(task.description, "Old task");
+        assert!(task.created_at.is_none());
+        assert!(task.updated_at.is_none());
+        assert!(task.completed_at.is_none());
+
+        // Cleanup
+        let _ = fs::remove_file(temp_path);
+    }
 }
@@ -0,0 +1,15 @@
+//! Service layer shared by the command line, the TUI and embedding code.
+//!
+//! This module contains the `TodoService` facade that applies dependency
+//! checks, recurring tasks and persistence on top of the project models.
+
+pub mod recurring_task_handler;
+pub mod task_changes;
+pub mod task_outcome;
+pub mod todo_service;
+
+// Re-export commonly used types
+pub use recurring_task_handler::RecurringTaskHandler;
+pub use task_changes::TaskChanges;
+pub use task_outcome::{BatchOutcome, Occurrence, TaskOutcome};
+pub use todo_service::TodoService;
@@ -0,0 +1,207 @@
+use crate::models::priority::Priority;
+use crate::models::recurrence::Recurrence;
+use crate::models::task::{Task, TaskWithoutId};
+use crate::models::todo_list::TodoList;
+use chrono::NaiveDate;
+
+/// Data structure to hold information about a recurring task
+#[derive(Debug, Clone)]
+pub struct RecurringTaskData {
+    pub description: String,
+    pub priority: Priority,
+    pub tags: Vec<String>,
+    pub parent_id: Option<usize>,
+    pub recurrence: Option<Recurrence>,
+    pub next_due_date: Option<NaiveDate>,
+    pub subtasks: Vec<SubtaskData>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SubtaskData {
+    pub description: String,
+    pub priority: Priority,
+}
+
+/// Handles recurring task logic including data collection, recreation, and subtask management
+///
+/// The data of a recurring task has to be collected before it is completed,
+/// since completing may change the task; the next occurrence is then created
+/// from that data.
+pub struct RecurringTaskHandler;
+
+impl RecurringTaskHandler {
+    /// Collects data from a single recurring task by ID
+    pub fn collect_recurring_task_data(
+        todo_list: &TodoList,
+        id: usize,
+    ) -> Option<RecurringTaskData> {
+        let task = todo_list.get_tasks().iter().find(|t| t.id == id)?;
+
+        if !task.is_recurring() {
+            return None;
+        }
+
+        Some(Self::extract_task_data(todo_list, task))
+    }
+
+    /// Collects data from multiple recurring tasks by IDs
+    pub fn collect_multiple_recurring_tasks(
+        todo_list: &TodoList,
+        ids: &[usize],
+    ) -> Vec<RecurringTaskData> {
+        todo_list
+            .get_tasks()
+            .iter()
+            .filter(|t| ids.contains(&t.id) && t.is_recurring())
+            .map(|task| Self::extract_task_data(todo_list, task))
+            .collect()
+    }
+
+    /// Collects data from all pending recurring tasks
+    pub fn collect_all_pending_recurring_tasks(todo_list: &TodoList) -> Vec<RecurringTaskData> {
+        todo_list
+            .get_tasks()
+            .iter()
+            .filter(|t| !t.is_completed() && t.is_recurring())
+            .map(|task| Self::extract_task_data(todo_list, task))
+            .collect()
+    }
+
+    /// Recreates a recurring task with its subtasks
+    pub fn recreate_recurring_task(todo_list: &mut TodoList, data: &RecurringTaskData) -> usize {
+        // Create new parent task
+        let mut new_task = TaskWithoutId::new(data.description.clone());
+        new_task.priority = data.priority;
+        new_task.tags = data.tags.clone();
+        new_task.parent_id = data.parent_id;
+        new_task.recurrence = data.recurrence;
+        new_task.due_date = data.next_due_date;
+
+        let new_id = todo_list.add_task(new_task);
+
+        // Recreate subtasks
+        Self::recreate_subtasks(todo_list, new_id, &data.subtasks);
+
+        new_id
+    }
+
+    // Private helper methods
+
+    fn extract_task_data(todo_list: &TodoList, task: &Task) -> RecurringTaskData {
+        let subtasks = todo_list
+            .get_subtasks(task.id)
+            .iter()
+            .map(|subtask| SubtaskData {
+                description: subtask.description.clone(),
+                priority: subtask.priority,
+            })
+            .collect();
+
+        RecurringTaskData {
+            description: task.description.clone(),
+            priority: task.priority,
+            tags: task.tags.clone(),
+            parent_id: task.parent_id,
+            recurrence: task.recurrence,
+            next_due_date: task.calculate_next_due_date(),
+            subtasks,
+        }
+    }
+
+    fn recreate_subtasks(todo_list: &mut TodoList, parent_id: usize, subtasks: &[SubtaskData]) {
+        for subtask_data in subtasks {
+            // New subtasks start out pending
+            if let Ok(subtask_id) =
+                todo_list.add_subtask(parent_id, subtask_data.description.clone())
+            {
+                // The subtask was just created, so setting its priority cannot fail
+                let _ = todo_list.set_task_priority(subtask_id, subtask_data.priority);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_recurring_task_data() {
+        let mut todo_list = TodoList::new();
+
+        // Add a recurring task
+        let mut task = TaskWithoutId::new("Daily task".to_string());
+        task.recurrence = Some(Recurrence::Daily);
+        let task_id = todo_list.add_task(task);
+
+        // Collect data
+        let data = RecurringTaskHandler::collect_recurring_task_data(&todo_list, task_id);
+        assert!(data.is_some());
+        assert_eq!(data.unwrap().description, "Daily task");
+    }
+
+    #[test]
+    fn test_collect_non_recurring_returns_none() {
+        let mut todo_list = TodoList::new();
+
+        // Add a non-recurring task
+        let task_id = todo_list.add_task(TaskWithoutId::new("Normal task".to_string()));
+
+        // Collect data should return None
+        let data = RecurringTaskHandler::collect_recurring_task_data(&todo_list, task_id);
+        assert!(data.is_none());
+    }
+
+    #[test]
+    fn test_recreate_recurring_task() {
+        let mut todo_list = TodoList::new();
+
+        let data = RecurringTaskData {
+            description: "Weekly task".to_string(),
+            priority: Priority::High,
+            tags: vec!["work".to_string()],
+            parent_id: None,
+            recurrence: Some(Recurrence::Weekly),
+            next_due_date: None,
+            subtasks: vec![],
+        };
+
+        let new_id = RecurringTaskHandler::recreate_recurring_task(&mut todo_list, &data);
+
+        let task = &todo_list.get_tasks()[0];
+        assert_eq!(task.id, new_id);
+        assert_eq!(task.description, "Weekly task");
+        assert_eq!(task.priority, Priority::High);
+    }
+
+    #[test]
+    fn test_recreate_with_subtasks() {
+        let mut todo_list = TodoList::new();
+
+        let data = RecurringTaskData {
+            description: "Parent task".to_string(),
+            priority: Priority::Medium,
+            tags: Vec::new(),
+            parent_id: None,
+            recurrence: Some(Recurrence::Daily),
+            next_due_date: None,
+            subtasks: vec![
+                SubtaskData {
+                    description: "Subtask 1".to_string(),
+                    priority: Priority::High,
+                },
+                SubtaskData {
+                    description: "Subtask 2".to_string(),
+                    priority: Priority::Low,
+                },
+            ],
+        };
+
+        let new_id = RecurringTaskHandler::recreate_recurring_task(&mut todo_list, &data);
+
+        let subtasks = todo_list.get_subtasks(new_id);
+        assert_eq!(subtasks.len(), 2);
+        assert_eq!(subtasks[0].description, "Subtask 1");
+        assert_eq!(subtasks[1].priority, Priority::Low);
+    }
+}
@@ -0,0 +1,39 @@
+use crate::models::priority::Priority;
+use crate::models::recurrence::Recurrence;
+use crate::models::task::TaskWithoutId;
+use chrono::NaiveDate;
+
+/// The editable fields of a task, e.g. as entered in the TUI task form.
+///
+/// Used to create a task or to update all fields of an existing task at once.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TaskChanges {
+    /// Task description
+    pub description: String,
+    /// Priority level
+    pub priority: Priority,
+    /// Due date (None to clear)
+    pub due_date: Option<NaiveDate>,
+    /// Tags, the first one being the category (empty to clear)
+    pub tags: Vec<String>,
+    /// Recurrence pattern (None to clear)
+    pub recurrence: Option<Recurrence>,
+    /// Parent task ID (None for a top-level task)
+    pub parent_id: Option<usize>,
+    /// IDs of the tasks this task depends on
+    pub depends_on: Vec<usize>,
+}
+
+impl TaskChanges {
+    /// Converts the fields into the data of a new task.
+    pub fn into_new_task(self) -> TaskWithoutId {
+        let mut task = TaskWithoutId::new(self.description);
+        task.priority = self.priority;
+        task.due_date = self.due_date;
+        task.tags = self.tags;
+        task.recurrence = self.recurrence;
+        task.parent_id = self.parent_id;
+        task.depends_on = self.depends_on;
+        task
+    }
+}
@@ -0,0 +1,41 @@
+use crate::models::task::Task;
+
+/// A task created because a recurring task was completed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Occurrence {
+    /// ID of the new task
+    pub id: usize,
+    /// Description of the new task
+    pub description: String,
+}
+
+/// Result of an operation on a single task that may complete it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskOutcome {
+    /// The task after the operation
+    pub task: Task,
+    /// The next occurrence, if the task was recurring and got completed
+    pub next_occurrence: Option<Occurrence>,
+}
+
+/// Result of an operation on several tasks.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BatchOutcome {
+    /// Number of tasks that were updated
+    pub updated: usize,
+    /// Requested IDs that do not exist
+    pub not_found: Vec<usize>,
+    /// Next occurrences of the recurring tasks that got completed
+    pub next_occurrences: Vec<Occurrence>,
+}
+
+impl From<(usize, Vec<usize>)> for BatchOutcome {
+    /// Converts the `(updated, not_found)` pair returned by the bulk `TodoList` methods.
+    fn from((updated, not_found): (usize, Vec<usize>)) -> Self {
+        Self {
+            updated,
+            not_found,
+            next_occurrences: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,929 @@
+use crate::controller::project_command::ProjectManager;
+use crate::models::priority::Priority;
+use crate::models::recurrence::Recurrence;
+use crate::models::saved_view::SavedView;
+use crate::models::task::{Task, TaskWithoutId};
+use crate::models::task_status::TaskStatus;
+use crate::models::todo_error::TodoError;
+use crate::models::todo_list::TodoList;
+use crate::persistence::{open_storage, Backup, LoadFailure, Quarantine, Storage, StorageChanges};
+use crate::service::recurring_task_handler::{RecurringTaskData, RecurringTaskHandler};
+use crate::service::task_changes::TaskChanges;
+use crate::service::task_outcome::{BatchOutcome, Occurrence, TaskOutcome};
+use chrono::{DateTime, Local, NaiveDate};
+use std::cell::RefCell;
+use std::path::Path;
+
+/// UI-agnostic entry point for working with projects and tasks.
+///
+/// `TodoService` owns the `ProjectManager` and applies the rules that go
+/// beyond a single `TodoList` call: dependencies must be completed first,
+/// completing a recurring task creates its next occurrence, and new tasks may
+/// only reference existing parents and dependencies. The command-line
+/// controllers and the TUI both go through this type, so embedding code gets
+/// the same behaviour.
+///
+/// Task operations apply to the current project.
+///
+/// With an incremental storage (see `Storage::is_incremental`) the service
+/// remembers what is stored, so that `save` only writes the changed rows.
+///
+/// # Examples
+///
+/// ```
+/// use todo_manager::controller::project_command::ProjectManager;
+/// use todo_manager::models::recurrence::Recurrence;
+/// use todo_manager::models::task::TaskWithoutId;
+/// use todo_manager::service::TodoService;
+///
+/// let mut service = TodoService::new(ProjectManager::new());
+///
+/// let mut task = TaskWithoutId::new("Water plants".to_string());
+/// task.recurrence = Some(Recurrence::Weekly);
+/// let id = service.add_task(task).unwrap();
+///
+/// let outcome = service.complete_task(id).unwrap();
+/// assert!(outcome.task.is_completed());
+/// let next = outcome.next_occurrence.unwrap();
+/// assert_eq!(next.description, "Water plants");
+/// assert!(!service.todo_list().get_tasks()[1].is_completed());
+/// ```
+pub struct TodoService {
+    project_manager: ProjectManager,
+    storage: Option<Box<dyn Storage>>,
+    /// The projects as last loaded from or saved to an incremental storage
+    saved: RefCell<Option<ProjectManager>>,
+}
+
+impl TodoService {
+    /// Creates a service for the given projects without persistence.
+    ///
+    /// `save` does nothing for such a service.
+    pub fn new(project_manager: ProjectManager) -> Self {
+        Self {
+            project_manager,
+            storage: None,
+            saved: RefCell::new(None),
+        }
+    }
+
+    /// Creates a service that saves the projects to `storage`.
+    pub fn with_storage(project_manager: ProjectManager, storage: Box<dyn Storage>) -> Self {
+        Self {
+            project_manager,
+            storage: Some(storage),
+            saved: RefCell::new(None),
+        }
+    }
+
+    /// Loads the projects stored at `path`, with the backend chosen by the
+    /// file extension (see `StorageKind::from_path`).
+    ///
+    /// A missing file starts with a new `ProjectManager`. Expired trash
+    /// entries are purged after loading.
+    ///
+    /// # Returns
+    ///
+    /// The service, or a `TodoError` if the file exists but cannot be read or parsed
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, TodoError> {
+        Self::load(open_storage(path))
+    }
+
+    /// Loads the projects from `storage`, like `open`.
+    pub fn load(storage: Box<dyn Storage>) -> Result<Self, TodoError> {
+        let project_manager = match storage.try_load_projects() {
+            Ok(project_manager) => project_manager,
+            Err(LoadFailure::Missing) => ProjectManager::new(),
+            Err(failure) => return Err(failure.into()),
+        };
+        Ok(Self::loaded(project_manager, storage))
+    }
+
+    /// Loads the projects stored at `path`, moving a corrupt file aside.
+    ///
+    /// Like `open`, but a corrupt file does not stop the program: it is moved
+    /// to `<file>.corrupt-<timestamp>` and a new session starts, so the next
+    /// save cannot overwrite it. The caller should show `Quarantine::warning`.
+    ///
+    /// # Returns
+    ///
+    /// The service and the quarantined file, if any, or a `TodoError` if the
+    /// file cannot be read, is from a newer version, or could not be moved aside.
+    /// Such a file must not be replaced, so no service is returned.
+    pub fn open_or_quarantine<P: AsRef<Path>>(
+        path: P,
+    ) -> Result<(Self, Option<Quarantine>), TodoError> {
+        Self::load_or_quarantine(open_storage(path))
+    }
+
+    /// Loads the projects from `storage`, like `open_or_quarantine`.
+    pub fn load_or_quarantine(
+        storage: Box<dyn Storage>,
+    ) -> Result<(Self, Option<Quarantine>), TodoError> {
+        match storage.try_load_projects() {
+            Ok(project_manager) => Ok((Self::loaded(project_manager, storage), None)),
+            Err(LoadFailure::Missing) => Ok((Self::loaded(ProjectManager::new(), storage), None)),
+            Err(LoadFailure::Corrupt(reason)) => {
+                let path = storage.quarantine(Local::now().naive_local())?;
+                let quarantine = Quarantine { path, reason };
+                Ok((
+                    Self::loaded(ProjectManager::new(), storage),
+                    Some(quarantine),
+                ))
+            }
+            Err(failure) => Err(failure.into()),
+        }
+    }
+
+    /// Creates a service for loaded projects, purging expired trash entries.
+    fn loaded(mut project_manager: ProjectManager, storage: Box<dyn Storage>) -> Self {
+        let saved = storage.is_incremental().then(|| project_manager.clone());
+        project_manager.purge_expired_trash(Local::now());
+        let service = Self::with_storage(project_manager, storage);
+        *service.saved.borrow_mut() = saved;
+        service
+    }
+
+    /// Saves the projects to the storage of the service, if it has one.
+    ///
+    /// An incremental storage only gets the changes since the projects were
+    /// loaded or last saved; the first save, and one that the storage cannot
+    /// write as changes, replaces all stored projects.
+    pub fn save(&self) -> Result<(), TodoError> {
+        let Some(storage) = &self.storage else {
+            return Ok(());
+        };
+        if !storage.is_incremental() {
+            return storage.save_projects(&self.project_manager);
+        }
+
+        let changes = self
+            .saved
+            .borrow()
+            .as_ref()
+            .map(|saved| StorageChanges::between(saved, &self.project_manager));
+        match changes {
+            Some(changes) if changes.is_empty() => return Ok(()),
+            Some(changes) if storage.save_changes(&self.project_manager, &changes)? => {}
+            _ => storage.save_projects(&self.project_manager)?,
+        }
+        *self.saved.borrow_mut() = Some(self.project_manager.clone());
+        Ok(())
+    }
+
+    /// Returns the storage of the service, if it has one.
+    pub fn storage(&self) -> Option<&dyn Storage> {
+        self.storage.as_deref()
+    }
+
+    /// Copies all projects into another, still empty storage.
+    ///
+    /// The storage of the service is not changed.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, `TodoError::StorageNotEmpty` if `target` already
+    /// holds data (or data it cannot read), or the error that prevented saving
+    pub fn convert_to(&self, target: &dyn Storage) -> Result<(), TodoError> {
+        self.check_convert_target(target)?;
+        target.save_projects(&self.project_manager)
+    }
+
+    /// Checks that `convert_to` may write to `target`, without writing anything.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if `target` is still empty, otherwise `TodoError::StorageNotEmpty`
+    pub fn check_convert_target(&self, target: &dyn Storage) -> Result<(), TodoError> {
+        match target.try_load_projects() {
+            Err(LoadFailure::Missing) => Ok(()),
+            _ => Err(TodoError::StorageNotEmpty(target.location())),
+        }
+    }
+
+    /// Lists the backups of the storage, newest first.
+    ///
+    /// A service without storage has no backups.
+    pub fn list_backups(&self) -> Result<Vec<Backup>, TodoError> {
+        match &self.storage {
+            Some(storage) => storage.list_backups(),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns a backup by its number, counting from 1 (the newest).
+    ///
+    /// # Returns
+    ///
+    /// The backup, or `TodoError::BackupNotFound` for an unknown number
+    pub fn find_backup(&self, number: usize) -> Result<Backup, TodoError> {
+        number
+            .checked_sub(1)
+            .and_then(|index| self.list_backups().ok()?.into_iter().nth(index))
+            .ok_or(TodoError::BackupNotFound(number))
+    }
+
+    /// Replaces all projects with the contents of a backup.
+    ///
+    /// Backups are numbered from 1 (the newest) as listed by `list_backups`.
+    /// The stored projects that are replaced are backed up first, and the
+    /// restored projects are not saved until `save` is called.
+    ///
+    /// # Returns
+    ///
+    /// The restored backup, `TodoError::BackupNotFound` for an unknown number,
+    /// or the error that prevented loading or taking a backup
+    pub fn restore_backup(&mut self, number: usize) -> Result<Backup, TodoError> {
+        let backup = self.find_backup(number)?;
+        let restored = backup.load()?;
+        if let Some(storage) = &self.storage {
+            storage.create_backup()?;
+        }
+        self.project_manager = restored;
+        // Save all projects next time
+        *self.saved.get_mut() = None;
+        Ok(backup)
+    }
+
+    /// Returns the projects.
+    pub fn project_manager(&self) -> &ProjectManager {
+        &self.project_manager
+    }
+
+    /// Returns the projects for changes the service does not cover.
+    ///
+    /// Changes made this way bypass the dependency and recurrence rules.
+    pub fn project_manager_mut(&mut self) -> &mut ProjectManager {
+        &mut self.project_manager
+    }
+
+    /// Replaces all projects, e.g. to restore an earlier snapshot.
+    pub fn replace_project_manager(&mut self, project_manager: ProjectManager) {
+        self.project_manager = project_manager;
+    }
+
+    /// Returns the todo list of the current project.
+    pub fn todo_list(&self) -> &TodoList {
+        self.project_manager.get_current_todo_list()
+    }
+
+    fn todo_list_mut(&mut self) -> &mut TodoList {
+        self.project_manager.get_current_todo_list_mut()
+    }
+
+    /// Adds a task to the current project.
+    ///
+    /// # Returns
+    ///
+    /// The ID of the new task, `TodoError::InvalidParent` if its parent does not
+    /// exist, or `TodoError::TaskNotFound` for a missing dependency
+    pub fn add_task(&mut self, new_task: TaskWithoutId) -> Result<usize, TodoError> {
+        if new_task.description.trim().is_empty() {
+            return Err(TodoError::EmptyDescription);
+        }
+        if let Some(parent_id) = new_task.parent_id {
+            if !self.task_exists(parent_id) {
+                return Err(TodoError::InvalidParent(parent_id));
+            }
+        }
+        if let Some(&missing) = new_task
+            .depends_on
+            .iter()
+            .find(|&&id| !self.task_exists(id))
+        {
+            return Err(TodoError::TaskNotFound(missing));
+        }
+
+        Ok(self.todo_list_mut().add_task(new_task))
+    }
+
+    /// Adds a subtask with the given description.
+    pub fn add_subtask(&mut self, parent_id: usize, description: &str) -> Result<usize, TodoError> {
+        if description.trim().is_empty() {
+            return Err(TodoError::EmptyDescription);
+        }
+        self.todo_list_mut()
+            .add_subtask(parent_id, description.to_string())
+    }
+
+    /// Moves a task and its subtasks to the trash.
+    pub fn remove_task(&mut self, id: usize) -> Result<Task, TodoError> {
+        self.todo_list_mut().remove_task(id)
+    }
+
+    /// Moves several tasks to the trash.
+    pub fn remove_tasks(&mut self, ids: &[usize]) -> BatchOutcome {
+        self.todo_list_mut().remove_tasks(ids).into()
+    }
+
+    /// Moves all tasks to the trash and returns how many were removed.
+    pub fn remove_all_tasks(&mut self) -> usize {
+        self.todo_list_mut().remove_all_tasks()
+    }
+
+    /// Restores a task from the trash; it may get a new ID.
+    pub fn restore_task(&mut self, id: usize) -> Result<&Task, TodoError> {
+        self.todo_list_mut().restore_task(id)
+    }
+
+    /// Completes a task.
+    ///
+    /// # Returns
+    ///
+    /// The completed task and the next occurrence of a recurring task, or
+    /// `TodoError::IncompleteDependencies` if a task it depends on is still open
+    pub fn complete_task(&mut self, id: usize) -> Result<TaskOutcome, TodoError> {
+        let dependencies = self.todo_list().get_incomplete_dependencies(id);
+        if !dependencies.is_empty() {
+            return Err(TodoError::IncompleteDependencies {
+                task_id: id,
+                dependencies,
+            });
+        }
+
+        let recurring_data = self.pending_recurring_data(&[id]);
+        let task = self.todo_list_mut().complete_task(id)?.clone();
+        let next_occurrence = self.recreate_all(&recurring_data).pop();
+
+        Ok(TaskOutcome {
+            task,
+            next_occurrence,
+        })
+    }
+
+    /// Completes several tasks, creating the next occurrence of recurring ones.
+    pub fn complete_tasks(&mut self, ids: &[usize]) -> BatchOutcome {
+        let recurring_data = self.pending_recurring_data(ids);
+        let mut outcome: BatchOutcome = self.todo_list_mut().complete_tasks(ids).into();
+        outcome.next_occurrences = self.recreate_all(&recurring_data);
+        outcome
+    }
+
+    /// Completes all tasks, creating the next occurrence of recurring ones.
+    pub fn complete_all_tasks(&mut self) -> BatchOutcome {
+        let recurring_data =
+            RecurringTaskHandler::collect_all_pending_recurring_tasks(self.todo_list());
+        let updated = self.todo_list_mut().complete_all_tasks();
+        BatchOutcome {
+            updated,
+            not_found: Vec::new(),
+            next_occurrences: self.recreate_all(&recurring_data),
+        }
+    }
+
+    /// Marks a task as pending.
+    pub fn uncomplete_task(&mut self, id: usize) -> Result<&Task, TodoError> {
+        self.todo_list_mut().uncomplete_task(id)
+    }
+
+    /// Marks several tasks as pending.
+    pub fn uncomplete_tasks(&mut self, ids: &[usize]) -> BatchOutcome {
+        self.todo_list_mut().uncomplete_tasks(ids).into()
+    }
+
+    /// Marks all tasks as pending and returns how many were changed.
+    pub fn uncomplete_all_tasks(&mut self) -> usize {
+        self.todo_list_mut().uncomplete_all_tasks()
+    }
+
+    /// Toggles a task between completed and pending.
+    ///
+    /// Completing a task this way follows the same rules as `complete_task`.
+    pub fn toggle_task(&mut self, id: usize) -> Result<TaskOutcome, TodoError> {
+        if self.task(id)?.is_completed() {
+            let task = self.todo_list_mut().uncomplete_task(id)?.clone();
+            Ok(TaskOutcome {
+                task,
+                next_occurrence: None,
+            })
+        } else {
+            self.complete_task(id)
+        }
+    }
+
+    /// Toggles several tasks, creating the next occurrence of recurring tasks that got completed.
+    pub fn toggle_tasks(&mut self, ids: &[usize]) -> BatchOutcome {
+        let recurring_data = self.pending_recurring_data(ids);
+        let mut outcome: BatchOutcome = self.todo_list_mut().toggle_tasks(ids).into();
+        outcome.next_occurrences = self.recreate_all(&recurring_data);
+        outcome
+    }
+
+    /// Toggles all tasks, creating the next occurrence of recurring tasks that got completed.
+    pub fn toggle_all_tasks(&mut self) -> BatchOutcome {
+        let recurring_data =
+            RecurringTaskHandler::collect_all_pending_recurring_tasks(self.todo_list());
+        let updated = self.todo_list_mut().toggle_all_tasks();
+        BatchOutcome {
+            updated,
+            not_found: Vec::new(),
+            next_occurrences: self.recreate_all(&recurring_data),
+        }
+    }
+
+    /// Sets the workflow status of a task.
+    ///
+    /// `TaskStatus::Completed` goes through `complete_task`.
+    pub fn set_status(&mut self, id: usize, status: TaskStatus) -> Result<TaskOutcome, TodoError> {
+        if status == TaskStatus::Completed {
+            return self.complete_task(id);
+        }
+        let task = self.todo_list_mut().set_task_status(id, status)?.clone();
+        Ok(TaskOutcome {
+            task,
+            next_occurrence: None,
+        })
+    }
+
+    /// Sets the workflow status of several tasks.
+    ///
+    /// `TaskStatus::Completed` goes through `complete_tasks`.
+    pub fn set_status_multiple(&mut self, ids: &[usize], status: TaskStatus) -> BatchOutcome {
+        if status == TaskStatus::Completed {
+            return self.complete_tasks(ids);
+        }
+        self.todo_list_mut().set_status_multiple(ids, status).into()
+    }
+
+    /// Sets the priority of a task.
+    pub fn set_priority(&mut self, id: usize, priority: Priority) -> Result<&Task, TodoError> {
+        self.todo_list_mut().set_task_priority(id, priority)
+    }
+
+    /// Sets the priority of several tasks.
+    pub fn set_priority_multiple(&mut self, ids: &[usize], priority: Priority) -> BatchOutcome {
+        self.todo_list_mut()
+            .set_priority_multiple(ids, priority)
+            .into()
+    }
+
+    /// Sets or clears the due date of a task.
+    pub fn set_due_date(
+        &mut self,
+        id: usize,
+        due_date: Option<NaiveDate>,
+    ) -> Result<&Task, TodoError> {
+        self.todo_list_mut().set_due_date(id, due_date)
+    }
+
+    /// Sets or clears the category of a task.
+    pub fn set_category(
+        &mut self,
+        id: usize,
+        category: Option<String>,
+    ) -> Result<&Task, TodoError> {
+        self.todo_list_mut().set_task_category(id, category)
+    }
+
+    /// Sets or clears the category of several tasks.
+    pub fn set_category_multiple(
+        &mut self,
+        ids: &[usize],
+        category: Option<String>,
+    ) -> BatchOutcome {
+        self.todo_list_mut()
+            .set_category_multiple(ids, category)
+            .into()
+    }
+
+    /// Adds tags to a task.
+    pub fn add_tags(&mut self, id: usize, tags: &[String]) -> Result<&Task, TodoError> {
+        self.todo_list_mut().add_task_tags(id, tags)
+    }
+
+    /// Replaces the tags of a task.
+    pub fn set_tags(&mut self, id: usize, tags: &[String]) -> Result<&Task, TodoError> {
+        self.todo_list_mut().set_task_tags(id, tags)
+    }
+
+    /// Removes tags from a task.
+    pub fn remove_tags(&mut self, id: usize, tags: &[String]) -> Result<&Task, TodoError> {
+        self.todo_list_mut().remove_task_tags(id, tags)
+    }
+
+    /// Adds tags to several tasks.
+    pub fn add_tags_multiple(&mut self, ids: &[usize], tags: &[String]) -> BatchOutcome {
+        self.todo_list_mut().add_tags_multiple(ids, tags).into()
+    }
+
+    /// Removes tags from several tasks.
+    pub fn remove_tags_multiple(&mut self, ids: &[usize], tags: &[String]) -> BatchOutcome {
+        self.todo_list_mut().remove_tags_multiple(ids, tags).into()
+    }
+
+    /// Sets or clears the recurrence of a task.
+    pub fn set_recurrence(
+        &mut self,
+        id: usize,
+        recurrence: Option<Recurrence>,
+    ) -> Result<&Task, TodoError> {
+        self.todo_list_mut().set_task_recurrence(id, recurrence)
+    }
+
+    /// Sets or clears the recurrence of several tasks.
+    pub fn set_recurrence_multiple(
+        &mut self,
+        ids: &[usize],
+        recurrence: Option<Recurrence>,
+    ) -> BatchOutcome {
+        self.todo_list_mut()
+            .set_recurrence_multiple(ids, recurrence)
+            .into()
+    }
+
+    /// Changes the description of a task.
+    ///
+    /// # Returns
+    ///
+    /// The previous description, or `TodoError::EmptyDescription` for a blank description
+    pub fn edit_task(&mut self, id: usize, new_description: &str) -> Result<String, TodoError> {
+        if new_description.trim().is_empty() {
+            return Err(TodoError::EmptyDescription);
+        }
+        let old_description = self.task(id)?.description.clone();
+        self.todo_list_mut()
+            .edit_task(id, new_description.to_string())?;
+        Ok(old_description)
+    }
+
+    /// Sets all editable fields of a task at once.
+    ///
+    /// The changes are applied to a copy of the list first, so the task stays
+    /// unchanged if any of them is rejected.
+    ///
+    /// # Returns
+    ///
+    /// The updated task, or the error of the first rejected field, e.g.
+    /// `TodoError::CircularParent` or `TodoError::CircularDependency`
+    pub fn update_task(&mut self, id: usize, changes: &TaskChanges) -> Result<&Task, TodoError> {
+        if changes.description.trim().is_empty() {
+            return Err(TodoError::EmptyDescription);
+        }
+        let old_dependencies = self.task(id)?.depends_on.clone();
+
+        let mut todo_list = self.todo_list().clone();
+        todo_list.edit_task(id, changes.description.clone())?;
+        todo_list.set_task_priority(id, changes.priority)?;
+        todo_list.set_due_date(id, changes.due_date)?;
+        todo_list.set_task_tags(id, &changes.tags)?;
+        todo_list.set_task_recurrence(id, changes.recurrence)?;
+        todo_list.set_task_parent(id, changes.parent_id)?;
+        for &dependency in &old_dependencies {
+            if !changes.depends_on.contains(&dependency) {
+                todo_list.remove_task_dependency(id, dependency)?;
+            }
+        }
+        for &dependency in &changes.depends_on {
+            if !old_dependencies.contains(&dependency) {
+                todo_list.add_task_dependency(id, dependency)?;
+            }
+        }
+
+        *self.todo_list_mut() = todo_list;
+        self.task(id)
+    }
+
+    /// Makes a task a subtask of another task, or a top-level task again.
+    pub fn set_parent(&mut self, id: usize, parent_id: Option<usize>) -> Result<&Task, TodoError> {
+        self.todo_list_mut().set_task_parent(id, parent_id)
+    }
+
+    /// Makes a task depend on another one.
+    pub fn add_dependency(
+        &mut self,
+        task_id: usize,
+        depends_on_id: usize,
+    ) -> Result<(), TodoError> {
+        self.todo_list_mut()
+            .add_task_dependency(task_id, depends_on_id)
+    }
+
+    /// Removes a dependency between two tasks.
+    pub fn remove_dependency(
+        &mut self,
+        task_id: usize,
+        depends_on_id: usize,
+    ) -> Result<(), TodoError> {
+        self.todo_list_mut()
+            .remove_task_dependency(task_id, depends_on_id)
+    }
+
+    /// Permanently deletes the trash of the current project and the deleted projects.
+    ///
+    /// # Returns
+    ///
+    /// The number of deleted tasks and projects
+    pub fn empty_trash(&mut self) -> (usize, usize) {
+        let task_count = self.todo_list_mut().empty_trash();
+        (task_count, self.project_manager.empty_project_trash())
+    }
+
+    /// Purges trash entries older than the retention period and returns how many were purged.
+    pub fn purge_expired_trash(&mut self, now: DateTime<Local>) -> usize {
+        self.project_manager.purge_expired_trash(now)
+    }
+
+    /// Sets the number of days after which trash entries are purged.
+    pub fn set_trash_retention_days(&mut self, days: u32) {
+        self.project_manager.set_trash_retention_days(days);
+    }
+
+    /// Saves a view and returns whether it replaced one with the same name.
+    pub fn save_view(&mut self, view: SavedView) -> bool {
+        self.project_manager.save_view(view)
+    }
+
+    /// Deletes a saved view and returns it.
+    pub fn delete_view(&mut self, name: &str) -> Option<SavedView> {
+        self.project_manager.delete_view(name)
+    }
+
+    /// Creates an empty project.
+    pub fn create_project(&mut self, name: &str) -> Result<(), TodoError> {
+        self.project_manager.create_project(name.to_string())
+    }
+
+    /// Makes another project the current one.
+    pub fn switch_project(&mut self, name: &str) -> Result<(), TodoError> {
+        self.project_manager.switch_project(name.to_string())
+    }
+
+    /// Moves a project to the trash.
+    pub fn delete_project(&mut self, name: &str) -> Result<(), TodoError> {
+        self.project_manager.delete_project(name.to_string())
+    }
+
+    /// Restores a project from the trash.
+    pub fn restore_project(&mut self, name: &str) -> Result<(), TodoError> {
+        self.project_manager.restore_project(name)
+    }
+
+    /// Renames a project.
+    pub fn rename_project(&mut self, old_name: &str, new_name: &str) -> Result<(), TodoError> {
+        self.project_manager
+            .rename_project(old_name.to_string(), new_name.to_string())
+    }
+
+    fn task(&self, id: usize) -> Result<&Task, TodoError> {
+        self.todo_list()
+            .get_tasks()
+            .iter()
+            .find(|task| task.id == id)
+            .ok_or(TodoError::TaskNotFound(id))
+    }
+
+    fn task_exists(&self, id: usize) -> bool {
+        self.task(id).is_ok()
+    }
+
+    /// Collects the recurring tasks among `ids` that are not completed yet.
+    fn pending_recurring_data(&self, ids: &[usize]) -> Vec<RecurringTaskData> {
+        let pending_ids: Vec<usize> = ids
+            .iter()
+            .copied()
+            .filter(|&id| self.task(id).is_ok_and(|task| !task.is_completed()))
+            .collect();
+        RecurringTaskHandler::collect_multiple_recurring_tasks(self.todo_list(), &pending_ids)
+    }
+
+    fn recreate_all(&mut self, recurring_data: &[RecurringTaskData]) -> Vec<Occurrence> {
+        recurring_data
+            .iter()
+            .map(|data| Occurrence {
+                id: RecurringTaskHandler::recreate_recurring_task(self.todo_list_mut(), data),
+                description: data.description.clone(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::persistence::MemoryStorage;
+    use std::fs;
+
+    fn recurring_task(description: &str) -> TaskWithoutId {
+        let mut task = TaskWithoutId::new(description.to_string());
+        task.recurrence = Some(Recurrence::Daily);
+        task
+    }
+
+    #[test]
+    fn test_open_or_quarantine_moves_corrupt_file_aside() {
+        let dir = std::env::temp_dir().join("todo_service_quarantine_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("tasks.json");
+        fs::write(&path, "{ broken").unwrap();
+
+        assert!(matches!(
+            TodoService::open(&path),
+            Err(TodoError::Serialization(_))
+        ));
+
+        let (service, quarantine) = TodoService::open_or_quarantine(&path).unwrap();
+        let quarantine = quarantine.unwrap();
+        assert!(service.todo_list().is_empty());
+        assert_eq!(fs::read_to_string(&quarantine.path).unwrap(), "{ broken");
+        assert!(!path.exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_restore_backup_replaces_projects() {
+        let dir = std::env::temp_dir().join("todo_service_restore_backup_test");
+        let _ = fs::remove_dir_all(&dir);
+        let mut service = TodoService::open(dir.join("tasks.json")).unwrap();
+        service
+            .add_task(TaskWithoutId::new("Keep me".to_string()))
+            .unwrap();
+        service.save().unwrap();
+        service.remove_task(1).unwrap();
+        service.save().unwrap();
+
+        assert_eq!(service.restore_backup(2), Err(TodoError::BackupNotFound(2)));
+        assert_eq!(service.restore_backup(0), Err(TodoError::BackupNotFound(0)));
+        service.restore_backup(1).unwrap();
+        assert_eq!(service.todo_list().get_tasks()[0].description, "Keep me");
+
+        // The replaced projects are backed up before the restore
+        let newest = service.list_backups().unwrap()[0].load().unwrap();
+        assert!(newest.get_current_todo_list().get_tasks().is_empty());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_convert_to_copies_into_empty_storage_only() {
+        let mut service = TodoService::new(ProjectManager::new());
+        service
+            .add_task(TaskWithoutId::new("Copy me".to_string()))
+            .unwrap();
+
+        let target = MemoryStorage::new();
+        service.convert_to(&target).unwrap();
+        assert_eq!(target.projects().as_ref(), Some(service.project_manager()));
+
+        assert_eq!(
+            service.convert_to(&target),
+            Err(TodoError::StorageNotEmpty("memory".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_sqlite_storage_saves_changed_rows_after_the_first_save() {
+        let dir = std::env::temp_dir().join("todo_service_sqlite_test");
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("tasks.db");
+
+        let mut service = TodoService::open(&path).unwrap();
+        let first = service
+            .add_task(TaskWithoutId::new("First".to_string()))
+            .unwrap();
+        service.save().unwrap();
+
+        let mut service = TodoService::open(&path).unwrap();
+        service.complete_task(first).unwrap();
+        service
+            .add_task(TaskWithoutId::new("Second".to_string()))
+            .unwrap();
+        service.save().unwrap();
+        service.save().unwrap();
+
+        // Only full saves back up the database
+        assert!(service.list_backups().unwrap().is_empty());
+        let reopened = TodoService::open(&path).unwrap();
+        assert_eq!(reopened.project_manager(), service.project_manager());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_add_task_checks_references() {
+        let mut service = TodoService::new(ProjectManager::new());
+        let mut task = TaskWithoutId::new("Child".to_string());
+        task.parent_id = Some(7);
+        assert_eq!(service.add_task(task), Err(TodoError::InvalidParent(7)));
+
+        let mut task = TaskWithoutId::new("Later".to_string());
+        task.depends_on = vec![3];
+        assert_eq!(service.add_task(task), Err(TodoError::TaskNotFound(3)));
+        assert!(service.todo_list().is_empty());
+    }
+
+    #[test]
+    fn test_update_task_is_all_or_nothing() {
+        let mut service = TodoService::new(ProjectManager::new());
+        let first = service
+            .add_task(TaskWithoutId::new("First".to_string()))
+            .unwrap();
+        let second = service.add_subtask(first, "Second").unwrap();
+
+        let mut changes = TaskChanges {
+            description: "Renamed".to_string(),
+            priority: Priority::High,
+            parent_id: Some(second),
+            ..TaskChanges::default()
+        };
+        assert_eq!(
+            service.update_task(first, &changes).unwrap_err(),
+            TodoError::CircularParent {
+                task_id: first,
+                parent_id: second
+            }
+        );
+        assert_eq!(service.todo_list().get_tasks()[0].description, "First");
+
+        changes.parent_id = None;
+        changes.depends_on = vec![second];
+        let task = service.update_task(first, &changes).unwrap();
+        assert_eq!(task.description, "Renamed");
+        assert_eq!(task.priority, Priority::High);
+        assert_eq!(task.depends_on, vec![second]);
+    }
+
+    #[test]
+    fn test_complete_requires_dependencies() {
+        let mut service = TodoService::new(ProjectManager::new());
+        let first = service
+            .add_task(TaskWithoutId::new("First".to_string()))
+            .unwrap();
+        let mut task = TaskWithoutId::new("Second".to_string());
+        task.depends_on = vec![first];
+        let second = service.add_task(task).unwrap();
+
+        assert_eq!(
+            service.complete_task(second),
+            Err(TodoError::IncompleteDependencies {
+                task_id: second,
+                dependencies: vec![first],
+            })
+        );
+        service.complete_task(first).unwrap();
+        assert!(service.complete_task(second).unwrap().task.is_completed());
+    }
+
+    #[test]
+    fn test_completing_twice_creates_one_occurrence() {
+        let mut service = TodoService::new(ProjectManager::new());
+        let id = service.add_task(recurring_task("Stand-up")).unwrap();
+
+        assert!(service.complete_task(id).unwrap().next_occurrence.is_some());
+        assert_eq!(service.complete_task(id).unwrap().next_occurrence, None);
+        assert_eq!(service.todo_list().get_tasks().len(), 2);
+    }
+
+    #[test]
+    fn test_toggle_and_status_create_occurrences() {
+        let mut service = TodoService::new(ProjectManager::new());
+        let id = service.add_task(recurring_task("Stand-up")).unwrap();
+
+        let outcome = service.toggle_task(id).unwrap();
+        let next_id = outcome.next_occurrence.unwrap().id;
+        assert_eq!(service.toggle_task(id).unwrap().next_occurrence, None);
+
+        let outcome = service.set_status(next_id, TaskStatus::Completed).unwrap();
+        assert!(outcome.next_occurrence.is_some());
+
+        let outcome = service.toggle_tasks(&[id, 99]);
+        assert_eq!(outcome.updated, 1);
+        assert_eq!(outcome.not_found, vec![99]);
+        assert_eq!(outcome.next_occurrences.len(), 1);
+    }
+
+    #[test]
+    fn test_edit_task_returns_old_description() {
+        let mut service = TodoService::new(ProjectManager::new());
+        let id = service
+            .add_task(TaskWithoutId::new("Old".to_string()))
+            .unwrap();
+
+        assert_eq!(service.edit_task(id, "New"), Ok("Old".to_string()));
+        assert_eq!(
+            service.edit_task(id, "  "),
+            Err(TodoError::EmptyDescription)
+        );
+        assert_eq!(
+            service.edit_task(42, "New"),
+            Err(TodoError::TaskNotFound(42))
+        );
+    }
+
+    #[test]
+    fn test_open_and_save() {
+        let path = std::env::temp_dir().join("todo_service_open_and_save.json");
+        let _ = std::fs::remove_file(&path);
+
+        let mut service = TodoService::open(&path).unwrap();
+        service
+            .add_task(TaskWithoutId::new("Persisted".to_string()))
+            .unwrap();
+        service.save().unwrap();
+
+        let reopened = TodoService::open(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(reopened.todo_list().get_tasks()[0].description, "Persisted");
+    }
+}
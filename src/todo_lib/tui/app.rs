@@ -1,6 +1,33 @@
+use std::collections::HashSet;
+
+use chrono::Local;
+
+use crate::models::agenda::{due_entries, AgendaEntry};
 use crate::models::TodoList;
 use crate::models::Task;
+use crate::models::TaskFilter;
+use crate::models::SavedView;
 use crate::models::task::TaskWithoutId;
+use crate::service::TodoService;
+use super::board::{build_columns, Board, BoardColumn, ColumnKey};
+use super::calendar::Calendar;
+use super::mouse::ClickAreas;
+use super::task_form::TaskForm;
+
+/// Number of lines of a card on the board
+pub const BOARD_CARD_HEIGHT: usize = 3;
+
+/// How the tasks of the current project are shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Screen {
+    /// Task list with subtasks as a tree
+    #[default]
+    Tree,
+    /// Kanban board with one column per status, priority or category
+    Board,
+    /// Month calendar with the tasks due on each day
+    Calendar,
+}
 
 /// Input mode for the TUI
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -9,18 +36,43 @@ pub enum InputMode {
     Normal,
     /// Adding a new task
     Adding,
-    /// Editing a task
-    Editing,
+    /// Filling in the task form to create or edit a task
+    TaskForm,
     /// Searching tasks
     Searching,
     /// Viewing help
     Help,
+    /// Entering the name of a new project
+    CreatingProject,
+    /// Entering a new name for the current project
+    RenamingProject,
+    /// Confirming the deletion of the current project
+    DeletingProject,
+}
+
+/// A row of the task tree
+pub struct TaskRow<'a> {
+    /// The task shown in this row
+    pub task: &'a Task,
+    /// Indentation guides drawn before the task, e.g. "│  └─ "
+    pub guides: String,
+    /// Whether subtasks of the task are shown in the tree (when expanded)
+    pub has_children: bool,
+    /// Whether the subtasks of the task are hidden
+    pub collapsed: bool,
+    /// Number of direct subtasks
+    pub subtask_count: usize,
+    /// Number of completed direct subtasks
+    pub completed_subtask_count: usize,
+    /// Whether the task matches the view and search; other rows are the
+    /// parents of matching subtasks
+    pub matches: bool,
 }
 
 /// The main TUI application state
 pub struct App {
-    /// The todo list
-    pub todo_list: TodoList,
+    /// The service holding the tasks
+    pub service: TodoService,
     /// Currently selected task index in the filtered list
     pub selected: usize,
     /// Current input mode
@@ -31,53 +83,173 @@ pub struct App {
     pub search_query: String,
     /// Whether the app should quit
     pub should_quit: bool,
-    /// Current filter (pending, completed, all)
-    pub filter: String,
+    /// Views that can be cycled through (built-in views followed by saved views)
+    pub views: Vec<SavedView>,
+    /// Index of the active view in `views`
+    pub current_view: usize,
     /// Scroll offset for task list
     pub scroll_offset: usize,
-    /// Task being edited (ID)
-    pub editing_task_id: Option<usize>,
+    /// Task form shown in `InputMode::TaskForm`
+    pub form: Option<TaskForm>,
+    /// IDs of the tasks whose subtasks are hidden
+    pub collapsed: HashSet<usize>,
+    /// How the tasks are shown
+    pub screen: Screen,
+    /// Columns and selection of the board
+    pub board: Board,
+    /// Selected day of the calendar
+    pub calendar: Calendar,
+    /// Areas of the widgets drawn in the last frame, for mouse clicks
+    pub areas: ClickAreas,
+    /// Number of lines the details pane is scrolled down
+    pub details_scroll: u16,
+    /// Task shown in the details pane; the scroll resets when it changes
+    pub details_task: Option<usize>,
     /// Status message to display
     pub status_message: Option<String>,
 }
 
 impl App {
     /// Create a new App
-    pub fn new(todo_list: TodoList) -> Self {
+    ///
+    /// The saved views of the projects are added after the built-in views.
+    pub fn new(service: TodoService) -> Self {
+        let saved_views = service.project_manager().get_views().clone();
         Self {
-            todo_list,
+            service,
             selected: 0,
             input_mode: InputMode::Normal,
             input: String::new(),
             search_query: String::new(),
             should_quit: false,
-            filter: String::from("all"),
+            views: SavedView::builtin(),
+            current_view: 0,
             scroll_offset: 0,
-            editing_task_id: None,
+            form: None,
+            collapsed: HashSet::new(),
+            screen: Screen::Tree,
+            board: Board::default(),
+            calendar: Calendar::new(Local::now().date_naive()),
+            areas: ClickAreas::default(),
+            details_scroll: 0,
+            details_task: None,
             status_message: None,
         }
+        .with_views(saved_views)
     }
 
-    /// Get the currently displayed tasks (filtered)
+    /// Add saved views after the built-in ones
+    ///
+    /// A saved view with the name of a built-in view replaces it.
+    pub fn with_views(mut self, views: Vec<SavedView>) -> Self {
+        for view in views {
+            match self
+                .views
+                .iter_mut()
+                .find(|existing| existing.name.eq_ignore_ascii_case(&view.name))
+            {
+                Some(existing) => *existing = view,
+                None => self.views.push(view),
+            }
+        }
+        self
+    }
+
+    /// Get the todo list of the current project
+    pub fn todo_list(&self) -> &TodoList {
+        self.service.todo_list()
+    }
+
+    /// Get the names of all projects, sorted
+    pub fn project_names(&self) -> Vec<String> {
+        self.service.project_manager().list_projects()
+    }
+
+    /// Get the name of the current project
+    pub fn current_project_name(&self) -> &str {
+        self.service.project_manager().get_current_project_name()
+    }
+
+    /// Get the active view
+    pub fn current_view(&self) -> &SavedView {
+        &self.views[self.current_view]
+    }
+
+    /// Get the currently displayed tasks in tree order
     pub fn get_displayed_tasks(&self) -> Vec<&Task> {
-        let all_tasks = self.todo_list.get_tasks();
-        
-        // Apply filter
-        let mut tasks: Vec<&Task> = match self.filter.as_str() {
-            "pending" => all_tasks.iter().filter(|t| !t.is_completed()).collect(),
-            "completed" => all_tasks.iter().filter(|t| t.is_completed()).collect(),
-            _ => all_tasks.iter().collect(),
+        self.get_task_rows().into_iter().map(|row| row.task).collect()
+    }
+
+    /// Get the tasks matching the active view and the search
+    pub fn get_matching_tasks(&self) -> Vec<&Task> {
+        // Apply the active view; views that fail to parse show all tasks
+        let mut tasks: Vec<&Task> = match self.current_view().to_filter() {
+            Ok(Some(filter)) => self.todo_list().get_filtered_tasks(&filter),
+            _ => self.todo_list().get_tasks().iter().collect(),
         };
-        
+
         // Apply search filter
         if !self.search_query.is_empty() {
             let query = self.search_query.to_lowercase();
             tasks.retain(|t| t.description.to_lowercase().contains(&query));
         }
-        
+
         tasks
     }
 
+    /// Get the board columns with the matching tasks as cards
+    pub fn get_board_columns(&self) -> Vec<BoardColumn<'_>> {
+        build_columns(self.board.grouping, &self.get_matching_tasks())
+    }
+
+    /// Get the rows of the task tree
+    ///
+    /// Tasks matching the view and search are shown under their parents,
+    /// which are kept in the tree even if they don't match themselves.
+    /// Subtasks of collapsed tasks are left out.
+    pub fn get_task_rows(&self) -> Vec<TaskRow<'_>> {
+        let todo_list = self.todo_list();
+        let filter = self.current_view().to_filter().ok().flatten();
+        let matching = self.get_matching_tasks();
+
+        let matching_ids: HashSet<usize> = matching.iter().map(|task| task.id).collect();
+        let mut visible = matching_ids.clone();
+        for task in &matching {
+            let mut parent_id = task.parent_id;
+            while let Some(id) = parent_id {
+                if !visible.insert(id) {
+                    break;
+                }
+                parent_id = todo_list
+                    .get_tasks()
+                    .iter()
+                    .find(|t| t.id == id)
+                    .and_then(|t| t.parent_id);
+            }
+        }
+
+        let is_root = |task: &Task| {
+            task.parent_id
+                .is_none_or(|parent_id| todo_list.get_tasks().iter().all(|t| t.id != parent_id))
+        };
+        let roots: Vec<&Task> = todo_list
+            .get_tasks()
+            .iter()
+            .filter(|task| visible.contains(&task.id) && is_root(task))
+            .collect();
+
+        let tree = TaskTree {
+            todo_list,
+            filter: filter.as_ref(),
+            visible: &visible,
+            matching: &matching_ids,
+            collapsed: &self.collapsed,
+        };
+        let mut rows = Vec::new();
+        tree.push_rows(roots, 0, "", &mut rows);
+        rows
+    }
+
     /// Move selection up
     pub fn select_previous(&mut self) {
         if self.selected > 0 {
@@ -96,24 +268,312 @@ impl App {
         }
     }
 
+    /// Select the task in a line of the task list, counted from the first
+    /// visible line
+    ///
+    /// Returns whether the line shows a task.
+    pub fn click_task(&mut self, line: usize) -> bool {
+        let index = self.scroll_offset + line;
+        if index >= self.get_displayed_tasks().len() {
+            return false;
+        }
+        self.selected = index;
+        true
+    }
+
+    /// Scroll the task list by a number of lines (negative to scroll up)
+    ///
+    /// The selection moves along when it would leave the visible lines.
+    pub fn scroll_tasks(&mut self, lines: isize) {
+        let task_count = self.get_displayed_tasks().len();
+        let height = (self.areas.task_list.height as usize).saturating_sub(2).max(1);
+        let max_offset = task_count.saturating_sub(height);
+        self.scroll_offset = self
+            .scroll_offset
+            .saturating_add_signed(lines)
+            .min(max_offset);
+        self.selected = self
+            .selected
+            .clamp(self.scroll_offset, self.scroll_offset + height - 1)
+            .min(task_count.saturating_sub(1));
+    }
+
+    /// Scroll the details pane by a number of lines (negative to scroll up)
+    pub fn scroll_details(&mut self, lines: isize) {
+        let scroll = (self.details_scroll as usize).saturating_add_signed(lines);
+        self.details_scroll = scroll.min(u16::MAX as usize) as u16;
+    }
+
+    /// Switch to the project in a line of the sidebar
+    pub fn click_project(&mut self, line: usize) {
+        let Some(name) = self.project_names().get(line).cloned() else {
+            return;
+        };
+        if name != self.current_project_name() {
+            self.switch_project(&name);
+        }
+    }
+
+    /// Switch to a view by its index in `views`
+    pub fn select_view(&mut self, index: usize) {
+        if index < self.views.len() {
+            self.current_view = index;
+            self.selected = 0;
+            self.scroll_offset = 0;
+        }
+    }
+
     /// Get the currently selected task
+    ///
+    /// On the board this is the selected card; on the calendar it is the
+    /// selected task of the open day.
     pub fn get_selected_task(&self) -> Option<&Task> {
-        let tasks = self.get_displayed_tasks();
-        tasks.get(self.selected).copied()
+        match self.screen {
+            Screen::Tree => self.get_displayed_tasks().get(self.selected).copied(),
+            Screen::Board => {
+                let columns = self.get_board_columns();
+                let column = columns.get(self.board.column)?;
+                let card = self.board.selected_in(self.board.column, column.tasks.len())?;
+                Some(column.tasks[card])
+            }
+            Screen::Calendar if self.calendar.day_open => self
+                .get_day_entries()
+                .get(self.calendar.selected)
+                .map(|entry| entry.task),
+            Screen::Calendar => None,
+        }
+    }
+
+    /// Switch between the task tree and the board
+    pub fn toggle_board(&mut self) {
+        self.toggle_screen(Screen::Board);
+    }
+
+    /// Switch between the task tree and the calendar
+    ///
+    /// The calendar opens on today.
+    pub fn toggle_calendar(&mut self) {
+        if self.screen != Screen::Calendar {
+            self.calendar = Calendar::new(Local::now().date_naive());
+        }
+        self.toggle_screen(Screen::Calendar);
+    }
+
+    fn toggle_screen(&mut self, screen: Screen) {
+        self.screen = if self.screen == screen {
+            Screen::Tree
+        } else {
+            screen
+        };
+    }
+
+    /// Group the board columns by the next field (status, priority, category)
+    pub fn cycle_board_grouping(&mut self) {
+        self.board.cycle_grouping();
+        self.status_message = Some(format!("Board grouped by {}", self.board.grouping.as_str()));
+    }
+
+    /// Focus the column left of the current one
+    pub fn board_previous_column(&mut self) {
+        self.board.previous_column();
+    }
+
+    /// Focus the column right of the current one
+    pub fn board_next_column(&mut self) {
+        let column_count = self.get_board_columns().len();
+        self.board.next_column(column_count);
+    }
+
+    /// Select the card above in the focused column
+    pub fn board_select_previous(&mut self) {
+        let card_count = self.board_card_count();
+        self.board.select_previous(card_count);
+    }
+
+    /// Select the card below in the focused column
+    pub fn board_select_next(&mut self) {
+        let card_count = self.board_card_count();
+        self.board.select_next(card_count);
+    }
+
+    /// Focus a board column and select the card in a line of it, counted
+    /// from the first visible line
+    ///
+    /// Returns whether the line shows a card.
+    pub fn click_card(&mut self, column: usize, line: usize) -> bool {
+        self.board.column = column;
+        let card_count = self.board_card_count();
+        let card = self.board.offset_of(column) + line / BOARD_CARD_HEIGHT;
+        if card >= card_count {
+            return false;
+        }
+        self.board.select(column, card);
+        true
+    }
+
+    fn board_card_count(&self) -> usize {
+        self.get_board_columns()
+            .get(self.board.column)
+            .map_or(0, |column| column.tasks.len())
+    }
+
+    /// Move the selected card to the column on the left (-1) or right (1)
+    ///
+    /// The task gets the status, priority or category of the target column,
+    /// and the selection follows the card. A new category only replaces the
+    /// first tag, the other tags are kept.
+    pub fn move_selected_card(&mut self, direction: isize) {
+        let columns = self.get_board_columns();
+        let Some((task_id, mut tags)) = self
+            .get_selected_task()
+            .map(|task| (task.id, task.tags.clone()))
+        else {
+            return;
+        };
+        let Some(target) = self
+            .board
+            .column
+            .checked_add_signed(direction)
+            .filter(|&target| target < columns.len())
+        else {
+            return;
+        };
+        let key = columns[target].key.clone();
+        drop(columns);
+
+        let result = match &key {
+            ColumnKey::Status(status) => self.service.set_status(task_id, *status).map(|_| ()),
+            ColumnKey::Priority(priority) => {
+                self.service.set_priority(task_id, *priority).map(|_| ())
+            }
+            ColumnKey::Category(category) => {
+                if !tags.is_empty() {
+                    tags.remove(0);
+                }
+                if let Some(category) = category {
+                    tags.retain(|tag| !tag.eq_ignore_ascii_case(category));
+                    tags.insert(0, category.clone());
+                }
+                self.service.set_tags(task_id, &tags).map(|_| ())
+            }
+        };
+
+        self.status_message = Some(match result {
+            Ok(()) => {
+                // Follow the card; category columns may have changed
+                let columns = self.get_board_columns();
+                let position = columns.iter().enumerate().find_map(|(index, column)| {
+                    column
+                        .tasks
+                        .iter()
+                        .position(|task| task.id == task_id)
+                        .map(|card| (index, card))
+                });
+                if let Some((column, card)) = position {
+                    self.board.column = column;
+                    self.board.select(column, card);
+                }
+                format!("Task {} moved to {}", task_id, key.title())
+            }
+            Err(err) => err.message(),
+        });
+    }
+
+    /// Get the matching tasks due in the month shown in the calendar,
+    /// including the occurrences of recurring tasks
+    pub fn get_calendar_entries(&self) -> Vec<AgendaEntry<'_>> {
+        due_entries(
+            &self.get_matching_tasks(),
+            self.calendar.month_start(),
+            self.calendar.month_end(),
+        )
+    }
+
+    /// Get the matching tasks due on the selected calendar day
+    pub fn get_day_entries(&self) -> Vec<AgendaEntry<'_>> {
+        due_entries(&self.get_matching_tasks(), self.calendar.day, self.calendar.day)
+    }
+
+    /// Move the selected calendar day by a number of days
+    pub fn calendar_move_days(&mut self, days: i64) {
+        self.calendar.move_days(days);
+    }
+
+    /// Move the calendar to the previous (-1) or next (1) month
+    pub fn calendar_move_months(&mut self, months: i32) {
+        self.calendar.move_months(months);
+    }
+
+    /// Select today in the calendar
+    pub fn calendar_today(&mut self) {
+        self.calendar.go_to(Local::now().date_naive());
+    }
+
+    /// Select a calendar day, closing the open day
+    pub fn click_calendar_day(&mut self, day: chrono::NaiveDate) {
+        self.calendar.go_to(day);
+        self.calendar.day_open = false;
+    }
+
+    /// Open the selected day and select the task in a line of its list,
+    /// counted from the first visible line
+    ///
+    /// Returns whether the line shows a task.
+    pub fn click_day_entry(&mut self, line: usize) -> bool {
+        let index = self.calendar.offset + line;
+        if index >= self.get_day_entries().len() {
+            return false;
+        }
+        self.calendar.day_open = true;
+        self.calendar.selected = index;
+        true
+    }
+
+    /// Open the selected day to go through its tasks
+    pub fn open_calendar_day(&mut self) {
+        if self.get_day_entries().is_empty() {
+            self.status_message = Some("No tasks due on this day".to_string());
+            return;
+        }
+        self.calendar.day_open = true;
+        self.calendar.selected = 0;
+    }
+
+    /// Close the open day and go back to choosing a day
+    pub fn close_calendar_day(&mut self) {
+        self.calendar.day_open = false;
+    }
+
+    /// Select the previous task of the open day
+    pub fn calendar_select_previous(&mut self) {
+        self.calendar.selected = self.calendar.selected.saturating_sub(1);
+    }
+
+    /// Select the next task of the open day
+    pub fn calendar_select_next(&mut self) {
+        let count = self.get_day_entries().len();
+        if self.calendar.selected + 1 < count {
+            self.calendar.selected += 1;
+        }
     }
 
     /// Toggle the completion status of the selected task
     pub fn toggle_selected_task(&mut self) {
         if let Some(task) = self.get_selected_task() {
             let task_id = task.id;
-            let new_status = !task.is_completed();
-            self.todo_list.toggle_task(task_id);
-            
-            self.status_message = Some(format!(
-                "Task {} marked as {}",
-                task_id,
-                if new_status { "completed" } else { "pending" }
-            ));
+            self.status_message = Some(match self.service.toggle_task(task_id) {
+                Ok(outcome) => {
+                    let status = if outcome.task.is_completed() { "completed" } else { "pending" };
+                    match outcome.next_occurrence {
+                        Some(next) => format!(
+                            "Task {} marked as {}, next occurrence is task {}",
+                            task_id, status, next.id
+                        ),
+                        None => format!("Task {} marked as {}", task_id, status),
+                    }
+                }
+                Err(err) => err.message(),
+            });
         }
     }
 
@@ -121,7 +581,7 @@ impl App {
     pub fn delete_selected_task(&mut self) {
         if let Some(task) = self.get_selected_task() {
             let task_id = task.id;
-            self.todo_list.remove_task(task_id);
+            let result = self.service.remove_task(task_id);
             
             // Adjust selection if needed
             let tasks_len = self.get_displayed_tasks().len();
@@ -129,7 +589,10 @@ impl App {
                 self.selected = tasks_len - 1;
             }
             
-            self.status_message = Some(format!("Task {} deleted", task_id));
+            self.status_message = Some(match result {
+                Ok(_) => format!("Task {} deleted", task_id),
+                Err(err) => err.message(),
+            });
         }
     }
 
@@ -139,17 +602,203 @@ impl App {
         self.input.clear();
     }
 
-    /// Start editing the selected task
+    /// Open the task form to create a new task
+    pub fn start_creating_task(&mut self) {
+        self.form = Some(TaskForm::new_task());
+        self.input_mode = InputMode::TaskForm;
+    }
+
+    /// Open the task form to edit all fields of the selected task
     pub fn start_editing(&mut self) {
+        if let Some(task) = self.get_selected_task() {
+            self.form = Some(TaskForm::edit_task(task));
+            self.input_mode = InputMode::TaskForm;
+        }
+    }
+
+    /// Save the task form
+    ///
+    /// The form stays open while a field is invalid, with the error shown
+    /// below that field.
+    pub fn submit_form(&mut self) {
+        let Some(form) = self.form.as_mut() else {
+            self.input_mode = InputMode::Normal;
+            return;
+        };
+        let Some(changes) = form.validate(chrono::Local::now().date_naive()) else {
+            return;
+        };
+
+        let result = match form.task_id {
+            Some(task_id) => self
+                .service
+                .update_task(task_id, &changes)
+                .map(|_| format!("Task {} updated", task_id)),
+            None => self
+                .service
+                .add_task(changes.into_new_task())
+                .map(|task_id| format!("Task {} added", task_id)),
+        };
+
+        match result {
+            Ok(message) => {
+                self.status_message = Some(message);
+                self.form = None;
+                self.input_mode = InputMode::Normal;
+            }
+            Err(err) => form.show_error(&err),
+        }
+    }
+
+    /// Open the task form to add a subtask under the selected task
+    pub fn start_adding_subtask(&mut self) {
+        if let Some(task) = self.get_selected_task() {
+            self.form = Some(TaskForm::new_subtask(task.id));
+            self.input_mode = InputMode::TaskForm;
+        }
+    }
+
+    /// Select the row of the task with the given ID, if it is shown
+    fn select_task(&mut self, task_id: usize) {
+        if let Some(index) = self
+            .get_displayed_tasks()
+            .iter()
+            .position(|task| task.id == task_id)
+        {
+            self.selected = index;
+        }
+    }
+
+    /// Hide the subtasks of the selected task
+    ///
+    /// If the task has no shown subtasks, the selection moves to its parent.
+    pub fn collapse_selected(&mut self) {
+        let rows = self.get_task_rows();
+        let Some(row) = rows.get(self.selected) else {
+            return;
+        };
+        let (task_id, parent_id) = (row.task.id, row.task.parent_id);
+        let can_collapse = row.has_children && !row.collapsed;
+        drop(rows);
+
+        if can_collapse {
+            self.collapsed.insert(task_id);
+        } else if let Some(parent_id) = parent_id {
+            self.select_task(parent_id);
+        }
+    }
+
+    /// Show the subtasks of the selected task
+    pub fn expand_selected(&mut self) {
         if let Some(task) = self.get_selected_task() {
             let task_id = task.id;
-            let description = task.description.clone();
-            self.input_mode = InputMode::Editing;
-            self.editing_task_id = Some(task_id);
-            self.input = description;
+            self.collapsed.remove(&task_id);
         }
     }
 
+    /// Hide the subtasks of all tasks
+    ///
+    /// The selection moves to the top-level task containing the selected task.
+    pub fn collapse_all(&mut self) {
+        let todo_list = self.service.todo_list();
+        let find = |id: usize| todo_list.get_tasks().iter().find(|task| task.id == id);
+
+        // Follow the parents of the selection up to the top level; the bound
+        // guards against stored parents that form a cycle
+        let mut root_id = self.get_selected_task().map(|task| task.id);
+        for _ in 0..todo_list.get_tasks().len() {
+            match root_id
+                .and_then(find)
+                .and_then(|task| task.parent_id)
+                .filter(|&parent_id| find(parent_id).is_some())
+            {
+                Some(parent_id) => root_id = Some(parent_id),
+                None => break,
+            }
+        }
+
+        self.collapsed = todo_list
+            .get_tasks()
+            .iter()
+            .filter_map(|task| task.parent_id)
+            .collect();
+        self.selected = 0;
+        if let Some(root_id) = root_id {
+            self.select_task(root_id);
+        }
+    }
+
+    /// Show the subtasks of all tasks
+    pub fn expand_all(&mut self) {
+        let selected_id = self.get_selected_task().map(|task| task.id);
+        self.collapsed.clear();
+        if let Some(task_id) = selected_id {
+            self.select_task(task_id);
+        }
+    }
+
+    /// Make the selected task a subtask of the sibling shown above it
+    pub fn indent_selected(&mut self) {
+        let tasks = self.get_displayed_tasks();
+        let Some(task) = tasks.get(self.selected) else {
+            return;
+        };
+        let (task_id, parent_id) = (task.id, task.parent_id);
+        let sibling_id = tasks[..self.selected]
+            .iter()
+            .rev()
+            .take_while(|other| Some(other.id) != parent_id)
+            .find(|other| other.parent_id == parent_id)
+            .map(|sibling| sibling.id);
+        drop(tasks);
+
+        match sibling_id {
+            Some(sibling_id) => self.move_task(task_id, Some(sibling_id)),
+            None => {
+                self.status_message =
+                    Some(format!("Task {} has no task above it to indent under", task_id));
+            }
+        }
+    }
+
+    /// Move the selected task up one level, next to its current parent
+    pub fn outdent_selected(&mut self) {
+        let Some(task) = self.get_selected_task() else {
+            return;
+        };
+        let task_id = task.id;
+        match task.parent_id {
+            Some(parent_id) => {
+                let grandparent_id = self
+                    .todo_list()
+                    .get_tasks()
+                    .iter()
+                    .find(|t| t.id == parent_id)
+                    .and_then(|parent| parent.parent_id);
+                self.move_task(task_id, grandparent_id);
+            }
+            None => {
+                self.status_message = Some(format!("Task {} is already a top-level task", task_id));
+            }
+        }
+    }
+
+    fn move_task(&mut self, task_id: usize, parent_id: Option<usize>) {
+        self.status_message = Some(match self.service.set_parent(task_id, parent_id) {
+            Ok(_) => {
+                if let Some(parent_id) = parent_id {
+                    self.collapsed.remove(&parent_id);
+                }
+                self.select_task(task_id);
+                match parent_id {
+                    Some(parent_id) => format!("Task {} moved under task {}", task_id, parent_id),
+                    None => format!("Task {} moved to the top level", task_id),
+                }
+            }
+            Err(err) => err.message(),
+        });
+    }
+
     /// Start searching
     pub fn start_searching(&mut self) {
         self.input_mode = InputMode::Searching;
@@ -161,29 +810,103 @@ impl App {
         self.input_mode = InputMode::Help;
     }
 
+    /// Switch to the next project in the sidebar, wrapping around
+    pub fn next_project(&mut self) {
+        self.switch_project_by_offset(1);
+    }
+
+    /// Switch to the previous project in the sidebar, wrapping around
+    pub fn previous_project(&mut self) {
+        let count = self.project_names().len();
+        self.switch_project_by_offset(count.saturating_sub(1));
+    }
+
+    fn switch_project_by_offset(&mut self, offset: usize) {
+        let names = self.project_names();
+        let current = names
+            .iter()
+            .position(|name| name == self.current_project_name())
+            .unwrap_or(0);
+        let target = names[(current + offset) % names.len()].clone();
+        self.switch_project(&target);
+    }
+
+    fn switch_project(&mut self, name: &str) {
+        self.status_message = Some(match self.service.switch_project(name) {
+            Ok(()) => format!("Switched to project '{}'", name),
+            Err(err) => err.message(),
+        });
+        self.search_query.clear();
+        self.collapsed.clear();
+        self.board.selected.clear();
+        self.board.offsets.clear();
+        self.calendar.day_open = false;
+        self.calendar.selected = 0;
+        self.selected = 0;
+        self.scroll_offset = 0;
+    }
+
+    /// Start creating a new project
+    pub fn start_creating_project(&mut self) {
+        self.input_mode = InputMode::CreatingProject;
+        self.input.clear();
+    }
+
+    /// Start renaming the current project
+    pub fn start_renaming_project(&mut self) {
+        self.input_mode = InputMode::RenamingProject;
+        self.input = self.current_project_name().to_string();
+    }
+
+    /// Ask for confirmation before deleting the current project
+    pub fn start_deleting_project(&mut self) {
+        if self.project_names().len() < 2 {
+            self.status_message = Some("Cannot delete the only project".to_string());
+            return;
+        }
+        self.input_mode = InputMode::DeletingProject;
+    }
+
+    /// Delete the current project and switch to another one
+    ///
+    /// The project is moved to the trash and can be restored with `project restore`.
+    pub fn confirm_delete_project(&mut self) {
+        self.input_mode = InputMode::Normal;
+        let name = self.current_project_name().to_string();
+        let Some(fallback) = self
+            .project_names()
+            .into_iter()
+            .find(|other| *other != name)
+        else {
+            return;
+        };
+
+        self.switch_project(&fallback);
+        self.status_message = Some(match self.service.delete_project(&name) {
+            Ok(()) => format!("Project '{}' deleted, switched to '{}'", name, fallback),
+            Err(err) => {
+                // Stay on the project that could not be deleted
+                let _ = self.service.switch_project(&name);
+                err.message()
+            }
+        });
+    }
+
     /// Submit the current input
     pub fn submit_input(&mut self) {
         match self.input_mode {
             InputMode::Adding => {
                 if !self.input.is_empty() {
                     let new_task = TaskWithoutId::new(self.input.clone());
-                    let task_id = self.todo_list.add_task(new_task);
-                    self.status_message = Some(format!("Task {} added", task_id));
-                    self.input.clear();
-                }
-                self.input_mode = InputMode::Normal;
-            }
-            InputMode::Editing => {
-                if let Some(task_id) = self.editing_task_id {
-                    if !self.input.is_empty() {
-                        self.todo_list.edit_task(task_id, self.input.clone());
-                        self.status_message = Some(format!("Task {} updated", task_id));
-                    }
+                    self.status_message = Some(match self.service.add_task(new_task) {
+                        Ok(task_id) => format!("Task {} added", task_id),
+                        Err(err) => err.message(),
+                    });
                     self.input.clear();
-                    self.editing_task_id = None;
                 }
                 self.input_mode = InputMode::Normal;
             }
+            InputMode::TaskForm => self.submit_form(),
             InputMode::Searching => {
                 self.search_query = self.input.clone();
                 self.input.clear();
@@ -191,6 +914,33 @@ impl App {
                 self.scroll_offset = 0;
                 self.input_mode = InputMode::Normal;
             }
+            InputMode::CreatingProject => {
+                let name = self.input.trim().to_string();
+                if !name.is_empty() {
+                    match self.service.create_project(&name) {
+                        Ok(()) => {
+                            self.switch_project(&name);
+                            self.status_message = Some(format!("Project '{}' created", name));
+                        }
+                        Err(err) => self.status_message = Some(err.message()),
+                    }
+                }
+                self.input.clear();
+                self.input_mode = InputMode::Normal;
+            }
+            InputMode::RenamingProject => {
+                let old_name = self.current_project_name().to_string();
+                let new_name = self.input.trim().to_string();
+                if !new_name.is_empty() && new_name != old_name {
+                    self.status_message =
+                        Some(match self.service.rename_project(&old_name, &new_name) {
+                            Ok(()) => format!("Project '{}' renamed to '{}'", old_name, new_name),
+                            Err(err) => err.message(),
+                        });
+                }
+                self.input.clear();
+                self.input_mode = InputMode::Normal;
+            }
             _ => {}
         }
     }
@@ -198,27 +948,31 @@ impl App {
     /// Cancel the current input
     pub fn cancel_input(&mut self) {
         self.input.clear();
-        self.editing_task_id = None;
+        self.form = None;
         self.input_mode = InputMode::Normal;
     }
 
-    /// Add a character to the input
+    /// Add a character to the input or the focused form field
     pub fn push_char(&mut self, c: char) {
-        self.input.push(c);
+        match self.form.as_mut() {
+            Some(form) => form.push_char(c),
+            None => self.input.push(c),
+        }
     }
 
-    /// Remove the last character from the input
+    /// Remove the last character from the input or the focused form field
     pub fn pop_char(&mut self) {
-        self.input.pop();
+        match self.form.as_mut() {
+            Some(form) => form.pop_char(),
+            None => {
+                self.input.pop();
+            }
+        }
     }
 
-    /// Cycle through filters
-    pub fn cycle_filter(&mut self) {
-        self.filter = match self.filter.as_str() {
-            "all" => "pending".to_string(),
-            "pending" => "completed".to_string(),
-            _ => "all".to_string(),
-        };
+    /// Cycle through views
+    pub fn cycle_view(&mut self) {
+        self.current_view = (self.current_view + 1) % self.views.len();
         self.selected = 0;
         self.scroll_offset = 0;
     }
@@ -235,3 +989,164 @@ impl App {
         self.should_quit = true;
     }
 }
+
+/// Builds the rows of the task tree
+struct TaskTree<'a, 'b> {
+    todo_list: &'a TodoList,
+    filter: Option<&'b TaskFilter>,
+    visible: &'b HashSet<usize>,
+    matching: &'b HashSet<usize>,
+    collapsed: &'b HashSet<usize>,
+}
+
+impl<'a> TaskTree<'a, '_> {
+    /// Add rows for the given sibling tasks and, recursively, their shown subtasks
+    fn push_rows(&self, mut siblings: Vec<&'a Task>, depth: usize, prefix: &str, rows: &mut Vec<TaskRow<'a>>) {
+        if let Some(filter) = self.filter {
+            let sort_order = filter.sort_order.unwrap_or_default();
+            self.todo_list.sort_tasks(&mut siblings, &filter.sort_keys, sort_order);
+        }
+
+        let count = siblings.len();
+        for (index, task) in siblings.into_iter().enumerate() {
+            // Depth guards against stored parents that form a cycle
+            if depth > self.todo_list.get_tasks().len() {
+                return;
+            }
+            let is_last = index + 1 == count;
+            let children: Vec<&Task> = self
+                .todo_list
+                .get_subtasks(task.id)
+                .into_iter()
+                .filter(|child| self.visible.contains(&child.id))
+                .collect();
+            let collapsed = self.collapsed.contains(&task.id);
+
+            let (guides, child_prefix) = if depth == 0 {
+                (String::new(), String::new())
+            } else {
+                (
+                    format!("{}{}", prefix, if is_last { "└─ " } else { "├─ " }),
+                    format!("{}{}", prefix, if is_last { "   " } else { "│  " }),
+                )
+            };
+
+            rows.push(TaskRow {
+                task,
+                guides,
+                has_children: !children.is_empty(),
+                collapsed,
+                subtask_count: self.todo_list.get_subtask_count(task.id),
+                completed_subtask_count: self.todo_list.get_completed_subtask_count(task.id),
+                matches: self.matching.contains(&task.id),
+            });
+
+            if !collapsed {
+                self.push_rows(children, depth + 1, &child_prefix, rows);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::project_command::ProjectManager;
+
+    /// Creates an app with a top-level task for each description.
+    fn app_with_tasks(descriptions: &[&str]) -> App {
+        let mut service = TodoService::new(ProjectManager::new());
+        for description in descriptions {
+            service
+                .add_task(TaskWithoutId::new(description.to_string()))
+                .unwrap();
+        }
+        App::new(service)
+    }
+
+    fn parent_of(app: &App, task_id: usize) -> Option<usize> {
+        app.todo_list()
+            .get_tasks()
+            .iter()
+            .find(|task| task.id == task_id)
+            .and_then(|task| task.parent_id)
+    }
+
+    #[test]
+    fn test_indent_moves_under_nearest_sibling_above() {
+        let mut app = app_with_tasks(&["A", "B"]);
+        app.service.add_subtask(1, "A child").unwrap();
+
+        // The tree shows A, A child, B; B skips A's subtask and goes under A
+        app.select_task(2);
+        app.indent_selected();
+
+        assert_eq!(parent_of(&app, 2), Some(1));
+        assert_eq!(app.get_selected_task().map(|task| task.id), Some(2));
+    }
+
+    #[test]
+    fn test_indent_expands_collapsed_target() {
+        let mut app = app_with_tasks(&["A", "B"]);
+        app.service.add_subtask(1, "A child").unwrap();
+        app.collapsed.insert(1);
+
+        app.select_task(2);
+        app.indent_selected();
+
+        assert_eq!(parent_of(&app, 2), Some(1));
+        assert!(!app.collapsed.contains(&1));
+        assert_eq!(app.get_selected_task().map(|task| task.id), Some(2));
+    }
+
+    #[test]
+    fn test_indent_first_child_does_nothing() {
+        let mut app = app_with_tasks(&["A"]);
+        app.service.add_subtask(1, "First child").unwrap();
+
+        app.select_task(2);
+        app.indent_selected();
+
+        assert_eq!(parent_of(&app, 2), Some(1));
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Task 2 has no task above it to indent under")
+        );
+    }
+
+    #[test]
+    fn test_outdent_moves_to_grandparent() {
+        let mut app = app_with_tasks(&["A"]);
+        app.service.add_subtask(1, "B").unwrap();
+        app.service.add_subtask(2, "C").unwrap();
+
+        app.select_task(3);
+        app.outdent_selected();
+        assert_eq!(parent_of(&app, 3), Some(1));
+
+        app.outdent_selected();
+        assert_eq!(parent_of(&app, 3), None);
+        app.outdent_selected();
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Task 3 is already a top-level task")
+        );
+    }
+
+    #[test]
+    fn test_editing_description_keeps_tags() {
+        let mut app = app_with_tasks(&["Report"]);
+        app.service
+            .add_tags(1, &["work".to_string(), "urgent".to_string()])
+            .unwrap();
+
+        app.select_task(1);
+        app.start_editing();
+        app.push_char('!');
+        app.submit_form();
+
+        let task = &app.todo_list().get_tasks()[0];
+        assert_eq!(task.description, "Report!");
+        assert_eq!(task.tags, vec!["work", "urgent"]);
+    }
+}
@@ -0,0 +1,372 @@
+use crate::models::Priority;
+use crate::models::Task;
+use crate::models::TaskStatus;
+
+/// What the columns of the board stand for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BoardGrouping {
+    /// One column per status
+    #[default]
+    Status,
+    /// One column per priority, highest first
+    Priority,
+    /// One column per category, after a column for tasks without one
+    Category,
+}
+
+impl BoardGrouping {
+    /// Get the grouping that follows this one
+    pub fn next(self) -> Self {
+        match self {
+            BoardGrouping::Status => BoardGrouping::Priority,
+            BoardGrouping::Priority => BoardGrouping::Category,
+            BoardGrouping::Category => BoardGrouping::Status,
+        }
+    }
+
+    /// Get the display name of the grouping
+    pub fn as_str(&self) -> &str {
+        match self {
+            BoardGrouping::Status => "Status",
+            BoardGrouping::Priority => "Priority",
+            BoardGrouping::Category => "Category",
+        }
+    }
+}
+
+/// The value a column stands for
+///
+/// Moving a card into a column sets this value on the task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnKey {
+    Status(TaskStatus),
+    Priority(Priority),
+    Category(Option<String>),
+}
+
+impl ColumnKey {
+    /// Get the column title
+    pub fn title(&self) -> String {
+        match self {
+            ColumnKey::Status(status) => status.as_str().to_string(),
+            ColumnKey::Priority(priority) => priority.as_str().to_string(),
+            ColumnKey::Category(Some(category)) => category.clone(),
+            ColumnKey::Category(None) => "No category".to_string(),
+        }
+    }
+
+    /// Check whether the task belongs in this column
+    pub fn contains(&self, task: &Task) -> bool {
+        match self {
+            ColumnKey::Status(status) => task.get_status() == *status,
+            ColumnKey::Priority(priority) => task.get_priority() == *priority,
+            ColumnKey::Category(category) => task.get_category() == category.as_ref(),
+        }
+    }
+}
+
+/// A column of the board with its cards
+pub struct BoardColumn<'a> {
+    /// The value the column stands for
+    pub key: ColumnKey,
+    /// The tasks shown as cards, in list order
+    pub tasks: Vec<&'a Task>,
+}
+
+/// Build the board columns for the given tasks
+///
+/// Status and priority boards always show all columns; a category board has
+/// a column for each category of the given tasks.
+pub fn build_columns<'a>(grouping: BoardGrouping, tasks: &[&'a Task]) -> Vec<BoardColumn<'a>> {
+    let keys: Vec<ColumnKey> = match grouping {
+        BoardGrouping::Status => TaskStatus::all()
+            .into_iter()
+            .map(ColumnKey::Status)
+            .collect(),
+        BoardGrouping::Priority => [Priority::High, Priority::Medium, Priority::Low]
+            .into_iter()
+            .map(ColumnKey::Priority)
+            .collect(),
+        BoardGrouping::Category => {
+            let mut categories: Vec<String> = tasks
+                .iter()
+                .filter_map(|task| task.get_category().cloned())
+                .collect();
+            categories.sort();
+            categories.dedup();
+            std::iter::once(None)
+                .chain(categories.into_iter().map(Some))
+                .map(ColumnKey::Category)
+                .collect()
+        }
+    };
+
+    keys.into_iter()
+        .map(|key| BoardColumn {
+            tasks: tasks
+                .iter()
+                .copied()
+                .filter(|task| key.contains(task))
+                .collect(),
+            key,
+        })
+        .collect()
+}
+
+/// Navigation state of the board
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Board {
+    /// What the columns stand for
+    pub grouping: BoardGrouping,
+    /// Index of the focused column
+    pub column: usize,
+    /// Selected card in each column
+    pub selected: Vec<usize>,
+    /// Scroll offset of each column, kept up to date when rendering
+    pub offsets: Vec<usize>,
+}
+
+impl Board {
+    /// Switch to the next grouping and start at the first column
+    pub fn cycle_grouping(&mut self) {
+        self.grouping = self.grouping.next();
+        self.column = 0;
+        self.selected.clear();
+        self.offsets.clear();
+    }
+
+    /// Get the selected card index in a column with `card_count` cards
+    pub fn selected_in(&self, column: usize, card_count: usize) -> Option<usize> {
+        if card_count == 0 {
+            return None;
+        }
+        Some(
+            self.selected
+                .get(column)
+                .copied()
+                .unwrap_or(0)
+                .min(card_count - 1),
+        )
+    }
+
+    /// Get the scroll offset of a column
+    pub fn offset_of(&self, column: usize) -> usize {
+        self.offsets.get(column).copied().unwrap_or(0)
+    }
+
+    /// Select a card in a column
+    pub fn select(&mut self, column: usize, card: usize) {
+        if self.selected.len() <= column {
+            self.selected.resize(column + 1, 0);
+        }
+        self.selected[column] = card;
+    }
+
+    /// Move the focus to the column left of the current one
+    pub fn previous_column(&mut self) {
+        self.column = self.column.saturating_sub(1);
+    }
+
+    /// Move the focus to the column right of the current one
+    pub fn next_column(&mut self, column_count: usize) {
+        if self.column + 1 < column_count {
+            self.column += 1;
+        }
+    }
+
+    /// Select the card above the selected one in the focused column
+    pub fn select_previous(&mut self, card_count: usize) {
+        if let Some(card) = self.selected_in(self.column, card_count) {
+            self.select(self.column, card.saturating_sub(1));
+        }
+    }
+
+    /// Select the card below the selected one in the focused column
+    pub fn select_next(&mut self, card_count: usize) {
+        if let Some(card) = self.selected_in(self.column, card_count) {
+            self.select(self.column, (card + 1).min(card_count - 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::project_command::ProjectManager;
+    use crate::models::task::TaskWithoutId;
+    use crate::service::TodoService;
+    use crate::tui::app::{App, Screen};
+
+    fn task(id: usize, category: Option<&str>) -> Task {
+        let mut task = Task::new(id, format!("Task {}", id));
+        task.set_category(category.map(str::to_string));
+        task
+    }
+
+    fn card_ids(column: &BoardColumn) -> Vec<usize> {
+        column.tasks.iter().map(|task| task.id).collect()
+    }
+
+    /// Creates an app on the board with a task for each category.
+    fn board_app(grouping: BoardGrouping, categories: &[Option<&str>]) -> App {
+        let mut service = TodoService::new(ProjectManager::new());
+        for (index, category) in categories.iter().enumerate() {
+            let id = service
+                .add_task(TaskWithoutId::new(format!("Task {}", index + 1)))
+                .unwrap();
+            service
+                .set_category(id, category.map(str::to_string))
+                .unwrap();
+        }
+        let mut app = App::new(service);
+        app.screen = Screen::Board;
+        app.board.grouping = grouping;
+        app
+    }
+
+    fn selected_id(app: &App) -> Option<usize> {
+        app.get_selected_task().map(|task| task.id)
+    }
+
+    #[test]
+    fn test_status_and_priority_boards_show_all_columns() {
+        let mut done = task(1, None);
+        done.set_status(TaskStatus::Completed);
+        let open = task(2, None);
+        let tasks = vec![&done, &open];
+
+        let columns = build_columns(BoardGrouping::Status, &tasks);
+        assert_eq!(columns.len(), TaskStatus::all().len());
+        assert_eq!(card_ids(&columns[0]), vec![2]);
+        assert_eq!(card_ids(&columns[4]), vec![1]);
+
+        let columns = build_columns(BoardGrouping::Priority, &tasks);
+        let keys: Vec<ColumnKey> = columns.iter().map(|column| column.key.clone()).collect();
+        assert_eq!(
+            keys,
+            vec![
+                ColumnKey::Priority(Priority::High),
+                ColumnKey::Priority(Priority::Medium),
+                ColumnKey::Priority(Priority::Low)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_category_board_has_a_column_per_category() {
+        let tasks = [
+            task(1, Some("work")),
+            task(2, None),
+            task(3, Some("home")),
+            task(4, Some("work")),
+        ];
+        let tasks: Vec<&Task> = tasks.iter().collect();
+
+        let columns = build_columns(BoardGrouping::Category, &tasks);
+        let titles: Vec<String> = columns.iter().map(|column| column.key.title()).collect();
+        assert_eq!(titles, vec!["No category", "home", "work"]);
+        assert_eq!(card_ids(&columns[2]), vec![1, 4]);
+    }
+
+    #[test]
+    fn test_board_selection_stays_within_columns() {
+        let mut board = Board::default();
+        assert_eq!(board.selected_in(0, 0), None);
+
+        board.select_next(2);
+        board.select_next(2);
+        assert_eq!(board.selected_in(0, 2), Some(1));
+        // Fewer cards after a move clamp the selection
+        assert_eq!(board.selected_in(0, 1), Some(0));
+
+        board.next_column(2);
+        board.next_column(2);
+        assert_eq!(board.column, 1);
+
+        board.cycle_grouping();
+        assert_eq!(board.grouping, BoardGrouping::Priority);
+        assert_eq!(board.column, 0);
+        assert!(board.selected.is_empty());
+    }
+
+    #[test]
+    fn test_moving_a_card_sets_status_and_follows_it() {
+        let mut app = board_app(BoardGrouping::Status, &[None, None]);
+        app.board_select_next();
+        assert_eq!(selected_id(&app), Some(2));
+
+        app.move_selected_card(1);
+        assert_eq!(
+            app.todo_list().get_tasks()[1].get_status(),
+            TaskStatus::InProgress
+        );
+        assert_eq!(app.board.column, 1);
+        assert_eq!(selected_id(&app), Some(2));
+
+        // There is no column left of the first one
+        app.board.column = 0;
+        app.move_selected_card(-1);
+        assert_eq!(
+            app.todo_list().get_tasks()[0].get_status(),
+            TaskStatus::Pending
+        );
+    }
+
+    #[test]
+    fn test_moving_a_card_sets_priority() {
+        let mut app = board_app(BoardGrouping::Priority, &[None]);
+        app.board.column = 1;
+        assert_eq!(selected_id(&app), Some(1));
+
+        app.move_selected_card(-1);
+        assert_eq!(
+            app.todo_list().get_tasks()[0].get_priority(),
+            Priority::High
+        );
+        assert_eq!(app.board.column, 0);
+        assert_eq!(selected_id(&app), Some(1));
+    }
+
+    #[test]
+    fn test_moving_a_card_follows_it_into_rebuilt_category_columns() {
+        let mut app = board_app(BoardGrouping::Category, &[Some("home"), Some("work"), None]);
+
+        // No category -> home: the card lands below task 1
+        app.move_selected_card(1);
+        assert_eq!(
+            app.todo_list().get_tasks()[2].get_category(),
+            Some(&"home".to_string())
+        );
+        assert_eq!(app.board.column, 1);
+        assert_eq!(selected_id(&app), Some(3));
+
+        // Moving the last work task away removes the work column
+        app.board.column = 2;
+        assert_eq!(selected_id(&app), Some(2));
+        app.move_selected_card(-1);
+        assert_eq!(app.get_board_columns().len(), 2);
+        assert_eq!(app.board.column, 1);
+        assert_eq!(selected_id(&app), Some(2));
+    }
+
+    #[test]
+    fn test_moving_a_card_keeps_its_other_tags() {
+        let mut app = board_app(BoardGrouping::Category, &[Some("home"), Some("work")]);
+        app.service
+            .add_tags(2, &["urgent".to_string(), "home".to_string()])
+            .unwrap();
+        let tags = |app: &App| app.todo_list().get_tasks()[1].tags.clone();
+
+        // work -> home: only the grouping tag changes, without a duplicate
+        app.board.column = 2;
+        app.move_selected_card(-1);
+        assert_eq!(tags(&app), vec!["home", "urgent"]);
+
+        // home -> no category drops the first tag only
+        app.board.column = 1;
+        app.board_select_next();
+        assert_eq!(selected_id(&app), Some(2));
+        app.move_selected_card(-1);
+        assert_eq!(tags(&app), vec!["urgent"]);
+    }
+}
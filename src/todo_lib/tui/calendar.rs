@@ -0,0 +1,93 @@
+use chrono::{Datelike, Duration, Months, NaiveDate};
+
+/// Navigation state of the month calendar
+///
+/// The shown month is the month of the selected day. A day can be opened to
+/// go through its tasks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Calendar {
+    /// The selected day
+    pub day: NaiveDate,
+    /// Whether the task list of the selected day has the focus
+    pub day_open: bool,
+    /// Selected task in the list of the open day
+    pub selected: usize,
+    /// Scroll offset of the task list of the day, kept up to date when rendering
+    pub offset: usize,
+}
+
+impl Calendar {
+    /// Create a calendar showing the given day
+    pub fn new(day: NaiveDate) -> Self {
+        Self {
+            day,
+            day_open: false,
+            selected: 0,
+            offset: 0,
+        }
+    }
+
+    /// Get the first day of the shown month
+    pub fn month_start(&self) -> NaiveDate {
+        self.day.with_day(1).unwrap_or(self.day)
+    }
+
+    /// Get the last day of the shown month
+    pub fn month_end(&self) -> NaiveDate {
+        self.month_start()
+            .checked_add_months(Months::new(1))
+            .map_or(self.day, |next_month| next_month - Duration::days(1))
+    }
+
+    /// Get the weeks of the shown month, Monday first
+    ///
+    /// Days outside the month are None.
+    pub fn weeks(&self) -> Vec<[Option<NaiveDate>; 7]> {
+        let start = self.month_start();
+        let end = self.month_end();
+        let mut weeks = Vec::new();
+        let mut week = [None; 7];
+        let mut date = start;
+
+        while date <= end {
+            let weekday = date.weekday().num_days_from_monday() as usize;
+            week[weekday] = Some(date);
+            if weekday == 6 {
+                weeks.push(week);
+                week = [None; 7];
+            }
+            date += Duration::days(1);
+        }
+        if week.iter().any(Option::is_some) {
+            weeks.push(week);
+        }
+        weeks
+    }
+
+    /// Select a day
+    pub fn go_to(&mut self, day: NaiveDate) {
+        self.day = day;
+        self.selected = 0;
+        self.offset = 0;
+    }
+
+    /// Move the selected day by a number of days (negative for earlier days)
+    pub fn move_days(&mut self, days: i64) {
+        self.go_to(self.day + Duration::days(days));
+    }
+
+    /// Move to the same day in the previous (-1) or next (1) month
+    ///
+    /// The day is clamped to the end of shorter months.
+    pub fn move_months(&mut self, months: i32) {
+        let months_to_move = Months::new(months.unsigned_abs());
+        let day = if months < 0 {
+            self.day.checked_sub_months(months_to_move)
+        } else {
+            self.day.checked_add_months(months_to_move)
+        };
+        if let Some(day) = day {
+            self.go_to(day);
+        }
+    }
+}
@@ -1,14 +1,21 @@
 use std::io;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind,
+        MouseButton, MouseEvent, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
 
-use super::app::{App, InputMode};
+use super::app::{App, InputMode, Screen};
+use super::mouse::{contains, list_line, ClickTracker};
 use super::ui;
 
+/// Number of lines the mouse wheel scrolls the task list and details pane
+const SCROLL_LINES: isize = 3;
+
 /// Run the TUI application
 pub fn run_tui(mut app: App) -> io::Result<App> {
     // Setup terminal
@@ -39,22 +46,37 @@ fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     app: &mut App,
 ) -> io::Result<()> {
+    let mut clicks = ClickTracker::default();
+
     loop {
         // Draw the UI
         terminal.draw(|f| ui::render(f, app))?;
 
         // Handle input
-        if let Event::Key(key) = event::read()? {
+        let event = event::read()?;
+        if let Event::Mouse(mouse) = event {
+            handle_mouse(app, mouse, &mut clicks);
+        } else if let Event::Key(key) = event {
             // Only process key press events (not release)
             if key.kind != KeyEventKind::Press {
                 continue;
             }
 
             match app.input_mode {
-                InputMode::Normal => handle_normal_mode(app, key.code),
-                InputMode::Adding | InputMode::Editing | InputMode::Searching => {
-                    handle_input_mode(app, key.code)
-                }
+                InputMode::Normal => match app.screen {
+                    Screen::Tree => handle_normal_mode(app, key.code),
+                    Screen::Board => handle_board_mode(app, key.code),
+                    Screen::Calendar => handle_calendar_mode(app, key.code),
+                },
+                InputMode::TaskForm => handle_form_mode(app, key.code),
+                InputMode::Adding
+                | InputMode::Searching
+                | InputMode::CreatingProject
+                | InputMode::RenamingProject => handle_input_mode(app, key.code),
+                InputMode::DeletingProject => match key.code {
+                    KeyCode::Char('y') | KeyCode::Char('Y') => app.confirm_delete_project(),
+                    _ => app.cancel_input(),
+                },
                 InputMode::Help => {
                     // Any key exits help
                     app.input_mode = InputMode::Normal;
@@ -77,23 +99,208 @@ fn handle_normal_mode(app: &mut App, key: KeyCode) {
         // Navigation
         KeyCode::Up | KeyCode::Char('k') => app.select_previous(),
         KeyCode::Down | KeyCode::Char('j') => app.select_next(),
+        KeyCode::Left | KeyCode::Char('h') => app.collapse_selected(),
+        KeyCode::Right | KeyCode::Char('l') => app.expand_selected(),
+        KeyCode::Char('-') => app.collapse_all(),
+        KeyCode::Char('+') | KeyCode::Char('=') => app.expand_all(),
 
         // Actions
         KeyCode::Enter | KeyCode::Char(' ') => app.toggle_selected_task(),
         KeyCode::Char('a') => app.start_adding(),
+        KeyCode::Char('A') => app.start_creating_task(),
         KeyCode::Char('e') => app.start_editing(),
+        KeyCode::Char('s') => app.start_adding_subtask(),
+        KeyCode::Char('>') => app.indent_selected(),
+        KeyCode::Char('<') => app.outdent_selected(),
         KeyCode::Char('d') => app.delete_selected_task(),
         KeyCode::Char('/') => app.start_searching(),
         KeyCode::Char('?') => app.show_help(),
-        KeyCode::Char('f') => app.cycle_filter(),
+        KeyCode::Char('f') => app.cycle_view(),
+        KeyCode::Char('b') => app.toggle_board(),
+        KeyCode::Char('C') => app.toggle_calendar(),
         KeyCode::Char('c') => app.clear_search(),
+
+        // Projects
+        KeyCode::Tab | KeyCode::Char(']') => app.next_project(),
+        KeyCode::BackTab | KeyCode::Char('[') => app.previous_project(),
+        KeyCode::Char('N') => app.start_creating_project(),
+        KeyCode::Char('R') => app.start_renaming_project(),
+        KeyCode::Char('X') => app.start_deleting_project(),
         KeyCode::Char('q') | KeyCode::Esc => app.quit(),
 
         _ => {}
     }
 }
 
-/// Handle keyboard input in input modes (adding, editing, searching)
+/// Handle keyboard input in normal mode while the board is shown
+///
+/// Navigation and moving cards use board keys; all other keys work as in
+/// normal mode on the selected card.
+fn handle_board_mode(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Up | KeyCode::Char('k') => app.board_select_previous(),
+        KeyCode::Down | KeyCode::Char('j') => app.board_select_next(),
+        KeyCode::Left | KeyCode::Char('h') => app.board_previous_column(),
+        KeyCode::Right | KeyCode::Char('l') => app.board_next_column(),
+        KeyCode::Char('<') => app.move_selected_card(-1),
+        KeyCode::Char('>') => app.move_selected_card(1),
+        KeyCode::Char('g') => app.cycle_board_grouping(),
+        _ => handle_normal_mode(app, key),
+    }
+}
+
+/// Handle keyboard input in normal mode while the calendar is shown
+///
+/// The arrow keys choose a day and Enter opens it. In an open day the
+/// up/down keys select a task, and all other keys work as in normal mode on
+/// the selected task.
+fn handle_calendar_mode(app: &mut App, key: KeyCode) {
+    if app.calendar.day_open {
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => app.calendar_select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => app.calendar_select_next(),
+            KeyCode::Esc => app.close_calendar_day(),
+            _ => handle_normal_mode(app, key),
+        }
+        return;
+    }
+
+    match key {
+        KeyCode::Left | KeyCode::Char('h') => app.calendar_move_days(-1),
+        KeyCode::Right | KeyCode::Char('l') => app.calendar_move_days(1),
+        KeyCode::Up | KeyCode::Char('k') => app.calendar_move_days(-7),
+        KeyCode::Down | KeyCode::Char('j') => app.calendar_move_days(7),
+        KeyCode::Char('<') | KeyCode::PageUp => app.calendar_move_months(-1),
+        KeyCode::Char('>') | KeyCode::PageDown => app.calendar_move_months(1),
+        KeyCode::Char('t') => app.calendar_today(),
+        KeyCode::Enter => app.open_calendar_day(),
+        _ => handle_normal_mode(app, key),
+    }
+}
+
+/// Handle mouse clicks and the scroll wheel
+fn handle_mouse(app: &mut App, mouse: MouseEvent, clicks: &mut ClickTracker) {
+    let (column, row) = (mouse.column, mouse.row);
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            let is_double_click = clicks.click(column, row);
+            handle_click(app, column, row, is_double_click);
+        }
+        MouseEventKind::ScrollUp => handle_scroll(app, column, row, -1),
+        MouseEventKind::ScrollDown => handle_scroll(app, column, row, 1),
+        _ => {}
+    }
+}
+
+/// Handle a left click at a screen cell
+///
+/// A click selects what it hits; a double-click on a task toggles its
+/// completion, and a double-click on a calendar day opens it. Clicking the
+/// help screen or outside a dialog closes it.
+fn handle_click(app: &mut App, column: u16, row: u16, is_double_click: bool) {
+    match app.input_mode {
+        InputMode::Normal => {}
+        InputMode::Help => {
+            app.input_mode = InputMode::Normal;
+            return;
+        }
+        _ => {
+            if !app.areas.modal.is_some_and(|modal| contains(modal, column, row)) {
+                app.cancel_input();
+            }
+            return;
+        }
+    }
+
+    let areas = app.areas.clone();
+    if let Some(&(_, view)) = areas.views.iter().find(|(area, _)| contains(*area, column, row)) {
+        app.select_view(view);
+        return;
+    }
+    if let Some(line) = list_line(areas.projects, column, row) {
+        app.click_project(line);
+        return;
+    }
+
+    let task_clicked = match app.screen {
+        Screen::Tree => {
+            list_line(areas.task_list, column, row).is_some_and(|line| app.click_task(line))
+        }
+        Screen::Board => areas
+            .board_columns
+            .iter()
+            .enumerate()
+            .find_map(|(index, area)| list_line(*area, column, row).map(|line| (index, line)))
+            .is_some_and(|(index, line)| app.click_card(index, line)),
+        Screen::Calendar => {
+            if let Some(&(_, day)) = areas
+                .calendar_days
+                .iter()
+                .find(|(area, _)| contains(*area, column, row))
+            {
+                app.click_calendar_day(day);
+                if is_double_click {
+                    app.open_calendar_day();
+                }
+                return;
+            }
+            list_line(areas.day_list, column, row).is_some_and(|line| app.click_day_entry(line))
+        }
+    };
+    if task_clicked && is_double_click {
+        app.toggle_selected_task();
+    }
+}
+
+/// Handle the scroll wheel at a screen cell, one step up (-1) or down (1)
+fn handle_scroll(app: &mut App, column: u16, row: u16, direction: isize) {
+    if app.input_mode != InputMode::Normal {
+        return;
+    }
+
+    let areas = &app.areas;
+    if contains(areas.details, column, row) {
+        app.scroll_details(direction * SCROLL_LINES);
+    } else if contains(areas.task_list, column, row) {
+        app.scroll_tasks(direction * SCROLL_LINES);
+    } else if let Some(index) = areas
+        .board_columns
+        .iter()
+        .position(|area| contains(*area, column, row))
+    {
+        app.board.column = index;
+        if direction < 0 {
+            app.board_select_previous();
+        } else {
+            app.board_select_next();
+        }
+    } else if contains(areas.day_list, column, row) && app.calendar.day_open {
+        if direction < 0 {
+            app.calendar_select_previous();
+        } else {
+            app.calendar_select_next();
+        }
+    }
+}
+
+/// Handle keyboard input in the task form
+fn handle_form_mode(app: &mut App, key: KeyCode) {
+    match key {
+        KeyCode::Tab | KeyCode::Down => {
+            if let Some(form) = app.form.as_mut() {
+                form.focus_next();
+            }
+        }
+        KeyCode::BackTab | KeyCode::Up => {
+            if let Some(form) = app.form.as_mut() {
+                form.focus_previous();
+            }
+        }
+        _ => handle_input_mode(app, key),
+    }
+}
+
+/// Handle keyboard input in input modes (adding, searching, task form)
 fn handle_input_mode(app: &mut App, key: KeyCode) {
     match key {
         KeyCode::Enter => app.submit_input(),
@@ -1,6 +1,11 @@
 pub mod app;
+pub mod board;
+pub mod calendar;
+pub mod mouse;
 pub mod ui;
 pub mod event;
+pub mod task_form;
 
-pub use app::App;
+pub use app::{App, Screen};
 pub use event::run_tui;
+pub use task_form::{FormField, TaskForm};
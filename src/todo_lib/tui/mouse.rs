@@ -0,0 +1,79 @@
+use std::time::{Duration, Instant};
+
+use chrono::NaiveDate;
+use ratatui::layout::{Position, Rect};
+
+/// Longest time between two clicks on the same cell that counts as a double-click
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+
+/// Screen areas of the clickable widgets
+///
+/// Recorded while rendering, so mouse events can be mapped to the items that
+/// were drawn in the last frame. Areas of widgets that are not shown are
+/// left empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClickAreas {
+    /// The project sidebar
+    pub projects: Rect,
+    /// The task tree
+    pub task_list: Rect,
+    /// The task details pane
+    pub details: Rect,
+    /// The columns of the board, in column order
+    pub board_columns: Vec<Rect>,
+    /// The day cells of the calendar
+    pub calendar_days: Vec<(Rect, NaiveDate)>,
+    /// The task list of the selected calendar day
+    pub day_list: Rect,
+    /// The view names in the status bar, with their index in `App::views`
+    pub views: Vec<(Rect, usize)>,
+    /// The open modal (input box or task form)
+    pub modal: Option<Rect>,
+}
+
+/// Check whether a screen cell lies in an area
+pub fn contains(area: Rect, column: u16, row: u16) -> bool {
+    area.contains(Position::new(column, row))
+}
+
+/// Get the line clicked inside a bordered list, counted from the first
+/// visible line
+///
+/// Returns None for clicks on the border or outside the area.
+pub fn list_line(area: Rect, column: u16, row: u16) -> Option<usize> {
+    let inner = Rect::new(
+        area.x.saturating_add(1),
+        area.y.saturating_add(1),
+        area.width.saturating_sub(2),
+        area.height.saturating_sub(2),
+    );
+    contains(inner, column, row).then(|| (row - inner.y) as usize)
+}
+
+/// Detects double-clicks from the left-button presses
+#[derive(Debug, Clone, Default)]
+pub struct ClickTracker {
+    last_click: Option<(Instant, u16, u16)>,
+}
+
+impl ClickTracker {
+    /// Register a click and check whether it completes a double-click
+    ///
+    /// A third click starts a new double-click instead of counting again.
+    pub fn click(&mut self, column: u16, row: u16) -> bool {
+        let now = Instant::now();
+        let is_double = matches!(
+            self.last_click,
+            Some((time, last_column, last_row))
+                if last_column == column
+                    && last_row == row
+                    && now.duration_since(time) <= DOUBLE_CLICK_INTERVAL
+        );
+        self.last_click = if is_double {
+            None
+        } else {
+            Some((now, column, row))
+        };
+        is_double
+    }
+}
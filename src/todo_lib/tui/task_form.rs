@@ -0,0 +1,230 @@
+use chrono::NaiveDate;
+
+use crate::models::parse_ids;
+use crate::models::task_field_parser::{
+    parse_due_date, parse_priority, parse_recurrence, parse_tags, parse_task_id,
+};
+use crate::models::ParseError;
+use crate::models::Task;
+use crate::models::TodoError;
+use crate::service::TaskChanges;
+
+/// A field of the task form
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormField {
+    Description,
+    Priority,
+    DueDate,
+    Tags,
+    Recurrence,
+    Parent,
+    Dependencies,
+}
+
+impl FormField {
+    /// All fields in the order they are shown
+    pub const ALL: [FormField; 7] = [
+        FormField::Description,
+        FormField::Priority,
+        FormField::DueDate,
+        FormField::Tags,
+        FormField::Recurrence,
+        FormField::Parent,
+        FormField::Dependencies,
+    ];
+
+    /// Label shown in front of the field
+    pub fn label(&self) -> &str {
+        match self {
+            FormField::Description => "Description",
+            FormField::Priority => "Priority",
+            FormField::DueDate => "Due date",
+            FormField::Tags => "Tags",
+            FormField::Recurrence => "Recurrence",
+            FormField::Parent => "Parent ID",
+            FormField::Dependencies => "Depends on",
+        }
+    }
+
+    /// Accepted input, shown while the field is focused
+    pub fn hint(&self) -> &str {
+        match self {
+            FormField::Description => "required",
+            FormField::Priority => "high/h, medium/med/m, low/l",
+            FormField::DueDate => "31.12.2025, 2025-12-31, tomorrow, fri, +3d, eom; empty for none",
+            FormField::Tags => "comma-separated, e.g. work, urgent; empty for none",
+            FormField::Recurrence => "daily, weekly, monthly; empty for none",
+            FormField::Parent => "task ID; empty for a top-level task",
+            FormField::Dependencies => "task IDs, e.g. 3,5 or 2-4; empty for none",
+        }
+    }
+}
+
+/// Multi-field form for creating and editing tasks
+///
+/// Values are parsed with the same parsers as the command line, so the TUI
+/// accepts exactly the same input. Errors are kept per field and shown
+/// below the field they belong to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskForm {
+    /// ID of the task being edited, or None when creating a task
+    pub task_id: Option<usize>,
+    /// Index of the focused field in `FormField::ALL`
+    pub focused: usize,
+    values: Vec<String>,
+    errors: Vec<Option<String>>,
+}
+
+impl TaskForm {
+    /// Create an empty form for a new task
+    pub fn new_task() -> Self {
+        let mut values = vec![String::new(); FormField::ALL.len()];
+        values[Self::index(FormField::Priority)] = "medium".to_string();
+        Self::with_values(None, values)
+    }
+
+    /// Create an empty form for a new subtask of the given task
+    pub fn new_subtask(parent_id: usize) -> Self {
+        let mut form = Self::new_task();
+        form.values[Self::index(FormField::Parent)] = parent_id.to_string();
+        form
+    }
+
+    /// Create a form filled in with the fields of an existing task
+    pub fn edit_task(task: &Task) -> Self {
+        let values = FormField::ALL
+            .iter()
+            .map(|field| match field {
+                FormField::Description => task.description.clone(),
+                FormField::Priority => task.priority.as_str().to_lowercase(),
+                FormField::DueDate => task
+                    .due_date
+                    .map(|date| date.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default(),
+                FormField::Tags => task.tags.join(", "),
+                FormField::Recurrence => task
+                    .recurrence
+                    .map(|recurrence| recurrence.as_str().to_lowercase())
+                    .unwrap_or_default(),
+                FormField::Parent => task.parent_id.map(|id| id.to_string()).unwrap_or_default(),
+                FormField::Dependencies => task
+                    .depends_on
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            })
+            .collect();
+        Self::with_values(Some(task.id), values)
+    }
+
+    fn with_values(task_id: Option<usize>, values: Vec<String>) -> Self {
+        Self {
+            task_id,
+            focused: 0,
+            errors: vec![None; values.len()],
+            values,
+        }
+    }
+
+    fn index(field: FormField) -> usize {
+        FormField::ALL
+            .iter()
+            .position(|other| *other == field)
+            .unwrap_or(0)
+    }
+
+    /// Get the focused field
+    pub fn focused_field(&self) -> FormField {
+        FormField::ALL[self.focused]
+    }
+
+    /// Get the current input of a field
+    pub fn value(&self, field: FormField) -> &str {
+        &self.values[Self::index(field)]
+    }
+
+    /// Get the validation error of a field, if any
+    pub fn error(&self, field: FormField) -> Option<&str> {
+        self.errors[Self::index(field)].as_deref()
+    }
+
+    /// Move the focus to the next field, wrapping around
+    pub fn focus_next(&mut self) {
+        self.focused = (self.focused + 1) % FormField::ALL.len();
+    }
+
+    /// Move the focus to the previous field, wrapping around
+    pub fn focus_previous(&mut self) {
+        self.focused = (self.focused + FormField::ALL.len() - 1) % FormField::ALL.len();
+    }
+
+    /// Add a character to the focused field
+    pub fn push_char(&mut self, c: char) {
+        self.values[self.focused].push(c);
+        self.errors[self.focused] = None;
+    }
+
+    /// Remove the last character from the focused field
+    pub fn pop_char(&mut self) {
+        self.values[self.focused].pop();
+        self.errors[self.focused] = None;
+    }
+
+    /// Parse all fields
+    ///
+    /// Returns the changes if every field is valid. Otherwise the errors are
+    /// stored with their fields and the focus moves to the first invalid one.
+    pub fn validate(&mut self, today: NaiveDate) -> Option<TaskChanges> {
+        let mut changes = TaskChanges::default();
+
+        for (index, field) in FormField::ALL.iter().enumerate() {
+            let value = self.values[index].trim();
+            let result = match field {
+                FormField::Description => {
+                    if value.is_empty() {
+                        Err(ParseError::EmptyInput("Task description".to_string()))
+                    } else {
+                        changes.description = value.to_string();
+                        Ok(())
+                    }
+                }
+                FormField::Priority => parse_priority(value).map(|p| changes.priority = p),
+                _ if value.is_empty() => Ok(()),
+                FormField::DueDate => parse_due_date(value, today).map(|d| changes.due_date = d),
+                FormField::Tags => {
+                    changes.tags = parse_tags(value);
+                    Ok(())
+                }
+                FormField::Recurrence => parse_recurrence(value).map(|r| changes.recurrence = r),
+                FormField::Parent => {
+                    parse_task_id(value, "parent ID").map(|id| changes.parent_id = Some(id))
+                }
+                FormField::Dependencies => parse_ids(value)
+                    .map(|ids| changes.depends_on = ids)
+                    .map_err(ParseError::InvalidId),
+            };
+            self.errors[index] = result.err().map(|err| err.message());
+        }
+
+        match self.errors.iter().position(Option::is_some) {
+            Some(first_invalid) => {
+                self.focused = first_invalid;
+                None
+            }
+            None => Some(changes),
+        }
+    }
+
+    /// Show an error returned when saving the task next to the field it concerns
+    pub fn show_error(&mut self, err: &TodoError) {
+        let field = match err {
+            TodoError::EmptyDescription => FormField::Description,
+            TodoError::InvalidParent(_) | TodoError::CircularParent { .. } => FormField::Parent,
+            _ => FormField::Dependencies,
+        };
+        let index = Self::index(field);
+        self.errors[index] = Some(err.message());
+        self.focused = index;
+    }
+}
@@ -2,15 +2,26 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
+use chrono::{Datelike, NaiveDate};
+
+use crate::models::agenda::AgendaEntry;
 use crate::models::Task;
-use super::app::{App, InputMode};
+use crate::models::task_status::TaskStatus;
+use super::app::{App, InputMode, Screen, TaskRow};
+use super::mouse::ClickAreas;
+use super::task_form::{FormField, TaskForm};
 
 /// Render the main UI
-pub fn render(f: &mut Frame, app: &App) {
+///
+/// Takes the app mutably to keep the scroll offsets of the lists and the
+/// areas of the clickable widgets.
+pub fn render(f: &mut Frame, app: &mut App) {
+    app.areas = ClickAreas::default();
+
     // Create the main layout
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -27,31 +38,45 @@ pub fn render(f: &mut Frame, app: &App) {
     // Render main content based on mode
     match app.input_mode {
         InputMode::Help => render_help(f, chunks[1]),
-        _ => render_main_content(f, chunks[1], app),
+        _ => match app.screen {
+            Screen::Tree => render_main_content(f, chunks[1], app),
+            Screen::Board => render_board_content(f, chunks[1], app),
+            Screen::Calendar => render_calendar_content(f, chunks[1], app),
+        },
     }
 
     // Render status bar
     render_status_bar(f, chunks[2], app);
 
     // Render modals on top if in input mode
-    if matches!(
+    if let (InputMode::TaskForm, Some(form)) = (app.input_mode, &app.form) {
+        app.areas.modal = Some(render_task_form(f, f.area(), form));
+    } else if matches!(
         app.input_mode,
-        InputMode::Adding | InputMode::Editing | InputMode::Searching
+        InputMode::Adding
+            | InputMode::Searching
+            | InputMode::CreatingProject
+            | InputMode::RenamingProject
+            | InputMode::DeletingProject
     ) {
-        render_input_modal(f, f.area(), app);
+        app.areas.modal = Some(render_input_modal(f, f.area(), app));
     }
 }
 
 /// Render the title bar
 fn render_title(f: &mut Frame, area: Rect, app: &App) {
     let title_text = format!(
-        "📝 TODO List Manager - {} mode",
+        "📝 TODO List Manager - {} - {} mode",
+        app.current_project_name(),
         match app.input_mode {
             InputMode::Normal => "Navigation",
             InputMode::Adding => "Adding Task",
-            InputMode::Editing => "Editing Task",
+            InputMode::TaskForm => "Task Form",
             InputMode::Searching => "Searching",
             InputMode::Help => "Help",
+            InputMode::CreatingProject => "New Project",
+            InputMode::RenamingProject => "Renaming Project",
+            InputMode::DeletingProject => "Deleting Project",
         }
     );
 
@@ -64,27 +89,405 @@ fn render_title(f: &mut Frame, area: Rect, app: &App) {
 }
 
 /// Render the main content area
-fn render_main_content(f: &mut Frame, area: Rect, app: &App) {
-    // Split into task list and details
+fn render_main_content(f: &mut Frame, area: Rect, app: &mut App) {
+    // Split into project sidebar, task list and details
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(20),
+            Constraint::Percentage(50),
+            Constraint::Percentage(30),
+        ])
+        .split(area);
+
+    render_project_sidebar(f, chunks[0], app);
+    render_task_list(f, chunks[1], app);
+    render_task_details(f, chunks[2], app);
+    app.areas.projects = chunks[0];
+    app.areas.task_list = chunks[1];
+    app.areas.details = chunks[2];
+}
+
+/// Render the project sidebar and the board
+fn render_board_content(f: &mut Frame, area: Rect, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(15), Constraint::Percentage(85)])
+        .split(area);
+
+    render_project_sidebar(f, chunks[0], app);
+    render_board(f, chunks[1], app);
+    app.areas.projects = chunks[0];
+}
+
+/// Render the board with one list of cards per column
+///
+/// Each column scrolls on its own; the offsets are stored in the app so they
+/// stay put between frames. The board follows the active view and search.
+fn render_board(f: &mut Frame, area: Rect, app: &mut App) {
+    let board_block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            " Board by {} - View: {} ",
+            app.board.grouping.as_str(),
+            app.current_view().name
+        ))
+        .border_style(Style::default().fg(Color::White));
+    let inner_area = board_block.inner(area);
+    f.render_widget(board_block, area);
+
+    let columns = app.get_board_columns();
+    if columns.is_empty() {
+        return;
+    }
+    let focused = app.board.column.min(columns.len() - 1);
+
+    let column_areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![
+            Constraint::Ratio(1, columns.len() as u32);
+            columns.len()
+        ])
+        .split(inner_area);
+
+    let mut offsets = Vec::with_capacity(columns.len());
+    for (index, column) in columns.iter().enumerate() {
+        let is_focused = index == focused;
+        let card_width = column_areas[index].width.saturating_sub(2) as usize;
+        let items: Vec<ListItem> = column
+            .tasks
+            .iter()
+            .map(|task| format_card(task, card_width))
+            .collect();
+
+        let border_color = if is_focused { Color::Cyan } else { Color::White };
+        let highlight = if is_focused {
+            Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" {} ({}) ", column.key.title(), column.tasks.len()))
+                    .border_style(Style::default().fg(border_color)),
+            )
+            .highlight_style(highlight);
+
+        let mut state = ListState::default()
+            .with_offset(app.board.offset_of(index))
+            .with_selected(app.board.selected_in(index, column.tasks.len()));
+        f.render_stateful_widget(list, column_areas[index], &mut state);
+        offsets.push(state.offset());
+    }
+
+    drop(columns);
+    app.board.offsets = offsets;
+    app.areas.board_columns = column_areas.to_vec();
+    // Category columns disappear when their last card leaves
+    app.board.column = focused;
+}
+
+/// Format a task as a board card: description, details and a separator
+fn format_card(task: &Task, width: usize) -> ListItem<'static> {
+    let description_style = if task.is_resolved() {
+        Style::default()
+            .fg(Color::Gray)
+            .add_modifier(Modifier::CROSSED_OUT)
+    } else {
+        Style::default().fg(Color::White)
+    };
+
+    let mut details = vec![Span::styled(
+        format!("{} {}", task.get_priority().symbol(), task.get_status_symbol()),
+        Style::default().fg(Color::Yellow),
+    )];
+    if let Some(due_date) = task.get_due_date() {
+        let today = chrono::Local::now().date_naive();
+        let due_color = if task.is_overdue(today) {
+            Color::Red
+        } else {
+            Color::Cyan
+        };
+        details.push(Span::styled(
+            format!(" {}", due_date.format("%Y-%m-%d")),
+            Style::default().fg(due_color),
+        ));
+    }
+    if let Some(category) = task.get_category() {
+        details.push(Span::styled(
+            format!(" [{}]", category),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+
+    ListItem::new(vec![
+        Line::from(Span::styled(
+            format!("{}. {}", task.id, task.description),
+            description_style,
+        )),
+        Line::from(details),
+        Line::from(Span::styled(
+            "─".repeat(width),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ])
+}
+
+/// Render the project sidebar, the month calendar and the selected day
+fn render_calendar_content(f: &mut Frame, area: Rect, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .constraints([
+            Constraint::Percentage(15),
+            Constraint::Percentage(50),
+            Constraint::Percentage(35),
+        ])
         .split(area);
 
-    render_task_list(f, chunks[0], app);
-    render_task_details(f, chunks[1], app);
+    render_project_sidebar(f, chunks[0], app);
+    render_calendar(f, chunks[1], app);
+    render_calendar_day(f, chunks[2], app);
+    app.areas.projects = chunks[0];
+}
+
+/// Render the month grid with the number of tasks due on each day
+///
+/// Occurrences of recurring tasks are counted too. Days with overdue tasks
+/// are red; today is marked yellow.
+fn render_calendar(f: &mut Frame, area: Rect, app: &mut App) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            " {} - View: {} ",
+            app.calendar.month_start().format("%B %Y"),
+            app.current_view().name
+        ))
+        .border_style(Style::default().fg(if app.calendar.day_open {
+            Color::White
+        } else {
+            Color::Cyan
+        }));
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let today = chrono::Local::now().date_naive();
+    let entries = app.get_calendar_entries();
+    let count_on = |date: NaiveDate| entries.iter().filter(|entry| entry.date == date).count();
+    let cell_width = (inner_area.width / 7).max(3) as usize;
+
+    let mut day_areas = Vec::new();
+    let mut lines = vec![Line::from(
+        ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"]
+            .iter()
+            .map(|name| {
+                Span::styled(
+                    format!("{:^width$}", name, width = cell_width),
+                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                )
+            })
+            .collect::<Vec<_>>(),
+    )];
+
+    for week in app.calendar.weeks() {
+        let mut day_spans = Vec::new();
+        let mut count_spans = Vec::new();
+        // The week starts after a blank line; a day cell covers its number and count
+        let week_y = inner_area.y + lines.len() as u16 + 1;
+        for (weekday, day) in week.into_iter().enumerate() {
+            let Some(date) = day else {
+                day_spans.push(Span::raw(" ".repeat(cell_width)));
+                count_spans.push(Span::raw(" ".repeat(cell_width)));
+                continue;
+            };
+            let count = count_on(date);
+            let cell = Rect::new(
+                inner_area.x + (weekday * cell_width) as u16,
+                week_y,
+                cell_width as u16,
+                2,
+            );
+            day_areas.push((cell.intersection(inner_area), date));
+
+            let mut style = if date < today && count > 0 {
+                Style::default().fg(Color::Red)
+            } else if date == today {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            if date == app.calendar.day {
+                style = style.add_modifier(Modifier::REVERSED);
+            }
+
+            let count_text = match count {
+                0 => String::new(),
+                1 => "1 task".to_string(),
+                count => format!("{} tasks", count),
+            };
+            day_spans.push(Span::styled(
+                format!("{:^width$}", date.day(), width = cell_width),
+                style,
+            ));
+            count_spans.push(Span::styled(
+                format!("{:^width$.width$}", count_text, width = cell_width),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(day_spans));
+        lines.push(Line::from(count_spans));
+    }
+
+    drop(entries);
+    f.render_widget(Paragraph::new(lines), inner_area);
+    app.areas.calendar_days = day_areas;
+}
+
+/// Render the tasks due on the selected day and, once the day is opened,
+/// the details of the selected task
+fn render_calendar_day(f: &mut Frame, area: Rect, app: &mut App) {
+    let entries = app.get_day_entries();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(area);
+
+    let items: Vec<ListItem> = entries.iter().map(format_calendar_entry).collect();
+    let (border_color, hint) = if app.calendar.day_open {
+        (Color::Cyan, "Esc to close")
+    } else {
+        (Color::White, "Enter to open")
+    };
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(
+                    " {} ({}) - {} ",
+                    app.calendar.day.format("%a %d.%m.%Y"),
+                    entries.len(),
+                    hint
+                ))
+                .border_style(Style::default().fg(border_color)),
+        )
+        .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+        .highlight_symbol("▶ ");
+
+    let mut state = ListState::default()
+        .with_offset(app.calendar.offset)
+        .with_selected(
+            Some(app.calendar.selected)
+                .filter(|&selected| app.calendar.day_open && selected < entries.len()),
+        );
+    f.render_stateful_widget(list, chunks[0], &mut state);
+
+    let content = match app.get_selected_task() {
+        Some(task) => format_task_details(task, app.todo_list()),
+        None if entries.is_empty() => vec![Line::from("No tasks due on this day")],
+        None => vec![Line::from("Press Enter to go through the tasks of this day")],
+    };
+    let selected_id = app.get_selected_task().map(|task| task.id);
+    drop(entries);
+    let scroll = details_scroll(app, selected_id, content.len());
+    let details = Paragraph::new(content)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Details ")
+                .border_style(Style::default().fg(Color::White)),
+        )
+        .wrap(Wrap { trim: true })
+        .scroll((scroll, 0));
+    f.render_widget(details, chunks[1]);
+
+    app.calendar.offset = state.offset();
+    app.areas.day_list = chunks[0];
+    app.areas.details = chunks[1];
+}
+
+/// Get the scroll position of the details pane for the shown task
+///
+/// The scroll resets when another task is shown, and stops at the last line.
+fn details_scroll(app: &mut App, task_id: Option<usize>, line_count: usize) -> u16 {
+    if app.details_task != task_id {
+        app.details_task = task_id;
+        app.details_scroll = 0;
+    }
+    app.details_scroll = app
+        .details_scroll
+        .min(line_count.saturating_sub(1).min(u16::MAX as usize) as u16);
+    app.details_scroll
+}
+
+/// Format a task due on the selected calendar day
+fn format_calendar_entry(entry: &AgendaEntry) -> ListItem<'static> {
+    let task = entry.task;
+    let mut spans = vec![
+        Span::styled(
+            format!("{} {} ", task.get_priority().symbol(), task.get_status_symbol()),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::raw(format!("{}. {}", task.id, task.description)),
+    ];
+    if entry.is_projection {
+        spans.push(Span::styled(
+            " (next occurrence)",
+            Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+        ));
+    }
+    ListItem::new(Line::from(spans))
+}
+
+/// Render the project sidebar
+fn render_project_sidebar(f: &mut Frame, area: Rect, app: &App) {
+    let project_manager = app.service.project_manager();
+    let current = app.current_project_name();
+
+    let items: Vec<ListItem> = app
+        .project_names()
+        .into_iter()
+        .map(|name| {
+            let task_count = project_manager
+                .get_project(&name)
+                .map_or(0, |project| project.todo_list.get_tasks().len());
+            let is_current = name == current;
+            let marker = if is_current { "▶ " } else { "  " };
+            let style = if is_current {
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            ListItem::new(format!("{}{} ({})", marker, name, task_count)).style(style)
+        })
+        .collect();
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Projects ")
+            .border_style(Style::default().fg(Color::White)),
+    );
+
+    f.render_widget(list, area);
 }
 
 /// Render the task list
-fn render_task_list(f: &mut Frame, area: Rect, app: &App) {
-    let tasks = app.get_displayed_tasks();
+///
+/// The list keeps the selected task visible; its scroll offset is stored in
+/// the app.
+fn render_task_list(f: &mut Frame, area: Rect, app: &mut App) {
+    let tasks = app.get_task_rows();
 
     let items: Vec<ListItem> = tasks
         .iter()
         .enumerate()
-        .map(|(i, task)| {
+        .map(|(i, row)| {
             let is_selected = i == app.selected;
-            let content = format_task(task, is_selected);
+            let content = format_task(row, is_selected);
             let style = if is_selected {
                 Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD)
             } else {
@@ -95,12 +498,12 @@ fn render_task_list(f: &mut Frame, area: Rect, app: &App) {
         .collect();
 
     let title = if app.search_query.is_empty() {
-        format!(" Tasks ({}) - Filter: {} ", tasks.len(), app.filter)
+        format!(" Tasks ({}) - View: {} ", tasks.len(), app.current_view().name)
     } else {
         format!(
-            " Tasks ({}) - Filter: {} | Search: '{}' ",
+            " Tasks ({}) - View: {} | Search: '{}' ",
             tasks.len(),
-            app.filter,
+            app.current_view().name,
             app.search_query
         )
     };
@@ -118,22 +521,43 @@ fn render_task_list(f: &mut Frame, area: Rect, app: &App) {
                 .add_modifier(Modifier::BOLD),
         );
 
-    f.render_widget(list, area);
+    let mut state = ListState::default()
+        .with_offset(app.scroll_offset)
+        .with_selected(Some(app.selected).filter(|&selected| selected < tasks.len()));
+    drop(tasks);
+    f.render_stateful_widget(list, area, &mut state);
+    app.scroll_offset = state.offset();
 }
 
-/// Format a task for display
-fn format_task(task: &Task, _is_selected: bool) -> Text<'_> {
+/// Format a row of the task tree for display
+fn format_task<'a>(row: &TaskRow<'a>, _is_selected: bool) -> Text<'a> {
+    let task = row.task;
     let mut spans = vec![];
 
+    // Tree guides and expand/collapse marker
+    spans.push(Span::styled(
+        row.guides.clone(),
+        Style::default().fg(Color::DarkGray),
+    ));
+    let marker = match (row.has_children, row.collapsed) {
+        (true, true) => "▸ ",
+        (true, false) => "▾ ",
+        (false, _) => "",
+    };
+    spans.push(Span::styled(marker, Style::default().fg(Color::Cyan)));
+
     // Status checkbox
-    let status_char = if task.is_completed() { "✓" } else { " " };
+    let status_color = match task.get_status() {
+        TaskStatus::Pending => Color::White,
+        TaskStatus::InProgress => Color::Cyan,
+        TaskStatus::Waiting => Color::Yellow,
+        TaskStatus::Blocked => Color::Red,
+        TaskStatus::Completed => Color::Green,
+        TaskStatus::Cancelled => Color::DarkGray,
+    };
     spans.push(Span::styled(
-        format!("[{}] ", status_char),
-        Style::default().fg(if task.is_completed() {
-            Color::Green
-        } else {
-            Color::White
-        }),
+        format!("[{}] ", task.get_status_symbol()),
+        Style::default().fg(status_color),
     ));
 
     // Priority indicator
@@ -148,10 +572,13 @@ fn format_task(task: &Task, _is_selected: bool) -> Text<'_> {
     ));
 
     // Task ID and description
-    let description_style = if task.is_completed() {
+    let description_style = if task.is_resolved() {
         Style::default()
             .fg(Color::Gray)
             .add_modifier(Modifier::CROSSED_OUT)
+    } else if !row.matches {
+        // Parent shown only for its matching subtasks
+        Style::default().fg(Color::DarkGray)
     } else {
         Style::default().fg(Color::White)
     };
@@ -160,6 +587,17 @@ fn format_task(task: &Task, _is_selected: bool) -> Text<'_> {
         description_style,
     ));
 
+    // Subtask progress
+    if row.subtask_count > 0 {
+        spans.push(Span::styled(
+            format!(
+                " ({}/{} subtasks)",
+                row.completed_subtask_count, row.subtask_count
+            ),
+            Style::default().fg(Color::LightCyan),
+        ));
+    }
+
     // Due date
     if let Some(due_date) = task.get_due_date() {
         let today = chrono::Local::now().date_naive();
@@ -177,10 +615,10 @@ fn format_task(task: &Task, _is_selected: bool) -> Text<'_> {
         ));
     }
 
-    // Category
-    if let Some(category) = task.get_category() {
+    // Tags
+    for tag in task.get_tags() {
         spans.push(Span::styled(
-            format!(" [{}]", category),
+            format!(" [{}]", tag),
             Style::default().fg(Color::Magenta),
         ));
     }
@@ -206,14 +644,14 @@ fn format_task(task: &Task, _is_selected: bool) -> Text<'_> {
 }
 
 /// Render task details panel
-fn render_task_details(f: &mut Frame, area: Rect, app: &App) {
+fn render_task_details(f: &mut Frame, area: Rect, app: &mut App) {
     let content = if let Some(task) = app.get_selected_task() {
-        format_task_details(task, &app.todo_list)
+        format_task_details(task, app.todo_list())
     } else {
         vec![Line::from("No task selected")]
     };
 
-    let stats = app.todo_list.get_statistics();
+    let stats = app.todo_list().get_statistics();
     let stats_text = format!(
         "\n\n═══ Statistics ═══\n\
         Total: {}\n\
@@ -236,6 +674,8 @@ fn render_task_details(f: &mut Frame, area: Rect, app: &App) {
     let mut all_content = content;
     all_content.push(Line::from(""));
     all_content.extend(stats_text.lines().map(|line| Line::from(line.to_string())));
+    let selected_id = app.get_selected_task().map(|task| task.id);
+    let scroll = details_scroll(app, selected_id, all_content.len());
 
     let details = Paragraph::new(all_content)
         .block(
@@ -244,7 +684,8 @@ fn render_task_details(f: &mut Frame, area: Rect, app: &App) {
                 .title(" Details & Statistics ")
                 .border_style(Style::default().fg(Color::White)),
         )
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap { trim: true })
+        .scroll((scroll, 0));
 
     f.render_widget(details, area);
 }
@@ -262,12 +703,11 @@ fn format_task_details(task: &Task, todo_list: &crate::models::TodoList) -> Vec<
     lines.push(Line::from(format!("ID: {}", task.id)));
     lines.push(Line::from(format!("Description: {}", task.description)));
 
-    let status = if task.is_completed() {
-        "Completed ✓"
-    } else {
-        "Pending"
-    };
-    lines.push(Line::from(format!("Status: {}", status)));
+    lines.push(Line::from(format!(
+        "Status: {} {}",
+        task.get_status().as_str(),
+        task.get_status_symbol()
+    )));
 
     lines.push(Line::from(format!("Priority: {:?}", task.get_priority())));
 
@@ -282,8 +722,8 @@ fn format_task_details(task: &Task, todo_list: &crate::models::TodoList) -> Vec<
         }
     }
 
-    if let Some(category) = task.get_category() {
-        lines.push(Line::from(format!("Category: {}", category)));
+    if !task.get_tags().is_empty() {
+        lines.push(Line::from(format!("Tags: {}", task.get_tags().join(", "))));
     }
 
     if let Some(recurrence) = task.get_recurrence() {
@@ -299,6 +739,27 @@ fn format_task_details(task: &Task, todo_list: &crate::models::TodoList) -> Vec<
         lines.push(Line::from(format!("Parent Task: {}", parent_id)));
     }
 
+    if let Some(created_at) = task.created_at {
+        lines.push(Line::from(format!(
+            "Created: {}",
+            created_at.format("%Y-%m-%d %H:%M")
+        )));
+    }
+
+    if let Some(updated_at) = task.updated_at {
+        lines.push(Line::from(format!(
+            "Updated: {}",
+            updated_at.format("%Y-%m-%d %H:%M")
+        )));
+    }
+
+    if let Some(completed_at) = task.completed_at {
+        lines.push(Line::from(format!(
+            "Completed: {}",
+            completed_at.format("%Y-%m-%d %H:%M")
+        )));
+    }
+
     let subtasks = todo_list.get_subtasks(task.id);
     if !subtasks.is_empty() {
         let subtasks_str: Vec<String> = subtasks.iter().map(|t| t.id.to_string()).collect();
@@ -309,32 +770,79 @@ fn format_task_details(task: &Task, todo_list: &crate::models::TodoList) -> Vec<
 }
 
 /// Render the status bar
-fn render_status_bar(f: &mut Frame, area: Rect, app: &App) {
+///
+/// The bar starts with the views, which can be clicked to switch to them,
+/// followed by the status message.
+fn render_status_bar(f: &mut Frame, area: Rect, app: &mut App) {
     let status_text = if let Some(ref msg) = app.status_message {
         msg.clone()
     } else {
         "Press '?' for help, 'q' to quit".to_string()
     };
 
-    let status = Paragraph::new(status_text)
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::White));
+    let inner_area = block.inner(area);
+
+    let mut spans = vec![Span::styled("Views:", Style::default().fg(Color::Gray))];
+    let mut x = inner_area.x + spans[0].width() as u16;
+    for (index, view) in app.views.iter().enumerate() {
+        let style = if index == app.current_view {
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Cyan)
+        };
+        let label = Span::styled(format!(" {} ", view.name), style);
+        let width = label.width() as u16;
+
+        // One space between the views
+        x += 1;
+        if x + width <= inner_area.right() {
+            app.areas
+                .views
+                .push((Rect::new(x, inner_area.y, width, 1), index));
+        }
+        x += width;
+        spans.push(Span::raw(" "));
+        spans.push(label);
+    }
+    spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
+    spans.push(Span::raw(status_text));
+
+    let status = Paragraph::new(Line::from(spans))
         .style(Style::default().fg(Color::White))
-        .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::White)));
+        .block(block);
 
     f.render_widget(status, area);
 }
 
 /// Render input modal
-fn render_input_modal(f: &mut Frame, area: Rect, app: &App) {
+///
+/// Returns the area of the modal.
+fn render_input_modal(f: &mut Frame, area: Rect, app: &App) -> Rect {
     let popup_area = centered_rect(60, 20, area);
 
     let title = match app.input_mode {
         InputMode::Adding => " Add New Task ",
-        InputMode::Editing => " Edit Task ",
         InputMode::Searching => " Search Tasks ",
+        InputMode::CreatingProject => " New Project ",
+        InputMode::RenamingProject => " Rename Project ",
+        InputMode::DeletingProject => " Delete Project ",
         _ => " Input ",
     };
 
-    let input_text = app.input.clone();
+    let input_text = if app.input_mode == InputMode::DeletingProject {
+        format!(
+            "Delete project '{}'? It is moved to the trash. (y/n)",
+            app.current_project_name()
+        )
+    } else {
+        app.input.clone()
+    };
     let input = Paragraph::new(input_text)
         .style(Style::default().fg(Color::Yellow))
         .block(
@@ -346,6 +854,67 @@ fn render_input_modal(f: &mut Frame, area: Rect, app: &App) {
 
     f.render_widget(Clear, popup_area);
     f.render_widget(input, popup_area);
+    popup_area
+}
+
+/// Render the task form with one line per field and its error below it
+///
+/// Returns the area of the form.
+fn render_task_form(f: &mut Frame, area: Rect, form: &TaskForm) -> Rect {
+    let popup_area = centered_rect(70, 70, area);
+
+    let mut lines = vec![];
+    for field in FormField::ALL {
+        let is_focused = field == form.focused_field();
+        let label_style = if is_focused {
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let cursor = if is_focused { "_" } else { "" };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<12} ", field.label()), label_style),
+            Span::styled(
+                format!("{}{}", form.value(field), cursor),
+                Style::default().fg(Color::Yellow),
+            ),
+        ]));
+
+        if let Some(error) = form.error(field) {
+            lines.push(Line::from(Span::styled(
+                format!("{:<12} ✗ {}", "", error),
+                Style::default().fg(Color::Red),
+            )));
+        } else if is_focused {
+            lines.push(Line::from(Span::styled(
+                format!("{:<12} {}", "", field.hint()),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Tab/↓ next field, Shift+Tab/↑ previous field, Enter save, Esc cancel",
+        Style::default().fg(Color::Gray).add_modifier(Modifier::ITALIC),
+    )));
+
+    let title = match form.task_id {
+        Some(id) => format!(" Edit Task {} ", id),
+        None => " New Task ".to_string(),
+    };
+    let form_widget = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(Style::default().fg(Color::Cyan)),
+        )
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(Clear, popup_area);
+    f.render_widget(form_widget, popup_area);
+    popup_area
 }
 
 /// Render help screen
@@ -360,16 +929,49 @@ fn render_help(f: &mut Frame, area: Rect) {
         Line::from("Navigation:"),
         Line::from("  ↑/k          - Move up"),
         Line::from("  ↓/j          - Move down"),
+        Line::from("  ←/h          - Collapse subtasks (or go to parent)"),
+        Line::from("  →/l          - Expand subtasks"),
+        Line::from("  -/+          - Collapse/expand all"),
+        Line::from(""),
+        Line::from("Board:"),
+        Line::from("  b            - Switch between task tree and board"),
+        Line::from("  g            - Group columns by status, priority or category"),
+        Line::from("  ←→/h l       - Focus column left/right"),
+        Line::from("  ↑↓/k j       - Select card in column"),
+        Line::from("  </>          - Move card to column left/right"),
+        Line::from(""),
+        Line::from("Calendar:"),
+        Line::from("  C            - Switch between task tree and calendar"),
+        Line::from("  ←→↑↓/h l k j - Select day (↑↓ move a week)"),
+        Line::from("  </>, PgUp/Dn - Previous/next month"),
+        Line::from("  t            - Go to today"),
+        Line::from("  Enter        - Open day: ↑↓ select task, Esc close"),
         Line::from(""),
         Line::from("Actions:"),
         Line::from("  Enter/Space  - Toggle task completion"),
-        Line::from("  a            - Add new task"),
-        Line::from("  e            - Edit selected task"),
+        Line::from("  a            - Quick add task (description only)"),
+        Line::from("  A            - Add new task with all fields"),
+        Line::from("  e            - Edit all fields of selected task"),
+        Line::from("  s            - Add subtask under selected task"),
+        Line::from("  >/<          - Indent/outdent selected task"),
         Line::from("  d            - Delete selected task"),
         Line::from("  /            - Search tasks"),
-        Line::from("  f            - Cycle filter (all/pending/completed)"),
+        Line::from("  f            - Cycle views (all/pending/completed, then saved views)"),
         Line::from("  c            - Clear search"),
         Line::from(""),
+        Line::from("Projects:"),
+        Line::from("  Tab/]        - Next project"),
+        Line::from("  Shift+Tab/[  - Previous project"),
+        Line::from("  N            - New project"),
+        Line::from("  R            - Rename current project"),
+        Line::from("  X            - Delete current project"),
+        Line::from(""),
+        Line::from("Mouse:"),
+        Line::from("  Click        - Select task, card, day, project or view"),
+        Line::from("  Double-click - Toggle task completion (open a calendar day)"),
+        Line::from("  Wheel        - Scroll task list, details or board column"),
+        Line::from("  Click        - Outside a dialog or on help: close it"),
+        Line::from(""),
         Line::from("Other:"),
         Line::from("  ?            - Show this help"),
         Line::from("  q/Esc        - Quit / Cancel"),
@@ -1,6 +1,7 @@
 use crate::models::priority::Priority;
 use crate::models::task::Task;
-use chrono::NaiveDate;
+use crate::models::task_status::TaskStatus;
+use chrono::{DateTime, Local, NaiveDate};
 use colored::*;
 
 /// Handles formatting of tasks for display
@@ -18,11 +19,11 @@ impl TaskFormatter {
     ///
     /// A formatted string ready for display
     pub fn format_task(task: &Task, id_width: usize) -> String {
-        let status_symbol = Self::format_status_symbol(task.is_completed());
+        let status_symbol = Self::format_status_symbol(task.status);
         let colored_priority = Self::format_priority(task.priority);
         let task_id_formatted = format!("{:>width$}", task.id, width = id_width);
 
-        let description_color = if task.is_completed() {
+        let description_color = if task.is_resolved() {
             task.description.bright_black()
         } else {
             task.description.white()
@@ -34,8 +35,8 @@ impl TaskFormatter {
             String::new()
         };
 
-        let category_str = if let Some(category) = &task.category {
-            format!(" {}", Self::format_category(category))
+        let tags_str = if !task.tags.is_empty() {
+            format!(" {}", Self::format_tags(&task.tags))
         } else {
             String::new()
         };
@@ -52,19 +53,63 @@ impl TaskFormatter {
             String::new()
         };
 
+        let timestamp_str = if let Some(timestamps) = Self::format_timestamps(task) {
+            format!(" {}", timestamps)
+        } else {
+            String::new()
+        };
+
         format!(
-            "{}. {} {} {}{}{}{}{}",
+            "{}. {} {} {}{}{}{}{}{}",
             task_id_formatted.bright_blue(),
             status_symbol,
             colored_priority,
             description_color,
             due_date_str,
-            category_str,
+            tags_str,
             recurrence_str,
-            dependency_str
+            dependency_str,
+            timestamp_str
         )
     }
 
+    /// Formats the creation, update and completion timestamps of a task
+    ///
+    /// The update time is omitted while it equals the creation time.
+    ///
+    /// # Returns
+    ///
+    /// The formatted timestamps, or None if the task has no timestamps (e.g. loaded from an old file)
+    pub fn format_timestamps(task: &Task) -> Option<ColoredString> {
+        let mut parts = Vec::new();
+
+        if let Some(created_at) = task.created_at {
+            parts.push(format!("created: {}", Self::format_timestamp(created_at)));
+        }
+        if let Some(updated_at) = task.updated_at {
+            if task.created_at != Some(updated_at) {
+                parts.push(format!("updated: {}", Self::format_timestamp(updated_at)));
+            }
+        }
+        if let Some(completed_at) = task.completed_at {
+            parts.push(format!(
+                "completed: {}",
+                Self::format_timestamp(completed_at)
+            ));
+        }
+
+        if parts.is_empty() {
+            None
+        } else {
+            Some(format!("({})", parts.join(", ")).bright_black())
+        }
+    }
+
+    /// Formats a single timestamp in the application's date format
+    pub fn format_timestamp(timestamp: DateTime<Local>) -> String {
+        timestamp.format("%d.%m.%Y %H:%M").to_string()
+    }
+
     /// Formats dependency information for display
     fn format_dependencies(dependencies: &[usize]) -> ColoredString {
         let dep_str = if dependencies.len() == 1 {
@@ -82,9 +127,13 @@ impl TaskFormatter {
         dep_str.bright_yellow()
     }
 
-    /// Formats a category badge for display
-    fn format_category(category: &str) -> ColoredString {
-        format!("[{}]", category).bright_magenta()
+    /// Formats the tag badges for display
+    fn format_tags(tags: &[String]) -> ColoredString {
+        tags.iter()
+            .map(|tag| format!("[{}]", tag))
+            .collect::<Vec<_>>()
+            .join(" ")
+            .bright_magenta()
     }
 
     /// Formats a recurrence indicator for display
@@ -120,12 +169,17 @@ impl TaskFormatter {
     }
 
     /// Formats the status symbol for a task
-    fn format_status_symbol(is_completed: bool) -> String {
-        if is_completed {
-            format!("[{}]", "✓".bright_green().bold())
-        } else {
-            format!("[{}]", " ".white())
-        }
+    fn format_status_symbol(status: TaskStatus) -> String {
+        let symbol = status.symbol();
+        let colored_symbol = match status {
+            TaskStatus::Pending => symbol.white(),
+            TaskStatus::InProgress => symbol.bright_cyan().bold(),
+            TaskStatus::Waiting => symbol.yellow(),
+            TaskStatus::Blocked => symbol.bright_red().bold(),
+            TaskStatus::Completed => symbol.bright_green().bold(),
+            TaskStatus::Cancelled => symbol.bright_black(),
+        };
+        format!("[{}]", colored_symbol)
     }
 
     /// Formats a priority with appropriate color
@@ -169,40 +223,84 @@ mod tests {
     #[test]
     fn test_format_status_symbol_completed() {
         setup();
-        let result = TaskFormatter::format_status_symbol(true);
+        let result = TaskFormatter::format_status_symbol(TaskStatus::Completed);
         assert!(result.contains("✓"));
     }
 
+    #[test]
+    fn test_format_status_symbol_blocked() {
+        setup();
+        let result = TaskFormatter::format_status_symbol(TaskStatus::Blocked);
+        assert!(result.contains("[!]"));
+    }
+
     #[test]
     fn test_format_status_symbol_pending() {
         setup();
-        let result = TaskFormatter::format_status_symbol(false);
+        let result = TaskFormatter::format_status_symbol(TaskStatus::Pending);
         assert!(result.contains("[ ]"));
     }
 
+    #[test]
+    fn test_format_task_with_tags() {
+        setup();
+        let mut task = Task::new(1, "Test".to_string());
+        task.add_tag("work");
+        task.add_tag("urgent");
+        let result = TaskFormatter::format_task(&task, 1);
+        assert!(result.contains("[work] [urgent]"));
+    }
+
+    #[test]
+    fn test_format_timestamps_none() {
+        setup();
+        let task = Task::new(1, "Test".to_string());
+        assert!(TaskFormatter::format_timestamps(&task).is_none());
+    }
+
+    #[test]
+    fn test_format_timestamps_created_and_completed() {
+        setup();
+        let mut task = Task::new(1, "Test".to_string());
+        task.touch();
+        let result = TaskFormatter::format_timestamps(&task).unwrap().to_string();
+        assert!(result.contains("created: "));
+        assert!(!result.contains("updated: "));
+
+        task.toggle_completion();
+        let result = TaskFormatter::format_timestamps(&task).unwrap().to_string();
+        assert!(result.contains("completed: "));
+    }
+
     #[test]
     fn test_calculate_max_id_width_single_digit() {
         let task1 = Task {
             id: 1,
             description: "Test".to_string(),
-            completed: false,
+            status: TaskStatus::Pending,
             priority: Priority::Medium,
             due_date: None,
-            category: None,
+            tags: Vec::new(),
             parent_id: None,
             recurrence: None,
             depends_on: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            completed_at: None,
         };
         let task2 = Task {
             id: 5,
             description: "Test".to_string(),
-            completed: false,
+            status: TaskStatus::Pending,
             priority: Priority::Medium,
             due_date: None,
-            category: None,
+            tags: Vec::new(),
             parent_id: None,
             recurrence: None,
             depends_on: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            completed_at: None,
         };
         let tasks = vec![&task1, &task2];
 
@@ -214,35 +312,44 @@ mod tests {
         let task1 = Task {
             id: 9,
             description: "Test".to_string(),
-            completed: false,
+            status: TaskStatus::Pending,
             priority: Priority::Medium,
             due_date: None,
-            category: None,
+            tags: Vec::new(),
             parent_id: None,
             recurrence: None,
             depends_on: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            completed_at: None,
         };
         let task2 = Task {
             id: 10,
             description: "Test".to_string(),
-            completed: false,
+            status: TaskStatus::Pending,
             priority: Priority::Medium,
             due_date: None,
-            category: None,
+            tags: Vec::new(),
             parent_id: None,
             recurrence: None,
             depends_on: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            completed_at: None,
         };
         let task3 = Task {
             id: 100,
             description: "Test".to_string(),
-            completed: false,
+            status: TaskStatus::Pending,
             priority: Priority::Medium,
             due_date: None,
-            category: None,
+            tags: Vec::new(),
             parent_id: None,
             recurrence: None,
             depends_on: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            completed_at: None,
         };
         let tasks = vec![&task1, &task2, &task3];
 
@@ -1,12 +1,13 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use crate::models::date_parser::parse_date;
 use crate::models::priority::Priority;
 use crate::ui::input::InputStream;
 #[cfg(test)]
 use crate::ui::output::FileOutputWriter;
 use crate::ui::output::OutputWriter;
-use chrono::NaiveDate;
+use chrono::{Local, NaiveDate};
 use colored::Colorize;
 
 /// Handles interactive prompts for user input.
@@ -95,7 +96,7 @@ impl<I: InputStream, O: OutputWriter> InteractiveTaskPropertiesPrompt<I, O> {
         self.output_writer.borrow_mut().write_line(&format!(
             "{} {}",
             "Due date".bright_yellow().bold(),
-            "[DD.MM.YYYY]:".bright_black()
+            "[DD.MM.YYYY, YYYY-MM-DD, tomorrow, fri, +3d, ...]:".bright_black()
         ));
 
         let input = self.input_stream.borrow_mut().get_next_input();
@@ -103,17 +104,15 @@ impl<I: InputStream, O: OutputWriter> InteractiveTaskPropertiesPrompt<I, O> {
             return None;
         }
 
-        match NaiveDate::parse_from_str(&input, "%d.%m.%Y") {
+        match parse_date(&input, Local::now().date_naive()) {
             Ok(date) => {
                 self.output_writer
                     .borrow_mut()
                     .show_success(&format!("Due date set to {}", date.format("%d.%m.%Y")));
                 Some(date)
             }
-            Err(_) => {
-                self.output_writer
-                    .borrow_mut()
-                    .show_error("Invalid date format. Use DD.MM.YYYY");
+            Err(err) => {
+                self.output_writer.borrow_mut().show_error(&err.message());
                 None
             }
         }
@@ -263,7 +262,7 @@ mod tests {
 
     #[test]
     fn test_prompt_due_date_invalid_format() {
-        let input_data = b"2025-12-25\n";
+        let input_data = b"12/25/2025\n";
         let input_reader = FileInputStream::new(&input_data[..]);
         let mut buffer = Vec::new();
         let output_writer = FileOutputWriter::new(&mut buffer);
@@ -276,7 +275,7 @@ mod tests {
 
         assert_eq!(result, None);
         let output = String::from_utf8(buffer).unwrap();
-        assert!(output.contains("Invalid date format"));
+        assert!(output.contains("ambiguous"));
     }
 
     #[test]
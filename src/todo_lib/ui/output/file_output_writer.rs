@@ -18,12 +18,17 @@ use std::io::Write;
 /// ```
 pub struct FileOutputWriter<W: Write> {
     writer: W,
+    /// Lines held back while buffering
+    buffer: Option<Vec<String>>,
 }
 
 impl<W: Write> FileOutputWriter<W> {
     /// Creates a new output writer with a custom writer (for testing).
     pub fn new(writer: W) -> Self {
-        FileOutputWriter { writer }
+        FileOutputWriter {
+            writer,
+            buffer: None,
+        }
     }
 }
 
@@ -61,7 +66,10 @@ impl<W: Write> OutputWriter for FileOutputWriter<W> {
     /// output.write_line("Task added successfully!");
     /// ```
     fn write_line(&mut self, text: &str) {
-        writeln!(self.writer, "{}", text).unwrap();
+        match &mut self.buffer {
+            Some(buffer) => buffer.push(text.to_string()),
+            None => writeln!(self.writer, "{}", text).unwrap(),
+        }
     }
 
     /// Displays the command prompt without a newline.
@@ -78,6 +86,28 @@ impl<W: Write> OutputWriter for FileOutputWriter<W> {
         write!(self.writer, "{}", "> ".bright_green().bold()).unwrap();
         self.writer.flush().unwrap();
     }
+
+    /// Holds back written lines until `end_buffering` is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use todo_manager::{FileOutputWriter, OutputWriter};
+    ///
+    /// let mut buffer = Vec::new();
+    /// let mut output = FileOutputWriter::new(&mut buffer);
+    /// output.begin_buffering();
+    /// output.write_line("Held back");
+    /// assert_eq!(output.end_buffering(), vec!["Held back".to_string()]);
+    /// ```
+    fn begin_buffering(&mut self) {
+        self.buffer.get_or_insert_with(Vec::new);
+    }
+
+    /// Stops holding back lines and returns the ones written while buffering.
+    fn end_buffering(&mut self) -> Vec<String> {
+        self.buffer.take().unwrap_or_default()
+    }
 }
 
 #[cfg(test)]
@@ -170,4 +200,17 @@ mod tests {
         assert!(result.contains("✓ Second operation"));
         assert!(result.contains("✗ An error occurred"));
     }
+
+    #[test]
+    fn test_buffered_lines_are_not_written() {
+        setup();
+        let mut buffer = Vec::new();
+        let mut output = FileOutputWriter::new(&mut buffer);
+        output.begin_buffering();
+        output.show_success("Held back");
+        let held = output.end_buffering();
+        output.write_line("Written");
+        assert_eq!(held, vec!["✓ Held back".to_string()]);
+        assert_eq!(String::from_utf8(buffer).unwrap(), "Written\n");
+    }
 }
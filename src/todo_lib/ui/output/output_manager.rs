@@ -1,3 +1,5 @@
+use crate::controller::change_preview::ChangePreview;
+use crate::ui::formatters::MessageFormatter;
 use crate::ui::output::OutputWriter;
 use colored::*;
 use std::cell::RefCell;
@@ -104,6 +106,77 @@ impl<O: OutputWriter> OutputManager<O> {
         self.output_writer.borrow_mut().show_error(message);
     }
 
+    /// Displays a summary of a change that was undone or redone.
+    ///
+    /// # Arguments
+    ///
+    /// * `verb` - What happened to the change (e.g. "Undid", "Redid")
+    /// * `command` - The command that originally made the change
+    /// * `changes` - Human-readable lines describing the applied differences
+    pub fn show_history_step(&mut self, verb: &str, command: &str, changes: &[String]) {
+        self.output_writer
+            .borrow_mut()
+            .show_success(&format!("{} '{}'", verb, command));
+        for change in changes {
+            self.output_writer.borrow_mut().write_line(&format!(
+                "  {} {}",
+                "•".bright_black(),
+                change
+            ));
+        }
+    }
+
+    /// Displays what a `--dry-run` command would change.
+    pub fn show_dry_run(&mut self, command: &str, preview: &ChangePreview) {
+        if preview.is_empty() {
+            self.output_writer
+                .borrow_mut()
+                .write_line(&MessageFormatter::warning(&format!(
+                    "Dry run: '{}' would not change any tasks.",
+                    command
+                )));
+            return;
+        }
+
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::warning(&format!(
+                "Dry run: '{}' would make these changes (nothing was changed):",
+                command
+            )));
+        self.show_preview_lines(preview);
+    }
+
+    /// Displays the changes of a command and asks whether to apply them.
+    pub fn show_confirmation_prompt(&mut self, command: &str, preview: &ChangePreview) {
+        self.output_writer
+            .borrow_mut()
+            .write_line(&MessageFormatter::warning(&format!(
+                "'{}' would remove or change {} existing tasks:",
+                command,
+                preview.affected_count()
+            )));
+        self.show_preview_lines(preview);
+        self.output_writer
+            .borrow_mut()
+            .write_line("Apply these changes? [y/N] (add --yes to skip this question)");
+    }
+
+    /// Displays that a command was cancelled at the confirmation prompt.
+    pub fn show_command_cancelled(&mut self, command: &str) {
+        self.output_writer
+            .borrow_mut()
+            .show_error(&format!("Cancelled '{}'. Nothing was changed.", command));
+    }
+
+    fn show_preview_lines(&mut self, preview: &ChangePreview) {
+        for line in preview.lines() {
+            self.output_writer
+                .borrow_mut()
+                .write_line(&format!("  {}", line));
+        }
+    }
+
     /// Handles an unknown command by displaying an error message.
     pub fn handle_unknown_command(&mut self, command: &str) {
         self.output_writer.borrow_mut().show_error(&format!(
@@ -13,4 +13,16 @@ pub trait OutputWriter {
 
     /// Displays the command prompt.
     fn write_prompt(&mut self);
+
+    /// Holds back written lines until `end_buffering` is called.
+    ///
+    /// Writers that do not support buffering keep writing immediately.
+    fn begin_buffering(&mut self) {}
+
+    /// Stops holding back lines and returns the ones written since `begin_buffering`.
+    ///
+    /// The caller decides whether to write the returned lines or drop them.
+    fn end_buffering(&mut self) -> Vec<String> {
+        Vec::new()
+    }
 }
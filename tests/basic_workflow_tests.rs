@@ -29,7 +29,7 @@ fn test_complete_workflow() {
 
     // Test completing a task
     let completed_task = todo_list.toggle_task(task2_id);
-    assert!(completed_task.is_some());
+    assert!(completed_task.is_ok());
     assert!(completed_task.unwrap().is_completed());
 
     // Test filtering completed and pending tasks
@@ -42,7 +42,7 @@ fn test_complete_workflow() {
 
     // Test removing a task
     let removed_task = todo_list.remove_task(task1_id);
-    assert!(removed_task.is_some());
+    assert!(removed_task.is_ok());
     assert_eq!(removed_task.unwrap().description, "Buy groceries");
     assert_eq!(todo_list.get_tasks().len(), 2);
 
@@ -64,7 +64,7 @@ fn test_task_id_persistence_after_removal() {
     let _task3_id = todo_list.add_task(TaskWithoutId::new("Task 3".to_string()));
 
     // Remove middle task
-    todo_list.remove_task(task2_id);
+    todo_list.remove_task(task2_id).unwrap();
 
     // Add new task - should get next sequential ID
     let task4_id = todo_list.add_task(TaskWithoutId::new("Task 4".to_string()));
@@ -90,11 +90,11 @@ fn test_empty_todo_list_operations() {
 
     // Test removing from empty list
     let result = todo_list.remove_task(1);
-    assert!(result.is_none());
+    assert!(result.is_err());
 
     // Test toggling non-existent task
     let result = todo_list.toggle_task(1);
-    assert!(result.is_none());
+    assert!(result.is_err());
 }
 
 /// Test task completion edge cases
@@ -128,17 +128,17 @@ fn test_concurrent_operations() {
         .collect();
 
     // Complete some tasks
-    todo_list.toggle_task(task_ids[1]);
-    todo_list.toggle_task(task_ids[3]);
+    todo_list.toggle_task(task_ids[1]).unwrap();
+    todo_list.toggle_task(task_ids[3]).unwrap();
 
     // Remove a completed task
     let removed = todo_list.remove_task(task_ids[1]);
-    assert!(removed.is_some());
+    assert!(removed.is_ok());
     assert!(removed.unwrap().is_completed());
 
     // Remove a pending task
     let removed = todo_list.remove_task(task_ids[0]);
-    assert!(removed.is_some());
+    assert!(removed.is_ok());
     assert!(!removed.unwrap().is_completed());
 
     // Verify final state
@@ -188,7 +188,7 @@ fn test_combined_operations_workflow() {
 
     // Complete tasks 2, 4, 6, 8
     for &id in &ids[1..9].iter().step_by(2).collect::<Vec<_>>() {
-        todo_list.toggle_task(*id);
+        todo_list.toggle_task(*id).unwrap();
     }
 
     assert_eq!(todo_list.get_completed_tasks().len(), 4);
@@ -197,7 +197,7 @@ fn test_combined_operations_workflow() {
     // Remove tasks 1, 3, 5
     for &id in &[ids[0], ids[2], ids[4]] {
         let removed = todo_list.remove_task(id);
-        assert!(removed.is_some());
+        assert!(removed.is_ok());
     }
 
     assert_eq!(todo_list.get_tasks().len(), 7);
@@ -215,9 +215,9 @@ fn test_multiple_toggle_and_remove() {
     let _id3 = todo_list.add_task(TaskWithoutId::new("Task 3".to_string()));
 
     // Toggle multiple times
-    todo_list.toggle_task(id1);
-    todo_list.toggle_task(id1);
-    todo_list.toggle_task(id2);
+    todo_list.toggle_task(id1).unwrap();
+    todo_list.toggle_task(id1).unwrap();
+    todo_list.toggle_task(id2).unwrap();
 
     assert_eq!(todo_list.get_completed_tasks().len(), 1);
     assert!(!todo_list
@@ -234,7 +234,7 @@ fn test_multiple_toggle_and_remove() {
         .is_completed());
 
     // Remove a task
-    todo_list.remove_task(id2);
+    todo_list.remove_task(id2).unwrap();
 
     assert_eq!(todo_list.get_tasks().len(), 2);
     assert_eq!(todo_list.get_completed_tasks().len(), 0);
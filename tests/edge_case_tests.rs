@@ -21,7 +21,7 @@ fn test_large_number_of_tasks() {
 
     // Complete every other task
     for i in (2..=num_tasks).step_by(2) {
-        todo_list.toggle_task(i);
+        todo_list.toggle_task(i).unwrap();
     }
 
     let completed_tasks = todo_list.get_completed_tasks();
@@ -33,7 +33,7 @@ fn test_large_number_of_tasks() {
     // Remove all completed tasks
     let completed_ids: Vec<usize> = completed_tasks.iter().map(|task| task.id).collect();
     for id in completed_ids {
-        todo_list.remove_task(id);
+        todo_list.remove_task(id).unwrap();
     }
 
     assert_eq!(todo_list.get_tasks().len(), num_tasks / 2);
@@ -82,21 +82,23 @@ fn test_stress_many_operations() {
     // Add subtasks to first 10 tasks
     for &id in ids.iter().take(10) {
         for j in 1..=3 {
-            todo_list.add_subtask(id, format!("Subtask {}.{}", id, j));
+            todo_list
+                .add_subtask(id, format!("Subtask {}.{}", id, j))
+                .unwrap();
         }
     }
 
     // Complete every 5th task
     for (i, &id) in ids.iter().enumerate() {
         if (i + 1) % 5 == 0 {
-            todo_list.toggle_task(id);
+            todo_list.toggle_task(id).unwrap();
         }
     }
 
     // Remove every 7th task (including cascade deletion of subtasks)
     for (i, &id) in ids.iter().enumerate() {
         if (i + 1) % 7 == 0 {
-            todo_list.remove_task(id);
+            todo_list.remove_task(id).unwrap();
         }
     }
 
@@ -1,5 +1,6 @@
 //! Additional integration tests for combined filtering feature
 
+use todo_manager::models::filter_expression::FilterExpression;
 use todo_manager::models::priority::Priority;
 use todo_manager::models::task::TaskWithoutId;
 use todo_manager::models::task_filter::TaskFilter;
@@ -19,16 +20,16 @@ fn test_combined_filtering() {
     let id5 = todo_list.add_task(TaskWithoutId::new("Another high priority".to_string()));
 
     // Set priorities
-    todo_list.set_task_priority(id1, Priority::High);
-    todo_list.set_task_priority(id2, Priority::High);
-    todo_list.set_task_priority(id3, Priority::Medium);
-    todo_list.set_task_priority(id4, Priority::Low);
-    todo_list.set_task_priority(id5, Priority::High);
+    todo_list.set_task_priority(id1, Priority::High).unwrap();
+    todo_list.set_task_priority(id2, Priority::High).unwrap();
+    todo_list.set_task_priority(id3, Priority::Medium).unwrap();
+    todo_list.set_task_priority(id4, Priority::Low).unwrap();
+    todo_list.set_task_priority(id5, Priority::High).unwrap();
 
     // Complete some tasks
-    todo_list.complete_task(id1);
-    todo_list.complete_task(id3);
-    todo_list.complete_task(id4);
+    todo_list.complete_task(id1).unwrap();
+    todo_list.complete_task(id3).unwrap();
+    todo_list.complete_task(id4).unwrap();
 
     // Test filtering by priority only
     let high_priority_filter = TaskFilter::all().with_priority(Priority::High);
@@ -105,11 +106,11 @@ fn test_filter_builder_methods() {
     let id2 = todo_list.add_task(TaskWithoutId::new("Task 2".to_string()));
     let id3 = todo_list.add_task(TaskWithoutId::new("Task 3".to_string()));
 
-    todo_list.set_task_priority(id1, Priority::High);
-    todo_list.set_task_priority(id2, Priority::High);
-    todo_list.set_task_priority(id3, Priority::Low);
+    todo_list.set_task_priority(id1, Priority::High).unwrap();
+    todo_list.set_task_priority(id2, Priority::High).unwrap();
+    todo_list.set_task_priority(id3, Priority::Low).unwrap();
 
-    todo_list.complete_task(id1);
+    todo_list.complete_task(id1).unwrap();
 
     // Test using builder pattern
     let filter = TaskFilter::all()
@@ -141,8 +142,8 @@ fn test_empty_filter_results() {
     let id1 = todo_list.add_task(TaskWithoutId::new("High 1".to_string()));
     let id2 = todo_list.add_task(TaskWithoutId::new("High 2".to_string()));
 
-    todo_list.set_task_priority(id1, Priority::High);
-    todo_list.set_task_priority(id2, Priority::High);
+    todo_list.set_task_priority(id1, Priority::High).unwrap();
+    todo_list.set_task_priority(id2, Priority::High).unwrap();
 
     // Try to filter for low priority tasks (should be empty)
     let low_filter = TaskFilter::all().with_priority(Priority::Low);
@@ -176,9 +177,9 @@ fn test_filter_all_matching() {
     let id2 = todo_list.add_task(TaskWithoutId::new("Task 2".to_string()));
     let id3 = todo_list.add_task(TaskWithoutId::new("Task 3".to_string()));
 
-    todo_list.set_task_priority(id1, Priority::High);
-    todo_list.set_task_priority(id2, Priority::High);
-    todo_list.set_task_priority(id3, Priority::High);
+    todo_list.set_task_priority(id1, Priority::High).unwrap();
+    todo_list.set_task_priority(id2, Priority::High).unwrap();
+    todo_list.set_task_priority(id3, Priority::High).unwrap();
 
     // Filter for pending high priority tasks (should match all)
     let filter = TaskFilter::all()
@@ -204,14 +205,14 @@ fn test_priority_filter_mixed_states() {
     let id3 = todo_list.add_task(TaskWithoutId::new("Medium task 3".to_string()));
     let id4 = todo_list.add_task(TaskWithoutId::new("Medium task 4".to_string()));
 
-    todo_list.set_task_priority(id1, Priority::Medium);
-    todo_list.set_task_priority(id2, Priority::Medium);
-    todo_list.set_task_priority(id3, Priority::Medium);
-    todo_list.set_task_priority(id4, Priority::Medium);
+    todo_list.set_task_priority(id1, Priority::Medium).unwrap();
+    todo_list.set_task_priority(id2, Priority::Medium).unwrap();
+    todo_list.set_task_priority(id3, Priority::Medium).unwrap();
+    todo_list.set_task_priority(id4, Priority::Medium).unwrap();
 
     // Complete some
-    todo_list.complete_task(id1);
-    todo_list.complete_task(id2);
+    todo_list.complete_task(id1).unwrap();
+    todo_list.complete_task(id2).unwrap();
 
     // Filter by priority only (should get all)
     let medium_filter = TaskFilter::all().with_priority(Priority::Medium);
@@ -249,8 +250,8 @@ fn test_filter_dynamic_updates() {
     let id1 = todo_list.add_task(TaskWithoutId::new("Task 1".to_string()));
     let id2 = todo_list.add_task(TaskWithoutId::new("Task 2".to_string()));
 
-    todo_list.set_task_priority(id1, Priority::High);
-    todo_list.set_task_priority(id2, Priority::High);
+    todo_list.set_task_priority(id1, Priority::High).unwrap();
+    todo_list.set_task_priority(id2, Priority::High).unwrap();
 
     // Initially both are pending high priority
     let filter = TaskFilter::all()
@@ -260,7 +261,7 @@ fn test_filter_dynamic_updates() {
     assert_eq!(tasks.len(), 2);
 
     // Complete one task
-    todo_list.complete_task(id1);
+    todo_list.complete_task(id1).unwrap();
 
     // Now only one should be pending high priority
     let tasks = todo_list.get_filtered_tasks(&filter);
@@ -275,3 +276,79 @@ fn test_filter_dynamic_updates() {
     assert_eq!(completed_tasks.len(), 1);
     assert_eq!(completed_tasks[0].id, id1);
 }
+
+/// Test include and exclude tag filtering
+#[test]
+fn test_tag_filtering() {
+    let mut todo_list = TodoList::new();
+
+    let id1 = todo_list.add_task(TaskWithoutId::new("Work urgent".to_string()));
+    let id2 = todo_list.add_task(TaskWithoutId::new("Work later".to_string()));
+    let id3 = todo_list.add_task(TaskWithoutId::new("Home".to_string()));
+
+    todo_list
+        .add_task_tags(id1, &["work".to_string(), "urgent".to_string()])
+        .unwrap();
+    todo_list.add_task_tags(id2, &["work".to_string()]).unwrap();
+    todo_list.add_task_tags(id3, &["home".to_string()]).unwrap();
+
+    let filter = TaskFilter::all().with_tag("work".to_string());
+    let ids: Vec<usize> = todo_list
+        .get_filtered_tasks(&filter)
+        .iter()
+        .map(|t| t.id)
+        .collect();
+    assert_eq!(ids, vec![id1, id2]);
+
+    let filter = TaskFilter::all()
+        .with_tag("work".to_string())
+        .without_tag("urgent".to_string());
+    let ids: Vec<usize> = todo_list
+        .get_filtered_tasks(&filter)
+        .iter()
+        .map(|t| t.id)
+        .collect();
+    assert_eq!(ids, vec![id2]);
+
+    // The category filter matches any of a task's tags
+    let filter = TaskFilter::all().with_category("urgent".to_string());
+    let ids: Vec<usize> = todo_list
+        .get_filtered_tasks(&filter)
+        .iter()
+        .map(|t| t.id)
+        .collect();
+    assert_eq!(ids, vec![id1]);
+}
+
+/// Test that a filter expression is combined with the simple filters
+#[test]
+fn test_expression_filtering() {
+    let mut todo_list = TodoList::new();
+
+    let id1 = todo_list.add_task(TaskWithoutId::new("Write report".to_string()));
+    let id2 = todo_list.add_task(TaskWithoutId::new("Plan trip".to_string()));
+    let id3 = todo_list.add_task(TaskWithoutId::new("Buy milk".to_string()));
+
+    todo_list.set_task_priority(id1, Priority::High).unwrap();
+    todo_list.add_task_dependency(id2, id1).unwrap();
+    todo_list
+        .add_subtask(id3, "Find store".to_string())
+        .unwrap();
+    todo_list.complete_task(id3).unwrap();
+
+    let expression = FilterExpression::parse("is:blocked or has:subtasks").unwrap();
+    let filter = TaskFilter::all().with_expression(expression);
+    let ids: Vec<usize> = todo_list
+        .get_filtered_tasks(&filter)
+        .iter()
+        .map(|task| task.id)
+        .collect();
+    assert_eq!(ids, vec![id2, id3]);
+
+    // The status filter still applies on top of the expression
+    let expression = FilterExpression::parse("is:blocked or has:subtasks").unwrap();
+    let filter = TaskFilter::all()
+        .with_status(TaskStatus::Pending)
+        .with_expression(expression);
+    assert_eq!(todo_list.get_filtered_tasks(&filter).len(), 1);
+}
@@ -26,11 +26,11 @@ fn test_complete_project_workflow() {
     assert_eq!(project_manager.get_current_todo_list().get_tasks().len(), 2);
 
     // Create Work project
-    assert!(project_manager.create_project("Work".to_string()).is_some());
+    assert!(project_manager.create_project("Work".to_string()).is_ok());
     assert_eq!(project_manager.project_count(), 2);
 
     // Switch to Work project
-    assert!(project_manager.switch_project("Work".to_string()).is_some());
+    assert!(project_manager.switch_project("Work".to_string()).is_ok());
     assert_eq!(project_manager.get_current_project_name(), "Work");
 
     // Work project should be empty
@@ -56,13 +56,13 @@ fn test_complete_project_workflow() {
     // Create Personal project
     assert!(project_manager
         .create_project("Personal".to_string())
-        .is_some());
+        .is_ok());
     assert_eq!(project_manager.project_count(), 3);
 
     // Switch to Personal project
     assert!(project_manager
         .switch_project("Personal".to_string())
-        .is_some());
+        .is_ok());
     assert_eq!(project_manager.get_current_project_name(), "Personal");
 
     // Personal project should be empty
@@ -74,7 +74,8 @@ fn test_complete_project_workflow() {
         .add_task(TaskWithoutId::new("Personal task 1".to_string()));
     project_manager
         .get_current_todo_list_mut()
-        .toggle_task(personal_task1);
+        .toggle_task(personal_task1)
+        .unwrap();
 
     assert_eq!(project_manager.get_current_todo_list().get_tasks().len(), 1);
     assert!(project_manager.get_current_todo_list().get_tasks()[0].is_completed());
@@ -82,7 +83,7 @@ fn test_complete_project_workflow() {
     // Switch back to default and verify tasks are still there
     assert!(project_manager
         .switch_project("default".to_string())
-        .is_some());
+        .is_ok());
     assert_eq!(project_manager.get_current_todo_list().get_tasks().len(), 2);
     assert_eq!(
         project_manager.get_current_todo_list().get_tasks()[0].description,
@@ -94,7 +95,7 @@ fn test_complete_project_workflow() {
     );
 
     // Switch back to Work and verify tasks are still there
-    assert!(project_manager.switch_project("Work".to_string()).is_some());
+    assert!(project_manager.switch_project("Work".to_string()).is_ok());
     assert_eq!(project_manager.get_current_todo_list().get_tasks().len(), 3);
     assert_eq!(
         project_manager.get_current_todo_list().get_tasks()[0].description,
@@ -113,7 +114,8 @@ fn test_complete_project_workflow() {
     // Complete a Work task
     project_manager
         .get_current_todo_list_mut()
-        .toggle_task(work_task2);
+        .toggle_task(work_task2)
+        .unwrap();
     assert_eq!(
         project_manager
             .get_current_todo_list()
@@ -133,7 +135,7 @@ fn test_complete_project_workflow() {
     assert_eq!(project_manager.get_current_project_name(), "Work");
     assert!(project_manager
         .delete_project("Personal".to_string())
-        .is_some());
+        .is_ok());
     assert_eq!(project_manager.project_count(), 2);
 
     // Should still be on Work after deletion (we didn't delete current project)
@@ -142,18 +144,16 @@ fn test_complete_project_workflow() {
     // Rename Work project
     assert!(project_manager
         .rename_project("Work".to_string(), "Office".to_string())
-        .is_some());
+        .is_ok());
 
     // Switch to renamed project
-    assert!(project_manager
-        .switch_project("Office".to_string())
-        .is_some());
+    assert!(project_manager.switch_project("Office".to_string()).is_ok());
     assert_eq!(project_manager.get_current_todo_list().get_tasks().len(), 3);
 
     // Verify we can't delete current project
     assert!(project_manager
         .delete_project("Office".to_string())
-        .is_none());
+        .is_err());
 
     // List all projects
     let projects = project_manager.list_projects();
@@ -174,8 +174,8 @@ fn test_project_task_isolation() {
         .add_task(TaskWithoutId::new("Default 2".to_string()));
 
     // Create and switch to Project A
-    pm.create_project("ProjectA".to_string());
-    pm.switch_project("ProjectA".to_string());
+    pm.create_project("ProjectA".to_string()).unwrap();
+    pm.switch_project("ProjectA".to_string()).unwrap();
     pm.get_current_todo_list_mut()
         .add_task(TaskWithoutId::new("A1".to_string()));
     pm.get_current_todo_list_mut()
@@ -184,28 +184,28 @@ fn test_project_task_isolation() {
         .add_task(TaskWithoutId::new("A3".to_string()));
 
     // Create and switch to Project B
-    pm.create_project("ProjectB".to_string());
-    pm.switch_project("ProjectB".to_string());
+    pm.create_project("ProjectB".to_string()).unwrap();
+    pm.switch_project("ProjectB".to_string()).unwrap();
     pm.get_current_todo_list_mut()
         .add_task(TaskWithoutId::new("B1".to_string()));
 
     // Verify isolation
     assert_eq!(pm.get_current_todo_list().get_tasks().len(), 1);
 
-    pm.switch_project("ProjectA".to_string());
+    pm.switch_project("ProjectA".to_string()).unwrap();
     assert_eq!(pm.get_current_todo_list().get_tasks().len(), 3);
 
-    pm.switch_project("default".to_string());
+    pm.switch_project("default".to_string()).unwrap();
     assert_eq!(pm.get_current_todo_list().get_tasks().len(), 2);
 
     // Delete ProjectA
-    pm.delete_project("ProjectA".to_string());
+    pm.delete_project("ProjectA".to_string()).unwrap();
 
     // Verify ProjectB and default are unaffected
-    pm.switch_project("ProjectB".to_string());
+    pm.switch_project("ProjectB".to_string()).unwrap();
     assert_eq!(pm.get_current_todo_list().get_tasks().len(), 1);
 
-    pm.switch_project("default".to_string());
+    pm.switch_project("default".to_string()).unwrap();
     assert_eq!(pm.get_current_todo_list().get_tasks().len(), 2);
 }
 
@@ -216,7 +216,7 @@ fn test_rapid_project_switching() {
 
     // Create multiple projects
     for i in 1..=5 {
-        pm.create_project(format!("Project{}", i));
+        pm.create_project(format!("Project{}", i)).unwrap();
     }
 
     // Add unique tasks to each project
@@ -227,7 +227,7 @@ fn test_rapid_project_switching() {
             format!("Project{}", i)
         };
 
-        pm.switch_project(project_name.clone());
+        pm.switch_project(project_name.clone()).unwrap();
         pm.get_current_todo_list_mut()
             .add_task(TaskWithoutId::new(format!("{} task", project_name)));
     }
@@ -240,7 +240,7 @@ fn test_rapid_project_switching() {
             format!("Project{}", i)
         };
 
-        pm.switch_project(project_name.clone());
+        pm.switch_project(project_name.clone()).unwrap();
         let tasks = pm.get_current_todo_list().get_tasks();
         assert_eq!(tasks.len(), 1);
         assert_eq!(tasks[0].description, format!("{} task", project_name));
@@ -257,13 +257,15 @@ fn test_projects_with_subtasks() {
         .get_current_todo_list_mut()
         .add_task(TaskWithoutId::new("Default parent".to_string()));
     pm.get_current_todo_list_mut()
-        .add_subtask(default_parent, "Default sub1".to_string());
+        .add_subtask(default_parent, "Default sub1".to_string())
+        .unwrap();
     pm.get_current_todo_list_mut()
-        .add_subtask(default_parent, "Default sub2".to_string());
+        .add_subtask(default_parent, "Default sub2".to_string())
+        .unwrap();
 
     // Create Work project with parent and subtasks
-    pm.create_project("Work".to_string());
-    pm.switch_project("Work".to_string());
+    pm.create_project("Work".to_string()).unwrap();
+    pm.switch_project("Work".to_string()).unwrap();
 
     let work_parent = pm
         .get_current_todo_list_mut()
@@ -282,8 +284,12 @@ fn test_projects_with_subtasks() {
         .unwrap();
 
     // Complete some subtasks
-    pm.get_current_todo_list_mut().toggle_task(work_sub1);
-    pm.get_current_todo_list_mut().toggle_task(work_sub3);
+    pm.get_current_todo_list_mut()
+        .toggle_task(work_sub1)
+        .unwrap();
+    pm.get_current_todo_list_mut()
+        .toggle_task(work_sub3)
+        .unwrap();
 
     assert_eq!(pm.get_current_todo_list().get_subtask_count(work_parent), 3);
     assert_eq!(
@@ -293,7 +299,7 @@ fn test_projects_with_subtasks() {
     );
 
     // Switch back to default and verify its subtasks are intact
-    pm.switch_project("default".to_string());
+    pm.switch_project("default".to_string()).unwrap();
     assert_eq!(
         pm.get_current_todo_list().get_subtask_count(default_parent),
         2
@@ -305,7 +311,7 @@ fn test_projects_with_subtasks() {
     );
 
     // Switch to Work and verify again
-    pm.switch_project("Work".to_string());
+    pm.switch_project("Work".to_string()).unwrap();
     assert_eq!(pm.get_current_todo_list().get_subtask_count(work_parent), 3);
     assert_eq!(
         pm.get_current_todo_list()
@@ -320,28 +326,28 @@ fn test_invalid_operations() {
     let mut pm = ProjectManager::new();
 
     // Try to create duplicate project
-    pm.create_project("Test".to_string());
-    assert!(pm.create_project("Test".to_string()).is_none());
+    pm.create_project("Test".to_string()).unwrap();
+    assert!(pm.create_project("Test".to_string()).is_err());
 
     // Try to switch to non-existent project
-    assert!(pm.switch_project("NonExistent".to_string()).is_none());
+    assert!(pm.switch_project("NonExistent".to_string()).is_err());
 
     // Try to delete non-existent project
-    assert!(pm.delete_project("NonExistent".to_string()).is_none());
+    assert!(pm.delete_project("NonExistent".to_string()).is_err());
 
     // Try to delete current project
-    assert!(pm.delete_project("default".to_string()).is_none());
+    assert!(pm.delete_project("default".to_string()).is_err());
 
     // Try to rename to existing name
-    pm.create_project("Another".to_string());
+    pm.create_project("Another".to_string()).unwrap();
     assert!(pm
         .rename_project("Test".to_string(), "Another".to_string())
-        .is_none());
+        .is_err());
 
     // Try to rename non-existent project
     assert!(pm
         .rename_project("DoesNotExist".to_string(), "NewName".to_string())
-        .is_none());
+        .is_err());
 }
 
 /// Test combined operations with projects: create, populate, rename, switch
@@ -350,13 +356,13 @@ fn test_complex_project_operations() {
     let mut pm = ProjectManager::new();
 
     // Create projects A, B, C
-    pm.create_project("A".to_string());
-    pm.create_project("B".to_string());
-    pm.create_project("C".to_string());
+    pm.create_project("A".to_string()).unwrap();
+    pm.create_project("B".to_string()).unwrap();
+    pm.create_project("C".to_string()).unwrap();
 
     // Add tasks to each
     for project in &["default", "A", "B", "C"] {
-        pm.switch_project(project.to_string());
+        pm.switch_project(project.to_string()).unwrap();
         for i in 1..=3 {
             pm.get_current_todo_list_mut()
                 .add_task(TaskWithoutId::new(format!("{}-{}", project, i)));
@@ -364,10 +370,11 @@ fn test_complex_project_operations() {
     }
 
     // Rename B to Beta
-    pm.rename_project("B".to_string(), "Beta".to_string());
+    pm.rename_project("B".to_string(), "Beta".to_string())
+        .unwrap();
 
     // Delete A
-    pm.delete_project("A".to_string());
+    pm.delete_project("A".to_string()).unwrap();
 
     // Verify remaining projects
     let projects = pm.list_projects();
@@ -379,7 +386,7 @@ fn test_complex_project_operations() {
     assert!(!projects.contains(&"B".to_string()));
 
     // Verify Beta has its tasks
-    pm.switch_project("Beta".to_string());
+    pm.switch_project("Beta".to_string()).unwrap();
     let tasks = pm.get_current_todo_list().get_tasks();
     assert_eq!(tasks.len(), 3);
     assert_eq!(tasks[0].description, "B-1");
@@ -396,9 +403,9 @@ fn test_empty_project_name() {
     let result = pm.create_project("".to_string());
 
     // Should succeed (data layer allows it, validation would be at UI layer)
-    assert!(result.is_some());
+    assert!(result.is_ok());
 
     // Can switch to it
-    assert!(pm.switch_project("".to_string()).is_some());
+    assert!(pm.switch_project("".to_string()).is_ok());
     assert_eq!(pm.get_current_project_name(), "");
 }
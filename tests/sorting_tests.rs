@@ -4,7 +4,8 @@ use chrono::NaiveDate;
 use todo_manager::models::priority::Priority;
 use todo_manager::models::task::TaskWithoutId;
 use todo_manager::models::task_filter::TaskFilter;
-use todo_manager::models::task_sort::{SortBy, SortOrder};
+use todo_manager::models::task_sort::{SortBy, SortKey, SortOrder};
+use todo_manager::models::task_status::TaskStatus;
 use todo_manager::models::todo_list::TodoList;
 
 #[test]
@@ -48,9 +49,9 @@ fn test_sort_by_priority_ascending() {
     let id2 = list.add_task(TaskWithoutId::new("High priority task".to_string()));
     let id3 = list.add_task(TaskWithoutId::new("Medium priority task".to_string()));
 
-    list.set_task_priority(id1, Priority::Low);
-    list.set_task_priority(id2, Priority::High);
-    list.set_task_priority(id3, Priority::Medium);
+    list.set_task_priority(id1, Priority::Low).unwrap();
+    list.set_task_priority(id2, Priority::High).unwrap();
+    list.set_task_priority(id3, Priority::Medium).unwrap();
 
     let filter = TaskFilter::all().with_sort_by(SortBy::Priority);
     let tasks = list.get_filtered_tasks(&filter);
@@ -69,9 +70,9 @@ fn test_sort_by_priority_descending() {
     let id2 = list.add_task(TaskWithoutId::new("High priority task".to_string()));
     let id3 = list.add_task(TaskWithoutId::new("Medium priority task".to_string()));
 
-    list.set_task_priority(id1, Priority::Low);
-    list.set_task_priority(id2, Priority::High);
-    list.set_task_priority(id3, Priority::Medium);
+    list.set_task_priority(id1, Priority::Low).unwrap();
+    list.set_task_priority(id2, Priority::High).unwrap();
+    list.set_task_priority(id3, Priority::Medium).unwrap();
 
     let filter = TaskFilter::all()
         .with_sort_by(SortBy::Priority)
@@ -95,8 +96,8 @@ fn test_sort_by_due_date_ascending() {
     let today = NaiveDate::from_ymd_opt(2025, 10, 25).unwrap();
     let tomorrow = NaiveDate::from_ymd_opt(2025, 10, 26).unwrap();
 
-    list.set_due_date(id1, Some(tomorrow));
-    list.set_due_date(id2, Some(today));
+    list.set_due_date(id1, Some(tomorrow)).unwrap();
+    list.set_due_date(id2, Some(today)).unwrap();
 
     let filter = TaskFilter::all().with_sort_by(SortBy::DueDate);
     let tasks = list.get_filtered_tasks(&filter);
@@ -118,8 +119,8 @@ fn test_sort_by_due_date_descending() {
     let today = NaiveDate::from_ymd_opt(2025, 10, 25).unwrap();
     let tomorrow = NaiveDate::from_ymd_opt(2025, 10, 26).unwrap();
 
-    list.set_due_date(id1, Some(tomorrow));
-    list.set_due_date(id2, Some(today));
+    list.set_due_date(id1, Some(tomorrow)).unwrap();
+    list.set_due_date(id2, Some(today)).unwrap();
 
     let filter = TaskFilter::all()
         .with_sort_by(SortBy::DueDate)
@@ -140,8 +141,10 @@ fn test_sort_by_category_ascending() {
     let id2 = list.add_task(TaskWithoutId::new("Personal task".to_string()));
     let id3 = list.add_task(TaskWithoutId::new("Task with no category".to_string()));
 
-    list.set_task_category(id1, Some("work".to_string()));
-    list.set_task_category(id2, Some("personal".to_string()));
+    list.set_task_category(id1, Some("work".to_string()))
+        .unwrap();
+    list.set_task_category(id2, Some("personal".to_string()))
+        .unwrap();
 
     let filter = TaskFilter::all().with_sort_by(SortBy::Category);
     let tasks = list.get_filtered_tasks(&filter);
@@ -160,8 +163,10 @@ fn test_sort_by_category_descending() {
     let id2 = list.add_task(TaskWithoutId::new("Personal task".to_string()));
     let id3 = list.add_task(TaskWithoutId::new("Task with no category".to_string()));
 
-    list.set_task_category(id1, Some("work".to_string()));
-    list.set_task_category(id2, Some("personal".to_string()));
+    list.set_task_category(id1, Some("work".to_string()))
+        .unwrap();
+    list.set_task_category(id2, Some("personal".to_string()))
+        .unwrap();
 
     let filter = TaskFilter::all()
         .with_sort_by(SortBy::Category)
@@ -182,7 +187,7 @@ fn test_sort_by_status_ascending() {
     list.add_task(TaskWithoutId::new("Pending task 1".to_string()));
     list.add_task(TaskWithoutId::new("Pending task 2".to_string()));
 
-    list.complete_task(id1);
+    list.complete_task(id1).unwrap();
 
     let filter = TaskFilter::all().with_sort_by(SortBy::Status);
     let tasks = list.get_filtered_tasks(&filter);
@@ -201,7 +206,7 @@ fn test_sort_by_status_descending() {
     list.add_task(TaskWithoutId::new("Pending task 1".to_string()));
     list.add_task(TaskWithoutId::new("Pending task 2".to_string()));
 
-    list.complete_task(id1);
+    list.complete_task(id1).unwrap();
 
     let filter = TaskFilter::all()
         .with_sort_by(SortBy::Status)
@@ -239,10 +244,10 @@ fn test_combined_filter_and_sort() {
     let id2 = list.add_task(TaskWithoutId::new("High priority pending".to_string()));
     let id3 = list.add_task(TaskWithoutId::new("Medium priority completed".to_string()));
 
-    list.set_task_priority(id1, Priority::Low);
-    list.set_task_priority(id2, Priority::High);
-    list.set_task_priority(id3, Priority::Medium);
-    list.complete_task(id3);
+    list.set_task_priority(id1, Priority::Low).unwrap();
+    list.set_task_priority(id2, Priority::High).unwrap();
+    list.set_task_priority(id3, Priority::Medium).unwrap();
+    list.complete_task(id3).unwrap();
 
     // Filter by pending status and sort by priority
     use todo_manager::models::task_status::TaskStatus;
@@ -256,3 +261,102 @@ fn test_combined_filter_and_sort() {
     assert_eq!(tasks[0].id, id2); // High priority
     assert_eq!(tasks[1].id, id1); // Low priority
 }
+
+#[test]
+fn test_sort_by_multiple_keys() {
+    let mut list = TodoList::new();
+    let id1 = list.add_task(TaskWithoutId::new("Low, due later".to_string()));
+    let id2 = list.add_task(TaskWithoutId::new("High, due later".to_string()));
+    let id3 = list.add_task(TaskWithoutId::new("Low, due soon".to_string()));
+    let id4 = list.add_task(TaskWithoutId::new("Low, due later too".to_string()));
+
+    list.set_task_priority(id1, Priority::Low).unwrap();
+    list.set_task_priority(id2, Priority::High).unwrap();
+    list.set_task_priority(id3, Priority::Low).unwrap();
+    list.set_task_priority(id4, Priority::Low).unwrap();
+    list.set_due_date(id1, Some(NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()))
+        .unwrap();
+    list.set_due_date(id2, Some(NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()))
+        .unwrap();
+    list.set_due_date(id3, Some(NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()))
+        .unwrap();
+    list.set_due_date(id4, Some(NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()))
+        .unwrap();
+
+    let filter = TaskFilter::all()
+        .with_sort_key(SortKey::new(SortBy::DueDate, SortOrder::Ascending))
+        .with_sort_key(SortKey::new(SortBy::Priority, SortOrder::Descending))
+        .with_sort_key(SortKey::new(SortBy::Id, SortOrder::Descending));
+    let ids: Vec<usize> = list
+        .get_filtered_tasks(&filter)
+        .iter()
+        .map(|t| t.id)
+        .collect();
+
+    // Earliest due date first, then low priority first, then highest ID first
+    assert_eq!(ids, vec![id3, id4, id1, id2]);
+}
+
+#[test]
+fn test_status_ties_are_broken_by_id() {
+    let mut list = TodoList::new();
+    for i in 1..=4 {
+        list.add_task(TaskWithoutId::new(format!("Task {}", i)));
+    }
+    list.set_task_status(4, TaskStatus::Completed).unwrap();
+    list.set_task_status(2, TaskStatus::Completed).unwrap();
+    list.set_task_status(3, TaskStatus::InProgress).unwrap();
+
+    let filter = TaskFilter::all().with_sort_by(SortBy::Status);
+    let ids: Vec<usize> = list
+        .get_filtered_tasks(&filter)
+        .iter()
+        .map(|t| t.id)
+        .collect();
+    // Pending before in-progress before completed, equal statuses by ID
+    assert_eq!(ids, vec![1, 3, 2, 4]);
+}
+
+#[test]
+fn test_sort_by_description_progress_and_dependencies() {
+    let mut list = TodoList::new();
+    let id1 = list.add_task(TaskWithoutId::new("write report".to_string()));
+    let id2 = list.add_task(TaskWithoutId::new("Buy milk".to_string()));
+    let id3 = list.add_task(TaskWithoutId::new("answer mail".to_string()));
+
+    let filter = TaskFilter::all().with_sort_by(SortBy::Description);
+    let ids: Vec<usize> = list
+        .get_filtered_tasks(&filter)
+        .iter()
+        .map(|t| t.id)
+        .collect();
+    assert_eq!(ids, vec![id3, id2, id1]);
+
+    // id1: 1 of 2 subtasks done, id2: 0 of 1 done, id3: no subtasks
+    let sub1 = list.add_subtask(id1, "Outline".to_string()).unwrap();
+    list.add_subtask(id1, "Draft".to_string()).unwrap();
+    list.add_subtask(id2, "Find store".to_string()).unwrap();
+    list.complete_task(sub1).unwrap();
+
+    let filter = TaskFilter::all().with_sort_by(SortBy::Progress);
+    let ids: Vec<usize> = list
+        .get_filtered_tasks(&filter)
+        .iter()
+        .filter(|t| !t.is_subtask())
+        .map(|t| t.id)
+        .collect();
+    assert_eq!(ids, vec![id2, id1, id3]);
+
+    list.add_task_dependency(id1, id2).unwrap();
+    list.add_task_dependency(id1, id3).unwrap();
+    list.add_task_dependency(id3, id2).unwrap();
+    let filter =
+        TaskFilter::all().with_sort_key(SortKey::new(SortBy::Dependencies, SortOrder::Descending));
+    let ids: Vec<usize> = list
+        .get_filtered_tasks(&filter)
+        .iter()
+        .filter(|t| !t.is_subtask())
+        .map(|t| t.id)
+        .collect();
+    assert_eq!(ids, vec![id1, id3, id2]);
+}
@@ -3,6 +3,7 @@
 //! Tests for subtask operations including parent-child relationships and cascade deletion
 
 use todo_manager::models::task::TaskWithoutId;
+use todo_manager::models::todo_error::TodoError;
 use todo_manager::models::todo_list::TodoList;
 
 /// Test subtask functionality
@@ -16,13 +17,13 @@ fn test_subtask_workflow() {
 
     // Add subtasks
     let subtask1_id = todo_list.add_subtask(parent_id, "Write code".to_string());
-    assert_eq!(subtask1_id, Some(2));
+    assert_eq!(subtask1_id, Ok(2));
 
     let subtask2_id = todo_list.add_subtask(parent_id, "Write tests".to_string());
-    assert_eq!(subtask2_id, Some(3));
+    assert_eq!(subtask2_id, Ok(3));
 
     let subtask3_id = todo_list.add_subtask(parent_id, "Write documentation".to_string());
-    assert_eq!(subtask3_id, Some(4));
+    assert_eq!(subtask3_id, Ok(4));
 
     // Test subtask count
     assert_eq!(todo_list.get_subtask_count(parent_id), 3);
@@ -31,11 +32,11 @@ fn test_subtask_workflow() {
     assert_eq!(todo_list.get_completed_subtask_count(parent_id), 0);
 
     // Complete one subtask
-    todo_list.toggle_task(subtask1_id.unwrap());
+    todo_list.toggle_task(subtask1_id.unwrap()).unwrap();
     assert_eq!(todo_list.get_completed_subtask_count(parent_id), 1);
 
     // Complete another subtask
-    todo_list.toggle_task(subtask2_id.unwrap());
+    todo_list.toggle_task(subtask2_id.unwrap()).unwrap();
     assert_eq!(todo_list.get_completed_subtask_count(parent_id), 2);
 
     // Get all subtasks
@@ -68,7 +69,7 @@ fn test_add_subtask_invalid_parent() {
 
     // Try to add subtask to non-existent parent
     let result = todo_list.add_subtask(999, "Invalid subtask".to_string());
-    assert_eq!(result, None);
+    assert_eq!(result, Err(TodoError::InvalidParent(999)));
 }
 
 /// Test subtask hierarchy with multiple parents
@@ -81,13 +82,23 @@ fn test_multiple_parent_subtasks() {
     let parent2_id = todo_list.add_task(TaskWithoutId::new("Project B".to_string()));
 
     // Add subtasks to first parent
-    todo_list.add_subtask(parent1_id, "Task A1".to_string());
-    todo_list.add_subtask(parent1_id, "Task A2".to_string());
+    todo_list
+        .add_subtask(parent1_id, "Task A1".to_string())
+        .unwrap();
+    todo_list
+        .add_subtask(parent1_id, "Task A2".to_string())
+        .unwrap();
 
     // Add subtasks to second parent
-    todo_list.add_subtask(parent2_id, "Task B1".to_string());
-    todo_list.add_subtask(parent2_id, "Task B2".to_string());
-    todo_list.add_subtask(parent2_id, "Task B3".to_string());
+    todo_list
+        .add_subtask(parent2_id, "Task B1".to_string())
+        .unwrap();
+    todo_list
+        .add_subtask(parent2_id, "Task B2".to_string())
+        .unwrap();
+    todo_list
+        .add_subtask(parent2_id, "Task B3".to_string())
+        .unwrap();
 
     // Verify counts
     assert_eq!(todo_list.get_subtask_count(parent1_id), 2);
@@ -127,7 +138,7 @@ fn test_remove_parent_removes_subtasks() {
 
     // Remove parent task
     let removed = todo_list.remove_task(parent_id);
-    assert!(removed.is_some());
+    assert!(removed.is_ok());
 
     // Subtasks should be removed as well (cascade deletion)
     assert_eq!(todo_list.get_tasks().len(), 0);
@@ -147,7 +158,7 @@ fn test_complete_parent_independent_of_subtasks() {
         .unwrap();
 
     // Complete parent
-    todo_list.toggle_task(parent_id);
+    todo_list.toggle_task(parent_id).unwrap();
 
     // Verify parent is complete but subtasks are not
     let parent = todo_list
@@ -166,7 +177,7 @@ fn test_complete_parent_independent_of_subtasks() {
         .any(|t| t.id == subtask2_id && t.is_completed()));
 
     // Complete one subtask
-    todo_list.toggle_task(subtask1_id);
+    todo_list.toggle_task(subtask1_id).unwrap();
 
     // Parent should still be complete
     let parent = todo_list
@@ -187,7 +198,7 @@ fn test_many_subtasks_single_parent() {
     // Add 20 subtasks
     for i in 1..=20 {
         let subtask_id = todo_list.add_subtask(parent_id, format!("Subtask {}", i));
-        assert!(subtask_id.is_some());
+        assert!(subtask_id.is_ok());
     }
 
     assert_eq!(todo_list.get_subtask_count(parent_id), 20);
@@ -202,14 +213,14 @@ fn test_many_subtasks_single_parent() {
 
     for (i, &subtask_id) in subtask_ids.iter().enumerate() {
         if (i + 1) % 3 == 0 {
-            todo_list.toggle_task(subtask_id);
+            todo_list.toggle_task(subtask_id).unwrap();
         }
     }
 
     assert_eq!(todo_list.get_completed_subtask_count(parent_id), 6);
 
     // Remove parent - should remove all subtasks too (cascade deletion)
-    todo_list.remove_task(parent_id);
+    todo_list.remove_task(parent_id).unwrap();
     assert_eq!(todo_list.get_tasks().len(), 0);
 }
 
@@ -236,14 +247,14 @@ fn test_subtask_removal_edge_cases() {
     assert_eq!(todo_list.get_tasks().len(), 5);
 
     // Remove a subtask from parent1
-    todo_list.remove_task(sub1_1);
+    todo_list.remove_task(sub1_1).unwrap();
 
     assert_eq!(todo_list.get_tasks().len(), 4);
     assert_eq!(todo_list.get_subtask_count(parent1), 1);
     assert_eq!(todo_list.get_subtask_count(parent2), 1);
 
     // Remove parent2 - should remove all its subtasks too (cascade deletion)
-    todo_list.remove_task(parent2);
+    todo_list.remove_task(parent2).unwrap();
 
     assert_eq!(todo_list.get_tasks().len(), 2); // parent1 and sub1_2
 
@@ -1,7 +1,10 @@
-use chrono::NaiveDate;
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
 use todo_manager::controller::task_command::{TaskCommand, TaskCommandInputParser, TaskSelection};
+use todo_manager::models::parse_error::ParseError;
 use todo_manager::models::priority::Priority;
 use todo_manager::models::recurrence::Recurrence;
+use todo_manager::models::task_sort::{SortBy, SortKey, SortOrder};
+use todo_manager::models::task_status::TaskStatus;
 
 #[test]
 fn test_parse_priority_command_single_id() {
@@ -111,6 +114,38 @@ fn test_parse_set_due_command_none() {
     }
 }
 
+#[test]
+fn test_parse_set_due_command_relative_date() {
+    let parser = TaskCommandInputParser::new();
+    let today = Local::now().date_naive();
+
+    let cmd = parser.try_parse("due", &["1", "+3d"]).unwrap().unwrap();
+    assert_eq!(
+        cmd,
+        TaskCommand::SetDueDate(1, Some(today + Duration::days(3)))
+    );
+
+    // Multi-word dates are joined
+    let cmd = parser
+        .try_parse("due", &["1", "next", "monday"])
+        .unwrap()
+        .unwrap();
+    match cmd {
+        TaskCommand::SetDueDate(1, Some(date)) => {
+            assert_eq!(date.weekday(), Weekday::Mon);
+            assert!(date > today + Duration::days(6));
+        }
+        _ => panic!("Expected SetDueDate command with date"),
+    }
+}
+
+#[test]
+fn test_parse_set_due_command_ambiguous_date() {
+    let parser = TaskCommandInputParser::new();
+    let result = parser.try_parse("set-due", &["1", "03/04/2025"]).unwrap();
+    assert!(matches!(result, Err(ParseError::InvalidDate(_))));
+}
+
 #[test]
 fn test_parse_set_due_command_invalid_date_format() {
     let parser = TaskCommandInputParser::new();
@@ -230,6 +265,41 @@ fn test_parse_set_category_command_aliases() {
     assert!(result.unwrap().is_ok());
 }
 
+#[test]
+fn test_parse_tag_command() {
+    let parser = TaskCommandInputParser::new();
+    let result = parser.try_parse("tag", &["1-3", "work", "+urgent"]);
+    match result.unwrap().unwrap() {
+        TaskCommand::Tag(TaskSelection::Multiple(ids), tags) => {
+            assert_eq!(ids, vec![1, 2, 3]);
+            assert_eq!(tags, vec!["work".to_string(), "urgent".to_string()]);
+        }
+        _ => panic!("Expected Tag command"),
+    }
+}
+
+#[test]
+fn test_parse_untag_command() {
+    let parser = TaskCommandInputParser::new();
+    let result = parser.try_parse("untag", &["all", "work"]);
+    match result.unwrap().unwrap() {
+        TaskCommand::Untag(TaskSelection::All, tags) => {
+            assert_eq!(tags, vec!["work".to_string()]);
+        }
+        _ => panic!("Expected Untag command"),
+    }
+}
+
+#[test]
+fn test_parse_tag_command_missing_args() {
+    let parser = TaskCommandInputParser::new();
+    let result = parser.try_parse("tag", &["1"]);
+    assert!(result.unwrap().is_err());
+
+    let result = parser.try_parse("untag", &["1", "+"]);
+    assert!(result.unwrap().is_err());
+}
+
 #[test]
 fn test_parse_set_recurring_command_daily() {
     let parser = TaskCommandInputParser::new();
@@ -603,6 +673,84 @@ fn test_parse_list_command_with_invalid_filter() {
     assert!(result.unwrap().is_err());
 }
 
+#[test]
+fn test_parse_add_command_with_inline_attributes() {
+    let parser = TaskCommandInputParser::new();
+    let result = parser
+        .try_parse(
+            "add",
+            &[
+                "Pay",
+                "invoice",
+                "!high",
+                "@finance",
+                "due:2025-03-01",
+                "after:7",
+            ],
+        )
+        .unwrap();
+    match result {
+        Ok(TaskCommand::Add(task)) => {
+            assert_eq!(task.description, "Pay invoice");
+            assert_eq!(task.priority, Priority::High);
+            assert_eq!(task.tags, vec!["finance".to_string()]);
+            assert_eq!(task.due_date, NaiveDate::from_ymd_opt(2025, 3, 1));
+            assert_eq!(task.depends_on, vec![7]);
+        }
+        _ => panic!("Expected Add command"),
+    }
+
+    let result = parser.try_parse("add", &["Task", "!urgent"]).unwrap();
+    assert!(matches!(result, Err(ParseError::InvalidValue { .. })));
+}
+
+#[test]
+fn test_parse_where_selection() {
+    let parser = TaskCommandInputParser::new();
+
+    let cmd = parser
+        .try_parse("complete", &["where", "overdue", "category:work"])
+        .unwrap()
+        .unwrap();
+    match cmd {
+        TaskCommand::Complete(TaskSelection::Filter(filter)) => {
+            assert_eq!(filter.category, Some("work".to_string()));
+        }
+        _ => panic!("Expected Complete command with a filter selection"),
+    }
+
+    // The last word is the value for commands that take one
+    let cmd = parser
+        .try_parse("priority", &["where", "todo", "low", "high"])
+        .unwrap()
+        .unwrap();
+    match cmd {
+        TaskCommand::SetPriority(TaskSelection::Filter(filter), priority) => {
+            assert_eq!(priority, Priority::High);
+            assert_eq!(filter.status, Some(TaskStatus::Pending));
+            assert_eq!(filter.priority, Some(Priority::Low));
+        }
+        _ => panic!("Expected SetPriority command with a filter selection"),
+    }
+}
+
+#[test]
+fn test_parse_where_selection_errors() {
+    let parser = TaskCommandInputParser::new();
+    assert!(matches!(
+        parser.try_parse("remove", &["where"]).unwrap(),
+        Err(ParseError::MissingArguments { .. })
+    ));
+    assert!(matches!(
+        parser.try_parse("priority", &["where", "high"]).unwrap(),
+        Err(ParseError::MissingArguments { .. })
+    ));
+    assert!(parser
+        .try_parse("complete", &["where", "bogus"])
+        .unwrap()
+        .is_err());
+}
+
 #[test]
 fn test_default_parser() {
     let parser: TaskCommandInputParser = Default::default();
@@ -610,3 +758,251 @@ fn test_default_parser() {
     assert!(result.is_some());
     assert!(result.unwrap().is_ok());
 }
+
+#[test]
+fn test_parse_status_command_single_id() {
+    let parser = TaskCommandInputParser::new();
+    let result = parser.try_parse("status", &["2", "in-progress"]);
+    assert!(result.is_some());
+    let cmd = result.unwrap().unwrap();
+    assert_eq!(
+        cmd,
+        TaskCommand::SetStatus(TaskSelection::Single(2), TaskStatus::InProgress)
+    );
+}
+
+#[test]
+fn test_parse_status_command_range_and_all() {
+    let parser = TaskCommandInputParser::new();
+    let cmd = parser
+        .try_parse("status", &["1-3", "blocked"])
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        cmd,
+        TaskCommand::SetStatus(TaskSelection::Multiple(vec![1, 2, 3]), TaskStatus::Blocked)
+    );
+
+    let cmd = parser
+        .try_parse("status", &["all", "Cancelled"])
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        cmd,
+        TaskCommand::SetStatus(TaskSelection::All, TaskStatus::Cancelled)
+    );
+}
+
+#[test]
+fn test_parse_status_command_invalid_state() {
+    let parser = TaskCommandInputParser::new();
+    let result = parser.try_parse("status", &["1", "someday"]);
+    assert!(result.is_some());
+    assert!(result.unwrap().is_err());
+}
+
+#[test]
+fn test_parse_status_command_missing_args() {
+    let parser = TaskCommandInputParser::new();
+    let result = parser.try_parse("status", &["1"]);
+    assert!(result.is_some());
+    assert!(result.unwrap().is_err());
+}
+
+#[test]
+fn test_parse_trash_commands() {
+    let parser = TaskCommandInputParser::new();
+    assert_eq!(
+        parser.try_parse("trash", &[]).unwrap().unwrap(),
+        TaskCommand::ShowTrash
+    );
+    assert_eq!(
+        parser.try_parse("restore", &["4"]).unwrap().unwrap(),
+        TaskCommand::Restore(4)
+    );
+    assert_eq!(
+        parser.try_parse("empty-trash", &[]).unwrap().unwrap(),
+        TaskCommand::EmptyTrash
+    );
+    assert_eq!(
+        parser
+            .try_parse("trash-retention", &["7"])
+            .unwrap()
+            .unwrap(),
+        TaskCommand::SetTrashRetention(7)
+    );
+}
+
+#[test]
+fn test_parse_restore_command_invalid() {
+    let parser = TaskCommandInputParser::new();
+    assert!(parser.try_parse("restore", &[]).unwrap().is_err());
+    assert!(parser.try_parse("restore", &["abc"]).unwrap().is_err());
+    assert!(parser
+        .try_parse("trash-retention", &["-1"])
+        .unwrap()
+        .is_err());
+}
+
+#[test]
+fn test_parse_list_command_keeps_simple_filters() {
+    let parser = TaskCommandInputParser::new();
+    let cmd = parser
+        .try_parse("list", &["pending", "high"])
+        .unwrap()
+        .unwrap();
+    match cmd {
+        TaskCommand::List(Some(filter)) => {
+            assert_eq!(filter.status, Some(TaskStatus::Pending));
+            assert_eq!(filter.priority, Some(Priority::High));
+            assert!(filter.expression.is_none());
+        }
+        _ => panic!("Expected List command with filter"),
+    }
+}
+
+#[test]
+fn test_parse_list_command_with_expression() {
+    let parser = TaskCommandInputParser::new();
+    let cmd = parser
+        .try_parse(
+            "list",
+            &[
+                "(high",
+                "or",
+                "due<friday)",
+                "and",
+                "not",
+                "is:blocked",
+                "sort:due",
+                "-r",
+            ],
+        )
+        .unwrap()
+        .unwrap();
+    match cmd {
+        TaskCommand::List(Some(filter)) => {
+            assert!(filter.expression.is_some());
+            assert_eq!(
+                filter.sort_keys,
+                vec![SortKey::new(SortBy::DueDate, SortOrder::Ascending)]
+            );
+            assert_eq!(filter.sort_order, Some(SortOrder::Descending));
+        }
+        _ => panic!("Expected List command with expression"),
+    }
+}
+
+#[test]
+fn test_parse_list_command_expression_error_has_column() {
+    let parser = TaskCommandInputParser::new();
+    let err = parser
+        .try_parse("list", &["high", "or", "(medium"])
+        .unwrap()
+        .unwrap_err();
+    match err {
+        ParseError::InvalidQuery { column, .. } => assert_eq!(column, 16),
+        other => panic!("Expected InvalidQuery, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_view_commands() {
+    let parser = TaskCommandInputParser::new();
+    assert_eq!(
+        parser
+            .try_parse("view", &["save", "work", "pending", "+work", "sort:due"])
+            .unwrap()
+            .unwrap(),
+        TaskCommand::SaveView("work".to_string(), "pending +work sort:due".to_string())
+    );
+    assert_eq!(
+        parser.try_parse("view", &["work"]).unwrap().unwrap(),
+        TaskCommand::ShowView("work".to_string())
+    );
+    assert_eq!(
+        parser.try_parse("view", &["list"]).unwrap().unwrap(),
+        TaskCommand::ListViews
+    );
+    assert_eq!(
+        parser.try_parse("views", &[]).unwrap().unwrap(),
+        TaskCommand::ListViews
+    );
+    assert_eq!(
+        parser
+            .try_parse("view", &["delete", "work"])
+            .unwrap()
+            .unwrap(),
+        TaskCommand::DeleteView("work".to_string())
+    );
+}
+
+#[test]
+fn test_parse_view_command_errors() {
+    let parser = TaskCommandInputParser::new();
+    assert!(parser.try_parse("view", &[]).unwrap().is_err());
+    assert!(parser
+        .try_parse("view", &["save", "work"])
+        .unwrap()
+        .is_err());
+    assert!(parser.try_parse("view", &["delete"]).unwrap().is_err());
+
+    // Reserved names and invalid filters are rejected before saving
+    assert!(parser
+        .try_parse("view", &["save", "list", "pending"])
+        .unwrap()
+        .is_err());
+    assert!(matches!(
+        parser
+            .try_parse("view", &["save", "work", "high", "or"])
+            .unwrap(),
+        Err(ParseError::InvalidQuery { .. })
+    ));
+}
+
+#[test]
+fn test_parse_list_command_with_multiple_sort_keys() {
+    let parser = TaskCommandInputParser::new();
+    let cmd = parser
+        .try_parse("list", &["pending", "sort:due,-priority,id"])
+        .unwrap()
+        .unwrap();
+    match cmd {
+        TaskCommand::List(Some(filter)) => assert_eq!(
+            filter.sort_keys,
+            vec![
+                SortKey::new(SortBy::DueDate, SortOrder::Ascending),
+                SortKey::new(SortBy::Priority, SortOrder::Descending),
+                SortKey::new(SortBy::Id, SortOrder::Ascending),
+            ]
+        ),
+        _ => panic!("Expected List command with filter"),
+    }
+
+    assert!(parser
+        .try_parse("list", &["sort:due", "sort:priority"])
+        .unwrap()
+        .is_err());
+    assert!(parser
+        .try_parse("list", &["sort:due,due"])
+        .unwrap()
+        .is_err());
+}
+
+#[test]
+fn test_parse_agenda_command() {
+    let parser = TaskCommandInputParser::new();
+    assert_eq!(
+        parser.try_parse("agenda", &[]).unwrap(),
+        Ok(TaskCommand::Agenda(7))
+    );
+    assert_eq!(
+        parser.try_parse("agenda", &["14"]).unwrap(),
+        Ok(TaskCommand::Agenda(14))
+    );
+    assert!(matches!(
+        parser.try_parse("agenda", &["0"]).unwrap(),
+        Err(ParseError::OutOfRange { .. })
+    ));
+    assert!(parser.try_parse("agenda", &["soon"]).unwrap().is_err());
+}
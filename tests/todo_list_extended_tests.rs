@@ -1,5 +1,6 @@
 use chrono::NaiveDate;
 use todo_manager::models::task::TaskWithoutId;
+use todo_manager::models::todo_error::TodoError;
 use todo_manager::models::todo_list::TodoList;
 
 #[test]
@@ -8,7 +9,7 @@ fn test_todo_list_add_subtask_success() {
     let parent_id = list.add_task(TaskWithoutId::new("Parent task".to_string()));
 
     let subtask_id = list.add_subtask(parent_id, "Subtask".to_string());
-    assert!(subtask_id.is_some());
+    assert!(subtask_id.is_ok());
 
     let subtasks = list.get_subtasks(parent_id);
     assert_eq!(subtasks.len(), 1);
@@ -19,7 +20,7 @@ fn test_todo_list_add_subtask_parent_not_found() {
     let mut list = TodoList::new();
 
     let subtask_id = list.add_subtask(999, "Subtask".to_string());
-    assert!(subtask_id.is_none());
+    assert!(subtask_id.is_err());
 }
 
 #[test]
@@ -29,7 +30,7 @@ fn test_todo_list_set_due_date() {
 
     let due_date = NaiveDate::from_ymd_opt(2025, 12, 31).unwrap();
     let result = list.set_due_date(task_id, Some(due_date));
-    assert!(result.is_some());
+    assert!(result.is_ok());
 
     let task = list.get_tasks().iter().find(|t| t.id == task_id).unwrap();
     assert_eq!(task.get_due_date(), Some(due_date));
@@ -42,9 +43,9 @@ fn test_todo_list_clear_due_date() {
 
     // Set then clear
     let due_date = NaiveDate::from_ymd_opt(2025, 12, 31).unwrap();
-    list.set_due_date(task_id, Some(due_date));
+    list.set_due_date(task_id, Some(due_date)).unwrap();
     let result = list.set_due_date(task_id, None);
-    assert!(result.is_some());
+    assert!(result.is_ok());
 
     let task = list.get_tasks().iter().find(|t| t.id == task_id).unwrap();
     assert_eq!(task.get_due_date(), None);
@@ -57,7 +58,7 @@ fn test_todo_list_add_dependency_success() {
     let task2_id = list.add_task(TaskWithoutId::new("Task 2".to_string()));
 
     let result = list.add_task_dependency(task2_id, task1_id);
-    assert!(result.is_some());
+    assert!(result.is_ok());
 
     let task2 = list.get_tasks().iter().find(|t| t.id == task2_id).unwrap();
     assert!(task2.get_dependencies().contains(&task1_id));
@@ -69,7 +70,7 @@ fn test_todo_list_add_dependency_self() {
     let task_id = list.add_task(TaskWithoutId::new("Task".to_string()));
 
     let result = list.add_task_dependency(task_id, task_id);
-    assert!(result.is_none());
+    assert_eq!(result, Err(TodoError::SelfDependency(task_id)));
 }
 
 #[test]
@@ -78,7 +79,7 @@ fn test_todo_list_add_dependency_task_not_found() {
     let task_id = list.add_task(TaskWithoutId::new("Task".to_string()));
 
     let result = list.add_task_dependency(task_id, 999);
-    assert!(result.is_none());
+    assert_eq!(result, Err(TodoError::TaskNotFound(999)));
 }
 
 #[test]
@@ -88,11 +89,17 @@ fn test_todo_list_add_dependency_circular() {
     let task2_id = list.add_task(TaskWithoutId::new("Task 2".to_string()));
 
     // task2 depends on task1
-    list.add_task_dependency(task2_id, task1_id);
+    list.add_task_dependency(task2_id, task1_id).unwrap();
 
     // Try to make task1 depend on task2 (would create circular dependency)
     let result = list.add_task_dependency(task1_id, task2_id);
-    assert!(result.is_none());
+    assert_eq!(
+        result,
+        Err(TodoError::CircularDependency {
+            task_id: task1_id,
+            depends_on_id: task2_id,
+        })
+    );
 }
 
 #[test]
@@ -101,10 +108,10 @@ fn test_todo_list_remove_dependency_success() {
     let task1_id = list.add_task(TaskWithoutId::new("Task 1".to_string()));
     let task2_id = list.add_task(TaskWithoutId::new("Task 2".to_string()));
 
-    list.add_task_dependency(task2_id, task1_id);
+    list.add_task_dependency(task2_id, task1_id).unwrap();
 
     let result = list.remove_task_dependency(task2_id, task1_id);
-    assert!(result.is_some());
+    assert!(result.is_ok());
 
     let task2 = list.get_tasks().iter().find(|t| t.id == task2_id).unwrap();
     assert!(!task2.get_dependencies().contains(&task1_id));
@@ -118,8 +125,8 @@ fn test_todo_list_get_dependent_tasks() {
     let task3_id = list.add_task(TaskWithoutId::new("Task 3".to_string()));
 
     // task2 and task3 both depend on task1
-    list.add_task_dependency(task2_id, task1_id);
-    list.add_task_dependency(task3_id, task1_id);
+    list.add_task_dependency(task2_id, task1_id).unwrap();
+    list.add_task_dependency(task3_id, task1_id).unwrap();
 
     let dependents = list.get_dependent_tasks(task1_id);
     assert_eq!(dependents.len(), 2);
@@ -132,15 +139,15 @@ fn test_todo_list_get_all_categories() {
     let mut list = TodoList::new();
 
     let mut task1 = TaskWithoutId::new("Task 1".to_string());
-    task1.category = Some("work".to_string());
+    task1.tags = vec!["work".to_string()];
     let _task1_id = list.add_task(task1);
 
     let mut task2 = TaskWithoutId::new("Task 2".to_string());
-    task2.category = Some("personal".to_string());
+    task2.tags = vec!["personal".to_string()];
     let _task2_id = list.add_task(task2);
 
     let mut task3 = TaskWithoutId::new("Task 3".to_string());
-    task3.category = Some("work".to_string());
+    task3.tags = vec!["work".to_string()];
     let _task3_id = list.add_task(task3);
 
     let categories = list.get_all_categories();
@@ -149,6 +156,27 @@ fn test_todo_list_get_all_categories() {
     assert!(categories.contains(&"personal".to_string()));
 }
 
+#[test]
+fn test_todo_list_get_all_categories_includes_every_tag() {
+    let mut list = TodoList::new();
+
+    let mut task1 = TaskWithoutId::new("Task 1".to_string());
+    task1.tags = vec!["work".to_string(), "urgent".to_string()];
+    list.add_task(task1);
+
+    let mut task2 = TaskWithoutId::new("Task 2".to_string());
+    task2.tags = vec!["work".to_string()];
+    list.add_task(task2);
+
+    assert_eq!(list.get_all_categories(), vec!["urgent", "work"]);
+
+    let stats = list.get_statistics();
+    assert_eq!(
+        stats.tag_counts,
+        vec![("urgent".to_string(), 1), ("work".to_string(), 2)]
+    );
+}
+
 #[test]
 fn test_todo_list_get_statistics() {
     let mut list = TodoList::new();
@@ -157,7 +185,7 @@ fn test_todo_list_get_statistics() {
     list.add_task(TaskWithoutId::new("Task 2".to_string()));
     list.add_task(TaskWithoutId::new("Task 3".to_string()));
 
-    list.complete_task(task1_id);
+    list.complete_task(task1_id).unwrap();
 
     let stats = list.get_statistics();
     assert_eq!(stats.total, 3);
@@ -171,7 +199,7 @@ fn test_todo_list_edit_task_description() {
     let task_id = list.add_task(TaskWithoutId::new("Old description".to_string()));
 
     let result = list.edit_task(task_id, "New description".to_string());
-    assert!(result.is_some());
+    assert!(result.is_ok());
 
     let task = list.get_tasks().iter().find(|t| t.id == task_id).unwrap();
     assert_eq!(task.description, "New description");
@@ -182,7 +210,7 @@ fn test_todo_list_edit_task_not_found() {
     let mut list = TodoList::new();
 
     let result = list.edit_task(999, "New description".to_string());
-    assert!(result.is_none());
+    assert!(result.is_err());
 }
 
 #[test]
@@ -190,9 +218,12 @@ fn test_todo_list_get_subtask_count() {
     let mut list = TodoList::new();
     let parent_id = list.add_task(TaskWithoutId::new("Parent".to_string()));
 
-    list.add_subtask(parent_id, "Subtask 1".to_string());
-    list.add_subtask(parent_id, "Subtask 2".to_string());
-    list.add_subtask(parent_id, "Subtask 3".to_string());
+    list.add_subtask(parent_id, "Subtask 1".to_string())
+        .unwrap();
+    list.add_subtask(parent_id, "Subtask 2".to_string())
+        .unwrap();
+    list.add_subtask(parent_id, "Subtask 3".to_string())
+        .unwrap();
 
     let count = list.get_subtask_count(parent_id);
     assert_eq!(count, 3);
@@ -203,12 +234,14 @@ fn test_todo_list_remove_parent_removes_subtasks() {
     let mut list = TodoList::new();
     let parent_id = list.add_task(TaskWithoutId::new("Parent".to_string()));
 
-    list.add_subtask(parent_id, "Subtask 1".to_string());
-    list.add_subtask(parent_id, "Subtask 2".to_string());
+    list.add_subtask(parent_id, "Subtask 1".to_string())
+        .unwrap();
+    list.add_subtask(parent_id, "Subtask 2".to_string())
+        .unwrap();
 
     assert_eq!(list.get_tasks().len(), 3);
 
-    list.remove_task(parent_id);
+    list.remove_task(parent_id).unwrap();
 
     // Parent and subtasks should be removed
     assert_eq!(list.get_tasks().len(), 0);
@@ -220,7 +253,8 @@ fn test_todo_list_task_with_multiple_subtasks() {
     let parent_id = list.add_task(TaskWithoutId::new("Main project".to_string()));
 
     for i in 1..=5 {
-        list.add_subtask(parent_id, format!("Subtask {}", i));
+        list.add_subtask(parent_id, format!("Subtask {}", i))
+            .unwrap();
     }
 
     let subtasks = list.get_subtasks(parent_id);
@@ -233,7 +267,7 @@ fn test_todo_list_complete_task_with_subtasks() {
     let parent_id = list.add_task(TaskWithoutId::new("Parent".to_string()));
     let subtask_id = list.add_subtask(parent_id, "Subtask".to_string()).unwrap();
 
-    list.complete_task(parent_id);
+    list.complete_task(parent_id).unwrap();
 
     let parent = list.get_tasks().iter().find(|t| t.id == parent_id).unwrap();
     assert!(parent.is_completed());
@@ -255,12 +289,12 @@ fn test_todo_list_nested_dependencies() {
     let task3_id = list.add_task(TaskWithoutId::new("Task 3".to_string()));
 
     // Chain: task3 -> task2 -> task1
-    list.add_task_dependency(task2_id, task1_id);
-    list.add_task_dependency(task3_id, task2_id);
+    list.add_task_dependency(task2_id, task1_id).unwrap();
+    list.add_task_dependency(task3_id, task2_id).unwrap();
 
     // Should not allow task1 -> task3 (circular)
     let result = list.add_task_dependency(task1_id, task3_id);
-    assert!(result.is_none());
+    assert!(result.is_err());
 }
 
 #[test]
@@ -269,10 +303,10 @@ fn test_todo_list_remove_task_cleans_up_references() {
     let task1_id = list.add_task(TaskWithoutId::new("Task 1".to_string()));
     let task2_id = list.add_task(TaskWithoutId::new("Task 2".to_string()));
 
-    list.add_task_dependency(task2_id, task1_id);
+    list.add_task_dependency(task2_id, task1_id).unwrap();
 
     // Remove task1
-    list.remove_task(task1_id);
+    list.remove_task(task1_id).unwrap();
 
     // Verify task1 is removed
     assert!(list.get_tasks().iter().all(|t| t.id != task1_id));
@@ -289,10 +323,10 @@ fn test_todo_list_overdue_tasks() {
     let future_date = NaiveDate::from_ymd_opt(2099, 12, 31).unwrap();
 
     let task1_id = list.add_task(TaskWithoutId::new("Overdue task".to_string()));
-    list.set_due_date(task1_id, Some(past_date));
+    list.set_due_date(task1_id, Some(past_date)).unwrap();
 
     let task2_id = list.add_task(TaskWithoutId::new("Future task".to_string()));
-    list.set_due_date(task2_id, Some(future_date));
+    list.set_due_date(task2_id, Some(future_date)).unwrap();
 
     // Test overdue detection
     let today = chrono::Local::now().date_naive();